@@ -22,6 +22,20 @@ const TILE_EXT: &str = "jpeg";
 const MIN_ZOOM: u32 = 0;
 const MAX_ZOOM: u32 = 8;
 
+/// DEM/elevation layer + WMTS config. Fetched alongside the basemap, but gated behind
+/// `GS_BUILD_ELEVATION` — see `main` — since most builds don't need it and it doubles the
+/// download volume.
+const ELEV_GIBS_LAYER: &str = "ASTER_GDEM_Greyscale_Shaded_Relief";
+const ELEV_GIBS_TILE_MATRIX_SET: &str = "GoogleMapsCompatible_Level8";
+
+/// File extension for raw elevation-post tiles (`terrain::ground_elevation_m` reads this
+/// format: `POSTS_PER_TILE x POSTS_PER_TILE` little-endian `i16` meters, row-major).
+const ELEV_TILE_EXT: &str = "elev";
+
+/// Single zoom level for the elevation layer — `terrain.rs` only ever samples at this zoom, so
+/// there's no reason to fetch (or store) the others.
+const ELEV_ZOOM: u32 = 8;
+
 /// Approximate North America bounds in lon/lat (WGS84)
 /// lon_min, lat_min, lon_max, lat_max
 const NA_BOUNDS: (f64, f64, f64, f64) = (-170.0, 5.0, -50.0, 83.0);
@@ -84,6 +98,17 @@ fn main() {
             eprintln!("build.rs: WARNING: failed to fetch tiles for z={z}: {e}");
         }
     }
+
+    // Elevation layer is optional and skipped unless explicitly requested — it doubles the
+    // download volume and most builds only need the basemap.
+    if env::var("GS_BUILD_ELEVATION").is_ok() {
+        println!("build.rs: GS_BUILD_ELEVATION set, fetching DEM tiles at z={ELEV_ZOOM}");
+        if let Err(e) = fetch_elevation_tiles_for_zoom(ELEV_ZOOM, &tiles_root, &client) {
+            eprintln!("build.rs: WARNING: failed to fetch elevation tiles: {e}");
+        }
+    } else {
+        println!("build.rs: GS_BUILD_ELEVATION not set, skipping DEM tile fetch");
+    }
 }
 
 /// Check whether tiles directory is non-empty.
@@ -196,6 +221,97 @@ fn fetch_tiles_for_zoom(
     Ok(())
 }
 
+/// Same tile-index/parallel-download plumbing as [`fetch_tiles_for_zoom`], pointed at the DEM
+/// layer instead of the basemap and writing `.elev` files instead of `.jpeg`. Stored alongside
+/// the basemap under the same `tiles/<z>/<x>/<y>` layout so `terrain::ground_elevation_m` and
+/// `map::tile_service` share one directory tree.
+fn fetch_elevation_tiles_for_zoom(
+    z: u32,
+    tiles_root: &Path,
+    client: &Client,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (lon_min, lat_min, lon_max, lat_max) = NA_BOUNDS;
+
+    let (x_min, y_max) = lonlat_to_tile(lon_min, lat_min, z);
+    let (x_max, y_min) = lonlat_to_tile(lon_max, lat_max, z);
+
+    let x_start = x_min.min(x_max);
+    let x_end = x_min.max(x_max);
+    let y_start = y_min.min(y_max);
+    let y_end = y_min.max(y_max);
+
+    let mut coords = Vec::new();
+    for x in x_start..=x_end {
+        for y in y_start..=y_end {
+            coords.push((x, y));
+        }
+    }
+
+    let z_dir = tiles_root.join(format!("{z}"));
+    fs::create_dir_all(&z_dir)?;
+
+    coords.par_iter().for_each(|&(x, y)| {
+        let x_dir = z_dir.join(format!("{x}"));
+        if let Err(e) = fs::create_dir_all(&x_dir) {
+            eprintln!(
+                "build.rs: failed to create directory {}: {e}",
+                x_dir.display()
+            );
+            return;
+        }
+
+        let tile_path = x_dir.join(format!("{y}.{ELEV_TILE_EXT}"));
+        if tile_path.exists() {
+            return;
+        }
+
+        let url = format!(
+            "{base}/{layer}/default/{matrix_set}/{z}/{y}/{x}.{ext}",
+            base = GIBS_BASE_URL,
+            layer = ELEV_GIBS_LAYER,
+            matrix_set = ELEV_GIBS_TILE_MATRIX_SET,
+            z = z,
+            y = y,
+            x = x,
+            ext = ELEV_TILE_EXT,
+        );
+
+        match client.get(&url).send() {
+            Ok(resp) => {
+                if !resp.status().is_success() {
+                    eprintln!(
+                        "build.rs: HTTP {} for elevation tile z={z}, x={x}, y={y}",
+                        resp.status()
+                    );
+                    return;
+                }
+                match resp.bytes() {
+                    Ok(bytes) => {
+                        if let Err(e) = write_tile(&tile_path, &bytes) {
+                            eprintln!(
+                                "build.rs: failed to write elevation tile {}: {e}",
+                                tile_path.display()
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!(
+                            "build.rs: failed to read body for elevation tile z={z}, x={x}, y={y}: {e}"
+                        );
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "build.rs: ERROR fetching elevation tile z={z}, x={x}, y={y} from {url}: {e}"
+                );
+            }
+        }
+    });
+
+    Ok(())
+}
+
 fn write_tile(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
     let mut file = std::fs::File::create(path)?;
     file.write_all(bytes)?;
@@ -0,0 +1,39 @@
+//! A monotonic, overflow-safe stand-in for wall-clock time, anchored once at startup.
+//!
+//! `telemetry_task::get_current_timestamp_ms` reads `SystemTime::now()` fresh on every call, so
+//! if the host clock steps backward — an NTP correction, an operator setting the system clock —
+//! telemetry ordering and the GPIO sequence timers built on `Instant` can disagree with whatever
+//! ends up persisted in a `timestamp_ms` column. `timestamp_ms` below anchors an `Instant` to a
+//! wall-clock epoch exactly once, then every later reading is that epoch plus monotonic elapsed
+//! time, so it can only move forward, can't overflow any sooner than the wall clock it was
+//! seeded from would, and two readings taken a known `Duration` apart always differ by exactly
+//! that amount.
+
+use crate::telemetry_task::get_current_timestamp_ms;
+use std::sync::OnceLock;
+use std::time::Instant;
+
+static ANCHOR: OnceLock<(Instant, u64)> = OnceLock::new();
+
+/// The `Instant` the anchor was taken at, and `get_current_timestamp_ms()`'s value at that same
+/// moment — set on first call, from whichever thread gets there first.
+fn anchor() -> (Instant, u64) {
+    *ANCHOR.get_or_init(|| (Instant::now(), get_current_timestamp_ms()))
+}
+
+/// Drop-in replacement for `telemetry_task::get_current_timestamp_ms` as the router's `Clock`:
+/// monotonic for the life of the process, immune to the host wall clock stepping backward.
+pub fn timestamp_ms() -> u64 {
+    let (anchor_instant, anchor_epoch_ms) = anchor();
+    anchor_epoch_ms.saturating_add(anchor_instant.elapsed().as_millis() as u64)
+}
+
+/// Converts a monotonic `Instant` (e.g. a GPIO sequence step's `step_started_at`) onto the same
+/// timescale `timestamp_ms()` and the SQLite `timestamp_ms` columns use, so a step's start time
+/// can be compared against or logged alongside persisted telemetry without drifting relative to
+/// whatever the wall clock happened to read when the two were captured.
+pub fn instant_to_timestamp_ms(instant: Instant) -> u64 {
+    let (anchor_instant, anchor_epoch_ms) = anchor();
+    let delta_ms = instant.saturating_duration_since(anchor_instant).as_millis() as u64;
+    anchor_epoch_ms.saturating_add(delta_ms)
+}
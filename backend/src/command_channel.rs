@@ -0,0 +1,184 @@
+// backend/src/command_channel.rs
+//
+// A reliability layer above `router.log`, for commands where fire-and-forget isn't good enough
+// — chiefly `Abort`. `radio::send_command_reliable` already retries/acks at the *link* layer
+// (ground station <-> board serial frame); this sits a layer above that, at the `sedsprintf`
+// packet level, so it also covers a command that made it across the link fine but was never
+// actioned (or whose ack never made it back) — `send_command_reliable`'s ack only proves the
+// bytes arrived, not that the board did anything with them.
+//
+// `send_reliable` frames the payload (length-prefixed, optionally zstd-compressed, tagged with
+// a monotonically increasing sequence id) and resends it over `router.log` on a backoff timer
+// until a matching ack shows up in the ring buffer or `RETRY_BACKOFF` is exhausted. The ack
+// convention — a `DataType::MessageData` packet whose string payload is `"ACK:<seq>"` — is
+// something the board firmware has to implement; this crate only owns the ground-station side.
+
+use crate::state::AppState;
+use crate::web::emit_warning;
+use sedsprintf_rs_2026::config::DataType;
+use sedsprintf_rs_2026::router::Router;
+use serde::Serialize;
+use std::sync::{atomic::{AtomicU64, Ordering}, Arc};
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Payloads over this size are zstd-compressed (fastest level, same tradeoff `web::ZSTD_LEVEL`
+/// makes for WS frames) before framing. Not worth it for a handful of bytes like `Abort`'s, but
+/// a future large critical-command payload benefits without this layer needing to change.
+const COMPRESS_THRESHOLD_BYTES: usize = 64;
+const ZSTD_LEVEL: i32 = 1;
+
+/// One retransmission per backoff step; the last entry is also the final wait before giving up,
+/// so `RETRY_BACKOFF.len()` is the max number of transmissions attempted.
+const RETRY_BACKOFF: &[Duration] = &[
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_millis(1000),
+    Duration::from_millis(2000),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryState {
+    Pending,
+    Acked,
+    Failed,
+}
+
+static NEXT_SEQ: AtomicU64 = AtomicU64::new(1);
+
+fn next_seq() -> u64 {
+    NEXT_SEQ.fetch_add(1, Ordering::Relaxed)
+}
+
+/// `flag (1 byte) || seq (8 bytes LE) || body_len (4 bytes LE) || body`, where `body` is `payload`
+/// itself (`flag == 0`) or its zstd-compressed form (`flag == 1`) if compression shrank it.
+fn frame_command(seq: u64, payload: &[u8]) -> Vec<u8> {
+    let (flag, body) = if payload.len() >= COMPRESS_THRESHOLD_BYTES {
+        match zstd::encode_all(payload, ZSTD_LEVEL) {
+            Ok(compressed) if compressed.len() < payload.len() => (1u8, compressed),
+            _ => (0u8, payload.to_vec()),
+        }
+    } else {
+        (0u8, payload.to_vec())
+    };
+
+    let mut framed = Vec::with_capacity(1 + 8 + 4 + body.len());
+    framed.push(flag);
+    framed.extend_from_slice(&seq.to_le_bytes());
+    framed.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    framed.extend_from_slice(&body);
+    framed
+}
+
+fn ack_payload(seq: u64) -> String {
+    format!("ACK:{seq}")
+}
+
+/// Scans the ring buffer for `DataType::MessageData` whose string payload is `ack_payload(seq)`
+/// — non-destructive, the same `recent` read `safety_task` uses, so this doesn't race packet
+/// processing over who gets to consume the ack.
+fn ack_seen(state: &Arc<AppState>, seq: u64) -> bool {
+    let expected = ack_payload(seq);
+    let rb = state.ring_buffer.lock().unwrap();
+    rb.recent(rb.len()).into_iter().any(|pkt| {
+        matches!(pkt.data_type(), DataType::MessageData)
+            && pkt.data_as_string().map(|s| s == expected).unwrap_or(false)
+    })
+}
+
+/// Sends `payload` as `data_type` through `router.log`, retrying on `RETRY_BACKOFF` until
+/// `ack_seen` or the backoff is exhausted. Tracks delivery state in `AppState::command_delivery`
+/// (polled by the frontend via `/api/command/delivery`) and narrates each state change through
+/// `emit_warning`, so an operator sees whether a critical command like `Abort` actually landed
+/// instead of assuming success after one `router.log` call.
+pub async fn send_reliable(
+    state: &Arc<AppState>,
+    router: &Router,
+    data_type: DataType,
+    payload: &[u8],
+) -> Result<(), String> {
+    let seq = next_seq();
+    state.command_delivery.lock().unwrap().insert(seq, DeliveryState::Pending);
+
+    for (attempt, backoff) in RETRY_BACKOFF.iter().enumerate() {
+        let framed = frame_command(seq, payload);
+        if let Err(e) = router.log::<u8>(data_type, &framed) {
+            emit_warning(state, format!("Command seq {seq}: send failed on attempt {}: {e}", attempt + 1));
+        }
+
+        sleep(*backoff).await;
+
+        if ack_seen(state, seq) {
+            state.command_delivery.lock().unwrap().insert(seq, DeliveryState::Acked);
+            emit_warning(state, format!("Command seq {seq} acknowledged after {} attempt(s)", attempt + 1));
+            return Ok(());
+        }
+    }
+
+    state.command_delivery.lock().unwrap().insert(seq, DeliveryState::Failed);
+    let message = format!(
+        "Command seq {seq} not acknowledged after {} attempts — operator should verify manually",
+        RETRY_BACKOFF.len()
+    );
+    emit_warning(state, message.clone());
+    Err(message)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandDeliveryDto {
+    pub seq: u64,
+    pub state: DeliveryState,
+}
+
+/// Snapshot of every command `send_reliable` has ever tracked, most recent first — backs
+/// `/api/command/delivery`.
+pub fn delivery_snapshot(state: &Arc<AppState>) -> Vec<CommandDeliveryDto> {
+    let mut entries: Vec<CommandDeliveryDto> = state
+        .command_delivery
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(&seq, &state)| CommandDeliveryDto { seq, state })
+        .collect();
+    entries.sort_by(|a, b| b.seq.cmp(&a.seq));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unframe(framed: &[u8]) -> (u8, u64, Vec<u8>) {
+        let flag = framed[0];
+        let seq = u64::from_le_bytes(framed[1..9].try_into().unwrap());
+        let body_len = u32::from_le_bytes(framed[9..13].try_into().unwrap()) as usize;
+        (flag, seq, framed[13..13 + body_len].to_vec())
+    }
+
+    #[test]
+    fn small_payload_is_framed_uncompressed() {
+        let framed = frame_command(7, b"Abort");
+        let (flag, seq, body) = unframe(&framed);
+        assert_eq!(flag, 0);
+        assert_eq!(seq, 7);
+        assert_eq!(body, b"Abort");
+    }
+
+    #[test]
+    fn large_payload_is_compressed_and_round_trips() {
+        let payload = vec![b'x'; COMPRESS_THRESHOLD_BYTES * 4];
+        let framed = frame_command(42, &payload);
+        let (flag, seq, body) = unframe(&framed);
+        assert_eq!(flag, 1);
+        assert_eq!(seq, 42);
+        assert!(body.len() < payload.len());
+        assert_eq!(zstd::decode_all(body.as_slice()).unwrap(), payload);
+    }
+
+    #[test]
+    fn ack_payload_is_seq_scoped() {
+        assert_eq!(ack_payload(3), "ACK:3");
+        assert_ne!(ack_payload(3), ack_payload(4));
+    }
+}
@@ -0,0 +1,187 @@
+//! Ballistic payload-deployment timing advisor, inspired by PX4's `bottle_drop` module: watches
+//! live altitude/velocity telemetry and integrates the fall in small time steps to predict when
+//! a deployable payload or parachute should go — surfaced to the frontend as the "Deploy"
+//! control's `BlinkMode` rather than a number operators have to interpret themselves.
+
+use crate::state::AppState;
+use sedsprintf_rs_2026::config::DataType;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+
+const GRAVITY_MPS2: f32 = 9.80665;
+const STEP_SECONDS: f32 = 0.05;
+/// Safety cap on the integration loop (20 minutes of simulated fall) so a bad telemetry sample
+/// (e.g. a huge upward velocity) can't spin the task forever.
+const MAX_STEPS: u32 = 24_000;
+
+/// Drag/mass parameters for the ballistic integration — tunable per payload, not per launch
+/// site, so these read their own `GS_DEPLOY_*` env vars rather than reusing `SequenceConfig`'s.
+#[derive(Debug, Clone, Copy)]
+pub struct DeployAdvisorConfig {
+    /// Air density (kg/m^3).
+    pub rho: f32,
+    /// Drag coefficient of the deployable.
+    pub cd: f32,
+    /// Reference cross-sectional area (m^2).
+    pub area_m2: f32,
+    /// Mass of the deployable (kg).
+    pub mass_kg: f32,
+    /// Predicted time-to-ground at or below which the deploy window is considered open.
+    pub lead_time_sec: f32,
+}
+
+impl DeployAdvisorConfig {
+    pub fn from_env() -> Self {
+        let env_f32 = |key: &str, default: f32| {
+            std::env::var(key).ok().and_then(|v| v.parse::<f32>().ok()).unwrap_or(default)
+        };
+
+        Self {
+            rho: env_f32("GS_DEPLOY_RHO", 1.225),
+            cd: env_f32("GS_DEPLOY_CD", 0.8),
+            area_m2: env_f32("GS_DEPLOY_AREA_M2", 0.3),
+            mass_kg: env_f32("GS_DEPLOY_MASS_KG", 2.0),
+            lead_time_sec: env_f32("GS_DEPLOY_LEAD_TIME_SEC", 5.0),
+        }
+    }
+}
+
+/// Result of integrating the fall from the latest telemetry sample.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DeployAdvisory {
+    pub time_to_ground_s: f32,
+    pub drift_m: f32,
+    /// True once `time_to_ground_s` has dropped to `lead_time_sec` or below — the "drop now" cue.
+    pub window_open: bool,
+}
+
+/// Integrates the ballistic fall in `STEP_SECONDS` steps from `altitude_m`/`vertical_velocity_mps`
+/// (positive up)/`horizontal_velocity_mps`, applying gravity and the quadratic drag term
+/// `a_drag = -0.5 * rho * Cd * A / m * v * |v|` to each axis independently, until altitude
+/// reaches zero. Returns the elapsed fall time and accumulated horizontal travel.
+pub fn predict_fall(
+    altitude_m: f32,
+    vertical_velocity_mps: f32,
+    horizontal_velocity_mps: f32,
+    cfg: &DeployAdvisorConfig,
+) -> (f32, f32) {
+    let k = 0.5 * cfg.rho * cfg.cd * cfg.area_m2 / cfg.mass_kg;
+
+    let mut h = altitude_m;
+    let mut vz = vertical_velocity_mps;
+    let mut vx = horizontal_velocity_mps;
+    let mut t = 0.0_f32;
+    let mut drift = 0.0_f32;
+
+    for _ in 0..MAX_STEPS {
+        if h <= 0.0 {
+            break;
+        }
+
+        let a_drag_z = -k * vz * vz.abs();
+        let a_drag_x = -k * vx * vx.abs();
+
+        vz += (-GRAVITY_MPS2 + a_drag_z) * STEP_SECONDS;
+        vx += a_drag_x * STEP_SECONDS;
+        h += vz * STEP_SECONDS;
+        drift += vx.abs() * STEP_SECONDS;
+        t += STEP_SECONDS;
+    }
+
+    (t, drift)
+}
+
+fn advise(altitude_m: f32, vertical_velocity_mps: f32, horizontal_velocity_mps: f32, cfg: &DeployAdvisorConfig) -> DeployAdvisory {
+    let (time_to_ground_s, drift_m) = predict_fall(altitude_m, vertical_velocity_mps, horizontal_velocity_mps, cfg);
+    DeployAdvisory {
+        time_to_ground_s,
+        drift_m,
+        window_open: time_to_ground_s <= cfg.lead_time_sec,
+    }
+}
+
+/// Spawns the background task that folds `BarometerData` (altitude) and `KalmanFilterData`
+/// (vertical velocity at index 1 — `flight_sim` now appends attitude-estimator output after
+/// index 2, but keeps `[altitude_m, velocity_mps, accel_g, ...]` stable at the front for this
+/// reader) into a running advisory written to `AppState.latest_deploy_advisory`. There's no
+/// dedicated horizontal-velocity channel in the current telemetry set, so horizontal drift is
+/// reported as zero until one exists — `predict_fall` still takes it as a parameter for when it
+/// does.
+pub fn start_deploy_advisor_task(state: Arc<AppState>) {
+    let cfg = DeployAdvisorConfig::from_env();
+
+    tokio::spawn(async move {
+        let mut tick = tokio::time::interval(Duration::from_millis(250));
+
+        let mut altitude_m: Option<f32> = None;
+        let mut vertical_velocity_mps: Option<f32> = None;
+
+        loop {
+            tick.tick().await;
+
+            let packets = {
+                let rb = state.ring_buffer.lock().unwrap();
+                rb.recent(rb.len()).into_iter().cloned().collect::<Vec<_>>()
+            };
+
+            for pkt in packets {
+                let Ok(values) = pkt.data_as_f32() else { continue };
+                match pkt.data_type() {
+                    DataType::BarometerData => {
+                        if let Some(altitude) = values.get(2) {
+                            altitude_m = Some(*altitude);
+                        }
+                    }
+                    DataType::KalmanFilterData => {
+                        if let Some(velocity) = values.get(1) {
+                            vertical_velocity_mps = Some(*velocity);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let Some(altitude_m) = altitude_m else { continue };
+            let vz = vertical_velocity_mps.unwrap_or(0.0);
+            let advisory = advise(altitude_m, vz, 0.0, &cfg);
+            *state.latest_deploy_advisory.lock().unwrap() = Some(advisory);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_cfg() -> DeployAdvisorConfig {
+        DeployAdvisorConfig { rho: 1.225, cd: 0.8, area_m2: 0.3, mass_kg: 2.0, lead_time_sec: 5.0 }
+    }
+
+    #[test]
+    fn free_fall_reaches_ground_in_finite_time() {
+        // Drag caps the descent near sqrt(g / k) ≈ 11.6 m/s with this config, so ~87 s.
+        let (t, _drift) = predict_fall(1_000.0, 0.0, 0.0, &test_cfg());
+        assert!(t > 0.0 && t < 120.0);
+    }
+
+    #[test]
+    fn already_on_the_ground_takes_no_time() {
+        let (t, drift) = predict_fall(0.0, -10.0, 0.0, &test_cfg());
+        assert_eq!(t, 0.0);
+        assert_eq!(drift, 0.0);
+    }
+
+    #[test]
+    fn window_opens_near_the_ground() {
+        let cfg = test_cfg();
+        assert!(!advise(1_000.0, 0.0, 0.0, &cfg).window_open);
+        assert!(advise(5.0, -1.0, 0.0, &cfg).window_open);
+    }
+
+    #[test]
+    fn horizontal_drift_accumulates_with_horizontal_velocity() {
+        let (_t, drift) = predict_fall(1_000.0, 0.0, 20.0, &test_cfg());
+        assert!(drift > 0.0);
+    }
+}
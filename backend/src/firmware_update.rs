@@ -0,0 +1,223 @@
+use crate::radio::{crc16_ccitt, RadioDevice};
+use crate::sequences::key_enabled;
+use crate::state::AppState;
+use groundstation_shared::FlightState;
+use serde::Serialize;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Size of one image slice sent per `FRAME_OP_WRITE` frame — comfortably under
+/// `radio::MAX_PACKET_SIZE` once the opcode, slot, offset and CRC trailer are added on.
+pub const FIRMWARE_CHUNK_SIZE: usize = 200;
+
+const FRAME_OP_ERASE: u8 = 0;
+const FRAME_OP_WRITE: u8 = 1;
+const FRAME_OP_COMMIT: u8 = 2;
+
+/// Which A/B image slot an update targets. The board always boots whichever slot it last
+/// successfully `FRAME_OP_COMMIT`ed, so a transfer that's interrupted or fails its CRC check
+/// leaves the other slot's firmware untouched and still bootable — the whole point of staging
+/// an update into a slot instead of overwriting the one currently running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum FirmwareSlot {
+    A,
+    B,
+}
+
+impl FirmwareSlot {
+    /// The slot's byte in erase/write/commit frames (`A` = 0, `B` = 1). Nothing here picks the
+    /// slot: the ground station has no way to ask a board which slot it is booted from over this
+    /// link (there's no response payload on `send_command_reliable`, only ack/nack), so the
+    /// caller passes in the inactive slot explicitly.
+    fn as_wire_byte(self) -> u8 {
+        match self {
+            FirmwareSlot::A => 0,
+            FirmwareSlot::B => 1,
+        }
+    }
+}
+
+/// Reported by `run_firmware_update` after every chunk lands, so a caller can show a progress
+/// bar and — if the transfer is later interrupted — knows which offset to resume from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct FirmwareUpdateProgress {
+    pub chunks_acked: u32,
+    pub total_chunks: u32,
+    pub resume_offset: u32,
+    /// Total retransmissions across every frame sent so far (erase, writes, commit) — each
+    /// block's own ack wait already retries internally via `send_command_reliable_counted`, this
+    /// is just the running sum an operator can watch to judge link quality mid-transfer.
+    pub retries_so_far: u32,
+}
+
+/// Tracked in `AppState.firmware_update_status` for the duration of one `run_firmware_update`
+/// call (and left in place, with `done: true`, until the next one starts) so `/api/firmware/status`
+/// has something to report between polls.
+#[derive(Clone, Debug, Serialize)]
+pub struct FirmwareUpdateStatus {
+    pub board: String,
+    pub slot: FirmwareSlot,
+    pub progress: Option<FirmwareUpdateProgress>,
+    pub error: Option<String>,
+    pub done: bool,
+}
+
+#[derive(Debug)]
+pub enum FirmwareUpdateError {
+    /// The key-enable switch wasn't on — same gate `start_sequence_task` checks before letting
+    /// any privileged actuation through.
+    NotAuthorized,
+    /// The flight state isn't eligible for a firmware push right now (armed or mid-fill) — an
+    /// update has no business competing with the radio link during the window commands like
+    /// `Dump`/`Abort` need to land without delay.
+    FlightStateNotEligible(FlightState),
+    EraseFailed(String),
+    /// Carries the offset the caller should resume from on retry.
+    WriteFailed { offset: u32, source: String },
+    CommitFailed(String),
+}
+
+impl fmt::Display for FirmwareUpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAuthorized => write!(f, "firmware update blocked: key-enable switch is off"),
+            Self::FlightStateNotEligible(state) => {
+                write!(f, "firmware update blocked: not eligible during flight state {state:?}")
+            }
+            Self::EraseFailed(e) => write!(f, "board erase failed: {e}"),
+            Self::WriteFailed { offset, source } => {
+                write!(f, "write at offset {offset} failed: {source}")
+            }
+            Self::CommitFailed(e) => write!(f, "final commit/reboot failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FirmwareUpdateError {}
+
+/// Firmware pushes are blocked during armed/fill states, the same window `sequences::key_enabled`
+/// gates privileged valve actuation for — mirrors `gpio_panel::is_fill_state`/
+/// `sequences::is_fill_state`, kept as its own small copy for the same reason those two are.
+fn is_update_blocked_state(state: FlightState) -> bool {
+    matches!(
+        state,
+        FlightState::Armed
+            | FlightState::PreFill
+            | FlightState::FillTest
+            | FlightState::NitrogenFill
+            | FlightState::NitrousFill
+    )
+}
+
+fn send_erase(radio: &mut dyn RadioDevice, slot: FirmwareSlot) -> Result<u32, FirmwareUpdateError> {
+    radio
+        .send_command_reliable_counted(&[FRAME_OP_ERASE, slot.as_wire_byte()])
+        .map_err(|e| FirmwareUpdateError::EraseFailed(e.to_string()))
+}
+
+fn send_write(
+    radio: &mut dyn RadioDevice,
+    slot: FirmwareSlot,
+    offset: u32,
+    chunk: &[u8],
+) -> Result<u32, FirmwareUpdateError> {
+    let mut frame = Vec::with_capacity(2 + 4 + chunk.len() + 2);
+    frame.push(FRAME_OP_WRITE);
+    frame.push(slot.as_wire_byte());
+    frame.extend_from_slice(&offset.to_le_bytes());
+    frame.extend_from_slice(chunk);
+    frame.extend_from_slice(&crc16_ccitt(chunk).to_le_bytes());
+
+    radio
+        .send_command_reliable_counted(&frame)
+        .map_err(|e| FirmwareUpdateError::WriteFailed { offset, source: e.to_string() })
+}
+
+fn send_commit(
+    radio: &mut dyn RadioDevice,
+    slot: FirmwareSlot,
+    total_len: u32,
+    image_crc: u16,
+) -> Result<u32, FirmwareUpdateError> {
+    let mut frame = Vec::with_capacity(2 + 4 + 2);
+    frame.push(FRAME_OP_COMMIT);
+    frame.push(slot.as_wire_byte());
+    frame.extend_from_slice(&total_len.to_le_bytes());
+    frame.extend_from_slice(&image_crc.to_le_bytes());
+
+    radio
+        .send_command_reliable_counted(&frame)
+        .map_err(|e| FirmwareUpdateError::CommitFailed(e.to_string()))
+}
+
+/// Pushes `image` to a flight board over `radio`, chunk by chunk: erase once, write each
+/// `FIRMWARE_CHUNK_SIZE`-byte chunk in sequence (each carrying its target offset and a CRC,
+/// acked by `send_command_reliable` before the next chunk goes out), then a final whole-image
+/// length/CRC commit frame that tells the board to verify and reboot into the new image.
+///
+/// `resume_from_offset` restarts mid-transfer after a dropped connection — pass `0` for a fresh
+/// transfer (which also re-erases the board first) or a `FirmwareUpdateProgress::resume_offset`
+/// from a previous failed attempt to pick up where it left off without re-erasing or resending
+/// chunks already written.
+///
+/// `slot` names the (inactive) A/B slot to erase/write/commit into, per [`FirmwareSlot`].
+///
+/// Gated on [`key_enabled`] so an update can't be pushed without the same physical
+/// authorization `start_sequence_task` requires for fill/arm actuation, and on the current
+/// flight state not being armed or mid-fill (see [`is_update_blocked_state`]).
+pub fn run_firmware_update(
+    state: &AppState,
+    radio: &Arc<Mutex<Box<dyn RadioDevice>>>,
+    slot: FirmwareSlot,
+    image: &[u8],
+    resume_from_offset: u32,
+    mut on_progress: impl FnMut(FirmwareUpdateProgress),
+) -> Result<(), FirmwareUpdateError> {
+    if !key_enabled(state) {
+        return Err(FirmwareUpdateError::NotAuthorized);
+    }
+    let flight_state = *state.state.lock().unwrap();
+    if is_update_blocked_state(flight_state) {
+        return Err(FirmwareUpdateError::FlightStateNotEligible(flight_state));
+    }
+
+    let total_chunks = image.len().div_ceil(FIRMWARE_CHUNK_SIZE) as u32;
+    let resume_chunk = resume_from_offset as usize / FIRMWARE_CHUNK_SIZE;
+    let mut retries_so_far = 0u32;
+
+    let mut radio = radio.lock().expect("radio mutex poisoned");
+
+    if resume_chunk == 0 {
+        retries_so_far += send_erase(&mut **radio, slot)? - 1;
+    } else {
+        tracing::info!(
+            "firmware update resuming at chunk {resume_chunk}/{total_chunks} (offset {resume_from_offset})"
+        );
+    }
+
+    for (i, chunk) in image
+        .chunks(FIRMWARE_CHUNK_SIZE)
+        .enumerate()
+        .skip(resume_chunk)
+    {
+        let offset = (i * FIRMWARE_CHUNK_SIZE) as u32;
+        retries_so_far += send_write(&mut **radio, slot, offset, chunk)? - 1;
+
+        on_progress(FirmwareUpdateProgress {
+            chunks_acked: i as u32 + 1,
+            total_chunks,
+            resume_offset: offset + chunk.len() as u32,
+            retries_so_far,
+        });
+    }
+
+    retries_so_far += send_commit(&mut **radio, slot, image.len() as u32, crc16_ccitt(image))? - 1;
+    on_progress(FirmwareUpdateProgress {
+        chunks_acked: total_chunks,
+        total_chunks,
+        resume_offset: image.len() as u32,
+        retries_so_far,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// backend/src/flight_session.rs
+//
+// A named window over the continuous `telemetry` table's rows — opened before launch, closed
+// after landing — so `/api/range` (and any later review tooling) can pull back just one flight
+// instead of the whole history. Bookkeeping only: nothing here touches the ring buffer or the
+// live WS stream, it just stamps `AppState.current_session` onto rows as `telemetry_task` queues
+// them for `run_db_writer`.
+
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FlightSession {
+    pub id: i64,
+    pub name: String,
+    pub opened_at_ms: i64,
+    pub closed_at_ms: Option<i64>,
+}
+
+pub async fn open(db: &SqlitePool, name: &str, opened_at_ms: i64) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO flight_sessions (name, opened_at_ms, closed_at_ms) VALUES (?, ?, NULL)",
+    )
+    .bind(name)
+    .bind(opened_at_ms)
+    .execute(db)
+    .await?;
+    Ok(result.last_insert_rowid())
+}
+
+/// A no-op if `id` doesn't exist or is already closed, so a caller that double-clicks "close"
+/// (or races another client) doesn't need to special-case the error.
+pub async fn close(db: &SqlitePool, id: i64, closed_at_ms: i64) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE flight_sessions SET closed_at_ms = ? WHERE id = ? AND closed_at_ms IS NULL")
+        .bind(closed_at_ms)
+        .bind(id)
+        .execute(db)
+        .await?;
+    Ok(())
+}
+
+/// `telemetry`'s `session_id` column was added after this table already shipped, and
+/// `CREATE TABLE IF NOT EXISTS` is a no-op against a DB file that predates it — run this once at
+/// startup (before `run_db_writer` can insert) so an existing `./data/groundstation.db` gets the
+/// column instead of every insert failing with "no such column: session_id".
+pub async fn ensure_session_id_column(db: &SqlitePool) -> Result<(), sqlx::Error> {
+    let columns = sqlx::query("PRAGMA table_info(telemetry)").fetch_all(db).await?;
+    let has_session_id = columns.iter().any(|row| row.get::<String, _>("name") == "session_id");
+
+    if !has_session_id {
+        sqlx::query("ALTER TABLE telemetry ADD COLUMN session_id INTEGER")
+            .execute(db)
+            .await?;
+    }
+    Ok(())
+}
+
+pub async fn list(db: &SqlitePool) -> Result<Vec<FlightSession>, sqlx::Error> {
+    let rows = sqlx::query("SELECT id, name, opened_at_ms, closed_at_ms FROM flight_sessions ORDER BY id DESC")
+        .fetch_all(db)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| FlightSession {
+            id: row.get::<i64, _>("id"),
+            name: row.get::<String, _>("name"),
+            opened_at_ms: row.get::<i64, _>("opened_at_ms"),
+            closed_at_ms: row.get::<Option<i64>, _>("closed_at_ms"),
+        })
+        .collect())
+}
@@ -6,7 +6,9 @@ use groundstation_shared::TelemetryCommand;
 #[cfg(feature = "testing")]
 use groundstation_shared::{Board, FlightState};
 #[cfg(feature = "testing")]
-use rand::RngExt;
+use rand::rngs::StdRng;
+#[cfg(feature = "testing")]
+use rand::{RngExt, SeedableRng};
 #[cfg(feature = "testing")]
 use sedsprintf_rs_2026::config::{DataEndpoint, DataType};
 #[cfg(feature = "testing")]
@@ -14,8 +16,14 @@ use sedsprintf_rs_2026::telemetry_packet::TelemetryPacket;
 #[cfg(feature = "testing")]
 use sedsprintf_rs_2026::TelemetryResult;
 #[cfg(feature = "testing")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "testing")]
 use std::collections::{HashMap, VecDeque};
 #[cfg(feature = "testing")]
+use std::io::{BufRead, BufReader, BufWriter, Write};
+#[cfg(feature = "testing")]
+use std::net::UdpSocket;
+#[cfg(feature = "testing")]
 use std::sync::{Arc, Mutex, OnceLock};
 
 #[cfg(feature = "testing")]
@@ -30,8 +38,652 @@ const FLIGHT_STATE_PERIOD_MS: u64 = 1_000;
 #[cfg(feature = "testing")]
 const HOUSEKEEPING_PERIOD_MS: u64 = 900;
 
+/// `lastErrorCode` values for `DataType::BoardStatus` — a flat code space shared across boards,
+/// same as how `FlightState`/`DataType` are already flat enums rather than per-board ones.
+#[cfg(feature = "testing")]
+const ERR_NONE: u16 = 0;
+#[cfg(feature = "testing")]
+const ERR_FUEL_OVER_PRESSURE: u16 = 1;
+#[cfg(feature = "testing")]
+const ERR_COMMAND_REJECTED: u16 = 2;
+
+/// Bit positions of `DataType::BoardStatus`'s packed flag byte — OR'd together so the ground UI
+/// can decode warnings straight out of one byte instead of needing a packet per condition.
+#[cfg(feature = "testing")]
+const FLAG_OVER_PRESSURE: u8 = 1 << 0;
+#[cfg(feature = "testing")]
+const FLAG_MAIN_POWER_RELAY: u8 = 1 << 1;
+#[cfg(feature = "testing")]
+const FLAG_IGNITER_ARMED: u8 = 1 << 2;
+#[cfg(feature = "testing")]
+const FLAG_FAULT_LATCH: u8 = 1 << 3;
+#[cfg(feature = "testing")]
+const FLAG_COMMS_OK: u8 = 1 << 4;
+
+/// Soft pressure cap, as a fraction of `TANK_BURST_LIMIT_PSI` — crossing it trips
+/// `FLAG_OVER_PRESSURE` and logs a warning before the structural limit itself is ever reached.
+#[cfg(feature = "testing")]
+const FUEL_PRESSURE_SOFT_CAP_FRACTION: f32 = 0.92;
+
+/// Structural pressure limit `update_tank`'s output is checked against — one limit regardless
+/// of which valve is driving pressure up, now that pressure is a modeled output rather than a
+/// per-valve hard-coded ceiling.
+#[cfg(feature = "testing")]
+const TANK_BURST_LIMIT_PSI: f32 = 1_100.0;
+/// Fixed internal volume of the oxidizer tank's liquid+ullage space (liters) — `update_tank`
+/// splits this between liquid N2O (`n2o_fill_fraction`) and the ullage gas above it.
+#[cfg(feature = "testing")]
+const TANK_VOLUME_L: f32 = 8.0;
+/// Ambient temperature (K, ~20 C) the tank slowly re-equilibrates toward when no valve is
+/// open — heat soak from the environment undoing a vent's evaporative cooling over time.
+#[cfg(feature = "testing")]
+const AMBIENT_TEMP_K: f32 = 293.15;
+#[cfg(feature = "testing")]
+const GAS_CONSTANT_J_PER_MOL_K: f32 = 8.314;
+#[cfg(feature = "testing")]
+const PASCALS_PER_PSI: f32 = 6_894.76;
+/// Per-tick increase in ullage nitrogen while `NitrogenOpen`: pumping in pressurant gas raises
+/// its ideal-gas partial pressure directly, with no vaporization involved.
+#[cfg(feature = "testing")]
+const N2_FILL_MOLES_PER_TICK: f32 = 0.015;
+/// Per-tick venting rate of ullage gas while `DumpOpen`.
+#[cfg(feature = "testing")]
+const N2_VENT_MOLES_PER_TICK: f32 = 0.03;
+/// Per-tick increase in liquid N2O fill while `NitrousOpen` (and not also dumping). Filling
+/// shrinks the ullage volume `update_tank` divides the N2 moles by, which is the entire
+/// "autogenous self-pressurization" effect — no separate pressure bump is coded for it.
+#[cfg(feature = "testing")]
+const N2O_FILL_FRACTION_PER_TICK: f32 = 0.004;
+/// Per-tick temperature drop while venting: boiling off nitrous to replace vented gas costs
+/// latent heat, so `DumpOpen`'s pressure drop tracks a cooling tank rather than a flat ramp.
+#[cfg(feature = "testing")]
+const DUMP_EVAPORATIVE_COOLING_K_PER_TICK: f32 = 0.12;
+/// Per-tick relaxation of `tank_temp_k` back toward `AMBIENT_TEMP_K` when not venting.
+#[cfg(feature = "testing")]
+const AMBIENT_RELAX_K_PER_TICK: f32 = 0.01;
+
+/// Seeds the sim's stored RNG (see `FlightSimState::rng`) when no scenario file overrides it —
+/// fixed rather than OS entropy so a bare `FlightSimState::new()` is itself reproducible.
+#[cfg(feature = "testing")]
+const DEFAULT_SIM_SEED: u64 = 0xA5A5_5A5A_1234_5678;
+#[cfg(feature = "testing")]
+const SIM_SEED_ENV: &str = "GS_SIM_SEED";
+/// Path to a [`Scenario`] JSON file to replay against a virtual clock instead of live commands
+/// and wall-clock time — see `load_scenario_file` and `next_state_aware_packet`.
+#[cfg(feature = "testing")]
+const SIM_SCENARIO_PATH_ENV: &str = "GS_SCENARIO_PATH";
+/// Path to append one JSON line per emitted packet to, for later byte-for-byte replay — see
+/// `FlightSimState::record_packet`.
+#[cfg(feature = "testing")]
+const SIM_RECORD_PATH_ENV: &str = "GS_SIM_RECORD_PATH";
+
+/// One scripted event in a [`Scenario`]: at `at_ms` (milliseconds on the scenario's own virtual
+/// clock, starting at 0), replay `command` against the sim exactly as `apply_command` would from
+/// a live operator click. `flight_state`, if present, is force-set afterward for cues no single
+/// command reaches on its own (e.g. jumping straight to `Coast` for a demo without flying the
+/// whole ascent) — same escape hatch a human demo operator would use by hand.
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScenarioEvent {
+    at_ms: u64,
+    command: String,
+    #[serde(default)]
+    flight_state: Option<String>,
+}
+
+/// A scripted, replayable flight: a fixed `seed` for `FlightSimState::rng` so the scripted
+/// commands plus every noise source they touch produce byte-identical telemetry run to run, and
+/// the timestamped `events` to replay against a virtual clock in place of live commands.
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Scenario {
+    seed: u64,
+    events: Vec<ScenarioEvent>,
+}
+
+/// Inverse of the `FlightState::Foo => "Foo"` match this file already writes out by hand in
+/// several places — used to parse `ScenarioEvent::flight_state`.
+#[cfg(feature = "testing")]
+fn flight_state_from_name(name: &str) -> Option<FlightState> {
+    Some(match name {
+        "Idle" => FlightState::Idle,
+        "PreFill" => FlightState::PreFill,
+        "NitrogenFill" => FlightState::NitrogenFill,
+        "FillTest" => FlightState::FillTest,
+        "NitrousFill" => FlightState::NitrousFill,
+        "Armed" => FlightState::Armed,
+        "Launch" => FlightState::Launch,
+        "Ascent" => FlightState::Ascent,
+        "Coast" => FlightState::Coast,
+        "Apogee" => FlightState::Apogee,
+        "ParachuteDeploy" => FlightState::ParachuteDeploy,
+        "Descent" => FlightState::Descent,
+        "Landed" => FlightState::Landed,
+        "Recovery" => FlightState::Recovery,
+        "Aborted" => FlightState::Aborted,
+        _ => return None,
+    })
+}
+
+/// Reads and parses a scenario file, same shape as `sequence_config::load_sequence_config_file`:
+/// no fallback here, that's `FlightSimState::new`'s job.
+#[cfg(feature = "testing")]
+fn load_scenario_file(path: &std::path::Path) -> Result<Scenario, String> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read scenario {path:?}: {e}"))?;
+    serde_json::from_str(&raw).map_err(|e| format!("Invalid scenario JSON: {e}"))
+}
+
+/// What a [`FaultSpec`] degrades, addressed by the same string identity this file already uses
+/// to talk about a `DataType`/`Board` outside the packet layer (`.as_str()`/`.sender_id()`)
+/// rather than the enums themselves, since both are opaque external types this crate can't
+/// derive `Eq`/`Hash` for. Not itself gated on `feature = "testing"` since `set_fault` (and so
+/// this type) must stay callable with the same signature regardless of the feature, same as
+/// `handle_command`'s unconditional `TelemetryCommand` parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FaultTarget {
+    /// A sensor channel, keyed by `DataType::as_str()` (e.g. `"BAROMETER_DATA"`).
+    DataType(String),
+    /// A valve command id (`ActuatorBoardCommands`/`ValveBoardCommands` as `u8`).
+    Valve(u8),
+    /// A board's heartbeat/status channel, keyed by `Board::sender_id()`.
+    Board(String),
+}
+
+/// A degraded-hardware fault to install via `set_fault`, the same way a [`TelemetryCommand`] is
+/// passed to `handle_command`. Every knob besides `target` defaults to "no effect" via
+/// `FaultSpec::new`, so arming one is just turning on the handful that apply to its target —
+/// `drop_fraction`/`bias`/`drift_per_tick`/`stuck_at`/`spike_amplitude` are read by
+/// `next_sensor_packet` for a `DataType` target, `drop_fraction` alone by `queue_housekeeping`
+/// for a `Board` target (its "freeze this heartbeat" probability), and `stuck_valve`/
+/// `response_delay_ms` by `apply_command`'s valve toggles for a `Valve` target.
+#[derive(Debug, Clone)]
+pub struct FaultSpec {
+    pub target: FaultTarget,
+    /// Fraction (0.0-1.0) of packets (or, for a `Board` target, heartbeat/status cycles) to drop.
+    pub drop_fraction: f32,
+    /// Constant offset added to every sample.
+    pub bias: f32,
+    /// Per-tick drift added to `bias`, accumulated in `drift_accum` below — a slow ramp rather
+    /// than an instant jump.
+    pub drift_per_tick: f32,
+    drift_accum: f32,
+    /// When set, every sample reports exactly this value regardless of the other knobs.
+    pub stuck_at: Option<f32>,
+    /// Extra uniform noise amplitude layered on top of whatever jitter the channel already has.
+    pub spike_amplitude: f32,
+    /// The valve ignores commanded toggles entirely, forever reporting whatever state it was
+    /// already in.
+    pub stuck_valve: bool,
+    /// The valve accepts a commanded toggle but only reports it after this many milliseconds —
+    /// see `FlightSimState::delayed_valve_changes`.
+    pub response_delay_ms: u64,
+}
+
+impl FaultSpec {
+    pub fn new(target: FaultTarget) -> Self {
+        Self {
+            target,
+            drop_fraction: 0.0,
+            bias: 0.0,
+            drift_per_tick: 0.0,
+            drift_accum: 0.0,
+            stuck_at: None,
+            spike_amplitude: 0.0,
+            stuck_valve: false,
+            response_delay_ms: 0,
+        }
+    }
+}
+
+/// Ground-truth kinematics + position for one tick, reported by whichever [`TrajectorySource`]
+/// is driving the sim — either the scripted profile or an external FDM over UDP.
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Copy)]
+struct TrajectorySample {
+    /// `Some` when the source wants to drive the flight-state machine (the scripted profile
+    /// always does); `None` to leave `flight_state` as whatever the ground sequence/launch
+    /// command already set it to, since a source like [`UdpFdmSource`] has no opinion on it.
+    flight_state: Option<FlightState>,
+    altitude_ft: f32,
+    velocity_fps: f32,
+    accel_g: f32,
+    roll_dps: f32,
+    pitch_dps: f32,
+    yaw_dps: f32,
+    fuel_flow_lpm: f32,
+    lat: f32,
+    lon: f32,
+}
+
+/// Where `FlightSimState::update_physics` gets post-launch kinematics from: the built-in
+/// [`ScriptedTrajectory`] curve, or a real six-DOF model fed in over UDP via [`UdpFdmSource`].
+#[cfg(feature = "testing")]
+trait TrajectorySource: Send {
+    /// Advance to `t` seconds since launch and report the current sample. `now_ms` is the
+    /// wall-clock tick time, for sources (like the UDP one) that need it for staleness checks.
+    /// `rng` is the sim's own seeded generator, threaded through rather than reached for via
+    /// `rand::rng()` so a scripted source's jitter replays identically for a given seed.
+    fn sample(&mut self, t: f32, now_ms: u64, rng: &mut StdRng) -> TrajectorySample;
+
+    /// Whether this source's last sample was held over from before a timeout rather than fresh.
+    /// The scripted profile never drops out.
+    fn dropout(&self) -> bool {
+        false
+    }
+}
+
+/// The original hard-coded piecewise altitude/velocity/acceleration timeline, now behind
+/// [`TrajectorySource`] so it's just the default choice rather than the only one.
+#[cfg(feature = "testing")]
+#[derive(Debug, Default)]
+struct ScriptedTrajectory;
+
+#[cfg(feature = "testing")]
+impl TrajectorySource for ScriptedTrajectory {
+    fn sample(&mut self, t: f32, _now_ms: u64, rng: &mut StdRng) -> TrajectorySample {
+        let (state, alt, vel, accel_g, flow_lpm) = if t < 2.0 {
+            (FlightState::Launch, 150.0 * (t / 2.0), 90.0, 3.2, 45.0)
+        } else if t < 34.0 {
+            let p = (t - 2.0) / 32.0;
+            (
+                FlightState::Ascent,
+                150.0 + 9_850.0 * p,
+                330.0 * (1.0 - 0.2 * p),
+                2.1,
+                58.0,
+            )
+        } else if t < 43.0 {
+            let p = (t - 34.0) / 9.0;
+            (
+                FlightState::Coast,
+                10_000.0 + 500.0 * p,
+                120.0 * (1.0 - p),
+                1.0,
+                0.0,
+            )
+        } else if t < 46.0 {
+            (FlightState::Apogee, 10_500.0, 0.0, 1.0, 0.0)
+        } else if t < 54.0 {
+            let p = (t - 46.0) / 8.0;
+            (
+                FlightState::ParachuteDeploy,
+                10_500.0 - 700.0 * p,
+                -80.0,
+                0.7,
+                0.0,
+            )
+        } else if t < 174.0 {
+            let p = (t - 54.0) / 120.0;
+            (
+                FlightState::Descent,
+                (9_800.0 * (1.0 - p)).max(0.0),
+                -85.0,
+                0.95,
+                0.0,
+            )
+        } else if t < 182.0 {
+            (FlightState::Landed, 0.0, 0.0, 1.0, 0.0)
+        } else {
+            (FlightState::Recovery, 0.0, 0.0, 1.0, 0.0)
+        };
+
+        let dlat_deg = (alt / 5_280.0) * 0.00001;
+        let dlon_deg = dlat_deg * 0.8;
+
+        TrajectorySample {
+            flight_state: Some(state),
+            altitude_ft: alt,
+            velocity_fps: vel,
+            accel_g,
+            roll_dps: rng.random_range(-2.0..2.0),
+            pitch_dps: rng.random_range(-2.0..2.0),
+            yaw_dps: rng.random_range(-6.0..6.0),
+            fuel_flow_lpm: flow_lpm,
+            lat: BASE_LAT + dlat_deg,
+            lon: BASE_LON + dlon_deg,
+        }
+    }
+}
+
+#[cfg(feature = "testing")]
+const FDM_DATAGRAM_FIELDS: usize = 10;
+#[cfg(feature = "testing")]
+const FDM_DATAGRAM_LEN: usize = FDM_DATAGRAM_FIELDS * 4;
+#[cfg(feature = "testing")]
+const FDM_DROPOUT_TIMEOUT_MS: u64 = 1_000;
+
+/// Fixed little-endian layout for one flight-dynamics-model datagram: `f32` lat (deg), lon (deg),
+/// altitude (ft), body-frame accelerations x/y/z (g), angular rates roll/pitch/yaw (deg/s), and
+/// airspeed (ft/s) — a 40-byte UDP packet per FDM tick, hand-packed the same way the rest of this
+/// crate already packs telemetry payloads (see `next_sensor_packet` below) rather than adding a
+/// serialization dependency.
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Copy)]
+struct FdmDatagram {
+    lat_deg: f32,
+    lon_deg: f32,
+    alt_ft: f32,
+    accel_x_g: f32,
+    accel_y_g: f32,
+    accel_z_g: f32,
+    roll_dps: f32,
+    pitch_dps: f32,
+    yaw_dps: f32,
+    airspeed_fps: f32,
+}
+
+#[cfg(feature = "testing")]
+impl FdmDatagram {
+    fn parse(buf: &[u8]) -> Option<Self> {
+        if buf.len() < FDM_DATAGRAM_LEN {
+            return None;
+        }
+        let f = |i: usize| f32::from_le_bytes(buf[i * 4..i * 4 + 4].try_into().unwrap());
+        Some(Self {
+            lat_deg: f(0),
+            lon_deg: f(1),
+            alt_ft: f(2),
+            accel_x_g: f(3),
+            accel_y_g: f(4),
+            accel_z_g: f(5),
+            roll_dps: f(6),
+            pitch_dps: f(7),
+            yaw_dps: f(8),
+            airspeed_fps: f(9),
+        })
+    }
+}
+
+/// Drives the sim from an external six-DOF flight dynamics model (e.g. JSBSim, X-Plane) over
+/// UDP instead of [`ScriptedTrajectory`]'s canned curve, so the ground station can be validated
+/// against a real physics engine. Each tick drains the socket down to the newest datagram —
+/// anything queued up behind it is stale and simply discarded, since only the current state
+/// matters for a live feed — and holds the last parsed datagram when nothing new has arrived,
+/// flagging a dropout once that hold exceeds [`FDM_DROPOUT_TIMEOUT_MS`].
+#[cfg(feature = "testing")]
+struct UdpFdmSource {
+    socket: UdpSocket,
+    last: Option<FdmDatagram>,
+    last_recv_ms: u64,
+    dropout: bool,
+}
+
+#[cfg(feature = "testing")]
+impl UdpFdmSource {
+    fn bind(port: u16) -> std::io::Result<Self> {
+        let socket = UdpSocket::bind(("0.0.0.0", port))?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket,
+            last: None,
+            last_recv_ms: 0,
+            dropout: true,
+        })
+    }
+}
+
+#[cfg(feature = "testing")]
+impl TrajectorySource for UdpFdmSource {
+    fn sample(&mut self, _t: f32, now_ms: u64, _rng: &mut StdRng) -> TrajectorySample {
+        let mut buf = [0u8; FDM_DATAGRAM_LEN];
+        loop {
+            match self.socket.recv(&mut buf) {
+                Ok(n) if n >= FDM_DATAGRAM_LEN => {
+                    if let Some(dg) = FdmDatagram::parse(&buf) {
+                        self.last = Some(dg);
+                        self.last_recv_ms = now_ms;
+                    }
+                }
+                // Short/garbage datagram: keep draining rather than giving up on the socket.
+                Ok(_) => continue,
+                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+                Err(_) => break,
+            }
+        }
+        self.dropout =
+            self.last.is_none() || now_ms.saturating_sub(self.last_recv_ms) > FDM_DROPOUT_TIMEOUT_MS;
+
+        let dg = self.last.unwrap_or(FdmDatagram {
+            lat_deg: BASE_LAT,
+            lon_deg: BASE_LON,
+            alt_ft: 0.0,
+            accel_x_g: 0.0,
+            accel_y_g: 0.0,
+            accel_z_g: 1.0,
+            roll_dps: 0.0,
+            pitch_dps: 0.0,
+            yaw_dps: 0.0,
+            airspeed_fps: 0.0,
+        });
+        let accel_g = (dg.accel_x_g.powi(2) + dg.accel_y_g.powi(2) + dg.accel_z_g.powi(2)).sqrt();
+
+        TrajectorySample {
+            flight_state: None,
+            altitude_ft: dg.alt_ft,
+            velocity_fps: dg.airspeed_fps,
+            accel_g,
+            roll_dps: dg.roll_dps,
+            pitch_dps: dg.pitch_dps,
+            yaw_dps: dg.yaw_dps,
+            // Fuel flow isn't part of the FDM model; `update_physics`'s pre-launch branch already
+            // derives it from valve state, and there's no post-launch fuel system here to mirror.
+            fuel_flow_lpm: 0.0,
+            lat: dg.lat_deg,
+            lon: dg.lon_deg,
+        }
+    }
+
+    fn dropout(&self) -> bool {
+        self.dropout
+    }
+}
+
+/// Env var naming the local UDP port to bind for [`UdpFdmSource`]; unset (the default) keeps the
+/// sim on [`ScriptedTrajectory`].
+#[cfg(feature = "testing")]
+const FDM_UDP_PORT_ENV: &str = "GS_FDM_UDP_PORT";
+
+#[cfg(feature = "testing")]
+fn default_trajectory_source() -> Box<dyn TrajectorySource> {
+    match std::env::var(FDM_UDP_PORT_ENV).ok().and_then(|p| p.parse::<u16>().ok()) {
+        Some(port) => match UdpFdmSource::bind(port) {
+            Ok(src) => Box::new(src),
+            Err(e) => {
+                tracing::warn!(
+                    "flight_sim: failed to bind {FDM_UDP_PORT_ENV}={port} ({e}), falling back to scripted trajectory"
+                );
+                Box::new(ScriptedTrajectory)
+            }
+        },
+        None => Box::new(ScriptedTrajectory),
+    }
+}
+
+/// Why `FlightSimState::set_flight_state` refused a requested transition — surfaced to the
+/// operator as a `BoardStatus` warning (see `report_rejected_transition`) instead of silently
+/// doing nothing. The command/`AppState`-level counterpart is `state_machine::RejectReason`;
+/// this one guards the sim's own physics/ground-sequence states that layer doesn't see.
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RejectReason {
+    from: FlightState,
+    to: FlightState,
+}
+
+/// One legal `(from, to)` edge in the sim's flight-state machine, guarded by a closure over
+/// valve/pressure conditions — mirrors PX4 commander's `state_machine_helper`, same as
+/// `state_machine.rs`'s command-level table, but for the states that table doesn't govern: the
+/// ground-fill sequence and the physics-driven post-launch states. `from: None` means "legal
+/// from any state" (just `Abort`, as in `state_machine.rs`).
+#[cfg(feature = "testing")]
+struct SimTransition {
+    from: Option<FlightState>,
+    to: FlightState,
+    guard: fn(&FlightSimState) -> bool,
+}
+
+#[cfg(feature = "testing")]
+fn always(_: &FlightSimState) -> bool {
+    true
+}
+
+#[cfg(feature = "testing")]
+fn guard_idle_to_prefill(s: &FlightSimState) -> bool {
+    let no_open = !s.valve_on(ValveBoardCommands::NormallyOpenOpen as u8);
+    let dump_closed = !s.valve_on(ValveBoardCommands::DumpOpen as u8);
+    no_open && dump_closed
+}
+
+#[cfg(feature = "testing")]
+fn guard_prefill_to_nitrogen_fill(s: &FlightSimState) -> bool {
+    s.valve_on(ActuatorBoardCommands::NitrogenOpen as u8)
+}
+
+#[cfg(feature = "testing")]
+fn guard_nitrogen_fill_to_fill_test(s: &FlightSimState) -> bool {
+    !s.valve_on(ActuatorBoardCommands::NitrogenOpen as u8)
+}
+
+#[cfg(feature = "testing")]
+fn guard_fill_test_to_nitrous_fill(s: &FlightSimState) -> bool {
+    s.saw_dump_open_after_n2
+        && s.saw_dump_closed_after_n2
+        && s.valve_on(ActuatorBoardCommands::NitrousOpen as u8)
+}
+
+/// Proportional gain on the gravity-vector correction in [`FlightSimState::update_attitude`] —
+/// how hard the complementary filter pulls the gyro-integrated estimate back toward the
+/// accelerometer-implied "down" direction each tick. Higher settles faster but trusts the
+/// (also noisy) accelerometer more.
+#[cfg(feature = "testing")]
+const ATTITUDE_ACCEL_CORRECTION_GAIN: f32 = 0.8;
+
+/// Renormalize after every quaternion product — repeated multiplication drifts off the unit
+/// sphere, same reason PX4's `attitude_estimator_q` renormalizes every integration step.
+#[cfg(feature = "testing")]
+fn quat_normalize(q: [f32; 4]) -> [f32; 4] {
+    let n = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+    if n < 1e-9 {
+        [1.0, 0.0, 0.0, 0.0]
+    } else {
+        [q[0] / n, q[1] / n, q[2] / n, q[3] / n]
+    }
+}
+
+#[cfg(feature = "testing")]
+fn quat_mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    let (aw, ax, ay, az) = (a[0], a[1], a[2], a[3]);
+    let (bw, bx, by, bz) = (b[0], b[1], b[2], b[3]);
+    [
+        aw * bw - ax * bx - ay * by - az * bz,
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+    ]
+}
+
+#[cfg(feature = "testing")]
+fn quat_conj(q: [f32; 4]) -> [f32; 4] {
+    [q[0], -q[1], -q[2], -q[3]]
+}
+
+#[cfg(feature = "testing")]
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Rotate `v` by unit quaternion `q` (`q * [0, v] * q_conj`), expanded via the standard
+/// double-cross-product form instead of building the throwaway pure-vector quaternion.
+#[cfg(feature = "testing")]
+fn quat_rotate_vec(q: [f32; 4], v: [f32; 3]) -> [f32; 3] {
+    let w = q[0];
+    let qv = [q[1], q[2], q[3]];
+    let uv = cross(qv, v);
+    let uuv = cross(qv, uv);
+    [
+        v[0] + 2.0 * (w * uv[0] + uuv[0]),
+        v[1] + 2.0 * (w * uv[1] + uuv[1]),
+        v[2] + 2.0 * (w * uv[2] + uuv[2]),
+    ]
+}
+
+/// One step of the PX4-style exponential-map integrator `q ← q ⊗ exp(½·ω·dt)`: for the
+/// sub-degree rotation a single tick covers, the small-angle approximation `exp(½·ω·dt) ≈
+/// [1, ½·ω·dt]` (renormalized) is indistinguishable from the true exponential.
+#[cfg(feature = "testing")]
+fn quat_integrate_step(q: [f32; 4], omega_rad_s: [f32; 3], dt_s: f32) -> [f32; 4] {
+    let half_step = quat_normalize([
+        1.0,
+        omega_rad_s[0] * dt_s * 0.5,
+        omega_rad_s[1] * dt_s * 0.5,
+        omega_rad_s[2] * dt_s * 0.5,
+    ]);
+    quat_normalize(quat_mul(q, half_step))
+}
+
+/// Derive roll/pitch/yaw (degrees, aerospace ZYX convention) from the estimator's quaternion for
+/// the ground display — the quaternion itself stays the wire-format source of truth.
+#[cfg(feature = "testing")]
+fn quat_to_euler_deg(q: [f32; 4]) -> (f32, f32, f32) {
+    let (w, x, y, z) = (q[0], q[1], q[2], q[3]);
+    let roll = f32::atan2(2.0 * (w * x + y * z), 1.0 - 2.0 * (x * x + y * y));
+    let sin_pitch = 2.0 * (w * y - z * x);
+    let pitch = if sin_pitch.abs() >= 1.0 {
+        std::f32::consts::FRAC_PI_2.copysign(sin_pitch)
+    } else {
+        sin_pitch.asin()
+    };
+    let yaw = f32::atan2(2.0 * (w * z + x * y), 1.0 - 2.0 * (y * y + z * z));
+    (roll.to_degrees(), pitch.to_degrees(), yaw.to_degrees())
+}
+
+/// Crude saturation vapor-pressure curve for N2O (`ln(p) = A - B/T`, an Antoine-style fit
+/// tuned to roughly track published data across the 250-310 K range this tank operates in —
+/// not metrology-grade, just enough that `update_tank`'s pressure and temperature move
+/// together realistically).
+#[cfg(feature = "testing")]
+fn n2o_vapor_pressure_psi(temp_k: f32) -> f32 {
+    const A: f32 = 12.87;
+    const B: f32 = 1_830.0;
+    (A - B / temp_k).exp()
+}
+
+/// Ideal-gas volume (liters) occupied by `moles` of gas at `temp_k` and `pressure_psi` — used
+/// to turn a per-tick mole change into the flow-rate `ground_fuel_flow_lpm` reports.
+#[cfg(feature = "testing")]
+fn ideal_gas_volume_l(moles: f32, temp_k: f32, pressure_psi: f32) -> f32 {
+    if pressure_psi <= 0.0 {
+        return 0.0;
+    }
+    let pressure_pa = pressure_psi * PASCALS_PER_PSI;
+    let volume_m3 = moles * GAS_CONSTANT_J_PER_MOL_K * temp_k / pressure_pa;
+    volume_m3 * 1_000.0
+}
+
+#[cfg(feature = "testing")]
+const SIM_TRANSITIONS: &[SimTransition] = &[
+    SimTransition { from: Some(FlightState::Idle), to: FlightState::PreFill, guard: guard_idle_to_prefill },
+    SimTransition { from: Some(FlightState::PreFill), to: FlightState::NitrogenFill, guard: guard_prefill_to_nitrogen_fill },
+    SimTransition { from: Some(FlightState::NitrogenFill), to: FlightState::FillTest, guard: guard_nitrogen_fill_to_fill_test },
+    SimTransition { from: Some(FlightState::FillTest), to: FlightState::NitrousFill, guard: guard_fill_test_to_nitrous_fill },
+    SimTransition { from: Some(FlightState::NitrousFill), to: FlightState::Armed, guard: always },
+    SimTransition { from: Some(FlightState::Armed), to: FlightState::Launch, guard: always },
+    SimTransition { from: Some(FlightState::Launch), to: FlightState::Ascent, guard: always },
+    SimTransition { from: Some(FlightState::Ascent), to: FlightState::Coast, guard: always },
+    SimTransition { from: Some(FlightState::Coast), to: FlightState::Apogee, guard: always },
+    SimTransition { from: Some(FlightState::Apogee), to: FlightState::ParachuteDeploy, guard: always },
+    SimTransition { from: Some(FlightState::ParachuteDeploy), to: FlightState::Descent, guard: always },
+    SimTransition { from: Some(FlightState::Descent), to: FlightState::Landed, guard: always },
+    SimTransition { from: Some(FlightState::Landed), to: FlightState::Recovery, guard: always },
+    SimTransition { from: None, to: FlightState::Aborted, guard: always },
+];
+
 #[cfg(feature = "testing")]
-#[derive(Debug)]
 struct FlightSimState {
     flight_state: FlightState,
     launch_time_ms: Option<u64>,
@@ -42,6 +694,12 @@ struct FlightSimState {
     next_valve_emit_idx: usize,
     fuel_tank_pressure_psi: f32,
     fuel_flow_lpm: f32,
+    /// Ullage gas temperature (K) — see `n2o_vapor_pressure_psi` and `update_tank`.
+    tank_temp_k: f32,
+    /// Liquid N2O fill level, 0.0 (empty) to 1.0 (full) of `TANK_VOLUME_L`.
+    n2o_fill_fraction: f32,
+    /// Moles of N2 pressurant gas in the ullage space; feeds `update_tank`'s ideal-gas term.
+    n2_ullage_moles: f32,
     battery_v: f32,
     battery_a: f32,
     altitude_ft: f32,
@@ -50,15 +708,85 @@ struct FlightSimState {
     roll_dps: f32,
     pitch_dps: f32,
     yaw_dps: f32,
+    lat: f32,
+    lon: f32,
+    /// Driftless ground-truth orientation, integrated straight from `roll_dps`/`pitch_dps`/
+    /// `yaw_dps` with no noise — exists only so `update_attitude` has something to synthesize a
+    /// gravity-referenced accelerometer reading from; never emitted.
+    true_q: [f32; 4],
+    /// The estimator's own state: gyro-integrated from noisy rates, then pulled back toward the
+    /// measured gravity direction each tick. This is what `KalmanFilterData` now reports.
+    est_q: [f32; 4],
+    last_attitude_tick_ms: u64,
+    trajectory: Box<dyn TrajectorySource>,
+    fdm_dropout_logged: bool,
+    warning_counter: u16,
+    last_error_code: u16,
+    over_pressure_active: bool,
+    fault_latched: bool,
     valves: HashMap<u8, bool>,
     saw_dump_open_after_n2: bool,
     saw_dump_closed_after_n2: bool,
     queued: VecDeque<TelemetryPacket>,
+    /// Every noise source in this file (gyro/accel jitter, `ScriptedTrajectory`'s wobble, the
+    /// attitude estimator's correction) draws from this instead of reaching for `rand::rng()`,
+    /// so re-seeding it (fresh or from a [`Scenario`]) makes a run byte-for-byte reproducible.
+    rng: StdRng,
+    /// `Some` while replaying a loaded [`Scenario`] — see `next_state_aware_packet`'s virtual
+    /// clock branch.
+    scenario: Option<Scenario>,
+    scenario_next_idx: usize,
+    /// `Some(t)` while a scenario drives the clock instead of `get_current_timestamp_ms`; `t` is
+    /// milliseconds since the scenario started and advances by `SENSOR_PERIOD_MS` per packet.
+    virtual_clock_ms: Option<u64>,
+    /// Open handle appending one JSON line per emitted packet when `GS_SIM_RECORD_PATH` is set.
+    recorder: Option<BufWriter<std::fs::File>>,
+    /// Active degraded-hardware faults, installed via `set_fault`. Looked up by linear scan
+    /// (there are never more than a handful at once) rather than keyed storage, since its two
+    /// non-valve targets (`DataType`, `Board`) are opaque external enums this crate can't derive
+    /// `Hash`/`Eq` for — matched by their existing `.as_str()`/`.sender_id()` string identity
+    /// instead.
+    faults: Vec<FaultSpec>,
+    /// Commanded valve toggles not yet applied, queued by a `response_delay_ms` fault —
+    /// `(commit_at_ms, cmd_id, value)`. Drained in `apply_delayed_valve_changes`.
+    delayed_valve_changes: Vec<(u64, u8, bool)>,
 }
 
 #[cfg(feature = "testing")]
 impl FlightSimState {
+    /// Reads `GS_SCENARIO_PATH`/`GS_SIM_SEED`/`GS_SIM_RECORD_PATH` the same way
+    /// `default_trajectory_source` reads `GS_FDM_UDP_PORT`: an unset or unusable env var falls
+    /// back to the old unscripted, freshly-but-deterministically-seeded behavior with a warning,
+    /// rather than failing the whole sim over an optional knob.
     fn new() -> Self {
+        let scenario = match std::env::var(SIM_SCENARIO_PATH_ENV) {
+            Ok(path) => match load_scenario_file(std::path::Path::new(&path)) {
+                Ok(s) => Some(s),
+                Err(e) => {
+                    tracing::warn!("flight_sim: failed to load scenario {path:?} ({e}), ignoring");
+                    None
+                }
+            },
+            Err(_) => None,
+        };
+        let seed = scenario.as_ref().map(|s| s.seed).unwrap_or_else(|| {
+            std::env::var(SIM_SEED_ENV)
+                .ok()
+                .and_then(|s| s.parse::<u64>().ok())
+                .unwrap_or(DEFAULT_SIM_SEED)
+        });
+        let recorder = match std::env::var(SIM_RECORD_PATH_ENV) {
+            Ok(path) => match std::fs::File::create(&path) {
+                Ok(f) => Some(BufWriter::new(f)),
+                Err(e) => {
+                    tracing::warn!("flight_sim: failed to open record file {path:?} ({e}), not recording");
+                    None
+                }
+            },
+            Err(_) => None,
+        };
+        let virtual_clock_ms = scenario.as_ref().map(|_| 0);
+
         Self {
             flight_state: FlightState::Idle,
             launch_time_ms: None,
@@ -69,6 +797,9 @@ impl FlightSimState {
             next_valve_emit_idx: 0,
             fuel_tank_pressure_psi: 5.0,
             fuel_flow_lpm: 0.0,
+            tank_temp_k: AMBIENT_TEMP_K,
+            n2o_fill_fraction: 0.0,
+            n2_ullage_moles: 0.0,
             battery_v: 12.4,
             battery_a: 1.2,
             altitude_ft: 0.0,
@@ -77,10 +808,28 @@ impl FlightSimState {
             roll_dps: 0.0,
             pitch_dps: 0.0,
             yaw_dps: 0.0,
+            lat: BASE_LAT,
+            lon: BASE_LON,
+            true_q: [1.0, 0.0, 0.0, 0.0],
+            est_q: [1.0, 0.0, 0.0, 0.0],
+            last_attitude_tick_ms: 0,
+            trajectory: default_trajectory_source(),
+            fdm_dropout_logged: false,
+            warning_counter: 0,
+            last_error_code: ERR_NONE,
+            over_pressure_active: false,
+            fault_latched: false,
             valves: HashMap::new(),
             saw_dump_open_after_n2: false,
             saw_dump_closed_after_n2: false,
             queued: VecDeque::new(),
+            rng: StdRng::seed_from_u64(seed),
+            scenario,
+            scenario_next_idx: 0,
+            virtual_clock_ms,
+            recorder,
+            faults: Vec::new(),
+            delayed_valve_changes: Vec::new(),
         }
     }
 
@@ -88,12 +837,77 @@ impl FlightSimState {
         self.valves.get(&cmd_id).copied().unwrap_or(false)
     }
 
-    fn set_flight_state(&mut self, fs: FlightState, now_ms: u64) {
+    /// Commit a commanded valve value, honoring a `stuck_valve` or `response_delay_ms` fault on
+    /// `key` if one is installed. A stuck valve drops the command and keeps reporting whatever
+    /// it already was; a slow-response valve reports the same way until `delayed_valve_changes`
+    /// commits it later. Returns the value that should actually go out in the
+    /// `UmbilicalStatus` this tick, which may not be `value`.
+    fn set_valve(&mut self, key: u8, value: bool, now_ms: u64) -> bool {
+        if let Some(fault) = self.faults.iter().find(|f| f.target == FaultTarget::Valve(key)) {
+            if fault.stuck_valve {
+                return self.valve_on(key);
+            }
+            if fault.response_delay_ms > 0 {
+                self.delayed_valve_changes.push((now_ms + fault.response_delay_ms, key, value));
+                return self.valve_on(key);
+            }
+        }
+        self.valves.insert(key, value);
+        value
+    }
+
+    /// Drain any commanded valve toggles whose `response_delay_ms` fault has elapsed, applying
+    /// them to `self.valves` just as `set_valve` would have done immediately without the fault.
+    fn apply_delayed_valve_changes(&mut self, now_ms: u64) {
+        let (due, pending): (Vec<_>, Vec<_>) =
+            self.delayed_valve_changes.drain(..).partition(|&(commit_at_ms, _, _)| commit_at_ms <= now_ms);
+        self.delayed_valve_changes = pending;
+        for (_, key, value) in due {
+            self.valves.insert(key, value);
+        }
+    }
+
+    /// Commit a transition only if `SIM_TRANSITIONS` has a matching edge whose guard currently
+    /// holds; otherwise refuse it with a [`RejectReason`] rather than assigning blindly. A
+    /// no-op (`fs` already current) always succeeds without consulting the table.
+    fn set_flight_state(&mut self, fs: FlightState, now_ms: u64) -> Result<(), RejectReason> {
         if self.flight_state == fs {
-            return;
+            return Ok(());
+        }
+        let from = self.flight_state;
+        let allowed = SIM_TRANSITIONS
+            .iter()
+            .any(|t| t.to == fs && (t.from.is_none() || t.from == Some(from)) && (t.guard)(self));
+        if !allowed {
+            return Err(RejectReason { from, to: fs });
         }
         self.flight_state = fs;
         self.queue_flight_state(now_ms);
+        Ok(())
+    }
+
+    /// Set `flight_state` directly, bypassing `SIM_TRANSITIONS` entirely — for a [`Scenario`]'s
+    /// authored `flight_state` override, which exists specifically to skip states a guarded
+    /// `set_flight_state` wouldn't otherwise let a demo jump over (e.g. straight to `Coast`
+    /// without flying the whole ascent). A scenario file is trusted input, unlike a live
+    /// operator command, so it doesn't need the same guard a `TelemetryCommand` does.
+    fn force_flight_state(&mut self, fs: FlightState, now_ms: u64) {
+        self.flight_state = fs;
+        self.queue_flight_state(now_ms);
+    }
+
+    /// Surface a refused transition to the operator: bumps `warning_counter`, sets
+    /// `last_error_code`, and latches the `BoardStatus` fault bit (see chunk12-2's frame) instead
+    /// of the rejection vanishing silently.
+    fn report_rejected_transition(&mut self, reason: RejectReason) {
+        tracing::warn!(
+            "flight_sim: rejected transition {:?} -> {:?}",
+            reason.from,
+            reason.to
+        );
+        self.warning_counter = self.warning_counter.wrapping_add(1);
+        self.last_error_code = ERR_COMMAND_REJECTED;
+        self.fault_latched = true;
     }
 
     fn queue_flight_state(&mut self, now_ms: u64) {
@@ -137,9 +951,50 @@ impl FlightSimState {
         }
     }
 
+    /// Compact health/fault frame, modeled on verbose-CAN status frames: a warning count and
+    /// last error code (sticky until the next one) plus a packed flag byte, so the ground UI can
+    /// decode board health without a packet per condition (see the `FLAG_*` bit constants).
+    fn queue_board_status(&mut self, board: Board, now_ms: u64) {
+        let mut flags = 0u8;
+        if self.over_pressure_active {
+            flags |= FLAG_OVER_PRESSURE;
+        }
+        if self.flight_state != FlightState::Aborted {
+            flags |= FLAG_MAIN_POWER_RELAY;
+        }
+        if self.valve_on(ActuatorBoardCommands::IgniterOn as u8) {
+            flags |= FLAG_IGNITER_ARMED;
+        }
+        if self.fault_latched {
+            flags |= FLAG_FAULT_LATCH;
+        }
+        // Every board in this sim is always reachable; a real comms-down condition would clear
+        // this bit per board instead.
+        flags |= FLAG_COMMS_OK;
+
+        let mut bytes = Vec::with_capacity(5);
+        bytes.extend_from_slice(&self.warning_counter.to_le_bytes());
+        bytes.extend_from_slice(&self.last_error_code.to_le_bytes());
+        bytes.push(flags);
+
+        if let Ok(pkt) = TelemetryPacket::new(
+            DataType::BoardStatus,
+            &[DataEndpoint::GroundStation],
+            board.sender_id(),
+            now_ms,
+            Arc::from(bytes.as_slice()),
+        ) {
+            self.queued.push_back(pkt);
+        }
+    }
+
     fn queue_housekeeping(&mut self, now_ms: u64) {
         for board in Board::ALL {
+            if self.fault_freezes_board(*board) {
+                continue;
+            }
             self.queue_board_heartbeat(*board, now_ms);
+            self.queue_board_status(*board, now_ms);
         }
 
         let keys = [
@@ -157,22 +1012,37 @@ impl FlightSimState {
         self.queue_umbilical_status(key, on, now_ms);
     }
 
+    /// Whether `board`'s heartbeat/status should be skipped this housekeeping cycle — a `Board`
+    /// fault's `drop_fraction` doubles as its "freeze this heartbeat" probability, so a fully
+    /// dead comms link is just `drop_fraction: 1.0` and a flaky one is anywhere below that.
+    fn fault_freezes_board(&mut self, board: Board) -> bool {
+        let drop_fraction = self
+            .faults
+            .iter()
+            .find(|f| f.target == FaultTarget::Board(board.sender_id().to_string()))
+            .map(|f| f.drop_fraction)
+            .unwrap_or(0.0);
+        drop_fraction > 0.0 && self.rng.random_range(0.0..1.0) < drop_fraction
+    }
+
     fn apply_command(&mut self, cmd: &TelemetryCommand, now_ms: u64) {
         match cmd {
             TelemetryCommand::Abort => {
                 self.launch_time_ms = None;
-                self.set_flight_state(FlightState::Aborted, now_ms);
+                // The wildcard `from: None` edge means this can't actually fail, but route it
+                // through the same table as everything else rather than special-casing it.
+                let _ = self.set_flight_state(FlightState::Aborted, now_ms);
             }
             TelemetryCommand::Launch => {
-                if self.flight_state == FlightState::Armed {
-                    self.launch_time_ms = Some(now_ms);
-                    self.set_flight_state(FlightState::Launch, now_ms);
+                match self.set_flight_state(FlightState::Launch, now_ms) {
+                    Ok(()) => self.launch_time_ms = Some(now_ms),
+                    Err(reason) => self.report_rejected_transition(reason),
                 }
             }
             TelemetryCommand::Dump => {
                 let key = ValveBoardCommands::DumpOpen as u8;
-                let next = !self.valve_on(key);
-                self.valves.insert(key, next);
+                let commanded = !self.valve_on(key);
+                let next = self.set_valve(key, commanded, now_ms);
                 self.queue_umbilical_status(key, next, now_ms);
                 if self.flight_state == FlightState::FillTest && next {
                     self.saw_dump_open_after_n2 = true;
@@ -186,77 +1056,75 @@ impl FlightSimState {
             }
             TelemetryCommand::NormallyOpen => {
                 let key = ValveBoardCommands::NormallyOpenOpen as u8;
-                let next = !self.valve_on(key);
-                self.valves.insert(key, next);
+                let commanded = !self.valve_on(key);
+                let next = self.set_valve(key, commanded, now_ms);
                 self.queue_umbilical_status(key, next, now_ms);
             }
             TelemetryCommand::Pilot => {
                 let key = ValveBoardCommands::PilotOpen as u8;
-                let next = !self.valve_on(key);
-                self.valves.insert(key, next);
+                let commanded = !self.valve_on(key);
+                let next = self.set_valve(key, commanded, now_ms);
                 self.queue_umbilical_status(key, next, now_ms);
             }
             TelemetryCommand::Igniter => {
                 let key = ActuatorBoardCommands::IgniterOn as u8;
-                let next = !self.valve_on(key);
-                self.valves.insert(key, next);
+                let commanded = !self.valve_on(key);
+                let next = self.set_valve(key, commanded, now_ms);
                 self.queue_umbilical_status(key, next, now_ms);
             }
             TelemetryCommand::RetractPlumbing => {
                 let key = ActuatorBoardCommands::RetractPlumbing as u8;
-                self.valves.insert(key, true);
-                self.queue_umbilical_status(key, true, now_ms);
+                let next = self.set_valve(key, true, now_ms);
+                self.queue_umbilical_status(key, next, now_ms);
             }
             TelemetryCommand::Nitrogen => {
                 let key = ActuatorBoardCommands::NitrogenOpen as u8;
-                let next = !self.valve_on(key);
-                self.valves.insert(key, next);
+                let commanded = !self.valve_on(key);
+                let next = self.set_valve(key, commanded, now_ms);
                 self.queue_umbilical_status(key, next, now_ms);
             }
             TelemetryCommand::Nitrous => {
                 let key = ActuatorBoardCommands::NitrousOpen as u8;
-                let next = !self.valve_on(key);
-                self.valves.insert(key, next);
+                let commanded = !self.valve_on(key);
+                let next = self.set_valve(key, commanded, now_ms);
                 self.queue_umbilical_status(key, next, now_ms);
+                // Opening nitrous is the one valve toggle that maps directly onto a ground-sequence
+                // edge (`FillTest` -> `NitrousFill`), so attempt it here and report if it's too
+                // early (fill test not yet passed) instead of only finding out via silent polling
+                // in `update_ground_sequence`.
+                if next {
+                    if let Err(reason) = self.set_flight_state(FlightState::NitrousFill, now_ms) {
+                        self.report_rejected_transition(reason);
+                    }
+                }
             }
         }
 
         self.update_ground_sequence(now_ms);
     }
 
+    /// Advance as far through the ground-fill sequence as `SIM_TRANSITIONS`'s guards currently
+    /// allow, one edge at a time — e.g. `FillTest` -> `NitrousFill` -> `Armed` both fire in the
+    /// same call once nitrous is open, same as the old hand-written `FillTest` arm did. A guard
+    /// not yet holding is just "not yet", not a rejection, so failures here are silent; only an
+    /// operator-initiated attempt (`Launch`, `Nitrous`) reports one.
     fn update_ground_sequence(&mut self, now_ms: u64) {
         if self.launch_time_ms.is_some() {
             return;
         }
 
-        let no_open = !self.valve_on(ValveBoardCommands::NormallyOpenOpen as u8);
-        let dump_closed = !self.valve_on(ValveBoardCommands::DumpOpen as u8);
-        let n2_open = self.valve_on(ActuatorBoardCommands::NitrogenOpen as u8);
-        let n2o_open = self.valve_on(ActuatorBoardCommands::NitrousOpen as u8);
-
-        match self.flight_state {
-            FlightState::Idle => {
-                if no_open && dump_closed {
-                    self.set_flight_state(FlightState::PreFill, now_ms);
-                }
-            }
-            FlightState::PreFill => {
-                if n2_open {
-                    self.set_flight_state(FlightState::NitrogenFill, now_ms);
-                }
+        loop {
+            let next = match self.flight_state {
+                FlightState::Idle => FlightState::PreFill,
+                FlightState::PreFill => FlightState::NitrogenFill,
+                FlightState::NitrogenFill => FlightState::FillTest,
+                FlightState::FillTest => FlightState::NitrousFill,
+                FlightState::NitrousFill => FlightState::Armed,
+                _ => return,
+            };
+            if self.set_flight_state(next, now_ms).is_err() {
+                return;
             }
-            FlightState::NitrogenFill => {
-                if !n2_open {
-                    self.set_flight_state(FlightState::FillTest, now_ms);
-                }
-            }
-            FlightState::FillTest => {
-                if self.saw_dump_open_after_n2 && self.saw_dump_closed_after_n2 && n2o_open {
-                    self.set_flight_state(FlightState::NitrousFill, now_ms);
-                    self.set_flight_state(FlightState::Armed, now_ms);
-                }
-            }
-            _ => {}
         }
     }
 
@@ -265,19 +1133,40 @@ impl FlightSimState {
         let n2o_open = self.valve_on(ActuatorBoardCommands::NitrousOpen as u8);
         let dump_open = self.valve_on(ValveBoardCommands::DumpOpen as u8);
 
-        if n2_open {
-            self.fuel_tank_pressure_psi = (self.fuel_tank_pressure_psi + 0.9).min(125.0);
-        } else if n2o_open && !dump_open {
-            self.fuel_tank_pressure_psi = (self.fuel_tank_pressure_psi + 0.45).min(210.0);
-        } else if dump_open {
-            self.fuel_tank_pressure_psi = (self.fuel_tank_pressure_psi - 1.8).max(0.0);
-        } else {
-            self.fuel_tank_pressure_psi = (self.fuel_tank_pressure_psi - 0.03).max(0.0);
+        self.update_tank(n2_open, n2o_open, dump_open);
+
+        let soft_cap_psi = TANK_BURST_LIMIT_PSI * FUEL_PRESSURE_SOFT_CAP_FRACTION;
+        let over_pressure_now = self.fuel_tank_pressure_psi > soft_cap_psi;
+        if over_pressure_now && !self.over_pressure_active {
+            self.warning_counter = self.warning_counter.wrapping_add(1);
+            self.last_error_code = ERR_FUEL_OVER_PRESSURE;
+            self.fault_latched = true;
         }
+        self.over_pressure_active = over_pressure_now;
 
         if let Some(t0_ms) = self.launch_time_ms {
             let t = (now_ms.saturating_sub(t0_ms) as f32) / 1000.0;
-            self.apply_flight_profile(t, now_ms);
+            let sample = self.trajectory.sample(t, now_ms, &mut self.rng);
+            if let Some(state) = sample.flight_state {
+                // Physics-driven, not operator-initiated: a guard miss here (e.g. a trajectory
+                // source that skips a state) stays silent rather than reporting a "rejection".
+                let _ = self.set_flight_state(state, now_ms);
+            }
+            self.altitude_ft = sample.altitude_ft;
+            self.velocity_fps = sample.velocity_fps;
+            self.accel_g = sample.accel_g;
+            self.roll_dps = sample.roll_dps;
+            self.pitch_dps = sample.pitch_dps;
+            self.yaw_dps = sample.yaw_dps;
+            self.fuel_flow_lpm = sample.fuel_flow_lpm;
+            self.lat = sample.lat;
+            self.lon = sample.lon;
+
+            let dropout = self.trajectory.dropout();
+            if dropout && !self.fdm_dropout_logged {
+                tracing::warn!("flight_sim: trajectory source dropout, holding last known sample");
+            }
+            self.fdm_dropout_logged = dropout;
         } else {
             self.altitude_ft = (self.altitude_ft - 0.5).max(0.0);
             self.velocity_fps = 0.0;
@@ -285,70 +1174,147 @@ impl FlightSimState {
             self.roll_dps = 0.2;
             self.pitch_dps = 0.2;
             self.yaw_dps = 0.3;
-            self.fuel_flow_lpm = if n2_open || n2o_open { 6.0 } else { 0.0 };
+            self.lat = BASE_LAT;
+            self.lon = BASE_LON;
+            self.fuel_flow_lpm = self.ground_fuel_flow_lpm(n2_open, n2o_open, dump_open);
         }
 
         self.battery_a = (1.0 + self.fuel_flow_lpm * 0.12).min(35.0);
         self.battery_v = (12.6 - self.battery_a * 0.03).max(10.5);
+
+        self.update_attitude(now_ms);
     }
 
-    fn apply_flight_profile(&mut self, t: f32, now_ms: u64) {
-        let (state, alt, vel, accel_g, flow_lpm) = if t < 2.0 {
-            (FlightState::Launch, 150.0 * (t / 2.0), 90.0, 3.2, 45.0)
-        } else if t < 34.0 {
-            let p = (t - 2.0) / 32.0;
-            (
-                FlightState::Ascent,
-                150.0 + 9_850.0 * p,
-                330.0 * (1.0 - 0.2 * p),
-                2.1,
-                58.0,
-            )
-        } else if t < 43.0 {
-            let p = (t - 34.0) / 9.0;
-            (
-                FlightState::Coast,
-                10_000.0 + 500.0 * p,
-                120.0 * (1.0 - p),
-                1.0,
-                0.0,
-            )
-        } else if t < 46.0 {
-            (FlightState::Apogee, 10_500.0, 0.0, 1.0, 0.0)
-        } else if t < 54.0 {
-            let p = (t - 46.0) / 8.0;
-            (
-                FlightState::ParachuteDeploy,
-                10_500.0 - 700.0 * p,
-                -80.0,
-                0.7,
-                0.0,
-            )
-        } else if t < 174.0 {
-            let p = (t - 54.0) / 120.0;
-            (
-                FlightState::Descent,
-                (9_800.0 * (1.0 - p)).max(0.0),
-                -85.0,
-                0.95,
-                0.0,
-            )
-        } else if t < 182.0 {
-            (FlightState::Landed, 0.0, 0.0, 1.0, 0.0)
+    /// Lumped thermodynamic update for the oxidizer tank: advances `n2_ullage_moles`,
+    /// `n2o_fill_fraction`, and `tank_temp_k` for this tick's valve state, then derives
+    /// `fuel_tank_pressure_psi` as the sum of the N2O's own saturation vapor pressure (a
+    /// function of `tank_temp_k` alone) and the N2 pressurant's ideal-gas partial pressure over
+    /// whatever ullage volume the liquid fill leaves behind. `NitrousOpen`'s self-pressurization
+    /// and `DumpOpen`'s cooling-driven pressure drop both fall out of those two numbers rather
+    /// than being separate hard-coded ramps.
+    fn update_tank(&mut self, n2_open: bool, n2o_open: bool, dump_open: bool) {
+        if n2_open {
+            self.n2_ullage_moles += N2_FILL_MOLES_PER_TICK;
+        }
+        if n2o_open && !dump_open {
+            self.n2o_fill_fraction = (self.n2o_fill_fraction + N2O_FILL_FRACTION_PER_TICK).min(1.0);
+        }
+        if dump_open {
+            self.n2_ullage_moles = (self.n2_ullage_moles - N2_VENT_MOLES_PER_TICK).max(0.0);
+            self.tank_temp_k = (self.tank_temp_k - DUMP_EVAPORATIVE_COOLING_K_PER_TICK).max(200.0);
         } else {
-            (FlightState::Recovery, 0.0, 0.0, 1.0, 0.0)
+            self.tank_temp_k += (AMBIENT_TEMP_K - self.tank_temp_k) * AMBIENT_RELAX_K_PER_TICK;
+        }
+
+        let ullage_volume_l = (TANK_VOLUME_L * (1.0 - self.n2o_fill_fraction)).max(0.05);
+        let ullage_volume_m3 = ullage_volume_l / 1_000.0;
+        let n2_partial_pressure_pa =
+            self.n2_ullage_moles * GAS_CONSTANT_J_PER_MOL_K * self.tank_temp_k / ullage_volume_m3;
+        let n2_partial_pressure_psi = n2_partial_pressure_pa / PASCALS_PER_PSI;
+
+        self.fuel_tank_pressure_psi = n2o_vapor_pressure_psi(self.tank_temp_k) + n2_partial_pressure_psi;
+    }
+
+    /// Pre-launch ground-ops flow reading, derived from whichever valve is moving gas/liquid
+    /// this tick rather than a flat constant. `DumpOpen` wins ties since the vent is the flow
+    /// an operator watching `FuelFlow` during a fill test actually cares about.
+    fn ground_fuel_flow_lpm(&self, n2_open: bool, n2o_open: bool, dump_open: bool) -> f32 {
+        let tick_minutes = (SENSOR_PERIOD_MS as f32 / 1_000.0) / 60.0;
+        if dump_open {
+            ideal_gas_volume_l(N2_VENT_MOLES_PER_TICK, self.tank_temp_k, self.fuel_tank_pressure_psi)
+                / tick_minutes
+        } else if n2o_open {
+            (N2O_FILL_FRACTION_PER_TICK * TANK_VOLUME_L) / tick_minutes
+        } else if n2_open {
+            ideal_gas_volume_l(N2_FILL_MOLES_PER_TICK, self.tank_temp_k, self.fuel_tank_pressure_psi)
+                / tick_minutes
+        } else {
+            0.0
+        }
+    }
+
+    /// PX4 `attitude_estimator_q`-style complementary filter: integrate the (noisy) gyro rates
+    /// into `est_q` via the exponential map, then rotate it a proportional amount toward the
+    /// direction a gravity-sensing accelerometer would report, instead of `KalmanFilterData`
+    /// just passing `roll_dps`/`pitch_dps`/`yaw_dps` through unfiltered. `true_q` integrates the
+    /// same rates with no noise so there's a gravity vector to measure against at all — this sim
+    /// has no independent ground-truth attitude otherwise.
+    fn update_attitude(&mut self, now_ms: u64) {
+        let dt_s = if self.last_attitude_tick_ms == 0 {
+            0.0
+        } else {
+            (now_ms.saturating_sub(self.last_attitude_tick_ms) as f32 / 1000.0).min(0.25)
         };
+        self.last_attitude_tick_ms = now_ms;
+        if dt_s <= 0.0 {
+            return;
+        }
+
+        let true_rates_rad_s = [
+            self.roll_dps.to_radians(),
+            self.pitch_dps.to_radians(),
+            self.yaw_dps.to_radians(),
+        ];
+        self.true_q = quat_integrate_step(self.true_q, true_rates_rad_s, dt_s);
+
+        let noisy_rates_rad_s = [
+            (self.roll_dps + self.rng.random_range(-0.15..0.15)).to_radians(),
+            (self.pitch_dps + self.rng.random_range(-0.15..0.15)).to_radians(),
+            (self.yaw_dps + self.rng.random_range(-0.45..0.45)).to_radians(),
+        ];
+        self.est_q = quat_integrate_step(self.est_q, noisy_rates_rad_s, dt_s);
 
-        self.set_flight_state(state, now_ms);
-        self.altitude_ft = alt;
-        self.velocity_fps = vel;
-        self.accel_g = accel_g;
-        self.fuel_flow_lpm = flow_lpm;
+        let world_down = [0.0, 0.0, 1.0];
+        let measured_gravity = quat_rotate_vec(quat_conj(self.true_q), world_down);
+        let noisy_measured_gravity = [
+            measured_gravity[0] + self.rng.random_range(-0.02..0.02),
+            measured_gravity[1] + self.rng.random_range(-0.02..0.02),
+            measured_gravity[2] + self.rng.random_range(-0.02..0.02),
+        ];
+        let predicted_gravity = quat_rotate_vec(quat_conj(self.est_q), world_down);
+        let correction = cross(predicted_gravity, noisy_measured_gravity);
+        let correction_rate = [
+            correction[0] * ATTITUDE_ACCEL_CORRECTION_GAIN,
+            correction[1] * ATTITUDE_ACCEL_CORRECTION_GAIN,
+            correction[2] * ATTITUDE_ACCEL_CORRECTION_GAIN,
+        ];
+        self.est_q = quat_integrate_step(self.est_q, correction_rate, dt_s);
+    }
 
-        let mut rng = rand::rng();
-        self.roll_dps = rng.random_range(-2.0..2.0);
-        self.pitch_dps = rng.random_range(-2.0..2.0);
-        self.yaw_dps = rng.random_range(-6.0..6.0);
+    /// Whether this tick's `dtype` sample should be dropped, per its `DataType` fault's
+    /// `drop_fraction` (0.0 if none installed).
+    fn fault_drops(&mut self, dtype: DataType) -> bool {
+        let drop_fraction = self
+            .faults
+            .iter()
+            .find(|f| f.target == FaultTarget::DataType(dtype.as_str().to_string()))
+            .map(|f| f.drop_fraction)
+            .unwrap_or(0.0);
+        drop_fraction > 0.0 && self.rng.random_range(0.0..1.0) < drop_fraction
+    }
+
+    /// Applies `dtype`'s fault (if any) to an already-computed sample in place: `stuck_at`
+    /// overrides every channel outright, otherwise `bias` plus the accumulated `drift_accum`
+    /// (which advances every call, faulted tick or not) is added and `spike_amplitude` layers
+    /// extra uniform noise on top.
+    fn apply_sensor_fault(&mut self, dtype: DataType, values: &mut [f32]) {
+        let key = dtype.as_str().to_string();
+        let Some(idx) = self.faults.iter().position(|f| f.target == FaultTarget::DataType(key)) else {
+            return;
+        };
+        self.faults[idx].drift_accum += self.faults[idx].drift_per_tick;
+        let fault = self.faults[idx].clone();
+
+        for v in values.iter_mut() {
+            if let Some(stuck) = fault.stuck_at {
+                *v = stuck;
+                continue;
+            }
+            *v += fault.bias + fault.drift_accum;
+            if fault.spike_amplitude > 0.0 {
+                *v += self.rng.random_range(-fault.spike_amplitude..fault.spike_amplitude);
+            }
+        }
     }
 
     fn next_sensor_packet(&mut self, now_ms: u64) -> TelemetryResult<TelemetryPacket> {
@@ -361,33 +1327,56 @@ impl FlightSimState {
             DataType::BarometerData,
             DataType::FuelTankPressure,
             DataType::FuelFlow,
+            DataType::TankTemperature,
             DataType::BatteryVoltage,
             DataType::BatteryCurrent,
             DataType::GpsData,
         ];
-        let dtype = seq[self.next_sensor_idx % seq.len()];
+        let mut dtype = seq[self.next_sensor_idx % seq.len()];
         self.next_sensor_idx = (self.next_sensor_idx + 1) % seq.len();
+        // A dropped channel just cedes its turn to the next one in `seq` rather than this call
+        // returning nothing — `next_state_aware_packet` always wants a packet back. Bounded by
+        // `seq.len()` so an all-channels-faulted config can't spin forever.
+        for _ in 0..seq.len() {
+            if !self.fault_drops(dtype) {
+                break;
+            }
+            dtype = seq[self.next_sensor_idx % seq.len()];
+            self.next_sensor_idx = (self.next_sensor_idx + 1) % seq.len();
+        }
 
-        let mut rng = rand::rng();
-        let values: Vec<f32> = match dtype {
+        let mut values: Vec<f32> = match dtype {
             DataType::GyroData => vec![
-                self.roll_dps + rng.random_range(-0.15..0.15),
-                self.pitch_dps + rng.random_range(-0.15..0.15),
-                self.yaw_dps + rng.random_range(-0.45..0.45),
+                self.roll_dps + self.rng.random_range(-0.15..0.15),
+                self.pitch_dps + self.rng.random_range(-0.15..0.15),
+                self.yaw_dps + self.rng.random_range(-0.45..0.45),
             ],
             DataType::AccelData => {
-                let az = self.accel_g * 9.80665 + rng.random_range(-0.25..0.25);
+                let az = self.accel_g * 9.80665 + self.rng.random_range(-0.25..0.25);
                 vec![
-                    rng.random_range(-0.35..0.35),
-                    rng.random_range(-0.35..0.35),
+                    self.rng.random_range(-0.35..0.35),
+                    self.rng.random_range(-0.35..0.35),
                     az,
                 ]
             }
-            DataType::KalmanFilterData => vec![
-                self.altitude_ft * 0.3048,
-                self.velocity_fps * 0.3048,
-                self.accel_g,
-            ],
+            DataType::KalmanFilterData => {
+                // Indices 0-2 keep their original [altitude_m, velocity_mps, accel_g] meaning
+                // (`deploy_advisor` still reads index 1 as vertical velocity) — the estimator's
+                // quaternion fills the next 4 of the 8 slots a `TelemetryRow` carries, and
+                // the last slot holds just the derived yaw since roll/pitch would overflow it;
+                // a consumer wanting those can derive them from the quaternion itself.
+                let (_, _, yaw_deg) = quat_to_euler_deg(self.est_q);
+                vec![
+                    self.altitude_ft * 0.3048,
+                    self.velocity_fps * 0.3048,
+                    self.accel_g,
+                    self.est_q[0],
+                    self.est_q[1],
+                    self.est_q[2],
+                    self.est_q[3],
+                    yaw_deg,
+                ]
+            }
             DataType::BarometerData => {
                 let altitude_m = self.altitude_ft * 0.3048;
                 let pressure_pa = 101_325.0_f32 * f32::powf(1.0 - altitude_m / 44_330.0, 5.255);
@@ -396,19 +1385,21 @@ impl FlightSimState {
             }
             DataType::FuelTankPressure => vec![self.fuel_tank_pressure_psi],
             DataType::FuelFlow => vec![self.fuel_flow_lpm],
+            DataType::TankTemperature => vec![self.tank_temp_k - 273.15],
             DataType::BatteryVoltage => vec![self.battery_v],
             DataType::BatteryCurrent => vec![self.battery_a],
             DataType::GpsData => {
-                let dlat_deg = (self.altitude_ft / 5_280.0) * 0.00001;
-                let dlon_deg = dlat_deg * 0.8;
                 vec![
-                    BASE_LAT + dlat_deg + rng.random_range(-0.00002..0.00002),
-                    BASE_LON + dlon_deg + rng.random_range(-0.00002..0.00002),
+                    self.lat + self.rng.random_range(-0.00002..0.00002),
+                    self.lon + self.rng.random_range(-0.00002..0.00002),
                     self.altitude_ft * 0.3048,
                 ]
             }
             _ => vec![0.0],
         };
+        self.apply_sensor_fault(dtype, &mut values);
+
+        self.record_packet(now_ms, dtype.as_str(), &values);
 
         let mut bytes = Vec::with_capacity(values.len() * 4);
         for v in values {
@@ -423,6 +1414,102 @@ impl FlightSimState {
             Arc::from(bytes.as_slice()),
         )
     }
+
+    /// Effective "now" for this tick: the scenario's virtual clock if one is driving playback,
+    /// otherwise the real wall clock passed in.
+    fn now_ms(&self, wall_clock_ms: u64) -> u64 {
+        self.virtual_clock_ms.unwrap_or(wall_clock_ms)
+    }
+
+    /// Replays every scripted event at or before `now_ms` that hasn't fired yet, exactly as
+    /// `apply_command` would take them live, plus an optional forced `flight_state` cue for
+    /// demos that need to skip ahead of what a single command reaches on its own.
+    fn drain_due_scenario_events(&mut self, now_ms: u64) {
+        loop {
+            let event = match &self.scenario {
+                Some(scenario) => scenario.events.get(self.scenario_next_idx).cloned(),
+                None => return,
+            };
+            let Some(event) = event else { return };
+            if event.at_ms > now_ms {
+                return;
+            }
+            self.scenario_next_idx += 1;
+
+            match crate::sequences::command_from_name(&event.command) {
+                Some(cmd) => self.apply_command(&cmd, now_ms),
+                None => tracing::warn!(
+                    "flight_sim: scenario event references unknown command {:?}",
+                    event.command
+                ),
+            }
+            if let Some(fs_name) = &event.flight_state {
+                match flight_state_from_name(fs_name) {
+                    Some(fs) => self.force_flight_state(fs, now_ms),
+                    None => tracing::warn!(
+                        "flight_sim: scenario event references unknown flight_state {:?}",
+                        fs_name
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Appends one JSON line (`RecordedPacket`) to the `GS_SIM_RECORD_PATH` log, if open. Only
+    /// `next_sensor_packet`'s high-rate channel is recorded — `queue_*`'s state/heartbeat/status
+    /// packets replay implicitly since they're deterministic functions of the same scripted
+    /// commands a [`Scenario`] already replays.
+    fn record_packet(&mut self, now_ms: u64, data_type: &str, values: &[f32]) {
+        let Some(writer) = self.recorder.as_mut() else {
+            return;
+        };
+        let record = RecordedPacket {
+            timestamp_ms: now_ms,
+            data_type: data_type.to_string(),
+            values: values.to_vec(),
+        };
+        if let Ok(line) = serde_json::to_string(&record) {
+            let _ = writeln!(writer, "{line}");
+            let _ = writer.flush();
+        }
+    }
+}
+
+/// One recorded line in a `GS_SIM_RECORD_PATH` log — enough to reconstruct the packet's payload
+/// bytes exactly (`next_sensor_packet` already builds them as `to_le_bytes()` of this same
+/// `values` vec) for a byte-for-byte replay.
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedPacket {
+    timestamp_ms: u64,
+    data_type: String,
+    values: Vec<f32>,
+}
+
+/// Reads a `GS_SIM_RECORD_PATH` log back and reconstructs each packet's payload bytes, in
+/// order — the byte-for-byte replay the recording mode exists for. Returns `(timestamp_ms,
+/// data_type, bytes)` tuples rather than `TelemetryPacket`s directly since reconstructing one
+/// also needs a sender id this log doesn't carry (it's a per-run replay aid, not a second
+/// telemetry source the rest of the app consumes).
+#[cfg(feature = "testing")]
+#[allow(dead_code)]
+fn replay_recorded_log(path: &std::path::Path) -> Result<Vec<(u64, String, Vec<u8>)>, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("Failed to open {path:?}: {e}"))?;
+    let mut out = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| format!("Failed to read {path:?}: {e}"))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let rec: RecordedPacket =
+            serde_json::from_str(&line).map_err(|e| format!("Invalid recorded line: {e}"))?;
+        let mut bytes = Vec::with_capacity(rec.values.len() * 4);
+        for v in rec.values {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        out.push((rec.timestamp_ms, rec.data_type, bytes));
+    }
+    Ok(out)
 }
 
 #[cfg(feature = "testing")]
@@ -432,9 +1519,10 @@ fn sender_for_datatype(dtype: DataType) -> &'static str {
         | DataType::AccelData
         | DataType::KalmanFilterData
         | DataType::FlightState => Board::FlightComputer.sender_id(),
-        DataType::BarometerData | DataType::FuelFlow | DataType::FuelTankPressure => {
-            Board::DaqBoard.sender_id()
-        }
+        DataType::BarometerData
+        | DataType::FuelFlow
+        | DataType::FuelTankPressure
+        | DataType::TankTemperature => Board::DaqBoard.sender_id(),
         DataType::BatteryVoltage | DataType::BatteryCurrent => Board::PowerBoard.sender_id(),
         DataType::GpsData => Board::GatewayBoard.sender_id(),
         _ => Board::GroundStation.sender_id(),
@@ -462,44 +1550,68 @@ fn sim() -> &'static Mutex<FlightSimState> {
 
 #[cfg(feature = "testing")]
 pub fn handle_command(cmd: &TelemetryCommand) -> bool {
-    let now_ms = get_current_timestamp_ms();
     let mut s = sim().lock().expect("flight sim mutex poisoned");
+    let now_ms = s.now_ms(get_current_timestamp_ms());
     s.apply_command(cmd, now_ms);
     true
 }
 
+/// Install or replace the fault for `spec.target`, callable like `handle_command` — tests and
+/// live demos toggle degraded-hardware scenarios through this one entry point. Passing a fresh
+/// `FaultSpec::new(target)` (every knob off) clears whatever fault was there before for the
+/// same target.
 #[cfg(feature = "testing")]
-pub fn next_state_aware_packet() -> TelemetryResult<TelemetryPacket> {
-    let now_ms = get_current_timestamp_ms();
+pub fn set_fault(spec: FaultSpec) {
     let mut s = sim().lock().expect("flight sim mutex poisoned");
+    s.faults.retain(|f| f.target != spec.target);
+    s.faults.push(spec);
+}
 
-    if let Some(pkt) = s.queued.pop_front() {
-        return Ok(pkt);
-    }
+#[cfg(feature = "testing")]
+pub fn next_state_aware_packet() -> TelemetryResult<TelemetryPacket> {
+    let mut s = sim().lock().expect("flight sim mutex poisoned");
+    let now_ms = s.now_ms(get_current_timestamp_ms());
+    s.apply_delayed_valve_changes(now_ms);
+    s.drain_due_scenario_events(now_ms);
 
-    if now_ms.saturating_sub(s.last_housekeeping_emit_ms) >= HOUSEKEEPING_PERIOD_MS {
-        s.last_housekeeping_emit_ms = now_ms;
-        s.queue_housekeeping(now_ms);
+    let result: TelemetryResult<TelemetryPacket> = (|| {
         if let Some(pkt) = s.queued.pop_front() {
             return Ok(pkt);
         }
-    }
 
-    if now_ms.saturating_sub(s.last_state_emit_ms) >= FLIGHT_STATE_PERIOD_MS {
-        s.last_state_emit_ms = now_ms;
-        s.queue_flight_state(now_ms);
-        if let Some(pkt) = s.queued.pop_front() {
-            return Ok(pkt);
+        if now_ms.saturating_sub(s.last_housekeeping_emit_ms) >= HOUSEKEEPING_PERIOD_MS {
+            s.last_housekeeping_emit_ms = now_ms;
+            s.queue_housekeeping(now_ms);
+            if let Some(pkt) = s.queued.pop_front() {
+                return Ok(pkt);
+            }
         }
-    }
 
-    if now_ms.saturating_sub(s.last_sensor_emit_ms) < SENSOR_PERIOD_MS {
-        // Keep packets flowing even under very fast poll cadence.
-        return s.next_sensor_packet(now_ms);
+        if now_ms.saturating_sub(s.last_state_emit_ms) >= FLIGHT_STATE_PERIOD_MS {
+            s.last_state_emit_ms = now_ms;
+            s.queue_flight_state(now_ms);
+            if let Some(pkt) = s.queued.pop_front() {
+                return Ok(pkt);
+            }
+        }
+
+        if now_ms.saturating_sub(s.last_sensor_emit_ms) < SENSOR_PERIOD_MS {
+            // Keep packets flowing even under very fast poll cadence.
+            return s.next_sensor_packet(now_ms);
+        }
+
+        s.last_sensor_emit_ms = now_ms;
+        s.next_sensor_packet(now_ms)
+    })();
+
+    // A scenario's virtual clock only advances here, by one sensor tick per packet polled —
+    // wall-clock mode needs no bookkeeping since `get_current_timestamp_ms` already moves on its
+    // own.
+    if s.virtual_clock_ms.is_some() {
+        s.virtual_clock_ms = Some(now_ms + SENSOR_PERIOD_MS);
     }
 
-    s.last_sensor_emit_ms = now_ms;
-    s.next_sensor_packet(now_ms)
+    result
 }
 
 #[cfg(not(feature = "testing"))]
@@ -507,7 +1619,231 @@ pub fn handle_command(_cmd: &TelemetryCommand) -> bool {
     false
 }
 
+#[cfg(not(feature = "testing"))]
+pub fn set_fault(_spec: FaultSpec) {}
+
 #[cfg(not(feature = "testing"))]
 pub fn next_state_aware_packet() -> TelemetryResult<TelemetryPacket> {
     unreachable!("flight sim only available with testing feature")
 }
+
+#[cfg(all(feature = "testing", test))]
+mod tests {
+    use super::*;
+
+    /// Drive the ground-fill sequence's valve toggles through to `Armed`, same edges
+    /// `update_ground_sequence`/`SIM_TRANSITIONS` are meant to walk.
+    fn arm(s: &mut FlightSimState) {
+        s.apply_command(&TelemetryCommand::Igniter, 0); // arbitrary no-op trigger: Idle -> PreFill
+        s.apply_command(&TelemetryCommand::Nitrogen, 0); // open N2: PreFill -> NitrogenFill
+        s.apply_command(&TelemetryCommand::Nitrogen, 0); // close N2: NitrogenFill -> FillTest
+        s.apply_command(&TelemetryCommand::Dump, 0); // open dump during FillTest
+        s.apply_command(&TelemetryCommand::Dump, 0); // close dump during FillTest
+        s.apply_command(&TelemetryCommand::Nitrous, 0); // open N2O: FillTest -> NitrousFill -> Armed
+    }
+
+    #[test]
+    fn ground_sequence_reaches_armed_in_order() {
+        let mut s = FlightSimState::new();
+        assert_eq!(s.flight_state, FlightState::Idle);
+        arm(&mut s);
+        assert_eq!(s.flight_state, FlightState::Armed);
+        assert_eq!(s.warning_counter, 0);
+    }
+
+    #[test]
+    fn nitrous_before_fill_test_is_rejected() {
+        let mut s = FlightSimState::new();
+        s.apply_command(&TelemetryCommand::Nitrous, 0);
+        assert_eq!(s.flight_state, FlightState::Idle);
+        assert_eq!(s.warning_counter, 1);
+        assert_eq!(s.last_error_code, ERR_COMMAND_REJECTED);
+    }
+
+    #[test]
+    fn launch_rejected_unless_armed() {
+        let mut s = FlightSimState::new();
+        s.apply_command(&TelemetryCommand::Launch, 0);
+        assert_eq!(s.flight_state, FlightState::Idle);
+        assert!(s.launch_time_ms.is_none());
+        assert_eq!(s.warning_counter, 1);
+        assert_eq!(s.last_error_code, ERR_COMMAND_REJECTED);
+    }
+
+    #[test]
+    fn launch_accepted_once_armed() {
+        let mut s = FlightSimState::new();
+        arm(&mut s);
+        s.apply_command(&TelemetryCommand::Launch, 1_000);
+        assert_eq!(s.flight_state, FlightState::Launch);
+        assert_eq!(s.launch_time_ms, Some(1_000));
+    }
+
+    #[test]
+    fn abort_legal_from_any_state() {
+        let mut s = FlightSimState::new();
+        arm(&mut s);
+        s.apply_command(&TelemetryCommand::Abort, 0);
+        assert_eq!(s.flight_state, FlightState::Aborted);
+    }
+
+    /// Two fresh instances with no `GS_SIM_SEED` set both fall back to `DEFAULT_SIM_SEED`, so
+    /// the same sequence of ticks must produce identical noise draws and attitude state —
+    /// the reproducibility `Scenario::seed` depends on.
+    #[test]
+    fn unseeded_instances_are_deterministic_with_each_other() {
+        let mut a = FlightSimState::new();
+        let mut b = FlightSimState::new();
+        arm(&mut a);
+        arm(&mut b);
+        a.apply_command(&TelemetryCommand::Launch, 1_000);
+        b.apply_command(&TelemetryCommand::Launch, 1_000);
+        for i in 0..20 {
+            let now_ms = 1_000 + i * SENSOR_PERIOD_MS;
+            let pa = a.next_sensor_packet(now_ms).expect("packet");
+            let pb = b.next_sensor_packet(now_ms).expect("packet");
+            assert_eq!(pa.data_as_f32().unwrap(), pb.data_as_f32().unwrap());
+        }
+        assert_eq!(a.est_q, b.est_q);
+    }
+
+    #[test]
+    fn scenario_events_fire_only_once_virtual_clock_reaches_them() {
+        let mut s = FlightSimState::new();
+        s.scenario = Some(Scenario {
+            seed: DEFAULT_SIM_SEED,
+            events: vec![ScenarioEvent {
+                at_ms: 5_000,
+                command: "Abort".to_string(),
+                flight_state: None,
+            }],
+        });
+
+        s.drain_due_scenario_events(1_000);
+        assert_eq!(s.flight_state, FlightState::Idle);
+        assert_eq!(s.scenario_next_idx, 0);
+
+        s.drain_due_scenario_events(5_000);
+        assert_eq!(s.flight_state, FlightState::Aborted);
+        assert_eq!(s.scenario_next_idx, 1);
+    }
+
+    #[test]
+    fn scenario_event_can_force_a_flight_state() {
+        let mut s = FlightSimState::new();
+        s.scenario = Some(Scenario {
+            seed: DEFAULT_SIM_SEED,
+            events: vec![ScenarioEvent {
+                at_ms: 0,
+                command: "Nitrogen".to_string(),
+                flight_state: Some("Coast".to_string()),
+            }],
+        });
+
+        s.drain_due_scenario_events(0);
+        assert_eq!(s.flight_state, FlightState::Coast);
+    }
+
+    #[test]
+    fn nitrogen_fill_raises_pressure_via_ideal_gas() {
+        let mut s = FlightSimState::new();
+        let before = s.fuel_tank_pressure_psi;
+        s.update_tank(true, false, false);
+        assert!(s.fuel_tank_pressure_psi > before);
+        assert!(s.n2_ullage_moles > 0.0);
+    }
+
+    #[test]
+    fn nitrous_fill_self_pressurizes_by_shrinking_ullage() {
+        let mut s = FlightSimState::new();
+        s.update_tank(true, false, false); // give the ullage some N2 to compress first
+        let before = s.fuel_tank_pressure_psi;
+        for _ in 0..10 {
+            s.update_tank(false, true, false);
+        }
+        assert!(s.n2o_fill_fraction > 0.0);
+        assert!(s.fuel_tank_pressure_psi > before);
+    }
+
+    #[test]
+    fn dump_cools_the_tank_and_lowers_vapor_pressure() {
+        let mut s = FlightSimState::new();
+        s.update_tank(true, false, false);
+        let before_temp = s.tank_temp_k;
+        let before_pressure = s.fuel_tank_pressure_psi;
+        for _ in 0..10 {
+            s.update_tank(false, false, true);
+        }
+        assert!(s.tank_temp_k < before_temp);
+        assert!(s.fuel_tank_pressure_psi < before_pressure);
+    }
+
+    #[test]
+    fn stuck_valve_ignores_commanded_toggle() {
+        let mut s = FlightSimState::new();
+        let key = ActuatorBoardCommands::NitrogenOpen as u8;
+        s.faults.push(FaultSpec { stuck_valve: true, ..FaultSpec::new(FaultTarget::Valve(key)) });
+        s.apply_command(&TelemetryCommand::Nitrogen, 0);
+        assert!(!s.valve_on(key));
+    }
+
+    #[test]
+    fn slow_response_valve_reports_toggle_only_after_delay() {
+        let mut s = FlightSimState::new();
+        let key = ValveBoardCommands::PilotOpen as u8;
+        s.faults.push(FaultSpec {
+            response_delay_ms: 500,
+            ..FaultSpec::new(FaultTarget::Valve(key))
+        });
+        s.apply_command(&TelemetryCommand::Pilot, 0);
+        assert!(!s.valve_on(key));
+        s.apply_delayed_valve_changes(400);
+        assert!(!s.valve_on(key));
+        s.apply_delayed_valve_changes(500);
+        assert!(s.valve_on(key));
+    }
+
+    #[test]
+    fn sensor_fault_stuck_at_overrides_every_sample() {
+        let mut s = FlightSimState::new();
+        s.faults.push(FaultSpec {
+            stuck_at: Some(42.0),
+            ..FaultSpec::new(FaultTarget::DataType(DataType::BatteryVoltage.as_str().to_string()))
+        });
+        let mut saw_battery_voltage = false;
+        for i in 0..20 {
+            let pkt = s.next_sensor_packet(i * SENSOR_PERIOD_MS).expect("packet");
+            if pkt.data_type().as_str() == "BATTERY_VOLTAGE" {
+                assert_eq!(pkt.data_as_f32().unwrap(), vec![42.0]);
+                saw_battery_voltage = true;
+            }
+        }
+        assert!(saw_battery_voltage);
+    }
+
+    #[test]
+    fn sensor_fault_full_drop_fraction_skips_the_channel_entirely() {
+        let mut s = FlightSimState::new();
+        s.faults.push(FaultSpec {
+            drop_fraction: 1.0,
+            ..FaultSpec::new(FaultTarget::DataType(DataType::GpsData.as_str().to_string()))
+        });
+        for i in 0..20 {
+            let pkt = s.next_sensor_packet(i * SENSOR_PERIOD_MS).expect("packet");
+            assert_ne!(pkt.data_type().as_str(), "GPS_DATA");
+        }
+    }
+
+    #[test]
+    fn board_fault_freezes_its_heartbeat_and_status() {
+        let mut a = FlightSimState::new();
+        let mut b = FlightSimState::new();
+        b.faults.push(FaultSpec {
+            drop_fraction: 1.0,
+            ..FaultSpec::new(FaultTarget::Board(Board::FlightComputer.sender_id().to_string()))
+        });
+        a.queue_housekeeping(0);
+        b.queue_housekeeping(0);
+        assert_eq!(b.queued.len(), a.queued.len() - 2);
+    }
+}
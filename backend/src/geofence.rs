@@ -0,0 +1,279 @@
+//! GPS/altitude geofence, modeled on PX4 navigator's geofence check: an ordered lon/lat polygon
+//! plus a min/max altitude band that every `GpsData`/`BarometerData` packet is checked against.
+//! A single breach warns; one that persists past `breach_dwell_count` escalates to an abort, so
+//! a lone noisy fix doesn't scrub the flight but a real excursion does.
+
+use crate::state::{AppState, CommandRequest};
+use crate::web::{emit_error, emit_warning};
+use groundstation_shared::TelemetryCommand;
+use sedsprintf_rs_2026::config::DataType;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+const DEFAULT_GEOFENCE_PATH: &str = "layout/geofence.json";
+
+/// One lon/lat vertex (WGS84 degrees) of the fence polygon.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Vertex {
+    pub lon: f64,
+    pub lat: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeofenceConfig {
+    pub version: u32,
+    /// Ordered polygon vertices (lon/lat, WGS84) — at least 3, else containment is meaningless.
+    pub polygon: Vec<Vertex>,
+    pub altitude_floor_m: f64,
+    pub altitude_ceiling_m: f64,
+    /// Consecutive breach readings tolerated before escalating a `WarningMsg` into an
+    /// `ErrorMsg` + `Abort`.
+    pub breach_dwell_count: u32,
+}
+
+impl GeofenceConfig {
+    /// Checks polygon size and that the altitude band isn't inverted, so a bad hand-edited
+    /// config fails loudly at load time instead of silently admitting everything (or nothing).
+    pub fn validate(&self) -> Result<(), String> {
+        if self.polygon.len() < 3 {
+            return Err("geofence polygon needs at least 3 vertices".to_string());
+        }
+        if self.altitude_floor_m >= self.altitude_ceiling_m {
+            return Err("altitude_floor_m must be below altitude_ceiling_m".to_string());
+        }
+        Ok(())
+    }
+
+    /// Standard ray-casting point-in-polygon test: counts how many polygon edges a horizontal
+    /// ray cast from `(lon, lat)` crosses. An edge crosses when its endpoints straddle `lat`
+    /// and the crossing longitude lies to the right of `lon`; an odd crossing count means the
+    /// point is inside.
+    pub fn contains_lonlat(&self, lon: f64, lat: f64) -> bool {
+        let mut inside = false;
+        let n = self.polygon.len();
+        for i in 0..n {
+            let a = self.polygon[i];
+            let b = self.polygon[(i + 1) % n];
+            let straddles = (a.lat > lat) != (b.lat > lat);
+            if straddles {
+                let x_cross = (b.lon - a.lon) * (lat - a.lat) / (b.lat - a.lat) + a.lon;
+                if lon < x_cross {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    pub fn altitude_ok(&self, altitude_m: f64) -> bool {
+        (self.altitude_floor_m..=self.altitude_ceiling_m).contains(&altitude_m)
+    }
+}
+
+pub fn geofence_path() -> PathBuf {
+    if let Ok(path) = std::env::var("GS_GEOFENCE_PATH") {
+        return PathBuf::from(path);
+    }
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_GEOFENCE_PATH)
+}
+
+/// Reads and validates the geofence config file, exactly like `layout::load_layout` reads
+/// `LayoutConfig` — no fallback here, that's `effective_geofence_config`'s job.
+pub fn load_geofence_config_file() -> Result<GeofenceConfig, String> {
+    let path = geofence_path();
+    let raw = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read geofence config {path:?}: {e}"))?;
+    let cfg: GeofenceConfig =
+        serde_json::from_str(&raw).map_err(|e| format!("Invalid geofence config JSON: {e}"))?;
+    cfg.validate()?;
+    Ok(cfg)
+}
+
+/// A small box around the dummy/sim launch site (the `BASE_LAT`/`BASE_LON` used throughout
+/// `dummy_packets`/`flight_sim`) with a generous altitude ceiling above the simulated apogee —
+/// used whenever no site-specific geofence file is present so a campaign that hasn't authored
+/// one yet still flies with a sane default fence instead of none at all.
+pub fn default_geofence_config() -> GeofenceConfig {
+    const BASE_LAT: f64 = 31.7619;
+    const BASE_LON: f64 = -106.485;
+    const HALF_WIDTH_DEG: f64 = 0.05;
+
+    GeofenceConfig {
+        version: 1,
+        polygon: vec![
+            Vertex { lon: BASE_LON - HALF_WIDTH_DEG, lat: BASE_LAT - HALF_WIDTH_DEG },
+            Vertex { lon: BASE_LON + HALF_WIDTH_DEG, lat: BASE_LAT - HALF_WIDTH_DEG },
+            Vertex { lon: BASE_LON + HALF_WIDTH_DEG, lat: BASE_LAT + HALF_WIDTH_DEG },
+            Vertex { lon: BASE_LON - HALF_WIDTH_DEG, lat: BASE_LAT + HALF_WIDTH_DEG },
+        ],
+        altitude_floor_m: -50.0,
+        altitude_ceiling_m: 5_000.0,
+        breach_dwell_count: 3,
+    }
+}
+
+/// Loads the geofence config file, falling back to [`default_geofence_config`] if it's missing
+/// or fails to parse/validate.
+pub fn effective_geofence_config() -> GeofenceConfig {
+    match load_geofence_config_file() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            eprintln!("Geofence config fallback to built-in default: {e}");
+            default_geofence_config()
+        }
+    }
+}
+
+/// Live breach-tracking state, one per running `start_geofence_task`.
+#[derive(Default)]
+struct GeofenceRuntime {
+    breach_streak: u32,
+    aborted: bool,
+}
+
+impl GeofenceRuntime {
+    /// Folds one containment/altitude check into the dwell counter and returns what (if
+    /// anything) changed: `None` while clear, `Some(false)` on the breach that just crossed
+    /// `breach_dwell_count` (abort), `Some(true)` on every breach before that (warn-only).
+    fn observe(&mut self, cfg: &GeofenceConfig, in_bounds: bool) -> Option<bool> {
+        if in_bounds {
+            self.breach_streak = 0;
+            self.aborted = false;
+            return None;
+        }
+
+        self.breach_streak += 1;
+        if self.breach_streak <= cfg.breach_dwell_count {
+            return Some(true);
+        }
+        if self.aborted {
+            return None; // already escalated once for this excursion
+        }
+        self.aborted = true;
+        Some(false)
+    }
+}
+
+fn describe_breach(lon: f64, lat: f64, altitude_m: Option<f64>, cfg: &GeofenceConfig) -> String {
+    let out_of_polygon = !cfg.contains_lonlat(lon, lat);
+    let out_of_altitude = altitude_m.is_some_and(|a| !cfg.altitude_ok(a));
+    match (out_of_polygon, out_of_altitude) {
+        (true, true) => format!(
+            "Geofence breach: ({lon:.6}, {lat:.6}) is outside the fence polygon and altitude is out of band"
+        ),
+        (true, false) => format!("Geofence breach: ({lon:.6}, {lat:.6}) is outside the fence polygon"),
+        (false, true) => format!(
+            "Geofence breach: altitude {:.0}m is outside [{:.0}, {:.0}]m",
+            altitude_m.unwrap_or_default(),
+            cfg.altitude_floor_m,
+            cfg.altitude_ceiling_m
+        ),
+        (false, false) => "Geofence breach cleared".to_string(),
+    }
+}
+
+/// Spawns the background task that watches the ring buffer for `GpsData`/`BarometerData`
+/// packets and checks each one against `effective_geofence_config()`. On the first breach it
+/// emits a `WarningMsg`; if the breach persists past `breach_dwell_count` ticks it escalates to
+/// an `ErrorMsg` and pushes an `Abort` onto `cmd_tx` — the same channel the frontend's Abort
+/// button and the GPIO abort pin use, so it's gated/acked identically.
+pub fn start_geofence_task(state: Arc<AppState>) {
+    let cfg = effective_geofence_config();
+
+    tokio::spawn(async move {
+        let mut tick = tokio::time::interval(Duration::from_millis(500));
+        let mut runtime = GeofenceRuntime::default();
+
+        let mut last_lon: Option<f64> = None;
+        let mut last_lat: Option<f64> = None;
+        let mut last_altitude_m: Option<f64> = None;
+
+        loop {
+            tick.tick().await;
+
+            let packets = {
+                let rb = state.ring_buffer.lock().unwrap();
+                rb.recent(rb.len()).into_iter().cloned().collect::<Vec<_>>()
+            };
+
+            for pkt in packets {
+                let Ok(values) = pkt.data_as_f32() else { continue };
+                match pkt.data_type() {
+                    DataType::GpsData => {
+                        if let (Some(lat), Some(lon)) = (values.first(), values.get(1)) {
+                            last_lat = Some(*lat as f64);
+                            last_lon = Some(*lon as f64);
+                        }
+                        if let Some(altitude_m) = values.get(2) {
+                            last_altitude_m = Some(*altitude_m as f64);
+                        }
+                    }
+                    DataType::BarometerData => {
+                        if let Some(altitude_m) = values.get(2) {
+                            last_altitude_m = Some(*altitude_m as f64);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let (Some(lon), Some(lat)) = (last_lon, last_lat) else { continue };
+            let in_polygon = cfg.contains_lonlat(lon, lat);
+            let in_altitude = last_altitude_m.is_none_or(|a| cfg.altitude_ok(a));
+
+            match runtime.observe(&cfg, in_polygon && in_altitude) {
+                None => {}
+                Some(true) => emit_warning(&state, describe_breach(lon, lat, last_altitude_m, &cfg)),
+                Some(false) => {
+                    let message = describe_breach(lon, lat, last_altitude_m, &cfg);
+                    emit_error(&state, format!("{message} — aborting"));
+                    let _ = state.cmd_tx.try_send(CommandRequest {
+                        id: None,
+                        cmd: TelemetryCommand::Abort,
+                        operator_id: "geofence".to_string(),
+                        operator_role: "flight_director".to_string(),
+                    });
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_geofence_config_is_valid() {
+        default_geofence_config().validate().expect("default geofence should validate");
+    }
+
+    #[test]
+    fn polygon_containment_ray_cast() {
+        let cfg = default_geofence_config();
+        assert!(cfg.contains_lonlat(-106.485, 31.7619)); // launch site itself
+        assert!(!cfg.contains_lonlat(-100.0, 31.7619)); // far outside the box
+    }
+
+    #[test]
+    fn altitude_band() {
+        let cfg = default_geofence_config();
+        assert!(cfg.altitude_ok(0.0));
+        assert!(!cfg.altitude_ok(-100.0));
+        assert!(!cfg.altitude_ok(10_000.0));
+    }
+
+    #[test]
+    fn breach_escalates_after_dwell_count() {
+        let cfg = GeofenceConfig { breach_dwell_count: 2, ..default_geofence_config() };
+        let mut runtime = GeofenceRuntime::default();
+
+        assert_eq!(runtime.observe(&cfg, false), Some(true));
+        assert_eq!(runtime.observe(&cfg, false), Some(true));
+        assert_eq!(runtime.observe(&cfg, false), Some(false)); // 3rd consecutive breach
+        assert_eq!(runtime.observe(&cfg, false), None); // already escalated
+        assert_eq!(runtime.observe(&cfg, true), None); // cleared
+    }
+}
@@ -7,17 +7,59 @@ pub enum Trigger {
     Both,
 }
 
+/// Which way a [`FlexPin`] is currently configured — shared between `real`/`dummy` so callers
+/// don't need a cfg-gated import just to flip a pin's direction.
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Input,
+    Output,
+}
+
 #[cfg(feature = "raspberry_pi")]
 mod real {
-    use super::Trigger;
-    use rppal::gpio::{Gpio, InputPin, Level, OutputPin, Trigger as PiTrigger};
-    use std::collections::HashMap;
+    use super::{Direction, Trigger};
+    use rppal::gpio::{Gpio, IoPin, Mode, Trigger as PiTrigger};
+    use std::collections::{HashMap, HashSet};
+    use std::sync::atomic::{AtomicU64, Ordering};
     use std::sync::{Arc, Mutex, OnceLock};
     use std::time::{Duration};
+    use tokio::sync::watch;
+
+    /// Debounce applied to interrupts registered by [`GpioPins::wait_for_edge`] — same value
+    /// `main.rs` already uses for the physical abort button, since neither caller has a reason
+    /// to want a different noise floor.
+    const EDGE_WAIT_DEBOUNCE: Duration = Duration::from_millis(50);
+
+    fn direction_to_mode(direction: Direction) -> Mode {
+        match direction {
+            Direction::Input => Mode::Input,
+            Direction::Output => Mode::Output,
+        }
+    }
+
+    /// A pin that can be flipped between [`Direction::Input`] and [`Direction::Output`] at
+    /// runtime via `rppal`'s `IoPin` (acquired with `Pin::into_io`), instead of committing to a
+    /// direction for the pin's whole lifetime the way `InputPin`/`OutputPin` do.
+    struct FlexPin {
+        inner: IoPin,
+        direction: Direction,
+    }
 
     pub struct GpioPins {
-        input_pins: Arc<Mutex<HashMap<u8, InputPin>>>,
-        output_pins: Arc<Mutex<HashMap<u8, OutputPin>>>,
+        pins: Arc<Mutex<HashMap<u8, FlexPin>>>,
+        /// Pins with an `rppal` interrupt already registered (by either
+        /// `setup_callback_input_pin` or `wait_for_edge`) — `rppal` only allows one interrupt
+        /// per pin, so both registration paths check and record here instead of silently
+        /// clobbering each other's callback.
+        interrupt_registered: Arc<Mutex<HashSet<u8>>>,
+        /// Per-pin level broadcast backing `wait_for_edge`: the `rppal` interrupt callback
+        /// writes the new level in and wakes every waiter subscribed via `watch::Receiver`.
+        edge_watch: Arc<Mutex<HashMap<u8, watch::Sender<bool>>>>,
+        /// Per-pin pulse counters backing [`GpioPins::setup_counter_input_pin`] — incremented
+        /// directly in the `rppal` interrupt callback so counting stays lossless under bursty
+        /// edges instead of being routed through a channel the UI side might fall behind on.
+        counters: Arc<Mutex<HashMap<u8, Arc<AtomicU64>>>>,
         gpio: Gpio,
     }
 
@@ -29,36 +71,50 @@ mod real {
             INSTANCE
                 .get_or_init(|| {
                     Arc::new(GpioPins {
-                        input_pins: Arc::new(Mutex::new(HashMap::new())),
-                        output_pins: Arc::new(Mutex::new(HashMap::new())),
+                        pins: Arc::new(Mutex::new(HashMap::new())),
+                        interrupt_registered: Arc::new(Mutex::new(HashSet::new())),
+                        edge_watch: Arc::new(Mutex::new(HashMap::new())),
+                        counters: Arc::new(Mutex::new(HashMap::new())),
                         gpio: Gpio::new().expect("Failed to initialize GPIO"),
                     })
                 })
                 .clone()
         }
 
-        pub fn setup_input_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
-            let pin = self.gpio.get(pin_number)?.into_input();
-            self.input_pins
+        fn configure_pin(&self, pin_number: u8, direction: Direction) -> Result<(), Box<dyn std::error::Error>> {
+            let inner = self.gpio.get(pin_number)?.into_io(direction_to_mode(direction));
+            self.pins
                 .lock()
                 .expect("failed to get lock")
-                .insert(pin_number, pin);
+                .insert(pin_number, FlexPin { inner, direction });
             Ok(())
         }
 
+        pub fn setup_input_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            self.configure_pin(pin_number, Direction::Input)
+        }
+
         pub fn setup_output_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
-            let pin = self.gpio.get(pin_number)?.into_output();
-            self.output_pins
-                .lock()
-                .expect("failed to get lock")
-                .insert(pin_number, pin);
+            self.configure_pin(pin_number, Direction::Output)
+        }
+
+        /// Flips an already-configured pin between input and output without re-acquiring it
+        /// from `Gpio` — e.g. a valve-sense line that's read between actuations and driven
+        /// during them.
+        pub fn set_direction(&self, pin_number: u8, direction: Direction) -> Result<(), Box<dyn std::error::Error>> {
+            let mut pins = self.pins.lock().expect("failed to get lock");
+            let pin = pins
+                .get_mut(&pin_number)
+                .ok_or_else(|| format!("pin {} not configured", pin_number))?;
+            pin.inner.set_mode(direction_to_mode(direction));
+            pin.direction = direction;
             Ok(())
         }
 
         pub fn read_input_pin(&self, pin_number: u8) -> Result<bool, Box<dyn std::error::Error>> {
-            let input_pins = self.input_pins.lock().expect("failed to get lock");
-            if let Some(pin) = input_pins.get(&pin_number) {
-                Ok(pin.is_high())
+            let pins = self.pins.lock().expect("failed to get lock");
+            if let Some(pin) = pins.get(&pin_number) {
+                Ok(pin.inner.is_high())
             } else {
                 Err(format!("Input pin {} not configured", pin_number).into())
             }
@@ -69,12 +125,12 @@ mod real {
             pin_number: u8,
             value: bool,
         ) -> Result<(), Box<dyn std::error::Error>> {
-            let mut output_pins = self.output_pins.lock().expect("failed to get lock");
-            if let Some(pin) = output_pins.get_mut(&pin_number) {
+            let mut pins = self.pins.lock().expect("failed to get lock");
+            if let Some(pin) = pins.get_mut(&pin_number) {
                 if value {
-                    pin.set_high();
+                    pin.inner.set_high();
                 } else {
-                    pin.set_low();
+                    pin.inner.set_low();
                 }
                 Ok(())
             } else {
@@ -82,6 +138,28 @@ mod real {
             }
         }
 
+        /// Infallible ergonomics for a pin the caller already knows is configured — panics
+        /// instead of threading a `Result` through call sites (valve/actuator sense-then-drive
+        /// sequences) that would just `.expect()` it anyway, mirroring this module's existing
+        /// `.expect()`-on-lock style.
+        pub fn is_high(&self, pin_number: u8) -> bool {
+            self.read_input_pin(pin_number).expect("pin not configured")
+        }
+
+        pub fn is_low(&self, pin_number: u8) -> bool {
+            !self.is_high(pin_number)
+        }
+
+        pub fn set_high(&self, pin_number: u8) {
+            self.write_output_pin(pin_number, true)
+                .expect("pin not configured")
+        }
+
+        pub fn set_low(&self, pin_number: u8) {
+            self.write_output_pin(pin_number, false)
+                .expect("pin not configured")
+        }
+
         fn to_pi_trigger(trigger: Trigger) -> PiTrigger {
             match trigger {
                 Trigger::RisingEdge => PiTrigger::RisingEdge,
@@ -100,10 +178,9 @@ mod real {
         where
             F: Fn(bool) + Send + 'static,
         {
-            let mut pins = self
-                .input_pins
-                .lock()
-                .map_err(|_| "failed to lock input_pins")?;
+            self.claim_interrupt(pin_number)?;
+
+            let mut pins = self.pins.lock().map_err(|_| "failed to lock pins")?;
 
             let pin = pins
                 .get_mut(&pin_number)
@@ -111,30 +188,603 @@ mod real {
 
             let pi_trigger = Self::to_pi_trigger(trigger);
 
-            pin.set_async_interrupt(pi_trigger, Some(debounce), move |event: rppal::gpio::Event| {
+            pin.inner.set_async_interrupt(pi_trigger, Some(debounce), move |event: rppal::gpio::Event| {
                     let level = event.trigger;
-                    callback(level == Trigger::RisingEdge);
+                    callback(level == PiTrigger::RisingEdge);
+
+            })?;
+
+            Ok(())
+        }
+
+        /// Records that `pin_number` now has an `rppal` interrupt registered, failing if one
+        /// already does — `rppal` only supports a single `set_async_interrupt` per pin, so a
+        /// second registration (from either `setup_callback_input_pin` or `wait_for_edge`)
+        /// would silently replace the first instead of erroring without this check.
+        fn claim_interrupt(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            let mut registered = self
+                .interrupt_registered
+                .lock()
+                .map_err(|_| "failed to lock interrupt_registered")?;
+            if !registered.insert(pin_number) {
+                return Err(format!("pin {} already has an active interrupt registration", pin_number).into());
+            }
+            Ok(())
+        }
+
+        /// Resolves the next time `pin_number`'s level crosses `trigger`, without blocking the
+        /// calling task — the async counterpart to `setup_callback_input_pin`'s `Fn(bool)`
+        /// callback, for code (the Dioxus event loop) that would rather `.await` an edge than
+        /// hand over a closure. The first call for a pin registers the `rppal` interrupt (via
+        /// `claim_interrupt`, so it composes with `setup_callback_input_pin`'s own guard);
+        /// later calls for the same pin just subscribe another waiter to the same broadcast.
+        pub async fn wait_for_edge(
+            &self,
+            pin_number: u8,
+            trigger: Trigger,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            let mut rx = {
+                let mut edge_watch = self
+                    .edge_watch
+                    .lock()
+                    .map_err(|_| "failed to lock edge_watch")?;
+
+                match edge_watch.get(&pin_number) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        self.claim_interrupt(pin_number)?;
+
+                        let mut pins = self.pins.lock().map_err(|_| "failed to lock pins")?;
+                        let pin = pins
+                            .get_mut(&pin_number)
+                            .ok_or_else(|| format!("input pin {} not configured", pin_number))?;
+
+                        let (tx, rx) = watch::channel(pin.inner.is_high());
+                        let tx_for_interrupt = tx.clone();
+                        let pi_trigger = Self::to_pi_trigger(trigger);
+
+                        pin.inner.set_async_interrupt(
+                            pi_trigger,
+                            Some(EDGE_WAIT_DEBOUNCE),
+                            move |event: rppal::gpio::Event| {
+                                let _ = tx_for_interrupt.send(event.trigger == PiTrigger::RisingEdge);
+                            },
+                        )?;
+
+                        edge_watch.insert(pin_number, tx);
+                        rx
+                    }
+                }
+            };
+
+            rx.changed()
+                .await
+                .map_err(|_| "edge watch channel closed")?;
+            Ok(*rx.borrow())
+        }
+
+        /// Drives `pin_number` via `rppal`'s software PWM (`frequency_hz`, `duty_cycle` in
+        /// `0.0..=1.0`) — used by the GPIO panel's blink-code LEDs to signal "waiting" states a
+        /// flat on/off can't distinguish.
+        pub fn set_pwm(
+            &self,
+            pin_number: u8,
+            frequency_hz: f64,
+            duty_cycle: f64,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let mut pins = self.pins.lock().map_err(|_| "failed to lock pins")?;
+            let pin = pins
+                .get_mut(&pin_number)
+                .ok_or_else(|| format!("pin {} not configured", pin_number))?;
+            pin.inner.set_pwm_frequency(frequency_hz, duty_cycle)?;
+            Ok(())
+        }
+
+        /// Stops any PWM previously started on `pin_number` via [`set_pwm`](Self::set_pwm),
+        /// leaving the pin's level as `write_output_pin`/`set_high`/`set_low` next set it.
+        pub fn clear_pwm(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            let mut pins = self.pins.lock().map_err(|_| "failed to lock pins")?;
+            let pin = pins
+                .get_mut(&pin_number)
+                .ok_or_else(|| format!("pin {} not configured", pin_number))?;
+            pin.inner.clear_pwm()?;
+            Ok(())
+        }
+
+        /// `rppal` has no register-level glitch-filter API on this backend, so there's no true
+        /// hardware filtering to program here — callers fall back to `setup_callback_input_pin`'s
+        /// own `debounce` parameter, which is what this always returning `Err` signals.
+        pub fn set_glitch_filter(
+            &self,
+            _pin_number: u8,
+            _width: Duration,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            Err("hardware glitch filtering is not available on the raspberry_pi backend".into())
+        }
+
+        /// Installs an `rppal` interrupt on `pin_number` whose only job is incrementing an
+        /// atomic counter — for flow-meter/tachometer sensors where every edge matters and
+        /// routing each one through a callback/channel into the UI risks dropping some under a
+        /// burst. Read the running total with `read_edge_count`.
+        pub fn setup_counter_input_pin(
+            &self,
+            pin_number: u8,
+            trigger: Trigger,
+            debounce: Duration,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            self.claim_interrupt(pin_number)?;
+
+            let counter = Arc::new(AtomicU64::new(0));
+            self.counters
+                .lock()
+                .map_err(|_| "failed to lock counters")?
+                .insert(pin_number, counter.clone());
+
+            let mut pins = self.pins.lock().map_err(|_| "failed to lock pins")?;
+            let pin = pins
+                .get_mut(&pin_number)
+                .ok_or_else(|| format!("input pin {} not configured", pin_number))?;
 
+            let pi_trigger = Self::to_pi_trigger(trigger);
+            pin.inner.set_async_interrupt(pi_trigger, Some(debounce), move |_event: rppal::gpio::Event| {
+                counter.fetch_add(1, Ordering::Relaxed);
             })?;
 
             Ok(())
         }
+
+        pub fn read_edge_count(&self, pin_number: u8) -> u64 {
+            self.counters
+                .lock()
+                .expect("failed to get lock")
+                .get(&pin_number)
+                .map(|c| c.load(Ordering::Relaxed))
+                .unwrap_or(0)
+        }
+
+        pub fn reset_edge_count(&self, pin_number: u8) {
+            if let Some(c) = self.counters.lock().expect("failed to get lock").get(&pin_number) {
+                c.store(0, Ordering::Relaxed);
+            }
+        }
+
+        /// All currently-tracked counter pins and their running totals — polled by
+        /// `pulse_task::start_pulse_task` to surface them as `TelemetryRow`s.
+        pub fn counter_snapshot(&self) -> Vec<(u8, u64)> {
+            self.counters
+                .lock()
+                .expect("failed to get lock")
+                .iter()
+                .map(|(pin, c)| (*pin, c.load(Ordering::Relaxed)))
+                .collect()
+        }
     }
 
     // Re-export so external code can just use `GpioPins` regardless of cfg.
     pub use GpioPins as GpioPinsReal;
 }
-#[cfg(not(feature = "raspberry_pi"))]
+
+/// Drives the panel's buttons/LEDs over the network instead of on-box, by speaking pigpiod's
+/// socket command interface (see <http://abyz.me.uk/rpi/pigpio/sif.html>) to a `pigpiod` daemon
+/// running on the Pi actually wired to the hardware. Selected instead of `real`/`dummy` by the
+/// `panel_remote` feature, with the daemon's address read from `GS_PIGPIOD_HOST`/
+/// `GS_PIGPIOD_PORT` — this decouples the safety-critical control surface from whatever machine
+/// runs telemetry/DB/web, the same way `real` decouples it from the dummy backend used off-Pi.
+#[cfg(feature = "panel_remote")]
+mod remote {
+    use super::{Direction, Trigger};
+    use std::collections::{HashMap, HashSet};
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex, OnceLock};
+    use std::time::Duration;
+    use tokio::sync::watch;
+
+    /// pigpiod socket-interface command codes (a command is 4 little-endian `u32`s: `cmd`, `p1`,
+    /// `p2`, `p3`; the response echoes `cmd`/`p1`/`p2` and carries the result in `p3`/`res`).
+    const CMD_MODES: u32 = 0;
+    const CMD_READ: u32 = 3;
+    const CMD_WRITE: u32 = 4;
+    const CMD_PWM: u32 = 5;
+    const CMD_PFS: u32 = 103;
+    const CMD_NOIB: u32 = 99;
+    const CMD_NB: u32 = 19;
+
+    const MODE_INPUT: u32 = 0;
+    const MODE_OUTPUT: u32 = 1;
+
+    fn default_host() -> String {
+        std::env::var("GS_PIGPIOD_HOST").unwrap_or_else(|_| "127.0.0.1".to_string())
+    }
+
+    fn default_port() -> u16 {
+        std::env::var("GS_PIGPIOD_PORT")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .unwrap_or(8888)
+    }
+
+    /// One round trip on the command socket: writes the 16-byte header (plus `ext`, for commands
+    /// that take extended data) and returns the response's `res` word.
+    fn send_command(
+        conn: &mut TcpStream,
+        cmd: u32,
+        p1: u32,
+        p2: u32,
+        ext: &[u8],
+    ) -> Result<i32, Box<dyn std::error::Error>> {
+        let mut header = [0u8; 16];
+        header[0..4].copy_from_slice(&cmd.to_le_bytes());
+        header[4..8].copy_from_slice(&p1.to_le_bytes());
+        header[8..12].copy_from_slice(&p2.to_le_bytes());
+        header[12..16].copy_from_slice(&(ext.len() as u32).to_le_bytes());
+        conn.write_all(&header)?;
+        if !ext.is_empty() {
+            conn.write_all(ext)?;
+        }
+
+        let mut response = [0u8; 16];
+        conn.read_exact(&mut response)?;
+        Ok(i32::from_le_bytes(response[12..16].try_into().unwrap()))
+    }
+
+    /// A pin's last-known direction, so `read_input_pin`/`write_output_pin` can give the same
+    /// "not configured" error the local backends do instead of silently talking to an
+    /// unconfigured gpio on the daemon.
+    struct TrackedPin {
+        direction: Direction,
+    }
+
+    pub struct GpioPins {
+        host: String,
+        port: u16,
+        /// The command socket — pigpiod serializes one command/response per connection, so every
+        /// `MODES`/`READ`/`WRITE`/`PWM` call takes this lock for its round trip.
+        conn: Mutex<TcpStream>,
+        pins: Mutex<HashMap<u8, TrackedPin>>,
+        interrupt_registered: Arc<Mutex<HashSet<u8>>>,
+        edge_watch: Arc<Mutex<HashMap<u8, watch::Sender<bool>>>>,
+        counters: Arc<Mutex<HashMap<u8, Arc<AtomicU64>>>>,
+        /// Set once the first callback/counter/`wait_for_edge` registration opens the daemon's
+        /// dedicated notification socket and starts the reader thread — `NB`'s bitmask is
+        /// reissued on that same handle each time a new pin joins so one connection covers every
+        /// monitored gpio.
+        notify_handle: Mutex<Option<(TcpStream, u32, u32)>>,
+    }
+
+    #[allow(dead_code)]
+    impl GpioPins {
+        /// Global singleton instance, connected to the `pigpiod` at `GS_PIGPIOD_HOST`:
+        /// `GS_PIGPIOD_PORT` (default `127.0.0.1:8888`, pigpiod's own default).
+        pub fn new() -> Arc<GpioPins> {
+            static INSTANCE: OnceLock<Arc<GpioPins>> = OnceLock::new();
+            INSTANCE
+                .get_or_init(|| {
+                    let host = default_host();
+                    let port = default_port();
+                    let conn = TcpStream::connect((host.as_str(), port))
+                        .expect("failed to connect to pigpiod");
+                    Arc::new(GpioPins {
+                        host,
+                        port,
+                        conn: Mutex::new(conn),
+                        pins: Mutex::new(HashMap::new()),
+                        interrupt_registered: Arc::new(Mutex::new(HashSet::new())),
+                        edge_watch: Arc::new(Mutex::new(HashMap::new())),
+                        counters: Arc::new(Mutex::new(HashMap::new())),
+                        notify_handle: Mutex::new(None),
+                    })
+                })
+                .clone()
+        }
+
+        fn configure_pin(&self, pin_number: u8, direction: Direction) -> Result<(), Box<dyn std::error::Error>> {
+            let mode = match direction {
+                Direction::Input => MODE_INPUT,
+                Direction::Output => MODE_OUTPUT,
+            };
+            let mut conn = self.conn.lock().map_err(|_| "failed to lock pigpiod connection")?;
+            send_command(&mut conn, CMD_MODES, pin_number as u32, mode, &[])?;
+            drop(conn);
+            self.pins
+                .lock()
+                .map_err(|_| "failed to lock pins")?
+                .insert(pin_number, TrackedPin { direction });
+            Ok(())
+        }
+
+        pub fn setup_input_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            self.configure_pin(pin_number, Direction::Input)
+        }
+
+        pub fn setup_output_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            self.configure_pin(pin_number, Direction::Output)
+        }
+
+        pub fn set_direction(&self, pin_number: u8, direction: Direction) -> Result<(), Box<dyn std::error::Error>> {
+            self.configure_pin(pin_number, direction)
+        }
+
+        pub fn read_input_pin(&self, pin_number: u8) -> Result<bool, Box<dyn std::error::Error>> {
+            if !self.pins.lock().map_err(|_| "failed to lock pins")?.contains_key(&pin_number) {
+                return Err(format!("Input pin {} not configured", pin_number).into());
+            }
+            let mut conn = self.conn.lock().map_err(|_| "failed to lock pigpiod connection")?;
+            let res = send_command(&mut conn, CMD_READ, pin_number as u32, 0, &[])?;
+            Ok(res != 0)
+        }
+
+        pub fn write_output_pin(
+            &self,
+            pin_number: u8,
+            value: bool,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            if !self.pins.lock().map_err(|_| "failed to lock pins")?.contains_key(&pin_number) {
+                return Err(format!("Output pin {} not configured", pin_number).into());
+            }
+            let mut conn = self.conn.lock().map_err(|_| "failed to lock pigpiod connection")?;
+            send_command(&mut conn, CMD_WRITE, pin_number as u32, value as u32, &[])?;
+            Ok(())
+        }
+
+        pub fn is_high(&self, pin_number: u8) -> bool {
+            self.read_input_pin(pin_number).expect("pin not configured")
+        }
+
+        pub fn is_low(&self, pin_number: u8) -> bool {
+            !self.is_high(pin_number)
+        }
+
+        pub fn set_high(&self, pin_number: u8) {
+            self.write_output_pin(pin_number, true).expect("pin not configured")
+        }
+
+        pub fn set_low(&self, pin_number: u8) {
+            self.write_output_pin(pin_number, false).expect("pin not configured")
+        }
+
+        pub fn set_pwm(
+            &self,
+            pin_number: u8,
+            frequency_hz: f64,
+            duty_cycle: f64,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let mut conn = self.conn.lock().map_err(|_| "failed to lock pigpiod connection")?;
+            send_command(&mut conn, CMD_PFS, pin_number as u32, frequency_hz.round() as u32, &[])?;
+            let duty_0_255 = (duty_cycle.clamp(0.0, 1.0) * 255.0).round() as u32;
+            send_command(&mut conn, CMD_PWM, pin_number as u32, duty_0_255, &[])?;
+            Ok(())
+        }
+
+        pub fn clear_pwm(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            let mut conn = self.conn.lock().map_err(|_| "failed to lock pigpiod connection")?;
+            send_command(&mut conn, CMD_PWM, pin_number as u32, 0, &[])?;
+            Ok(())
+        }
+
+        /// `gpioGlitchFilter` isn't in pigpio's documented socket-interface command table the way
+        /// `CMD_PFS`/`CMD_NOIB`/`CMD_NB` above are, and there's no way to check a candidate command
+        /// code against a real daemon from here — sending an unverified code to a live pigpiod on
+        /// flight/ground hardware risks silently executing a different command than intended. So,
+        /// like `real`'s `rppal` backend, this always errors and lets `setup_callback_input_pin`'s
+        /// software `debounce` do the filtering instead of claiming a hardware guarantee this
+        /// can't actually provide.
+        pub fn set_glitch_filter(
+            &self,
+            _pin_number: u8,
+            _width: Duration,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            Err("hardware glitch filtering is not available on the panel_remote backend".into())
+        }
+
+        /// Opens (if not already open) the dedicated notification socket: `NOIB` allocates a
+        /// handle bound to this connection, after which pigpiod streams unsolicited 12-byte
+        /// reports (`seqno: u16`, `flags: u16`, `tick: u32`, `level_bits: u32`) on it instead of
+        /// command responses — so, unlike `conn`, nothing else may issue commands on it once
+        /// `NB` starts the stream.
+        fn ensure_notify_thread(&self, extra_bit: u8) -> Result<(), Box<dyn std::error::Error>> {
+            let mut handle = self.notify_handle.lock().map_err(|_| "failed to lock notify_handle")?;
+            let bit = 1u32 << extra_bit;
+
+            if let Some((_stream, h, bits)) = handle.as_mut() {
+                *bits |= bit;
+                let mut conn = self.conn.lock().map_err(|_| "failed to lock pigpiod connection")?;
+                send_command(&mut conn, CMD_NB, *h, *bits, &[])?;
+                return Ok(());
+            }
+
+            let mut stream = TcpStream::connect((self.host.as_str(), self.port))?;
+            let h = send_command(&mut stream, CMD_NOIB, 0, 0, &[])? as u32;
+            let bits = bit;
+            send_command(&mut stream, CMD_NB, h, bits, &[])?;
+
+            let reader = stream.try_clone()?;
+            let callbacks: Arc<Mutex<HashMap<u8, Arc<dyn Fn(bool) + Send + Sync>>>> = Arc::new(Mutex::new(HashMap::new()));
+            let edge_watch = self.edge_watch.clone();
+            let counters = self.counters.clone();
+            spawn_notify_reader(reader, callbacks, edge_watch, counters);
+
+            *handle = Some((stream, h, bits));
+            Ok(())
+        }
+
+        pub fn setup_callback_input_pin<F>(
+            &self,
+            pin_number: u8,
+            _trigger: Trigger,
+            _debounce: std::time::Duration,
+            callback: F,
+        ) -> Result<(), Box<dyn std::error::Error>>
+        where
+            F: Fn(bool) + Send + 'static,
+        {
+            let mut registered = self
+                .interrupt_registered
+                .lock()
+                .map_err(|_| "failed to lock interrupt_registered")?;
+            if !registered.insert(pin_number) {
+                return Err(format!("pin {} already has an active interrupt registration", pin_number).into());
+            }
+            drop(registered);
+
+            // Surfaced through `edge_watch` by the reader thread; route it into the caller's
+            // callback the same way a local interrupt would.
+            let mut rx = {
+                let mut edge_watch = self.edge_watch.lock().map_err(|_| "failed to lock edge_watch")?;
+                edge_watch
+                    .entry(pin_number)
+                    .or_insert_with(|| watch::channel(false).0)
+                    .subscribe()
+            };
+            tokio::spawn(async move {
+                loop {
+                    if rx.changed().await.is_err() {
+                        return;
+                    }
+                    callback(*rx.borrow());
+                }
+            });
+
+            self.ensure_notify_thread(pin_number)
+        }
+
+        pub async fn wait_for_edge(
+            &self,
+            pin_number: u8,
+            _trigger: Trigger,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            let mut rx = {
+                let mut edge_watch = self.edge_watch.lock().map_err(|_| "failed to lock edge_watch")?;
+                match edge_watch.get(&pin_number) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        self.ensure_notify_thread(pin_number)?;
+                        edge_watch
+                            .entry(pin_number)
+                            .or_insert_with(|| watch::channel(false).0)
+                            .subscribe()
+                    }
+                }
+            };
+            rx.changed().await.map_err(|_| "edge watch channel closed")?;
+            Ok(*rx.borrow())
+        }
+
+        pub fn setup_counter_input_pin(
+            &self,
+            pin_number: u8,
+            _trigger: Trigger,
+            _debounce: std::time::Duration,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            self.counters
+                .lock()
+                .map_err(|_| "failed to lock counters")?
+                .entry(pin_number)
+                .or_insert_with(|| Arc::new(AtomicU64::new(0)));
+            self.ensure_notify_thread(pin_number)
+        }
+
+        pub fn read_edge_count(&self, pin_number: u8) -> u64 {
+            self.counters
+                .lock()
+                .expect("failed to get lock")
+                .get(&pin_number)
+                .map(|c| c.load(Ordering::Relaxed))
+                .unwrap_or(0)
+        }
+
+        pub fn reset_edge_count(&self, pin_number: u8) {
+            if let Some(c) = self.counters.lock().expect("failed to get lock").get(&pin_number) {
+                c.store(0, Ordering::Relaxed);
+            }
+        }
+
+        pub fn counter_snapshot(&self) -> Vec<(u8, u64)> {
+            self.counters
+                .lock()
+                .expect("failed to get lock")
+                .iter()
+                .map(|(pin, c)| (*pin, c.load(Ordering::Relaxed)))
+                .collect()
+        }
+    }
+
+    /// Background reader for the notification socket: parses pigpiod's 12-byte reports and, for
+    /// each gpio whose level bit flipped since the previous report, bumps that pin's counter (if
+    /// any, and only when the new level is high) and publishes the new level on `edge_watch` for `wait_for_edge`/callback subscribers.
+    fn spawn_notify_reader(
+        mut reader: TcpStream,
+        _callbacks: Arc<Mutex<HashMap<u8, Arc<dyn Fn(bool) + Send + Sync>>>>,
+        edge_watch: Arc<Mutex<HashMap<u8, watch::Sender<bool>>>>,
+        counters: Arc<Mutex<HashMap<u8, Arc<AtomicU64>>>>,
+    ) {
+        std::thread::spawn(move || {
+            let mut last_level_bits: u32 = 0;
+            let mut report = [0u8; 12];
+            loop {
+                if reader.read_exact(&mut report).is_err() {
+                    return;
+                }
+                let level_bits = u32::from_le_bytes(report[8..12].try_into().unwrap());
+                let changed_bits = level_bits ^ last_level_bits;
+                last_level_bits = level_bits;
+
+                for pin in 0u8..32 {
+                    if changed_bits & (1 << pin) == 0 {
+                        continue;
+                    }
+                    let level = level_bits & (1 << pin) != 0;
+                    if level {
+                        if let Some(counter) = counters.lock().expect("failed to get lock").get(&pin) {
+                            counter.fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                    if let Some(tx) = edge_watch.lock().expect("failed to get lock").get(&pin) {
+                        let _ = tx.send(level);
+                    }
+                }
+            }
+        });
+    }
+
+    pub use GpioPins as GpioPinsRemote;
+}
+
+#[cfg(not(any(feature = "raspberry_pi", feature = "panel_remote")))]
 mod dummy {
-    use super::Trigger;
+    use super::{Direction, Trigger};
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
     use std::sync::{Arc, Mutex, OnceLock};
     use std::time::Duration;
+    use tokio::sync::watch;
+
+    /// Dummy counterpart to the real `FlexPin`: just a level plus the direction it was last
+    /// configured for (the dummy backend doesn't distinguish reads/writes by direction, but
+    /// tracks it anyway so `set_direction` has something to flip).
+    struct FlexPin {
+        level: bool,
+        direction: Direction,
+    }
 
     #[derive(Clone)]
     pub struct GpioPins {
-        input_pins: Arc<Mutex<HashMap<u8, bool>>>,
-        output_pins: Arc<Mutex<HashMap<u8, bool>>>,
+        pins: Arc<Mutex<HashMap<u8, FlexPin>>>,
+        /// Per-pin level broadcast backing `wait_for_edge`/`simulate_edge` — mirrors the real
+        /// implementation's `edge_watch`, but driven by test code instead of an `rppal`
+        /// interrupt.
+        edge_watch: Arc<Mutex<HashMap<u8, watch::Sender<bool>>>>,
+        /// Per-pin pulse counters mirroring the real implementation's `counters` — driven by
+        /// `bump_counter` instead of an `rppal` interrupt.
+        counters: Arc<Mutex<HashMap<u8, Arc<AtomicU64>>>>,
+        /// Per-pin `(frequency_hz, duty_cycle)` mirroring the real implementation's software
+        /// PWM — driven by `set_pwm`/`clear_pwm` with no hardware behind it, inspectable via
+        /// `pwm_state` for tests.
+        pwm: Arc<Mutex<HashMap<u8, (f64, f64)>>>,
+        /// Per-pin glitch-filter width last requested via `set_glitch_filter`, inspectable via
+        /// `glitch_filter_state` for tests.
+        glitch_filters: Arc<Mutex<HashMap<u8, Duration>>>,
     }
     #[allow(dead_code)]
 
@@ -145,33 +795,47 @@ mod dummy {
             INSTANCE
                 .get_or_init(|| {
                     Arc::new(GpioPins {
-                        input_pins: Arc::new(Mutex::new(HashMap::new())),
-                        output_pins: Arc::new(Mutex::new(HashMap::new())),
+                        pins: Arc::new(Mutex::new(HashMap::new())),
+                        edge_watch: Arc::new(Mutex::new(HashMap::new())),
+                        counters: Arc::new(Mutex::new(HashMap::new())),
+                        pwm: Arc::new(Mutex::new(HashMap::new())),
+                        glitch_filters: Arc::new(Mutex::new(HashMap::new())),
                     })
                 })
                 .clone()
         }
 
-        pub fn setup_input_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
-            self.input_pins
+        fn configure_pin(&self, pin_number: u8, direction: Direction) -> Result<(), Box<dyn std::error::Error>> {
+            self.pins
                 .lock()
                 .expect("failed to get lock")
-                .insert(pin_number, false);
+                .insert(pin_number, FlexPin { level: false, direction });
             Ok(())
         }
 
+        pub fn setup_input_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            self.configure_pin(pin_number, Direction::Input)
+        }
+
         pub fn setup_output_pin(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
-            self.output_pins
-                .lock()
-                .expect("failed to get lock")
-                .insert(pin_number, false);
+            self.configure_pin(pin_number, Direction::Output)
+        }
+
+        /// Flips an already-configured pin's tracked direction; the dummy backend has no real
+        /// hardware mode to change, so this just records it for parity with the real impl.
+        pub fn set_direction(&self, pin_number: u8, direction: Direction) -> Result<(), Box<dyn std::error::Error>> {
+            let mut pins = self.pins.lock().expect("failed to get lock");
+            let pin = pins
+                .get_mut(&pin_number)
+                .ok_or_else(|| format!("pin {} not configured", pin_number))?;
+            pin.direction = direction;
             Ok(())
         }
 
         pub fn read_input_pin(&self, pin_number: u8) -> Result<bool, Box<dyn std::error::Error>> {
-            let input_pins = self.input_pins.lock().expect("failed to get lock");
-            if let Some(pin) = input_pins.get(&pin_number) {
-                Ok(*pin)
+            let pins = self.pins.lock().expect("failed to get lock");
+            if let Some(pin) = pins.get(&pin_number) {
+                Ok(pin.level)
             } else {
                 Err(format!("Input pin {} not configured", pin_number).into())
             }
@@ -180,16 +844,36 @@ mod dummy {
         pub fn write_output_pin(
             &self,
             pin_number: u8,
-            _value: bool,
+            value: bool,
         ) -> Result<(), Box<dyn std::error::Error>> {
-            let mut output_pins = self.output_pins.lock().expect("failed to get lock");
-            if let Some(_pin) = output_pins.get_mut(&pin_number) {
+            let mut pins = self.pins.lock().expect("failed to get lock");
+            if let Some(pin) = pins.get_mut(&pin_number) {
+                pin.level = value;
                 Ok(())
             } else {
                 Err(format!("Output pin {} not configured", pin_number).into())
             }
         }
 
+        /// Infallible ergonomics mirroring the real implementation's — see its doc comment.
+        pub fn is_high(&self, pin_number: u8) -> bool {
+            self.read_input_pin(pin_number).expect("pin not configured")
+        }
+
+        pub fn is_low(&self, pin_number: u8) -> bool {
+            !self.is_high(pin_number)
+        }
+
+        pub fn set_high(&self, pin_number: u8) {
+            self.write_output_pin(pin_number, true)
+                .expect("pin not configured")
+        }
+
+        pub fn set_low(&self, pin_number: u8) {
+            self.write_output_pin(pin_number, false)
+                .expect("pin not configured")
+        }
+
         pub fn setup_callback_input_pin<F>(
             &self,
             _pin_number: u8,
@@ -203,6 +887,156 @@ mod dummy {
             // No-op in dummy implementation
             Ok(())
         }
+
+        /// Dummy counterpart to the real `set_pwm`: just records the last `(frequency_hz,
+        /// duty_cycle)` requested, for `pwm_state` to report back in tests.
+        pub fn set_pwm(
+            &self,
+            pin_number: u8,
+            frequency_hz: f64,
+            duty_cycle: f64,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            self.pwm
+                .lock()
+                .map_err(|_| "failed to lock pwm")?
+                .insert(pin_number, (frequency_hz, duty_cycle));
+            Ok(())
+        }
+
+        /// Dummy counterpart to the real `clear_pwm`: drops `pin_number`'s recorded PWM state.
+        pub fn clear_pwm(&self, pin_number: u8) -> Result<(), Box<dyn std::error::Error>> {
+            self.pwm.lock().map_err(|_| "failed to lock pwm")?.remove(&pin_number);
+            Ok(())
+        }
+
+        /// The `(frequency_hz, duty_cycle)` most recently set via `set_pwm`, or `None` if the
+        /// pin has never had PWM started (or it's since been cleared via `clear_pwm`) — mirrors
+        /// `counter_snapshot`'s role for `setup_counter_input_pin`.
+        pub fn pwm_state(&self, pin_number: u8) -> Option<(f64, f64)> {
+            self.pwm.lock().expect("failed to get lock").get(&pin_number).copied()
+        }
+
+        /// Dummy counterpart to `real`'s always-failing `set_glitch_filter`: the dummy backend
+        /// has no hardware to genuinely filter with, but unlike `real` it's also never the
+        /// backend anything is debounced *against* — so it just records the width and succeeds,
+        /// the same way `set_pwm` records state instead of driving real silicon.
+        pub fn set_glitch_filter(
+            &self,
+            pin_number: u8,
+            width: Duration,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            self.glitch_filters
+                .lock()
+                .map_err(|_| "failed to lock glitch_filters")?
+                .insert(pin_number, width);
+            Ok(())
+        }
+
+        pub fn glitch_filter_state(&self, pin_number: u8) -> Option<Duration> {
+            self.glitch_filters.lock().expect("failed to get lock").get(&pin_number).copied()
+        }
+
+        /// Dummy counterpart to the real `wait_for_edge`: resolves on the next
+        /// [`simulate_edge`](Self::simulate_edge) call for this pin rather than a real
+        /// interrupt, so UI/sequencing logic built on `wait_for_edge` can be exercised in tests
+        /// without hardware.
+        pub async fn wait_for_edge(
+            &self,
+            pin_number: u8,
+            _trigger: Trigger,
+        ) -> Result<bool, Box<dyn std::error::Error>> {
+            let mut rx = {
+                let mut edge_watch = self
+                    .edge_watch
+                    .lock()
+                    .map_err(|_| "failed to lock edge_watch")?;
+                edge_watch
+                    .entry(pin_number)
+                    .or_insert_with(|| watch::channel(false).0)
+                    .subscribe()
+            };
+
+            rx.changed()
+                .await
+                .map_err(|_| "edge watch channel closed")?;
+            Ok(*rx.borrow())
+        }
+
+        /// Dummy counterpart to the real `setup_counter_input_pin`: no interrupt to register,
+        /// just starts `pin_number`'s counter at zero so `bump_counter`/`read_edge_count` have
+        /// something to operate on.
+        pub fn setup_counter_input_pin(
+            &self,
+            pin_number: u8,
+            _trigger: Trigger,
+            _debounce: Duration,
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            self.counters
+                .lock()
+                .map_err(|_| "failed to lock counters")?
+                .entry(pin_number)
+                .or_insert_with(|| Arc::new(AtomicU64::new(0)));
+            Ok(())
+        }
+
+        pub fn read_edge_count(&self, pin_number: u8) -> u64 {
+            self.counters
+                .lock()
+                .expect("failed to get lock")
+                .get(&pin_number)
+                .map(|c| c.load(Ordering::Relaxed))
+                .unwrap_or(0)
+        }
+
+        pub fn reset_edge_count(&self, pin_number: u8) {
+            if let Some(c) = self.counters.lock().expect("failed to get lock").get(&pin_number) {
+                c.store(0, Ordering::Relaxed);
+            }
+        }
+
+        /// Mirrors the real implementation's `counter_snapshot`, for tasks that poll it without
+        /// caring which backend is compiled in.
+        pub fn counter_snapshot(&self) -> Vec<(u8, u64)> {
+            self.counters
+                .lock()
+                .expect("failed to get lock")
+                .iter()
+                .map(|(pin, c)| (*pin, c.load(Ordering::Relaxed)))
+                .collect()
+        }
+
+        /// Pushes `n` synthetic pulses onto `pin_number`'s counter — the hook tests use to
+        /// exercise `setup_counter_input_pin` consumers (e.g. `pulse_task`) without hardware.
+        pub fn bump_counter(&self, pin_number: u8, n: u64) {
+            self.counters
+                .lock()
+                .expect("failed to get lock")
+                .entry(pin_number)
+                .or_insert_with(|| Arc::new(AtomicU64::new(0)))
+                .fetch_add(n, Ordering::Relaxed);
+        }
+
+        /// Drives `pin_number`'s simulated level to `level`, waking anyone awaiting
+        /// `wait_for_edge` on it and updating what `read_input_pin` reports — the hook tests use
+        /// to exercise edge-triggered logic off-device.
+        pub fn simulate_edge(&self, pin_number: u8, level: bool) {
+            self.pins
+                .lock()
+                .expect("failed to get lock")
+                .entry(pin_number)
+                .or_insert(FlexPin { level: false, direction: Direction::Input })
+                .level = level;
+
+            let mut edge_watch = self.edge_watch.lock().expect("failed to get lock");
+            match edge_watch.get(&pin_number) {
+                Some(tx) => {
+                    let _ = tx.send(level);
+                }
+                None => {
+                    edge_watch.insert(pin_number, watch::channel(level).0);
+                }
+            }
+        }
     }
 
     pub use GpioPins as GpioPinsDummy;
@@ -212,5 +1046,68 @@ mod dummy {
 #[cfg(feature = "raspberry_pi")]
 pub use real::GpioPinsReal as GpioPins;
 
-#[cfg(not(feature = "raspberry_pi"))]
+#[cfg(all(feature = "panel_remote", not(feature = "raspberry_pi")))]
+pub use remote::GpioPinsRemote as GpioPins;
+
+#[cfg(not(any(feature = "raspberry_pi", feature = "panel_remote")))]
 pub use dummy::GpioPinsDummy as GpioPins;
+
+#[cfg(all(test, not(feature = "raspberry_pi")))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn wait_for_edge_resolves_on_simulated_edge() {
+        let gpio = GpioPins::new();
+        gpio.setup_input_pin(7).unwrap();
+
+        let waiter = tokio::spawn({
+            let gpio = gpio.clone();
+            async move { gpio.wait_for_edge(7, Trigger::RisingEdge).await.unwrap() }
+        });
+
+        // Give the spawned task a chance to subscribe before the edge fires.
+        tokio::task::yield_now().await;
+        gpio.simulate_edge(7, true);
+
+        assert!(waiter.await.unwrap());
+        assert!(gpio.read_input_pin(7).unwrap());
+    }
+
+    #[test]
+    fn set_direction_flips_an_already_configured_pin() {
+        let gpio = GpioPins::new();
+        gpio.setup_output_pin(9).unwrap();
+        gpio.set_high(9);
+
+        gpio.set_direction(9, Direction::Input).unwrap();
+        assert!(gpio.is_high(9));
+    }
+
+    #[test]
+    fn bump_counter_accumulates_and_reset_clears_it() {
+        let gpio = GpioPins::new();
+        gpio.setup_counter_input_pin(21, Trigger::RisingEdge, Duration::from_millis(0)).unwrap();
+
+        gpio.bump_counter(21, 3);
+        gpio.bump_counter(21, 4);
+        assert_eq!(gpio.read_edge_count(21), 7);
+        assert_eq!(gpio.counter_snapshot(), vec![(21, 7)]);
+
+        gpio.reset_edge_count(21);
+        assert_eq!(gpio.read_edge_count(21), 0);
+    }
+
+    #[test]
+    fn set_pwm_records_state_and_clear_pwm_removes_it() {
+        let gpio = GpioPins::new();
+        gpio.setup_output_pin(14).unwrap();
+
+        assert_eq!(gpio.pwm_state(14), None);
+        gpio.set_pwm(14, 1.0, 0.5).unwrap();
+        assert_eq!(gpio.pwm_state(14), Some((1.0, 0.5)));
+
+        gpio.clear_pwm(14).unwrap();
+        assert_eq!(gpio.pwm_state(14), None);
+    }
+}
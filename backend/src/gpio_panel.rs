@@ -1,7 +1,8 @@
 use crate::gpio::Trigger;
 use crate::rocket_commands::{ActuatorBoardCommands, ValveBoardCommands};
-use crate::state::AppState;
-use crate::web::emit_error;
+use crate::sequence_config::{effective_sequence_config, Guard, SequenceDefConfig, SequenceStepDef};
+use crate::state::{AppState, CommandRequest};
+use crate::web::{emit_error, emit_warning};
 use groundstation_shared::{FlightState, TelemetryCommand};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -34,78 +35,138 @@ pub const NORMALLY_OPEN_PIN: u8 = 26;
 pub const NORMALLY_OPEN_LED: u8 = 29;
 //####################################################################
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum FillStep {
-    CloseNormallyOpen,
-    CloseDump,
-    OpenNitrogen,
-    WaitForPressure,
-    CloseNitrogen,
-    LeakCheck,
-    OpenDump,
-    DumpWait,
-    OpenNitrous,
-    ReadyToLaunch,
+/// Reads the same `GS_SEQUENCE_*` knobs `sequences::SequenceConfig::from_env` does, so the
+/// physical panel and the web action policy advance through one shared procedure file
+/// (`sequence_config::effective_sequence_config`) instead of each reading its own copy.
+fn sequence_env_defaults() -> (f32, u64, f32) {
+    let pressure_min_psi = std::env::var("GS_SEQUENCE_PRESSURE_MIN_PSI")
+        .ok()
+        .and_then(|v| v.parse::<f32>().ok())
+        .unwrap_or(10.0);
+    let leak_check_sec = std::env::var("GS_SEQUENCE_LEAK_CHECK_SEC")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(60);
+    let max_leak_drop_psi = std::env::var("GS_SEQUENCE_MAX_LEAK_DROP_PSI")
+        .ok()
+        .and_then(|v| v.parse::<f32>().ok())
+        .unwrap_or(1.0);
+    (pressure_min_psi, leak_check_sec, max_leak_drop_psi)
 }
 
-#[derive(Clone, Copy, Debug)]
-struct PanelConfig {
-    leak_check: Duration,
-    dump_wait: Duration,
-    pressure_threshold_psi: f32,
+/// Early-abort threshold for the leak-check step's live pressure-drop rate. This supplements
+/// `LeakCheckConfig::max_drop_psi` (an end-of-window total-drop check shared with `sequences.rs`
+/// via `SequenceDefConfig`) with a per-tick check of the panel's own: a slow leak that would
+/// still clear the total-drop budget by the time the hold expires can still be caught early if
+/// it's draining fast enough to matter, instead of burning the whole hold duration to find out.
+fn leak_rate_env_default() -> f32 {
+    std::env::var("GPIO_MAX_LEAK_RATE_PSI_PER_SEC")
+        .ok()
+        .and_then(|v| v.parse::<f32>().ok())
+        .unwrap_or(0.05)
 }
 
-impl PanelConfig {
-    fn from_env() -> Self {
-        let leak_check = std::env::var("GPIO_LEAK_CHECK_SEC")
-            .ok()
-            .and_then(|v| v.parse::<u64>().ok())
-            .map(Duration::from_secs)
-            .unwrap_or_else(|| Duration::from_secs(10));
-        let dump_wait = std::env::var("GPIO_DUMP_WAIT_SEC")
-            .ok()
-            .and_then(|v| v.parse::<u64>().ok())
-            .map(Duration::from_secs)
-            .unwrap_or_else(|| Duration::from_secs(5));
-        let pressure_threshold_psi = std::env::var("GPIO_PRESSURE_THRESHOLD_PSI")
-            .ok()
-            .and_then(|v| v.parse::<f32>().ok())
-            .unwrap_or(10.0);
-
-        Self {
-            leak_check,
-            dump_wait,
-            pressure_threshold_psi,
+/// Hardware glitch-filter widths per button class: longer on ABORT, since a spurious trip from
+/// electrical noise is far costlier than a few extra milliseconds of latency, shorter on the
+/// valve buttons, where operators expect an immediate response.
+fn glitch_filter_env_defaults() -> (Duration, Duration) {
+    let abort_filter_us = std::env::var("GPIO_ABORT_FILTER_US")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(5_000);
+    let valve_filter_us = std::env::var("GPIO_VALVE_FILTER_US")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or(200);
+    (Duration::from_micros(abort_filter_us), Duration::from_micros(valve_filter_us))
+}
+
+/// Tries to program a true hardware/daemon-level glitch filter for `pin`. The `real` and
+/// `remote` backends always error `set_glitch_filter` (`real`/rppal has no register-level filter
+/// to program; `remote`/pigpiod has no verified socket command for it), in which case the
+/// caller's software `debounce` stays in effect as a fallback. The dummy backend records the
+/// width and succeeds, so there the software debounce is zero.
+fn debounce_for_pin(
+    gpio: &crate::gpio::GpioPins,
+    pin: u8,
+    filter_width: Duration,
+    software_fallback: Duration,
+) -> Duration {
+    match gpio.set_glitch_filter(pin, filter_width) {
+        Ok(()) => Duration::ZERO,
+        Err(e) => {
+            eprintln!(
+                "GPIO pin {pin}: hardware glitch filter unavailable ({e}), falling back to software debounce"
+            );
+            software_fallback
         }
     }
 }
 
+/// How a valve button's LED should present the current step, beyond plain on/off: `Solid` means
+/// the operator still needs to press it, `SlowPulse`/`FastPulse` mean its valve is already where
+/// the step wants it but the step itself hasn't advanced yet (a timed hold vs. a pressure
+/// threshold), and `Off` means it isn't part of the current step at all. Backed by PWM via
+/// `set_led_pattern` instead of a flat `write_output_pin`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum BlinkPattern {
+    Solid,
+    SlowPulse,
+    FastPulse,
+    #[default]
+    Off,
+}
+
+/// Software-PWM frequencies for the two wait patterns — slow enough to read as "hold" vs. fast
+/// enough to read as "almost there" at a glance, without needing to watch closely.
+const SLOW_PULSE_HZ: f64 = 1.0;
+const FAST_PULSE_HZ: f64 = 4.0;
+const PULSE_DUTY_CYCLE: f64 = 0.5;
+
 #[derive(Clone, Copy, Debug, Default)]
 struct AllowedActions {
     abort: bool,
     launch: bool,
-    dump: bool,
-    normally_open: bool,
+    dump: BlinkPattern,
+    normally_open: BlinkPattern,
     pilot: bool,
     igniter: bool,
-    nitrogen: bool,
-    nitrous: bool,
+    nitrogen: BlinkPattern,
+    nitrous: BlinkPattern,
     fill_lines: bool,
 }
 
+/// Live progress through the loaded [`SequenceDefConfig`]'s steps — `step_id` replaces what used
+/// to be a hardcoded `FillStep` enum, so advancing is just a string compared against whatever
+/// the procedure file defines. Mirrors `sequences::SequenceRuntime`, kept as its own small copy
+/// here since the two tasks track the same procedure independently rather than sharing state.
 #[derive(Debug)]
 struct SequenceState {
-    step: FillStep,
+    step_id: String,
     step_started_at: Option<Instant>,
+    pressure_at_close_psi: Option<f32>,
+    /// Set once the live pressure-drop rate trips `GPIO_MAX_LEAK_RATE_PSI_PER_SEC` mid-hold;
+    /// once set, `update_sequence` refuses to leave this `leak_check` step at all (not even
+    /// down `fail_next`) until the operator takes the sequence out of the fill states, e.g. by
+    /// dumping. Reset whenever the sequence leaves the fill states.
+    leak_detected: bool,
 }
 
 pub fn setup_gpio_panel(state: Arc<AppState>) -> Result<(), Box<dyn std::error::Error>> {
     let gpio = state.gpio.clone();
-    let cfg = PanelConfig::from_env();
+    let (pressure_min_psi, leak_check_sec, max_leak_drop_psi) = sequence_env_defaults();
+    let max_leak_rate_psi_per_sec = leak_rate_env_default();
+    let seq_def = Arc::new(effective_sequence_config(
+        pressure_min_psi,
+        leak_check_sec,
+        max_leak_drop_psi,
+    ));
     let allowed = Arc::new(Mutex::new(AllowedActions::default()));
     let seq = Arc::new(Mutex::new(SequenceState {
-        step: FillStep::CloseNormallyOpen,
+        step_id: seq_def.first_step_id().to_string(),
         step_started_at: None,
+        pressure_at_close_psi: None,
+        leak_detected: false,
     }));
 
     // Inputs (buttons)
@@ -131,7 +192,7 @@ pub fn setup_gpio_panel(state: Arc<AppState>) -> Result<(), Box<dyn std::error::
 
     setup_callbacks(&state, allowed.clone())?;
 
-    tokio::spawn(gpio_led_task(state, cfg, allowed, seq));
+    tokio::spawn(gpio_led_task(state, seq_def, allowed, seq, max_leak_rate_psi_per_sec));
 
     Ok(())
 }
@@ -142,19 +203,29 @@ fn setup_callbacks(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let tx = state.cmd_tx.clone();
     let gpio = state.gpio.clone();
-    let debounce = Duration::from_millis(50);
+    let software_debounce = Duration::from_millis(50);
+    let (abort_filter, valve_filter) = glitch_filter_env_defaults();
+    let abort_debounce = debounce_for_pin(&gpio, ABORT_PIN, abort_filter, software_debounce);
 
     let allowed_abort = allowed.clone();
     let tx_abort = tx.clone();
     let state_abort = state.clone();
-    gpio.setup_callback_input_pin(ABORT_PIN, Trigger::RisingEdge, debounce, move |is_high| {
+    gpio.setup_callback_input_pin(ABORT_PIN, Trigger::RisingEdge, abort_debounce, move |is_high| {
         if !is_high {
             return;
         }
         if !allowed_abort.lock().unwrap().abort {
             return;
         }
-        if tx_abort.try_send(TelemetryCommand::Abort).is_err() {
+        if tx_abort
+            .try_send(CommandRequest {
+                id: None,
+                cmd: TelemetryCommand::Abort,
+                operator_id: "gpio-panel".to_string(),
+                operator_role: "flight_director".to_string(),
+            })
+            .is_err()
+        {
             eprintln!("GPIO abort button: failed to send command");
         }
         emit_error(&state_abort, "Manual abort button pressed!".to_string());
@@ -167,25 +238,25 @@ fn setup_callbacks(
         LAUNCH_PIN,
         |a| a.launch,
         TelemetryCommand::Launch,
-        debounce,
+        debounce_for_pin(&gpio, LAUNCH_PIN, valve_filter, software_debounce),
     )?;
     setup_button_callback(
         gpio.clone(),
         allowed.clone(),
         tx.clone(),
         DUMP_PIN,
-        |a| a.dump,
+        |a| a.dump == BlinkPattern::Solid,
         TelemetryCommand::Dump,
-        debounce,
+        debounce_for_pin(&gpio, DUMP_PIN, valve_filter, software_debounce),
     )?;
     setup_button_callback(
         gpio.clone(),
         allowed.clone(),
         tx.clone(),
         NORMALLY_OPEN_PIN,
-        |a| a.normally_open,
+        |a| a.normally_open == BlinkPattern::Solid,
         TelemetryCommand::NormallyOpen,
-        debounce,
+        debounce_for_pin(&gpio, NORMALLY_OPEN_PIN, valve_filter, software_debounce),
     )?;
     setup_button_callback(
         gpio.clone(),
@@ -194,25 +265,25 @@ fn setup_callbacks(
         PILOT_VALVE_PIN,
         |a| a.pilot,
         TelemetryCommand::Pilot,
-        debounce,
+        debounce_for_pin(&gpio, PILOT_VALVE_PIN, valve_filter, software_debounce),
     )?;
     setup_button_callback(
         gpio.clone(),
         allowed.clone(),
         tx.clone(),
         NITROGEN_TANK_VALVE_PIN,
-        |a| a.nitrogen,
+        |a| a.nitrogen == BlinkPattern::Solid,
         TelemetryCommand::Nitrogen,
-        debounce,
+        debounce_for_pin(&gpio, NITROGEN_TANK_VALVE_PIN, valve_filter, software_debounce),
     )?;
     setup_button_callback(
         gpio.clone(),
         allowed.clone(),
         tx.clone(),
         NITROUS_TANK_VALVE_PIN,
-        |a| a.nitrous,
+        |a| a.nitrous == BlinkPattern::Solid,
         TelemetryCommand::Nitrous,
-        debounce,
+        debounce_for_pin(&gpio, NITROUS_TANK_VALVE_PIN, valve_filter, software_debounce),
     )?;
     setup_button_callback(
         gpio.clone(),
@@ -221,7 +292,7 @@ fn setup_callbacks(
         RETRACT_PIN,
         |a| a.fill_lines,
         TelemetryCommand::RetractPlumbing,
-        debounce,
+        debounce_for_pin(&gpio, RETRACT_PIN, valve_filter, software_debounce),
     )?;
 
     Ok(())
@@ -230,7 +301,7 @@ fn setup_callbacks(
 fn setup_button_callback<F>(
     gpio: Arc<crate::gpio::GpioPins>,
     allowed: Arc<Mutex<AllowedActions>>,
-    tx: mpsc::Sender<TelemetryCommand>,
+    tx: mpsc::Sender<CommandRequest>,
     pin: u8,
     can_press: F,
     cmd: TelemetryCommand,
@@ -246,7 +317,15 @@ where
         if !can_press(&allowed.lock().unwrap()) {
             return;
         }
-        if tx.try_send(cmd.clone()).is_err() {
+        if tx
+            .try_send(CommandRequest {
+                id: None,
+                cmd: cmd.clone(),
+                operator_id: "gpio-panel".to_string(),
+                operator_role: "flight_director".to_string(),
+            })
+            .is_err()
+        {
             eprintln!("GPIO button pin {pin}: failed to send command");
         }
     })?;
@@ -255,17 +334,18 @@ where
 
 async fn gpio_led_task(
     state: Arc<AppState>,
-    cfg: PanelConfig,
+    seq_def: Arc<SequenceDefConfig>,
     allowed: Arc<Mutex<AllowedActions>>,
     seq: Arc<Mutex<SequenceState>>,
+    max_leak_rate_psi_per_sec: f32,
 ) {
     let mut tick = interval(Duration::from_millis(200));
     loop {
         tick.tick().await;
 
         let flight_state = *state.state.lock().unwrap();
-        update_sequence(&state, &cfg, &seq, flight_state);
-        let actions = compute_allowed_actions(&state, flight_state, &cfg, &seq);
+        update_sequence(&state, &seq_def, &seq, flight_state, max_leak_rate_psi_per_sec);
+        let actions = compute_allowed_actions(&state, flight_state, &seq_def, &seq);
 
         {
             let mut slot = allowed.lock().unwrap();
@@ -275,97 +355,163 @@ async fn gpio_led_task(
         let gpio = &state.gpio;
         set_led(gpio, ABORT_PIN_LED, actions.abort);
         set_led(gpio, LAUNCH_PIN_LED, actions.launch);
-        set_led(gpio, DUMP_PIN_LED, actions.dump);
-        set_led(gpio, NORMALLY_OPEN_LED, actions.normally_open);
+        set_led_pattern(gpio, DUMP_PIN_LED, actions.dump);
+        set_led_pattern(gpio, NORMALLY_OPEN_LED, actions.normally_open);
         set_led(gpio, PILOT_VALVE_LED, actions.pilot);
-        set_led(gpio, NITROGEN_TANK_VALVE_LED, actions.nitrogen);
-        set_led(gpio, NITROUS_TANK_VALVE_LED, actions.nitrous);
+        set_led_pattern(gpio, NITROGEN_TANK_VALVE_LED, actions.nitrogen);
+        set_led_pattern(gpio, NITROUS_TANK_VALVE_LED, actions.nitrous);
         set_led(gpio, RETRACT_PIN_LED, actions.fill_lines);
     }
 }
 
+/// Reads the valve named by a `Guard::Valve`/`EnabledCommandDef` (the same `TelemetryCommand`
+/// name strings `sequences::command_name` produces) against the live umbilical snapshot — the
+/// panel's own copy of the lookup `sequences::ValveSnapshot::actuated_for_cmd` does for the web
+/// action policy.
+fn valve_state_for(state: &AppState, cmd: &str) -> Option<bool> {
+    let valve = |c| state.get_umbilical_valve_state(c);
+    match cmd {
+        "NormallyOpen" => valve(ValveBoardCommands::NormallyOpenOpen as u8),
+        "Dump" => valve(ValveBoardCommands::DumpOpen as u8),
+        "Nitrogen" => valve(ActuatorBoardCommands::NitrogenOpen as u8),
+        "Nitrous" => valve(ActuatorBoardCommands::NitrousOpen as u8),
+        "Pilot" => valve(ValveBoardCommands::PilotOpen as u8),
+        "RetractPlumbing" => valve(ActuatorBoardCommands::RetractPlumbing as u8),
+        _ => None,
+    }
+}
+
+/// Interprets one step of `seq_def` generically, same as `sequences::advance_sequence`: a
+/// `leak_check` step captures a pressure baseline on first visit, then on every tick checks the
+/// live pressure-drop rate against `max_leak_rate_psi_per_sec` (failing safe — warn, don't
+/// advance — if a reading is ever missing); once the hold duration elapses without tripping that
+/// rate check, it falls back to comparing the total drop against `max_drop_psi` and transitions
+/// to `pass_next`/`fail_next`. Any other step transitions to `next` once every one of its
+/// `guards` is satisfied.
 fn update_sequence(
     state: &AppState,
-    cfg: &PanelConfig,
+    seq_def: &SequenceDefConfig,
     seq: &Arc<Mutex<SequenceState>>,
     flight_state: FlightState,
+    max_leak_rate_psi_per_sec: f32,
 ) {
     if !is_fill_state(flight_state) {
         let mut s = seq.lock().unwrap();
-        s.step = FillStep::CloseNormallyOpen;
+        s.step_id = seq_def.first_step_id().to_string();
         s.step_started_at = None;
+        s.pressure_at_close_psi = None;
+        s.leak_detected = false;
         return;
     }
 
     let now = Instant::now();
-    let valve = |cmd| state.get_umbilical_valve_state(cmd);
-    let normally_open = valve(ValveBoardCommands::NormallyOpenOpen as u8);
-    let dump_open = valve(ValveBoardCommands::DumpOpen as u8);
-    let nitrogen_open = valve(ActuatorBoardCommands::NitrogenOpen as u8);
-    let nitrous_open = valve(ActuatorBoardCommands::NitrousOpen as u8);
     let pressure = *state.latest_fuel_tank_pressure.lock().unwrap();
 
     let mut s = seq.lock().unwrap();
-    match s.step {
-        FillStep::CloseNormallyOpen => {
-            if normally_open == Some(false) {
-                s.step = FillStep::CloseDump;
-            }
-        }
-        FillStep::CloseDump => {
-            if dump_open == Some(false) {
-                s.step = FillStep::OpenNitrogen;
-            }
-        }
-        FillStep::OpenNitrogen => {
-            if nitrogen_open == Some(true) {
-                s.step = FillStep::WaitForPressure;
-            }
-        }
-        FillStep::WaitForPressure => {
-            if pressure.is_some_and(|p| p >= cfg.pressure_threshold_psi) {
-                s.step = FillStep::CloseNitrogen;
-            }
-        }
-        FillStep::CloseNitrogen => {
-            if nitrogen_open == Some(false) {
-                s.step = FillStep::LeakCheck;
-                s.step_started_at = Some(now);
-            }
-        }
-        FillStep::LeakCheck => {
-            let elapsed = s.step_started_at.map(|t| now.saturating_duration_since(t));
-            if elapsed.is_some_and(|d| d >= cfg.leak_check) {
-                s.step = FillStep::OpenDump;
-                s.step_started_at = None;
-            }
-        }
-        FillStep::OpenDump => {
-            if dump_open == Some(true) {
-                s.step = FillStep::DumpWait;
-                s.step_started_at = Some(now);
-            }
+    let Some(step) = seq_def.step(&s.step_id) else {
+        return; // unknown step id — shouldn't happen once the config's passed validate()
+    };
+
+    if let Some(leak) = &step.leak_check {
+        if s.leak_detected {
+            return; // stuck here until the operator takes the sequence out of the fill states
         }
-        FillStep::DumpWait => {
-            let elapsed = s.step_started_at.map(|t| now.saturating_duration_since(t));
-            if elapsed.is_some_and(|d| d >= cfg.dump_wait) {
-                s.step = FillStep::OpenNitrous;
-                s.step_started_at = None;
+
+        let started = *s.step_started_at.get_or_insert(now);
+        let p_start = *s.pressure_at_close_psi.get_or_insert_with(|| pressure.unwrap_or(0.0));
+        let elapsed = now.saturating_duration_since(started);
+
+        let Some(current) = pressure else {
+            emit_warning(
+                state,
+                format!("leak check on step {:?}: no pressure reading this tick, holding", s.step_id),
+            );
+            return;
+        };
+
+        let elapsed_secs = elapsed.as_secs_f32();
+        if elapsed_secs > 0.0 {
+            let rate_psi_per_sec = (p_start - current) / elapsed_secs;
+            if rate_psi_per_sec > max_leak_rate_psi_per_sec {
+                s.leak_detected = true;
+                emit_error(
+                    state,
+                    format!(
+                        "leak detected on step {:?}: pressure dropping at {rate_psi_per_sec:.2} psi/s \
+                         (limit {max_leak_rate_psi_per_sec:.2} psi/s) — refusing to proceed past leak check",
+                        s.step_id
+                    ),
+                );
+                return;
             }
         }
-        FillStep::OpenNitrous => {
-            if nitrous_open == Some(true) {
-                s.step = FillStep::ReadyToLaunch;
-            }
+
+        if elapsed < Duration::from_secs(leak.hold_duration_sec) {
+            return;
         }
-        FillStep::ReadyToLaunch => {}
+
+        let pass = current >= p_start - leak.max_drop_psi;
+
+        s.step_id = if pass { leak.pass_next.clone() } else { leak.fail_next.clone() };
+        s.step_started_at = None;
+        s.pressure_at_close_psi = None;
+        return;
+    }
+
+    let valve_state = |cmd: &str| valve_state_for(state, cmd);
+    if step.guards.iter().all(|g| g.is_satisfied(&valve_state, pressure))
+        && let Some(next_id) = &step.next
+    {
+        s.step_id = next_id.clone();
+    }
+}
+
+/// Classifies one valve button's LED for the current step: `Solid` while its named valve hasn't
+/// yet reached the target `enabled_commands` asks for (the operator still has something to do),
+/// `FastPulse`/`SlowPulse` once it has but the step's own `guards` haven't all cleared yet (a
+/// pressure threshold vs. anything else), and `Off` if the step doesn't mention this button at
+/// all. A `leak_check` step has no `enabled_commands` of its own, so every gated button just
+/// pulses slowly for its timed duration instead.
+fn blink_for_button(
+    step: &SequenceStepDef,
+    cmd: &str,
+    valve_state: &dyn Fn(&str) -> Option<bool>,
+    pressure_psi: Option<f32>,
+) -> BlinkPattern {
+    if step.leak_check.is_some() {
+        return BlinkPattern::SlowPulse;
+    }
+
+    let Some(ec) = step.enabled_commands.iter().find(|ec| ec.cmd == cmd) else {
+        return BlinkPattern::Off;
+    };
+
+    let satisfied = match (&ec.valve, ec.equals) {
+        (Some(valve), Some(target)) => valve_state(valve) == Some(target),
+        _ => false,
+    };
+    if !satisfied {
+        return BlinkPattern::Solid;
+    }
+
+    if step.guards.iter().all(|g| g.is_satisfied(valve_state, pressure_psi)) {
+        return BlinkPattern::Solid;
+    }
+    if step
+        .guards
+        .iter()
+        .any(|g| matches!(g, Guard::PressureAtLeast { .. } | Guard::PressureBelow { .. }))
+    {
+        BlinkPattern::FastPulse
+    } else {
+        BlinkPattern::SlowPulse
     }
 }
 
 fn compute_allowed_actions(
     state: &AppState,
     flight_state: FlightState,
-    cfg: &PanelConfig,
+    seq_def: &SequenceDefConfig,
     seq: &Arc<Mutex<SequenceState>>,
 ) -> AllowedActions {
     let mut actions = AllowedActions::default();
@@ -373,7 +519,7 @@ fn compute_allowed_actions(
 
     if flight_state == FlightState::Armed {
         actions.launch = true;
-        actions.dump = true;
+        actions.dump = BlinkPattern::Solid;
         return actions;
     }
 
@@ -381,40 +527,15 @@ fn compute_allowed_actions(
         return actions;
     }
 
-    let valve = |cmd| state.get_umbilical_valve_state(cmd);
-    let normally_open = valve(ValveBoardCommands::NormallyOpenOpen as u8);
-    let dump_open = valve(ValveBoardCommands::DumpOpen as u8);
-    let nitrogen_open = valve(ActuatorBoardCommands::NitrogenOpen as u8);
-    let nitrous_open = valve(ActuatorBoardCommands::NitrousOpen as u8);
     let pressure = *state.latest_fuel_tank_pressure.lock().unwrap();
-
-    let step = seq.lock().unwrap().step;
-
-    match step {
-        FillStep::CloseNormallyOpen => {
-            actions.normally_open = normally_open != Some(false);
-        }
-        FillStep::CloseDump => {
-            actions.dump = dump_open != Some(false);
-        }
-        FillStep::OpenNitrogen => {
-            actions.nitrogen = nitrogen_open != Some(true);
-        }
-        FillStep::WaitForPressure => {
-            let _ = pressure.filter(|p| *p >= cfg.pressure_threshold_psi);
-        }
-        FillStep::CloseNitrogen => {
-            actions.nitrogen = nitrogen_open != Some(false);
-        }
-        FillStep::LeakCheck => {}
-        FillStep::OpenDump => {
-            actions.dump = dump_open != Some(true);
-        }
-        FillStep::DumpWait => {}
-        FillStep::OpenNitrous => {
-            actions.nitrous = nitrous_open != Some(true);
-        }
-        FillStep::ReadyToLaunch => {}
+    let valve_state = |cmd: &str| valve_state_for(state, cmd);
+
+    let step_id = seq.lock().unwrap().step_id.clone();
+    if let Some(step) = seq_def.step(&step_id) {
+        actions.normally_open = blink_for_button(step, "NormallyOpen", &valve_state, pressure);
+        actions.dump = blink_for_button(step, "Dump", &valve_state, pressure);
+        actions.nitrogen = blink_for_button(step, "Nitrogen", &valve_state, pressure);
+        actions.nitrous = blink_for_button(step, "Nitrous", &valve_state, pressure);
     }
 
     // Keep extra buttons aligned with frontend availability during fill states.
@@ -440,3 +561,18 @@ fn set_led(gpio: &crate::gpio::GpioPins, pin: u8, on: bool) {
         eprintln!("GPIO LED pin {pin} write failed: {e}");
     }
 }
+
+/// Drives `pin` from a [`BlinkPattern`] instead of a flat level: `Solid`/`Off` clear any running
+/// PWM and fall back to `set_led`, while `SlowPulse`/`FastPulse` start software PWM at the
+/// matching frequency so the operator can tell a timed wait from a pressure wait at a glance.
+fn set_led_pattern(gpio: &crate::gpio::GpioPins, pin: u8, pattern: BlinkPattern) {
+    let result = match pattern {
+        BlinkPattern::Solid => gpio.clear_pwm(pin).and_then(|_| gpio.write_output_pin(pin, true)),
+        BlinkPattern::Off => gpio.clear_pwm(pin).and_then(|_| gpio.write_output_pin(pin, false)),
+        BlinkPattern::SlowPulse => gpio.set_pwm(pin, SLOW_PULSE_HZ, PULSE_DUTY_CYCLE),
+        BlinkPattern::FastPulse => gpio.set_pwm(pin, FAST_PULSE_HZ, PULSE_DUTY_CYCLE),
+    };
+    if let Err(e) = result {
+        eprintln!("GPIO LED pin {pin} blink-pattern write failed: {e}");
+    }
+}
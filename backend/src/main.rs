@@ -2,20 +2,30 @@
 
 #[cfg(feature = "testing")]
 mod dummy_packets;
+mod clock;
+mod command_channel;
+mod deploy_advisor;
+mod firmware_update;
+mod flight_session;
+mod geofence;
 mod gpio;
 mod map;
+mod pulse_task;
 mod radio;
 mod ring_buffer;
+mod safety_config;
 mod safety_task;
 mod state;
+mod state_machine;
 mod telemetry_task;
+mod terrain;
 mod web;
 
 use crate::map::{ensure_map_data, DEFAULT_MAP_REGION};
 use crate::ring_buffer::RingBuffer;
 use crate::safety_task::safety_task;
 use crate::state::AppState;
-use crate::telemetry_task::{get_current_timestamp_ms, telemetry_task};
+use crate::telemetry_task::telemetry_task;
 
 use crate::gpio::Trigger::RisingEdge;
 #[cfg(feature = "testing")]
@@ -37,11 +47,13 @@ use std::time::Duration;
 use tokio::sync::{broadcast, mpsc};
 
 fn clock() -> Box<dyn sedsprintf_rs_2026::router::Clock + Send + Sync> {
-    Box::new(get_current_timestamp_ms)
+    Box::new(crate::clock::timestamp_ms)
 }
 
 const GPIO_IGNITION_PIN: u8 = 5;
 const GPIO_ABORT_PIN: u8 = 9;
+// TODO: Set the correct GPIO pin number once the flow-meter sensor is wired up; placeholder.
+const GPIO_FLOW_METER_PIN: u8 = 13;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -52,6 +64,10 @@ async fn main() -> anyhow::Result<()> {
         .expect("failed to setup gpio pin");
     gpio.setup_output_pin(GPIO_IGNITION_PIN)
         .expect("failed to setup gpio pin");
+    gpio.setup_input_pin(GPIO_FLOW_METER_PIN)
+        .expect("failed to setup gpio pin");
+    gpio.setup_counter_input_pin(GPIO_FLOW_METER_PIN, RisingEdge, Duration::from_millis(5))
+        .expect("failed to setup gpio counter input");
 
     let gpio_clone = gpio.clone();
 
@@ -85,7 +101,26 @@ async fn main() -> anyhow::Result<()> {
             v4           REAL,
             v5           REAL,
             v6           REAL,
-            v7           REAL
+            v7           REAL,
+            session_id   INTEGER
+        );
+        "#,
+    )
+    .execute(&db)
+    .await?;
+
+    // `session_id` was added to `telemetry` after the table above first shipped; the
+    // `CREATE TABLE IF NOT EXISTS` is a no-op against a pre-existing DB, so migrate it in
+    // explicitly instead of letting every insert fail at runtime.
+    crate::flight_session::ensure_session_id_column(&db).await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS flight_sessions (
+            id           INTEGER PRIMARY KEY AUTOINCREMENT,
+            name         TEXT    NOT NULL,
+            opened_at_ms INTEGER NOT NULL,
+            closed_at_ms INTEGER
         );
         "#,
     )
@@ -120,6 +155,42 @@ async fn main() -> anyhow::Result<()> {
     // --- Channels ---
     let (cmd_tx, cmd_rx) = mpsc::channel(32);
     let (ws_tx, _ws_rx) = broadcast::channel(512);
+    let db_write_tx = crate::telemetry_task::start_db_writer_task(db.clone());
+
+    // --- Radios ---
+    let rocket_radio: Arc<Mutex<Box<dyn RadioDevice>>> =
+        match Radio::open(ROCKET_RADIO_PORT, RADIO_BAUDRATE) {
+            Ok(r) => {
+                println!("Rocket radio online");
+                Arc::new(Mutex::new(Box::new(r)))
+            }
+            Err(e) => {
+                println!("Rocket radio missing, using DummyRadio: {}", e);
+                #[cfg(feature = "testing")]
+                {
+                    Arc::new(Mutex::new(Box::new(DummyRadio::new("Rocket Radio"))))
+                }
+                #[cfg(not(feature = "testing"))]
+                panic!("Rocket radio missing and testing mode not enabled")
+            }
+        };
+
+    let umbilical_radio: Arc<Mutex<Box<dyn RadioDevice>>> =
+        match Radio::open(UMBILICAL_RADIO_PORT, RADIO_BAUDRATE) {
+            Ok(r) => {
+                println!("Umbilical radio online");
+                Arc::new(Mutex::new(Box::new(r)))
+            }
+            Err(e) => {
+                println!("Umbilical radio missing, using DummyRadio: {}", e);
+                #[cfg(feature = "testing")]
+                {
+                    Arc::new(Mutex::new(Box::new(DummyRadio::new("Umbilical Radio"))))
+                }
+                #[cfg(not(feature = "testing"))]
+                panic!("Umbilical radio missing and testing mode not enabled")
+            }
+        };
 
     // --- Shared state ---
     let state = Arc::new(AppState {
@@ -132,6 +203,17 @@ async fn main() -> anyhow::Result<()> {
         state: Arc::new(Mutex::new(FlightState::Startup)),
         state_tx: broadcast::channel(16).0,
         gpio,
+        cmd_ack_tx: broadcast::channel(64).0,
+        pending_acks: Arc::new(Mutex::new(std::collections::HashMap::new())),
+        latest_deploy_advisory: Arc::new(Mutex::new(None)),
+        annotations_tx: broadcast::channel(256).0,
+        rocket_radio: rocket_radio.clone(),
+        umbilical_radio: umbilical_radio.clone(),
+        firmware_update_status: Arc::new(Mutex::new(None)),
+        flight_phase: Arc::new(Mutex::new(crate::safety_config::Phase::PreLaunch)),
+        db_write_tx,
+        current_session: Arc::new(Mutex::new(None)),
+        command_delivery: Arc::new(Mutex::new(std::collections::HashMap::new())),
     });
 
     // --- Router endpoint handlers ---
@@ -158,41 +240,6 @@ async fn main() -> anyhow::Result<()> {
 
     let cfg = sedsprintf_rs_2026::router::BoardConfig::new([ground_station_handler, abort_handler]);
 
-    // --- Radios ---
-    let rocket_radio: Arc<Mutex<Box<dyn RadioDevice>>> =
-        match Radio::open(ROCKET_RADIO_PORT, RADIO_BAUDRATE) {
-            Ok(r) => {
-                println!("Rocket radio online");
-                Arc::new(Mutex::new(Box::new(r)))
-            }
-            Err(e) => {
-                println!("Rocket radio missing, using DummyRadio: {}", e);
-                #[cfg(feature = "testing")]
-                {
-                    Arc::new(Mutex::new(Box::new(DummyRadio::new("Rocket Radio"))))
-                }
-                #[cfg(not(feature = "testing"))]
-                panic!("Rocket radio missing and testing mode not enabled")
-            }
-        };
-
-    let umbilical_radio: Arc<Mutex<Box<dyn RadioDevice>>> =
-        match Radio::open(UMBILICAL_RADIO_PORT, RADIO_BAUDRATE) {
-            Ok(r) => {
-                println!("Umbilical radio online");
-                Arc::new(Mutex::new(Box::new(r)))
-            }
-            Err(e) => {
-                println!("Umbilical radio missing, using DummyRadio: {}", e);
-                #[cfg(feature = "testing")]
-                {
-                    Arc::new(Mutex::new(Box::new(DummyRadio::new("Umbilical Radio"))))
-                }
-                #[cfg(not(feature = "testing"))]
-                panic!("Umbilical radio missing and testing mode not enabled")
-            }
-        };
-
     let serialized_handler = {
         let rocket_radio: Arc<Mutex<Box<dyn RadioDevice>>> = Arc::clone(&rocket_radio);
         let umbilical_radio: Arc<Mutex<Box<dyn RadioDevice>>> = Arc::clone(&umbilical_radio);
@@ -201,14 +248,14 @@ async fn main() -> anyhow::Result<()> {
                 .lock()
                 .map_err(|_| TelemetryError::HandlerError("Radio mutex poisoned"))?;
             guard
-                .send_data(pkt)
+                .send_command_reliable(pkt)
                 .map_err(|_| TelemetryError::HandlerError("Tx Handler failed"))?;
-            
+
             let mut guard = umbilical_radio
                 .lock()
                 .map_err(|_| TelemetryError::HandlerError("Radio mutex poisoned"))?;
             guard
-                .send_data(pkt)
+                .send_command_reliable(pkt)
                 .map_err(|_| TelemetryError::HandlerError("Tx Handler failed"))?;
             Ok(())
         })
@@ -231,13 +278,23 @@ async fn main() -> anyhow::Result<()> {
             Duration::from_millis(50),
             move |_| {
                 // now we use the owned clones captured by `move`
-                router_for_cb
-                    .log::<u8>(DataType::Abort, "Manual abort button pressed!".as_bytes())
-                    .expect("failed to log Abort command");
-
                 emit_error(&state_for_cb, "Manual abort button pressed!".to_string());
-
                 println!("Manual abort button pressed!");
+
+                // `send_reliable` retries on a backoff and blocks for it, so it runs on its own
+                // task rather than delaying this callback (and the GPIO edge-watch loop driving
+                // it — see `gpio::setup_callback_input_pin`) for however long that takes.
+                let router_for_abort = router_for_cb.clone();
+                let state_for_abort = state_for_cb.clone();
+                tokio::spawn(async move {
+                    let _ = crate::command_channel::send_reliable(
+                        &state_for_abort,
+                        &router_for_abort,
+                        DataType::Abort,
+                        "Manual abort button pressed!".as_bytes(),
+                    )
+                    .await;
+                });
             },
         )
         .expect("failed to setup gpio callback input");
@@ -248,6 +305,9 @@ async fn main() -> anyhow::Result<()> {
     // --- Background tasks ---
     let _tt = tokio::spawn(telemetry_task(state.clone(), router.clone(), vec!(rocket_radio, umbilical_radio), cmd_rx));
     let _st = tokio::spawn(safety_task(state.clone(), router.clone()));
+    crate::geofence::start_geofence_task(state.clone());
+    crate::deploy_advisor::start_deploy_advisor_task(state.clone());
+    crate::pulse_task::start_pulse_task(state.clone());
 
     // --- Webserver ---
     let app: Router = web::router(state);
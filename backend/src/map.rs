@@ -1,29 +1,87 @@
-use std::path::PathBuf;
+use anyhow::Context;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tokio::fs;
 use tower_http::services::ServeDir;
 
 /// Default region for map tiles. Change this to switch regions in code.
 pub const DEFAULT_MAP_REGION: &str = "north_america";
 
+const MANIFEST_FILE: &str = "manifest.sha256";
+const MAX_TILE_FETCH_ATTEMPTS: u32 = 3;
+
+fn region_base_dir(region: &str) -> PathBuf {
+    PathBuf::from(format!("./backend/data/maps/{region}"))
+}
+
+fn tiles_dir_path(region: &str) -> PathBuf {
+    region_base_dir(region).join("tiles")
+}
+
+fn manifest_path(region: &str) -> PathBuf {
+    region_base_dir(region).join(MANIFEST_FILE)
+}
+
+/// Approximate bounding box in lon/lat degrees (WGS84).
+#[derive(Clone, Copy, Debug)]
+pub struct TileBoundingBox {
+    pub lon_min: f64,
+    pub lat_min: f64,
+    pub lon_max: f64,
+    pub lat_max: f64,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ZoomRange {
+    pub min: u32,
+    pub max: u32,
+}
+
 /// Ensure tiles for a given region are available locally.
 /// Directory layout after success:
 ///   ./data/maps/<region>/tiles/{z}/{x}/{y}.png
+///
+/// When `GS_MAP_VERIFY_TILES=1` is set, also re-hashes every tile listed in `manifest.sha256`
+/// (written by [`bootstrap_region`]) and fails loudly on a missing or corrupted tile rather than
+/// letting `tile_service` quietly serve bad imagery.
 pub async fn ensure_map_data(region: &str) -> anyhow::Result<()> {
-    let base_dir = PathBuf::from(format!("./backend/data/maps/{region}"));
-    let tiles_dir = base_dir.join("tiles");
+    let tiles_dir = tiles_dir_path(region);
 
     if fs::try_exists(&tiles_dir).await.unwrap_or(false) {
         let mut entries = fs::read_dir(&tiles_dir).await?;
-        if entries.next_entry().await?.is_some() {
-            // Tiles exist, all good.
-            return Ok(());
+        if entries.next_entry().await?.is_none() {
+            anyhow::bail!(
+                "No tiles found in {}. Run `groundstation_maps bootstrap-{region}` to generate offline tiles.",
+                tiles_dir.display()
+            );
+        }
+    } else {
+        anyhow::bail!(
+            "No tiles found in {}. Run `groundstation_maps bootstrap-{region}` to generate offline tiles.",
+            tiles_dir.display()
+        );
+    }
+
+    if should_verify_tiles() {
+        let report = verify_region(region).await?;
+        if !report.is_clean() {
+            anyhow::bail!(
+                "Map tile integrity check failed for {region}: {} missing, {} corrupted (see {})",
+                report.missing.len(),
+                report.corrupted.len(),
+                manifest_path(region).display()
+            );
         }
     }
 
-    anyhow::bail!(
-        "No tiles found in {}. Run `groundstation_maps bootstrap-{region}` to generate offline tiles.",
-        tiles_dir.display()
-    );
+    Ok(())
+}
+
+fn should_verify_tiles() -> bool {
+    std::env::var("GS_MAP_VERIFY_TILES").ok().as_deref() == Some("1")
 }
 
 /// Service that serves `/tiles/{z}/{x}/{y}.png` for a region.
@@ -31,3 +89,183 @@ pub fn tile_service(region: &str) -> ServeDir {
     let tiles_dir = format!("./backend/data/maps/{region}/tiles");
     ServeDir::new(tiles_dir)
 }
+
+/// Downloads XYZ tiles (`{z}/{x}/{y}.png`) for `region` across `bbox`/`zoom_range` from
+/// `url_template` (containing literal `{z}`/`{x}`/`{y}` placeholders), writing them into the
+/// same layout `ensure_map_data`/`tile_service` expect. A tile already on disk is skipped, so a
+/// dropped connection just needs the same call re-run to pick up where it left off. Each
+/// downloaded tile's SHA-256 is recorded in `manifest.sha256` for [`verify_region`] to check
+/// later.
+pub async fn bootstrap_region(
+    region: &str,
+    bbox: TileBoundingBox,
+    zoom_range: ZoomRange,
+    url_template: &str,
+) -> anyhow::Result<()> {
+    let tiles_dir = tiles_dir_path(region);
+    fs::create_dir_all(&tiles_dir).await?;
+
+    let client = reqwest::Client::builder()
+        .user_agent("GroundStationMapBootstrap/0.1")
+        .build()?;
+
+    let mut manifest = load_manifest(region).await.unwrap_or_default();
+
+    for z in zoom_range.min..=zoom_range.max {
+        let (x_a, y_a) = lonlat_to_tile(bbox.lon_min, bbox.lat_min, z);
+        let (x_b, y_b) = lonlat_to_tile(bbox.lon_max, bbox.lat_max, z);
+
+        let x_start = x_a.min(x_b);
+        let x_end = x_a.max(x_b);
+        let y_start = y_a.min(y_b);
+        let y_end = y_a.max(y_b);
+
+        for x in x_start..=x_end {
+            for y in y_start..=y_end {
+                let rel_path = format!("{z}/{x}/{y}.png");
+                let tile_path = tiles_dir.join(&rel_path);
+
+                if fs::try_exists(&tile_path).await.unwrap_or(false) {
+                    continue; // already have it — resumable after a dropped connection
+                }
+
+                if let Some(dir) = tile_path.parent() {
+                    fs::create_dir_all(dir).await?;
+                }
+
+                let url = url_template
+                    .replace("{z}", &z.to_string())
+                    .replace("{x}", &x.to_string())
+                    .replace("{y}", &y.to_string());
+
+                match fetch_tile(&client, &url).await {
+                    Ok(Some(bytes)) => {
+                        write_tile_atomic(&tile_path, &bytes).await?;
+                        manifest.insert(rel_path, sha256_hex(&bytes));
+                    }
+                    Ok(None) => {
+                        // no tile at this coordinate (e.g. open ocean) — not an error
+                    }
+                    Err(e) => {
+                        eprintln!("bootstrap_region: giving up on tile {rel_path}: {e:#}");
+                    }
+                }
+            }
+        }
+
+        write_manifest(region, &manifest).await?;
+    }
+
+    Ok(())
+}
+
+async fn fetch_tile(client: &reqwest::Client, url: &str) -> anyhow::Result<Option<Vec<u8>>> {
+    for attempt in 1..=MAX_TILE_FETCH_ATTEMPTS {
+        match client.get(url).send().await {
+            Ok(resp) if resp.status().as_u16() == 404 => return Ok(None),
+            Ok(resp) if resp.status().is_success() => {
+                return Ok(Some(resp.bytes().await?.to_vec()));
+            }
+            Ok(resp) if attempt == MAX_TILE_FETCH_ATTEMPTS => {
+                anyhow::bail!("HTTP {}", resp.status());
+            }
+            Err(e) if attempt == MAX_TILE_FETCH_ATTEMPTS => return Err(e.into()),
+            _ => {}
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    Ok(None)
+}
+
+async fn write_tile_atomic(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let tmp_path = path.with_extension("png.part");
+    fs::write(&tmp_path, bytes).await?;
+    fs::rename(&tmp_path, path).await?;
+    Ok(())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn parse_manifest(raw: &str) -> HashMap<String, String> {
+    raw.lines()
+        .filter_map(|line| line.split_once("  "))
+        .map(|(digest, path)| (path.to_string(), digest.to_string()))
+        .collect()
+}
+
+async fn load_manifest(region: &str) -> anyhow::Result<HashMap<String, String>> {
+    let raw = fs::read_to_string(manifest_path(region)).await?;
+    Ok(parse_manifest(&raw))
+}
+
+async fn write_manifest(region: &str, manifest: &HashMap<String, String>) -> anyhow::Result<()> {
+    let mut entries: Vec<_> = manifest.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    let body: String = entries
+        .into_iter()
+        .map(|(path, digest)| format!("{digest}  {path}\n"))
+        .collect();
+
+    fs::write(manifest_path(region), body).await?;
+    Ok(())
+}
+
+/// Outcome of re-hashing every tile `manifest.sha256` lists against what's actually on disk.
+#[derive(Debug, Default)]
+pub struct TileVerificationReport {
+    pub verified: usize,
+    pub missing: Vec<String>,
+    pub corrupted: Vec<String>,
+}
+
+impl TileVerificationReport {
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.corrupted.is_empty()
+    }
+}
+
+/// Re-hashes every tile listed in `region`'s `manifest.sha256` and reports which ones are
+/// missing from disk or whose digest no longer matches — used by `ensure_map_data` (behind
+/// `GS_MAP_VERIFY_TILES=1`) so a truncated download or bit-rotted tile gets caught at startup
+/// instead of served to a pilot mid-flight.
+pub async fn verify_region(region: &str) -> anyhow::Result<TileVerificationReport> {
+    let manifest = load_manifest(region)
+        .await
+        .with_context(|| format!("no {} for region {region}", MANIFEST_FILE))?;
+    let tiles_dir = tiles_dir_path(region);
+
+    let mut report = TileVerificationReport::default();
+    for (rel_path, expected_digest) in &manifest {
+        match fs::read(tiles_dir.join(rel_path)).await {
+            Ok(bytes) if &sha256_hex(&bytes) == expected_digest => report.verified += 1,
+            Ok(_) => report.corrupted.push(rel_path.clone()),
+            Err(_) => report.missing.push(rel_path.clone()),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Convert lon/lat (deg) to XYZ tile indices for Web Mercator at zoom `z`.
+fn lonlat_to_tile(lon_deg: f64, lat_deg: f64, zoom: u32) -> (u32, u32) {
+    let lat_rad = lat_deg.to_radians();
+    let n = 2f64.powi(zoom as i32);
+
+    let x = ((lon_deg + 180.0) / 360.0 * n).floor();
+    let y = (1.0 - ((lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / PI)) / 2.0 * n;
+
+    let max_idx = n - 1.0;
+    let x = x.max(0.0).min(max_idx) as u32;
+    let y = y.max(0.0).min(max_idx) as u32;
+
+    (x, y)
+}
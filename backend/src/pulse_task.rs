@@ -0,0 +1,61 @@
+//! Polls `GpioPins`' edge counters (flow-meter/tachometer pulses — see `gpio::setup_counter_input_pin`)
+//! and surfaces each pin's running total as a `TelemetryRow`, broadcast on `ws_tx` like
+//! `telemetry_task::handle_packet` does for router packets, so the existing dashboard pipeline
+//! picks it up without any frontend-specific handling. Unlike `handle_packet`, which queues rows
+//! on `db_write_tx` tagged with the open session, each row here is inserted into the `telemetry`
+//! table inline and without a `session_id`.
+
+use crate::state::AppState;
+use crate::telemetry_task::get_current_timestamp_ms;
+use groundstation_shared::TelemetryRow;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// `TelemetryRow.data_type` for a pulse-counter sample: `v0` is the pin number, `v1` the
+/// counter's running total at the time of the tick.
+pub const PULSE_COUNT_DATA_TYPE: &str = "PulseCount";
+
+pub fn start_pulse_task(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut tick = tokio::time::interval(Duration::from_millis(200));
+
+        loop {
+            tick.tick().await;
+
+            let ts_ms = get_current_timestamp_ms() as i64;
+            for (pin, count) in state.gpio.counter_snapshot() {
+                let row = TelemetryRow {
+                    timestamp_ms: ts_ms,
+                    data_type: PULSE_COUNT_DATA_TYPE.to_string(),
+                    v0: Some(pin as f32),
+                    v1: Some(count as f32),
+                    v2: None,
+                    v3: None,
+                    v4: None,
+                    v5: None,
+                    v6: None,
+                    v7: None,
+                };
+
+                sqlx::query(
+                    "INSERT INTO telemetry (timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                )
+                    .bind(row.timestamp_ms)
+                    .bind(&row.data_type)
+                    .bind(row.v0)
+                    .bind(row.v1)
+                    .bind(row.v2)
+                    .bind(row.v3)
+                    .bind(row.v4)
+                    .bind(row.v5)
+                    .bind(row.v6)
+                    .bind(row.v7)
+                    .execute(&state.db)
+                    .await
+                    .expect("DB insert into telemetry failed");
+
+                let _ = state.ws_tx.send(row);
+            }
+        }
+    });
+}
@@ -1,22 +1,73 @@
+#[cfg(not(feature = "testing"))]
 use crate::dummy_packets::get_dummy_packet;
 use anyhow::Context;
 use sedsprintf_rs_2026::router::Router;
-use sedsprintf_rs_2026::{TelemetryError, TelemetryResult};
+use sedsprintf_rs_2026::TelemetryResult;
 use serial::{SerialPort, SystemPort};
 use std::error::Error;
 use std::io::{Read, Write};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 pub const RADIO_PORT: &str = "/dev/ttyUSB1";
 pub const RADIO_BAUDRATE: usize = 57_600;
 pub const MAX_PACKET_SIZE: usize = 256;
 
+/// Marks the start of a frame on the wire — `recv_packet` scans the stream byte-by-byte for
+/// this before trusting anything that follows as a length, so a dropped or corrupted byte
+/// mid-stream costs at most the rest of that one frame instead of permanently misaligning
+/// every `read_exact` after it.
+const RADIO_SYNC: [u8; 2] = [0xAA, 0x55];
+
+/// A frame carrying a payload that expects a matching `FRAME_TYPE_ACK` reply — telemetry from
+/// the board and commands from the ground station are both sent this way.
+const FRAME_TYPE_DATA: u8 = 0;
+/// A frame with no payload, sent back in reply to a `FRAME_TYPE_DATA` frame, carrying that
+/// frame's sequence number so the original sender knows it arrived.
+const FRAME_TYPE_ACK: u8 = 1;
+
+/// How long `send_command_reliable` waits for the matching `ACK` before retransmitting.
+pub const COMMAND_ACK_TIMEOUT: Duration = Duration::from_millis(500);
+/// Retransmits `send_command_reliable` attempts before giving up and surfacing an error —
+/// e.g. 3 means up to 4 total transmissions of the same frame.
+pub const COMMAND_MAX_RETRIES: u8 = 3;
+
+/// CRC-16-CCITT (poly `0x1021`, init `0xFFFF`) over `length || type || seq || payload` —
+/// appended as a 2-byte LE trailer by `write_frame`, recomputed by `read_frame` to catch the
+/// partial-byte corruption a noisy 57 600-baud link produces, which a bare length prefix has
+/// no way to detect.
+pub(crate) fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &b in data {
+        crc ^= (b as u16) << 8;
+        for _ in 0..8 {
+            if crc & 0x8000 != 0 {
+                crc = (crc << 1) ^ 0x1021;
+            } else {
+                crc <<= 1;
+            }
+        }
+    }
+    crc
+}
+
 // ======================================================================
 //  Radio Device Trait
 // ======================================================================
 pub trait RadioDevice: Send {
     fn recv_packet(&mut self, router: &Router) -> TelemetryResult<()>;
     fn send_data(&mut self, payload: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Sends `payload` and blocks for a matching `ACK`, retransmitting the same frame up to
+    /// `COMMAND_MAX_RETRIES` times before giving up — unlike `send_data`, the caller knows
+    /// whether the other side actually got it, which matters for something like a valve or
+    /// igniter command.
+    fn send_command_reliable(&mut self, payload: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Same as [`send_command_reliable`](Self::send_command_reliable), but also reports how many
+    /// transmissions it took (1 meaning the first one landed) — for a caller that needs to surface
+    /// retry counts, like a firmware update's per-block progress, rather than just success/failure.
+    fn send_command_reliable_counted(
+        &mut self,
+        payload: &[u8],
+    ) -> Result<u32, Box<dyn Error + Send + Sync>>;
 }
 
 // ======================================================================
@@ -24,6 +75,14 @@ pub trait RadioDevice: Send {
 // ======================================================================
 pub struct Radio {
     inner: SystemPort,
+    /// Monotonically increasing, shared by `send_data` and `send_command_reliable` alike, so
+    /// two frames in flight at once never collide — `recv_packet`'s dedup on the other end
+    /// keys off this.
+    next_seq: u8,
+    /// The last `FRAME_TYPE_DATA` sequence number `recv_packet` has already forwarded to the
+    /// router — lets a retransmitted command (identical seq, resent because its `ACK` got
+    /// lost) be re-acked without being applied twice.
+    last_applied_seq: Option<u8>,
 }
 
 impl Radio {
@@ -40,46 +99,173 @@ impl Radio {
             })
             .context("failed to configure serial port")?;
         inner.set_timeout(Duration::from_millis(200))?;
-        Ok(Self { inner })
+        Ok(Self { inner, next_seq: 0, last_applied_seq: None })
+    }
+
+    fn take_seq(&mut self) -> u8 {
+        let seq = self.next_seq;
+        self.next_seq = self.next_seq.wrapping_add(1);
+        seq
+    }
+
+    /// Reads one byte at a time until the last two bytes read match `RADIO_SYNC`, so a
+    /// misaligned stream (garbage left over from a corrupted frame, or a port that was opened
+    /// mid-transmission) resynchronizes on its own instead of needing the port reopened.
+    fn sync_to_preamble(&mut self) -> std::io::Result<()> {
+        let mut window = [0u8; 2];
+        self.inner.read_exact(&mut window)?;
+        while window != RADIO_SYNC {
+            window[0] = window[1];
+            let mut next = [0u8; 1];
+            self.inner.read_exact(&mut next)?;
+            window[1] = next[0];
+        }
+        Ok(())
+    }
+
+    /// Blocks for one well-formed frame: `RADIO_SYNC || LE length || type || seq || payload ||
+    /// LE CRC-16-CCITT(length || type || seq || payload)`. A bad length or a CRC mismatch is
+    /// not a fatal error here — it just means whatever came through was corrupted, so this
+    /// discards it and resumes scanning for the next sync word internally instead of making
+    /// every caller re-implement that retry.
+    fn read_frame(&mut self) -> std::io::Result<(u8, u8, Vec<u8>)> {
+        loop {
+            self.sync_to_preamble()?;
+
+            let mut len_buf = [0u8; 2];
+            self.inner.read_exact(&mut len_buf)?;
+            let frame_len = u16::from_le_bytes(len_buf) as usize;
+
+            // `type` + `seq` are always present; `payload` beyond that is optional (ACKs carry
+            // none).
+            if frame_len < 2 || frame_len > MAX_PACKET_SIZE + 2 {
+                continue;
+            }
+
+            let mut body = vec![0u8; frame_len + 2];
+            self.inner.read_exact(&mut body)?;
+            let (content, crc_bytes) = body.split_at(frame_len);
+            let received_crc = u16::from_le_bytes([crc_bytes[0], crc_bytes[1]]);
+
+            let mut crc_input = Vec::with_capacity(2 + frame_len);
+            crc_input.extend_from_slice(&len_buf);
+            crc_input.extend_from_slice(content);
+
+            if crc16_ccitt(&crc_input) != received_crc {
+                continue;
+            }
+
+            let frame_type = content[0];
+            let seq = content[1];
+            return Ok((frame_type, seq, content[2..].to_vec()));
+        }
+    }
+
+    fn write_frame(&mut self, frame_type: u8, seq: u8, payload: &[u8]) -> std::io::Result<()> {
+        let frame_len = 2 + payload.len();
+        let len_bytes = (frame_len as u16).to_le_bytes();
+
+        let mut crc_input = Vec::with_capacity(2 + frame_len);
+        crc_input.extend_from_slice(&len_bytes);
+        crc_input.push(frame_type);
+        crc_input.push(seq);
+        crc_input.extend_from_slice(payload);
+        let crc_bytes = crc16_ccitt(&crc_input).to_le_bytes();
+
+        self.inner.write_all(&RADIO_SYNC)?;
+        self.inner.write_all(&len_bytes)?;
+        self.inner.write_all(&[frame_type, seq])?;
+        self.inner.write_all(payload)?;
+        self.inner.write_all(&crc_bytes)?;
+        self.inner.flush()
+    }
+
+    /// Reads frames until one is the `ACK` for `seq`, `COMMAND_ACK_TIMEOUT` elapses, or a real
+    /// I/O error occurs. A `DATA` frame seen while waiting is still acked (the sender on the
+    /// other end is retransmitting it on its own timer, independent of ours) but its payload
+    /// can't be forwarded from here — `recv_packet` has the router handle, this doesn't.
+    fn wait_for_ack(&mut self, seq: u8) -> std::io::Result<bool> {
+        let deadline = Instant::now() + COMMAND_ACK_TIMEOUT;
+        while Instant::now() < deadline {
+            match self.read_frame() {
+                Ok((FRAME_TYPE_ACK, ack_seq, _)) if ack_seq == seq => return Ok(true),
+                Ok((FRAME_TYPE_DATA, data_seq, _)) => {
+                    let _ = self.write_frame(FRAME_TYPE_ACK, data_seq, &[]);
+                }
+                Ok(_) => {} // stale ACK for a previous attempt — keep waiting
+                Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(false)
     }
 }
 
 impl RadioDevice for Radio {
-    /// Blocking receive of one TelemetryPacket
+    /// Blocking receive of one `FRAME_TYPE_DATA` frame, acking it and forwarding its payload to
+    /// `router`. `ACK` frames seen here (e.g. one that arrived after `send_command_reliable`
+    /// already gave up and moved on) have nothing waiting on them and are dropped.
     fn recv_packet(&mut self, router: &Router) -> TelemetryResult<()> {
-        // read length prefix
-        let mut len_buf = [0u8; 2];
-        self.inner.read_exact(&mut len_buf)?;
-        let frame_len = u16::from_le_bytes(len_buf) as usize;
-
-        if frame_len == 0 || frame_len > MAX_PACKET_SIZE {
-            return Err(TelemetryError::HandlerError(
-                "invalid frame length from radio",
-            ));
-        }
+        loop {
+            let (frame_type, seq, payload) = self.read_frame()?;
 
-        // read payload
-        let mut payload = vec![0u8; frame_len];
-        self.inner.read_exact(&mut payload)?;
+            if frame_type != FRAME_TYPE_DATA {
+                continue;
+            }
 
-        router.rx_serialized_packet_to_queue(&*payload)
+            // Every DATA frame gets acked, even a repeat — the sender is still retransmitting
+            // because it never saw the first ACK go out.
+            let _ = self.write_frame(FRAME_TYPE_ACK, seq, &[]);
+
+            if self.last_applied_seq == Some(seq) {
+                continue; // already applied this one — don't double up the command/telemetry
+            }
+            self.last_applied_seq = Some(seq);
+
+            return router.rx_serialized_packet_to_queue(&payload);
+        }
     }
 
-    /// Blocking send of serialized bytes (length-prefixed).
+    /// Fire-and-forget send, framed as a single `FRAME_TYPE_DATA` frame with no wait for `ACK`.
     fn send_data(&mut self, payload: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>> {
         let len = payload.len();
 
-        if len == 0 || len > u16::MAX as usize {
+        if len == 0 || len > u16::MAX as usize - 2 {
             return Err(format!("packet too large to send over radio: {len} bytes").into());
         }
 
-        let len_bytes = (len as u16).to_le_bytes();
-
-        self.inner.write_all(&len_bytes)?;
-        self.inner.write_all(payload)?;
-        self.inner.flush()?;
+        let seq = self.take_seq();
+        self.write_frame(FRAME_TYPE_DATA, seq, payload)?;
         Ok(())
     }
+
+    fn send_command_reliable(&mut self, payload: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.send_command_reliable_counted(payload).map(|_attempts| ())
+    }
+
+    fn send_command_reliable_counted(
+        &mut self,
+        payload: &[u8],
+    ) -> Result<u32, Box<dyn Error + Send + Sync>> {
+        let len = payload.len();
+
+        if len == 0 || len > u16::MAX as usize - 2 {
+            return Err(format!("command too large to send over radio: {len} bytes").into());
+        }
+
+        let seq = self.take_seq();
+
+        for attempt in 0..=COMMAND_MAX_RETRIES {
+            self.write_frame(FRAME_TYPE_DATA, seq, payload)?;
+            if self.wait_for_ack(seq)? {
+                return Ok(attempt as u32 + 1);
+            }
+            tracing::warn!("radio command seq {seq} unacked (attempt {attempt}), retrying");
+        }
+
+        Err(format!("command seq {seq} not acknowledged after {} attempts", COMMAND_MAX_RETRIES + 1)
+            .into())
+    }
 }
 
 // ======================================================================
@@ -90,12 +276,20 @@ pub struct DummyRadio;
 
 impl DummyRadio {
     pub fn new() -> Self {
-        DummyRadio 
+        DummyRadio
     }
 }
 
 impl RadioDevice for DummyRadio {
+    /// With the `testing` feature, pulls from `flight_sim`'s physics-driven profile (altitude,
+    /// velocity and `FlightState` all consistent with each other and with commands already
+    /// applied via `flight_sim::handle_command`) instead of `get_dummy_packet`'s independent
+    /// per-sensor random draws, so a dummy-radio demo/replay session produces telemetry the
+    /// dashboard, geofence and deploy advisor can reason about coherently.
     fn recv_packet(&mut self, router: &Router) -> TelemetryResult<()> {
+        #[cfg(feature = "testing")]
+        let pkt = crate::flight_sim::next_state_aware_packet()?;
+        #[cfg(not(feature = "testing"))]
         let pkt = get_dummy_packet()?;
 
         // No incoming packets in dummy mode
@@ -109,4 +303,22 @@ impl RadioDevice for DummyRadio {
         );
         Ok(())
     }
+
+    /// No hardware to round-trip a real ACK through, so this simulates one immediately —
+    /// callers (and tests) exercising the reliable command path don't need real hardware to
+    /// see the success case.
+    fn send_command_reliable(&mut self, payload: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.send_command_reliable_counted(payload).map(|_attempts| ())
+    }
+
+    fn send_command_reliable_counted(
+        &mut self,
+        payload: &[u8],
+    ) -> Result<u32, Box<dyn Error + Send + Sync>> {
+        tracing::info!(
+            "DummyRadio: simulating immediate ACK for {}-byte reliable command",
+            payload.len()
+        );
+        Ok(1)
+    }
 }
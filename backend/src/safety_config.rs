@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const DEFAULT_SAFETY_CONFIG_PATH: &str = "layout/safety_rules.json";
+
+/// Flight phase, estimated live by `safety_task::PhaseEstimator` from acceleration magnitude and
+/// altitude trend. A rule that sets `SafetyRule::active_phases` only evaluates while the
+/// estimator is in one of the named phases; a rule that leaves it `None` evaluates in every
+/// phase, matching the envelope's previous always-on behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    PreLaunch,
+    Boost,
+    Coast,
+    Apogee,
+    Descent,
+    Landed,
+}
+
+/// One bound check against a single field of a telemetry packet's decoded `f32` values. Keyed by
+/// `data_type` (matched against `DataType::as_str()` — `DataType` is an opaque external enum this
+/// crate can't derive `Deserialize` for, the same workaround `flight_sim::FaultTarget::DataType`
+/// already uses) and `field_index` (the position `pkt.data_as_f32()` returns it at, e.g. 0/1/2
+/// for `AccelData`'s X/Y/Z).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SafetyRule {
+    pub data_type: String,
+    pub field_index: usize,
+    pub min: f32,
+    pub max: f32,
+    /// Maximum allowed `|value - previous| / Δt` (seconds) before this rule also counts as
+    /// breached, even with the value inside `min..=max`. `None` disables the rate check.
+    #[serde(default)]
+    pub max_rate_per_sec: Option<f32>,
+    /// Number of consecutive breaching samples required before a warning fires — absorbs
+    /// single-sample noise spikes.
+    #[serde(default = "default_debounce_count")]
+    pub debounce_count: u32,
+    /// Once latched, the value must return inside `bound ± hysteresis` before the rule can
+    /// re-arm and fire again on a later breach.
+    #[serde(default)]
+    pub hysteresis: f32,
+    /// Whether a latched breach of this rule also drives the safety task's abort path, in
+    /// addition to the warning it always emits.
+    #[serde(default)]
+    pub abort_on_breach: bool,
+    /// Phases this rule evaluates in. `None` (the default) means every phase — a rule doesn't
+    /// need to know about phases to work the way it did before this field existed.
+    #[serde(default)]
+    pub active_phases: Option<Vec<Phase>>,
+    pub message: String,
+}
+
+impl SafetyRule {
+    pub fn is_active_in(&self, phase: Phase) -> bool {
+        self.active_phases.as_ref().is_none_or(|phases| phases.contains(&phase))
+    }
+}
+
+fn default_debounce_count() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SafetyConfig {
+    pub rules: Vec<SafetyRule>,
+}
+
+impl SafetyConfig {
+    /// Checks every rule references a field index a real packet could plausibly produce isn't
+    /// knowable without the external `DataType`'s decoder, so this only checks what the config
+    /// itself can get wrong: a bad hand-edited file fails loudly at load time rather than
+    /// silently never firing.
+    pub fn validate(&self) -> Result<(), String> {
+        for rule in &self.rules {
+            if rule.min > rule.max {
+                return Err(format!(
+                    "rule for {:?}[{}] has min {} > max {}",
+                    rule.data_type, rule.field_index, rule.min, rule.max
+                ));
+            }
+            if rule.debounce_count == 0 {
+                return Err(format!(
+                    "rule for {:?}[{}] has debounce_count 0 — must be at least 1",
+                    rule.data_type, rule.field_index
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn safety_config_path() -> PathBuf {
+    if let Ok(path) = std::env::var("GS_SAFETY_CONFIG_PATH") {
+        return PathBuf::from(path);
+    }
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_SAFETY_CONFIG_PATH)
+}
+
+/// Reads and validates the safety config file, exactly like `sequence_config::load_sequence_config_file`
+/// reads `SequenceDefConfig` — no fallback here, that's `effective_safety_config`'s job.
+pub fn load_safety_config_file() -> Result<SafetyConfig, String> {
+    let path = safety_config_path();
+    let raw = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read safety config {path:?}: {e}"))?;
+    let cfg: SafetyConfig =
+        serde_json::from_str(&raw).map_err(|e| format!("Invalid safety config JSON: {e}"))?;
+    cfg.validate()?;
+    Ok(cfg)
+}
+
+/// The built-in acceleration envelope `safety_task` used to hardcode, expressed as rules — used
+/// whenever no safety config file is present (or it fails to parse/validate) so a campaign that
+/// hasn't written one yet still flies with the original thresholds.
+pub fn default_safety_config() -> SafetyConfig {
+    SafetyConfig {
+        rules: vec![
+            SafetyRule {
+                data_type: "ACCEL_DATA".to_string(),
+                field_index: 0,
+                min: -10.0,
+                max: 10.0,
+                max_rate_per_sec: None,
+                debounce_count: 1,
+                hysteresis: 0.0,
+                abort_on_breach: false,
+                active_phases: Some(vec![Phase::PreLaunch, Phase::Landed]),
+                message: "Critical: Acceleration X threshold exceeded!".to_string(),
+            },
+            SafetyRule {
+                data_type: "ACCEL_DATA".to_string(),
+                field_index: 1,
+                min: -10.0,
+                max: 10.0,
+                max_rate_per_sec: None,
+                debounce_count: 1,
+                hysteresis: 0.0,
+                abort_on_breach: false,
+                active_phases: Some(vec![Phase::PreLaunch, Phase::Landed]),
+                message: "Critical: Acceleration Y threshold exceeded!".to_string(),
+            },
+            SafetyRule {
+                data_type: "ACCEL_DATA".to_string(),
+                field_index: 2,
+                min: -10.0,
+                max: 100.0,
+                max_rate_per_sec: None,
+                debounce_count: 1,
+                hysteresis: 0.0,
+                abort_on_breach: false,
+                active_phases: Some(vec![Phase::PreLaunch, Phase::Landed]),
+                message: "Critical: Acceleration Z threshold exceeded!".to_string(),
+            },
+        ],
+    }
+}
+
+/// Loads the safety config file, falling back to [`default_safety_config`] if it's missing or
+/// fails to parse/validate — a campaign that hasn't authored custom thresholds yet still flies
+/// with the original envelope.
+pub fn effective_safety_config() -> SafetyConfig {
+    match load_safety_config_file() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            eprintln!("Safety config fallback to built-in defaults: {e}");
+            default_safety_config()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_safety_config_is_valid() {
+        let cfg = default_safety_config();
+        cfg.validate().expect("default safety config should validate");
+        assert_eq!(cfg.rules.len(), 3);
+    }
+
+    #[test]
+    fn rejects_inverted_bounds() {
+        let cfg = SafetyConfig {
+            rules: vec![SafetyRule {
+                data_type: "ACCEL_DATA".to_string(),
+                field_index: 0,
+                min: 10.0,
+                max: -10.0,
+                max_rate_per_sec: None,
+                debounce_count: 1,
+                hysteresis: 0.0,
+                abort_on_breach: false,
+                active_phases: None,
+                message: "bad".to_string(),
+            }],
+        };
+        assert!(cfg.validate().is_err());
+    }
+
+    #[test]
+    fn active_phases_limits_rule_to_listed_phases() {
+        let cfg = default_safety_config();
+        let ground_only = &cfg.rules[0];
+        assert!(ground_only.is_active_in(Phase::PreLaunch));
+        assert!(ground_only.is_active_in(Phase::Landed));
+        assert!(!ground_only.is_active_in(Phase::Boost));
+    }
+}
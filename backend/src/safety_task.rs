@@ -1,19 +1,187 @@
+use crate::safety_config::{effective_safety_config, Phase, SafetyRule};
 use crate::state::AppState;
 use crate::web::emit_warning;
 use sedsprintf_rs_2026::config::DataType;
 use sedsprintf_rs_2026::router::Router;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::time::{sleep, Duration};
 
-const ACCELERATION_X_MIN_THRESHOLD: f32 = -10.0; // m/s²
-const ACCELERATION_X_MAX_THRESHOLD: f32 = 10.0; // m/s²
+/// Tracked per `(data_type, field_index)` across ticks so a [`SafetyRule`]'s debounce/hysteresis/
+/// rate-of-change checks can see history instead of judging each sample in isolation.
+#[derive(Debug, Default)]
+struct RuleState {
+    last_value: Option<f32>,
+    last_timestamp_ms: Option<u64>,
+    consecutive_breaches: u32,
+    /// Set once `debounce_count` consecutive breaches fire the warning; held until the value
+    /// returns inside `min..=max` widened by `hysteresis`, so a value oscillating right at the
+    /// bound doesn't re-warn every tick.
+    latched: bool,
+}
+
+/// Checks `rule` against `value`/`timestamp_ms`, updates `rule_state` in place, and returns
+/// `true` on a rising edge — i.e. the tick where this breach first becomes the one that should be
+/// reported — so the caller only calls `emit_warning`/triggers an abort once per latch.
+fn evaluate_rule(rule: &SafetyRule, rule_state: &mut RuleState, value: f32, timestamp_ms: u64) -> bool {
+    let out_of_bounds = value < rule.min || value > rule.max;
+    let rate_exceeded = rule.max_rate_per_sec.is_some_and(|max_rate| {
+        match (rule_state.last_value, rule_state.last_timestamp_ms) {
+            (Some(last_value), Some(last_ts)) if timestamp_ms > last_ts => {
+                let dt_secs = (timestamp_ms - last_ts) as f32 / 1000.0;
+                (value - last_value).abs() / dt_secs > max_rate
+            }
+            _ => false,
+        }
+    });
+    let breached = out_of_bounds || rate_exceeded;
+
+    let rising_edge = if breached {
+        rule_state.consecutive_breaches += 1;
+        !rule_state.latched && rule_state.consecutive_breaches >= rule.debounce_count
+    } else {
+        if rule_state.latched {
+            let back_inside = value >= rule.min - rule.hysteresis && value <= rule.max + rule.hysteresis;
+            if back_inside {
+                rule_state.latched = false;
+            }
+        }
+        rule_state.consecutive_breaches = 0;
+        false
+    };
+
+    if rising_edge {
+        rule_state.latched = true;
+    }
+
+    rule_state.last_value = Some(value);
+    rule_state.last_timestamp_ms = Some(timestamp_ms);
 
-const ACCELERATION_Y_MIN_THRESHOLD: f32 = -10.0; // m/s²
-const ACCELERATION_Y_MAX_THRESHOLD: f32 = 10.0; // m/s²
-const ACCELERATION_Z_MIN_THRESHOLD: f32 = -10.0; // m/s²
-const ACCELERATION_Z_MAX_THRESHOLD: f32 = 100.0; // m/s²
+    rising_edge
+}
+
+const PHASE_DEBOUNCE_SAMPLES: u32 = 3;
+const BOOST_ACCEL_MAG_MPS2: f32 = 30.0;
+const COAST_ACCEL_MAG_MPS2: f32 = 2.0;
+const GRAVITY_MPS2: f32 = 9.81;
+const LANDED_ACCEL_MAG_TOLERANCE_MPS2: f32 = 2.0;
+const ALTITUDE_STABLE_DELTA_M: f32 = 1.0;
+const BURNOUT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Windowed flight-phase estimator, fed one `AccelData`/`BarometerData` sample at a time. Guesses
+/// which part of the flight is underway from acceleration magnitude and altitude trend alone, so
+/// `SafetyRule::active_phases` can keep tight ground-only limits from firing spuriously during
+/// boost/coast without an operator toggling anything by hand. Each candidate transition must hold
+/// for `PHASE_DEBOUNCE_SAMPLES` consecutive samples before it takes effect, the same debounce
+/// shape `RuleState` uses for breaches.
+///
+/// `accel_consecutive`/`altitude_consecutive` are tracked separately rather than as one shared
+/// counter: `safety_task`'s packet loop (and `flight_sim::next_sensor_packet`'s round-robin feed)
+/// calls `observe_accel_magnitude` on every `AccelData` packet and `observe_altitude` on every
+/// `BarometerData` packet regardless of current phase, interleaved with every other channel. A
+/// single shared counter meant whichever source *wasn't* the current phase's transition trigger
+/// reset it to 0 via its own `None` candidate before the real trigger could ever reach
+/// `PHASE_DEBOUNCE_SAMPLES` — no transition past the initial phase could ever fire.
+struct PhaseEstimator {
+    phase: Phase,
+    accel_consecutive: u32,
+    altitude_consecutive: u32,
+    phase_entered_at: Instant,
+    last_altitude_m: Option<f32>,
+    altitude_rising: Option<bool>,
+    altitude_stable: bool,
+}
+
+impl PhaseEstimator {
+    fn new() -> Self {
+        Self {
+            phase: Phase::PreLaunch,
+            accel_consecutive: 0,
+            altitude_consecutive: 0,
+            phase_entered_at: Instant::now(),
+            last_altitude_m: None,
+            altitude_rising: None,
+            altitude_stable: false,
+        }
+    }
+
+    fn observe_accel_magnitude(&mut self, magnitude: f32) {
+        let candidate = match self.phase {
+            Phase::PreLaunch => (magnitude > BOOST_ACCEL_MAG_MPS2).then_some(Phase::Boost),
+            Phase::Boost => {
+                let burned_out = self.phase_entered_at.elapsed() > BURNOUT_TIMEOUT;
+                (magnitude < COAST_ACCEL_MAG_MPS2 || burned_out).then_some(Phase::Coast)
+            }
+            // Coast -> Apogee and Apogee -> Descent transition on altitude trend instead, in
+            // `observe_altitude`.
+            Phase::Coast | Phase::Apogee => None,
+            Phase::Descent => {
+                let near_g = (magnitude - GRAVITY_MPS2).abs() < LANDED_ACCEL_MAG_TOLERANCE_MPS2;
+                (near_g && self.altitude_stable).then_some(Phase::Landed)
+            }
+            Phase::Landed => None,
+        };
+        self.advance(candidate, true);
+    }
+
+    fn observe_altitude(&mut self, altitude_m: f32) {
+        if let Some(last) = self.last_altitude_m {
+            let rising = altitude_m > last;
+            self.altitude_rising = Some(rising);
+            self.altitude_stable = (altitude_m - last).abs() < ALTITUDE_STABLE_DELTA_M;
+
+            let candidate = match self.phase {
+                Phase::Coast if !rising => Some(Phase::Apogee),
+                Phase::Apogee if !rising => Some(Phase::Descent),
+                _ => None,
+            };
+            self.advance(candidate, false);
+        }
+        self.last_altitude_m = Some(altitude_m);
+    }
+
+    /// `from_accel` picks which of the two independent counters this candidate advances/resets —
+    /// see the struct doc comment for why they can't share one.
+    fn advance(&mut self, candidate: Option<Phase>, from_accel: bool) {
+        let counter_before = if from_accel { self.accel_consecutive } else { self.altitude_consecutive };
+
+        let Some(next) = candidate else {
+            // No candidate this tick: only the source that just observed a non-candidate sample
+            // resets — the other source's progress is untouched.
+            if from_accel {
+                self.accel_consecutive = 0;
+            } else {
+                self.altitude_consecutive = 0;
+            }
+            return;
+        };
+
+        let count = counter_before + 1;
+        if count >= PHASE_DEBOUNCE_SAMPLES {
+            self.phase = next;
+            self.phase_entered_at = Instant::now();
+            // A phase change zeroes both counters, not just the one that triggered it, so a
+            // sample from the other source doesn't inherit unrelated progress into the new phase.
+            self.accel_consecutive = 0;
+            self.altitude_consecutive = 0;
+        } else if from_accel {
+            self.accel_consecutive = count;
+        } else {
+            self.altitude_consecutive = count;
+        }
+    }
+
+    fn phase(&self) -> Phase {
+        self.phase
+    }
+}
 
 pub async fn safety_task(state: Arc<AppState>, router: Arc<Router>) {
+    let config = effective_safety_config();
+    let mut rule_states: HashMap<(String, usize), RuleState> = HashMap::new();
+    let mut phase_estimator = PhaseEstimator::new();
+
     let mut abort = false;
     let mut count: u64 = 0;
     loop {
@@ -50,55 +218,156 @@ pub async fn safety_task(state: Arc<AppState>, router: Arc<Router>) {
 
         // Loop through all recent packets and check safety conditions
         for pkt in packets {
-            // Example safety check: if accel X > threshold, warn
             match pkt.data_type() {
-                DataType::AccelData => {
-                    let values = pkt.data_as_f32().unwrap_or_else(|_| vec![0f32; 3]);
-
-                    // X axis: use `first()` and collapse the nested if
-                    if let Some(accel_x) = values.first()
-                        && ((ACCELERATION_X_MIN_THRESHOLD > *accel_x)
-                            || (*accel_x > ACCELERATION_X_MAX_THRESHOLD))
-                    {
-                        emit_warning(&state, "Critical: Acceleration X threshold exceeded!");
-                    }
-
-                    // Y axis: collapse nested if
-                    if let Some(accel_y) = values.get(1)
-                        && ((ACCELERATION_Y_MIN_THRESHOLD > *accel_y)
-                            || (*accel_y > ACCELERATION_Y_MAX_THRESHOLD))
-                    {
-                        emit_warning(&state, "Critical: Acceleration Y threshold exceeded!");
-                    }
-
-                    // Z axis: collapse nested if
-                    if let Some(accel_z) = values.get(2)
-                        && ((ACCELERATION_Z_MIN_THRESHOLD > *accel_z)
-                            || (*accel_z > ACCELERATION_Z_MAX_THRESHOLD))
-                    {
-                        emit_warning(&state, "Critical: Acceleration Z threshold exceeded!");
-                    }
-                }
                 DataType::GenericError => {
                     abort = true;
                     emit_warning(&state, "Generic Error received from vehicle!");
                     println!("Safety: Generic Error packet received");
                 }
-                _ => {}
+                data_type => {
+                    let Ok(values) = pkt.data_as_f32() else { continue };
+                    let timestamp_ms = pkt.timestamp() as u64;
+
+                    match data_type {
+                        DataType::AccelData => {
+                            if let (Some(&x), Some(&y), Some(&z)) =
+                                (values.first(), values.get(1), values.get(2))
+                            {
+                                phase_estimator
+                                    .observe_accel_magnitude((x * x + y * y + z * z).sqrt());
+                            }
+                        }
+                        DataType::BarometerData => {
+                            if let Some(&altitude_m) = values.get(2) {
+                                phase_estimator.observe_altitude(altitude_m);
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    let current_phase = phase_estimator.phase();
+                    let data_type_str = data_type.as_str();
+                    for rule in config.rules.iter().filter(|rule| {
+                        rule.data_type == data_type_str && rule.is_active_in(current_phase)
+                    }) {
+                        let Some(&value) = values.get(rule.field_index) else { continue };
+                        let rule_state = rule_states
+                            .entry((rule.data_type.clone(), rule.field_index))
+                            .or_default();
+
+                        if evaluate_rule(rule, rule_state, value, timestamp_ms) {
+                            emit_warning(&state, rule.message.clone());
+                            if rule.abort_on_breach {
+                                abort = true;
+                            }
+                        }
+                    }
+                }
             }
         }
 
+        *state.flight_phase.lock().unwrap() = phase_estimator.phase();
+
         if abort {
-            // Send abort command via router
-            router
-                .log::<u8>(
-                    DataType::Abort,
-                    "Safety Task Abort Command Issued".as_bytes(),
-                )
-                .expect("failed to log Abort command");
+            // Retries on a backoff until a matching ack turns up in the ring buffer (or gives
+            // up and says so via `emit_warning`/`AppState::command_delivery`) instead of the
+            // single fire-and-forget `router.log` this replaced, which had no way to tell
+            // whether the vehicle actually aborted.
+            let _ = crate::command_channel::send_reliable(
+                &state,
+                &router,
+                DataType::Abort,
+                "Safety Task Abort Command Issued".as_bytes(),
+            )
+            .await;
             println!("Safety task: Abort command sent");
             // Once aborted, we can exit the loop
             break;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boost_requires_debounce_count_consecutive_samples() {
+        let mut estimator = PhaseEstimator::new();
+        for _ in 0..PHASE_DEBOUNCE_SAMPLES - 1 {
+            estimator.observe_accel_magnitude(BOOST_ACCEL_MAG_MPS2 + 1.0);
+            assert_eq!(estimator.phase(), Phase::PreLaunch);
+        }
+        estimator.observe_accel_magnitude(BOOST_ACCEL_MAG_MPS2 + 1.0);
+        assert_eq!(estimator.phase(), Phase::Boost);
+    }
+
+    #[test]
+    fn a_single_dip_below_threshold_resets_the_debounce_count() {
+        let mut estimator = PhaseEstimator::new();
+        estimator.observe_accel_magnitude(BOOST_ACCEL_MAG_MPS2 + 1.0);
+        estimator.observe_accel_magnitude(BOOST_ACCEL_MAG_MPS2 - 1.0); // candidate drops out
+        estimator.observe_accel_magnitude(BOOST_ACCEL_MAG_MPS2 + 1.0);
+        // Only one consecutive boost-candidate sample landed after the reset, two short of
+        // PHASE_DEBOUNCE_SAMPLES (3), so the phase hasn't advanced yet.
+        assert_eq!(estimator.phase(), Phase::PreLaunch);
+    }
+
+    #[test]
+    fn coast_to_apogee_to_descent_follow_altitude_trend() {
+        let mut estimator = PhaseEstimator::new();
+        for _ in 0..PHASE_DEBOUNCE_SAMPLES {
+            estimator.observe_accel_magnitude(BOOST_ACCEL_MAG_MPS2 + 1.0);
+        }
+        assert_eq!(estimator.phase(), Phase::Boost);
+
+        for _ in 0..PHASE_DEBOUNCE_SAMPLES {
+            estimator.observe_accel_magnitude(COAST_ACCEL_MAG_MPS2 - 1.0);
+        }
+        assert_eq!(estimator.phase(), Phase::Coast);
+
+        // Rising altitude samples establish a baseline; falling ones are the Coast -> Apogee
+        // candidate.
+        estimator.observe_altitude(100.0);
+        estimator.observe_altitude(200.0);
+        for i in 0..PHASE_DEBOUNCE_SAMPLES {
+            estimator.observe_altitude(200.0 - (i as f32) - 1.0);
+        }
+        assert_eq!(estimator.phase(), Phase::Apogee);
+
+        for i in 0..PHASE_DEBOUNCE_SAMPLES {
+            estimator.observe_altitude(190.0 - (i as f32) - 1.0);
+        }
+        assert_eq!(estimator.phase(), Phase::Descent);
+    }
+
+    /// `flight_sim::next_sensor_packet`'s round-robin interleaves exactly one `AccelData` and one
+    /// `BarometerData` sample per 10-packet cycle, and `safety_task`'s loop calls
+    /// `observe_accel_magnitude`/`observe_altitude` on every one of them regardless of phase —
+    /// unlike the other tests above, which only ever call one observer in isolation. A shared
+    /// debounce counter would have the off-phase observer's `None` candidate reset progress
+    /// before the real trigger ever reached `PHASE_DEBOUNCE_SAMPLES`; this drives both the way the
+    /// real packet loop does to prove that doesn't happen.
+    #[test]
+    fn interleaved_accel_and_altitude_samples_still_reach_boost_and_coast() {
+        let mut estimator = PhaseEstimator::new();
+
+        // PreLaunch -> Boost: each accel sample exceeds threshold, each interleaved altitude
+        // sample is a no-op candidate in PreLaunch (`_ => None`) but must not reset accel's count.
+        for _ in 0..PHASE_DEBOUNCE_SAMPLES {
+            estimator.observe_accel_magnitude(BOOST_ACCEL_MAG_MPS2 + 1.0);
+            estimator.observe_altitude(0.0);
+        }
+        assert_eq!(estimator.phase(), Phase::Boost);
+
+        // Boost -> Coast: same interleaving, now with the altitude samples moving (so
+        // `observe_altitude` computes a rising/falling trend) while accel drives the transition.
+        let mut altitude = 0.0;
+        for _ in 0..PHASE_DEBOUNCE_SAMPLES {
+            estimator.observe_accel_magnitude(COAST_ACCEL_MAG_MPS2 - 1.0);
+            altitude += 50.0;
+            estimator.observe_altitude(altitude);
+        }
+        assert_eq!(estimator.phase(), Phase::Coast);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::sequences::BlinkMode;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+const DEFAULT_SEQUENCE_PATH: &str = "layout/fill_sequence.json";
+
+/// A condition checked against the live valve/pressure snapshot — the building block for both
+/// a step's auto-advance `guards` and an `EnabledCommandDef`'s own gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Guard {
+    /// The named valve — the same string as its `TelemetryCommand` name ("Dump", "Nitrogen",
+    /// "Nitrous", "NormallyOpen", ...) — currently reads `equals`.
+    Valve { valve: String, equals: bool },
+    /// Latest fuel tank pressure reading is at or above `psi`.
+    PressureAtLeast { psi: f32 },
+    /// Latest fuel tank pressure reading is below `psi`.
+    PressureBelow { psi: f32 },
+}
+
+impl Guard {
+    pub fn is_satisfied(&self, valve_state: &dyn Fn(&str) -> Option<bool>, pressure_psi: Option<f32>) -> bool {
+        match self {
+            Guard::Valve { valve, equals } => valve_state(valve) == Some(*equals),
+            Guard::PressureAtLeast { psi } => pressure_psi.is_some_and(|p| p >= *psi),
+            Guard::PressureBelow { psi } => pressure_psi.is_some_and(|p| p < *psi),
+        }
+    }
+}
+
+/// A timed hold check: entering a step with this set captures the current pressure as a
+/// baseline and starts a clock; once `hold_duration_sec` elapses the runtime compares the
+/// latest reading against that baseline and transitions to `pass_next` or `fail_next`,
+/// emitting the matching message (once) if present.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeakCheckConfig {
+    pub hold_duration_sec: u64,
+    pub max_drop_psi: f32,
+    pub pass_next: String,
+    pub fail_next: String,
+    pub pass_message: Option<String>,
+    pub fail_message: Option<String>,
+}
+
+/// One command the operator may trigger while this step is current. `valve`/`equals` mirror a
+/// `Guard::Valve` — the control is enabled while the named valve hasn't yet reached `equals` —
+/// and are `None` for a command with no valve of its own to watch (e.g. `Launch`, which is
+/// simply enabled or not). `fixed_blink` overrides the default "fast if recently commanded,
+/// else slow" blink with a constant value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnabledCommandDef {
+    pub cmd: String,
+    pub valve: Option<String>,
+    pub equals: Option<bool>,
+    pub fixed_blink: Option<BlinkMode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequenceStepDef {
+    pub id: String,
+    #[serde(default)]
+    pub guards: Vec<Guard>,
+    pub leak_check: Option<LeakCheckConfig>,
+    /// Step to transition to once `guards` are all satisfied. Ignored (guards aren't even
+    /// checked) when `leak_check` is set — that step advances on its own timer instead.
+    /// `None` marks a terminal step, e.g. `armed_ready`.
+    pub next: Option<String>,
+    #[serde(default)]
+    pub enabled_commands: Vec<EnabledCommandDef>,
+    /// Emitted once, the first time this step is reached.
+    pub on_enter_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequenceDefConfig {
+    pub version: u32,
+    pub steps: Vec<SequenceStepDef>,
+}
+
+impl SequenceDefConfig {
+    pub fn step(&self, id: &str) -> Option<&SequenceStepDef> {
+        self.steps.iter().find(|s| s.id == id)
+    }
+
+    pub fn first_step_id(&self) -> &str {
+        self.steps
+            .first()
+            .map(|s| s.id.as_str())
+            .unwrap_or("armed_ready")
+    }
+
+    /// Checks every `next`/`pass_next`/`fail_next` points at a step that actually exists and
+    /// that step ids are unique, so a bad hand-edited config fails loudly at load time instead
+    /// of silently getting stuck on an unknown step at runtime.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.steps.is_empty() {
+            return Err("sequence config has no steps".to_string());
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for step in &self.steps {
+            if !seen.insert(step.id.as_str()) {
+                return Err(format!("duplicate step id {:?}", step.id));
+            }
+        }
+
+        let exists = |id: &str| self.steps.iter().any(|s| s.id == id);
+        for step in &self.steps {
+            if let Some(next) = &step.next
+                && !exists(next)
+            {
+                return Err(format!("step {:?} points to unknown next step {next:?}", step.id));
+            }
+            if let Some(leak) = &step.leak_check {
+                if !exists(&leak.pass_next) {
+                    return Err(format!(
+                        "step {:?} leak_check.pass_next {:?} is not a known step",
+                        step.id, leak.pass_next
+                    ));
+                }
+                if !exists(&leak.fail_next) {
+                    return Err(format!(
+                        "step {:?} leak_check.fail_next {:?} is not a known step",
+                        step.id, leak.fail_next
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn sequence_config_path() -> PathBuf {
+    if let Ok(path) = std::env::var("GS_SEQUENCE_CONFIG_PATH") {
+        return PathBuf::from(path);
+    }
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_SEQUENCE_PATH)
+}
+
+/// Reads and validates the sequence config file, exactly like `layout::load_layout` reads
+/// `LayoutConfig` — no fallback here, that's `effective_sequence_config`'s job.
+pub fn load_sequence_config_file() -> Result<SequenceDefConfig, String> {
+    let path = sequence_config_path();
+    let raw = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read sequence config {path:?}: {e}"))?;
+    let cfg: SequenceDefConfig =
+        serde_json::from_str(&raw).map_err(|e| format!("Invalid sequence config JSON: {e}"))?;
+    cfg.validate()?;
+    Ok(cfg)
+}
+
+/// The built-in nitrogen-fill / leak-check / nitrous-fill procedure, parameterized by the same
+/// `GS_SEQUENCE_*` env vars `SequenceConfig::from_env` already reads — used whenever no sequence
+/// config file is present (or it fails to parse/validate) so a campaign that hasn't written one
+/// yet still gets the procedure this replaced.
+pub fn default_sequence_config(
+    pressure_min_psi: f32,
+    leak_check_duration_sec: u64,
+    max_leak_drop_psi: f32,
+) -> SequenceDefConfig {
+    let valve_guard = |valve: &str, equals: bool| Guard::Valve { valve: valve.to_string(), equals };
+    let valve_enabled = |cmd: &str, valve: &str, equals: bool| EnabledCommandDef {
+        cmd: cmd.to_string(),
+        valve: Some(valve.to_string()),
+        equals: Some(equals),
+        fixed_blink: None,
+    };
+
+    SequenceDefConfig {
+        version: 1,
+        steps: vec![
+            SequenceStepDef {
+                id: "setup_valves".to_string(),
+                guards: vec![valve_guard("NormallyOpen", false), valve_guard("Dump", false)],
+                leak_check: None,
+                next: Some("nitrogen_fill".to_string()),
+                enabled_commands: vec![
+                    valve_enabled("NormallyOpen", "NormallyOpen", false),
+                    valve_enabled("Dump", "Dump", false),
+                ],
+                on_enter_message: None,
+            },
+            SequenceStepDef {
+                id: "nitrogen_fill".to_string(),
+                guards: vec![valve_guard("Nitrogen", true), Guard::PressureAtLeast { psi: pressure_min_psi }],
+                leak_check: None,
+                next: Some("close_nitrogen".to_string()),
+                enabled_commands: vec![valve_enabled("Nitrogen", "Nitrogen", true)],
+                on_enter_message: None,
+            },
+            SequenceStepDef {
+                id: "close_nitrogen".to_string(),
+                guards: vec![valve_guard("Nitrogen", false)],
+                leak_check: None,
+                next: Some("nitrogen_leak_check".to_string()),
+                enabled_commands: vec![valve_enabled("Nitrogen", "Nitrogen", false)],
+                on_enter_message: None,
+            },
+            SequenceStepDef {
+                id: "nitrogen_leak_check".to_string(),
+                guards: vec![],
+                leak_check: Some(LeakCheckConfig {
+                    hold_duration_sec: leak_check_duration_sec,
+                    max_drop_psi: max_leak_drop_psi,
+                    pass_next: "dump_nitrogen".to_string(),
+                    fail_next: "nitrogen_fill".to_string(),
+                    pass_message: Some(
+                        "Nitrogen hold check passed. Good to proceed to nitrous fill.".to_string(),
+                    ),
+                    fail_message: Some(
+                        "Nitrogen hold check failed: pressure dropped. Refill required.".to_string(),
+                    ),
+                }),
+                next: None,
+                enabled_commands: vec![],
+                on_enter_message: None,
+            },
+            SequenceStepDef {
+                id: "dump_nitrogen".to_string(),
+                guards: vec![valve_guard("Dump", true)],
+                leak_check: None,
+                next: Some("close_dump".to_string()),
+                enabled_commands: vec![valve_enabled("Dump", "Dump", true)],
+                on_enter_message: None,
+            },
+            SequenceStepDef {
+                id: "close_dump".to_string(),
+                guards: vec![valve_guard("Dump", false)],
+                leak_check: None,
+                next: Some("open_nitrous".to_string()),
+                enabled_commands: vec![valve_enabled("Dump", "Dump", false)],
+                on_enter_message: None,
+            },
+            SequenceStepDef {
+                id: "open_nitrous".to_string(),
+                guards: vec![valve_guard("Nitrous", true)],
+                leak_check: None,
+                next: Some("armed_ready".to_string()),
+                enabled_commands: vec![valve_enabled("Nitrous", "Nitrous", true)],
+                on_enter_message: None,
+            },
+            SequenceStepDef {
+                id: "armed_ready".to_string(),
+                guards: vec![],
+                leak_check: None,
+                next: None,
+                enabled_commands: vec![EnabledCommandDef {
+                    cmd: "Launch".to_string(),
+                    valve: None,
+                    equals: None,
+                    fixed_blink: Some(BlinkMode::Slow),
+                }],
+                on_enter_message: Some(
+                    "Nitrous fill complete. Key is accepted; launch can proceed when enabled."
+                        .to_string(),
+                ),
+            },
+        ],
+    }
+}
+
+/// Loads the sequence config file, falling back to [`default_sequence_config`] (built from the
+/// same env-var knobs `SequenceConfig::from_env` already reads) if the file is missing or fails
+/// to parse/validate — a campaign that hasn't authored a custom procedure yet still flies with
+/// the original one.
+pub fn effective_sequence_config(
+    pressure_min_psi: f32,
+    leak_check_duration_sec: u64,
+    max_leak_drop_psi: f32,
+) -> SequenceDefConfig {
+    match load_sequence_config_file() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            eprintln!("Sequence config fallback to built-in defaults: {e}");
+            default_sequence_config(pressure_min_psi, leak_check_duration_sec, max_leak_drop_psi)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_sequence_config_is_valid() {
+        let cfg = default_sequence_config(10.0, 60, 1.0);
+        cfg.validate().expect("default sequence config should validate");
+        assert_eq!(cfg.first_step_id(), "setup_valves");
+    }
+}
@@ -1,8 +1,9 @@
 use crate::rocket_commands::{ActuatorBoardCommands, ValveBoardCommands};
+use crate::sequence_config::{effective_sequence_config, SequenceDefConfig, SequenceStepDef};
 use crate::state::AppState;
 use groundstation_shared::{FlightState, TelemetryCommand};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -22,6 +23,9 @@ pub struct ActionControl {
     pub enabled: bool,
     pub blink: BlinkMode,
     pub actuated: Option<bool>,
+    /// Whether the frontend should require a second click within a timeout before calling
+    /// `send_cmd` for this control — see [`requires_confirmation`].
+    pub requires_confirmation: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -37,18 +41,6 @@ pub struct PersistentNotification {
     pub message: String,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum SequenceStep {
-    SetupValves,
-    NitrogenFill,
-    CloseNitrogen,
-    NitrogenLeakCheck,
-    DumpNitrogen,
-    CloseDump,
-    OpenNitrous,
-    ArmedReady,
-}
-
 #[derive(Clone, Debug)]
 struct SequenceConfig {
     leak_check_duration: Duration,
@@ -113,23 +105,27 @@ impl SequenceConfig {
     }
 }
 
+/// Live progress through `SequenceDefConfig`'s steps — `step_id` replaces what used to be a
+/// hardcoded `SequenceStep` enum variant, so advancing/looping is just string comparisons
+/// against whatever the loaded config defines.
 #[derive(Clone, Debug)]
 struct SequenceRuntime {
-    step: SequenceStep,
+    step_id: String,
     step_started_at: Option<Instant>,
     pressure_at_close_psi: Option<f32>,
-    notified_leak_pass: bool,
-    notified_armed: bool,
+    /// Keys already notified once (`"{step_id}:enter"`, `"{step_id}:pass"`, `"{step_id}:fail"`)
+    /// — never cleared, so a message fires at most once per session even if a fail/retry loop
+    /// revisits the same step.
+    notified: HashSet<String>,
 }
 
-impl Default for SequenceRuntime {
-    fn default() -> Self {
+impl SequenceRuntime {
+    fn new(cfg: &SequenceDefConfig) -> Self {
         Self {
-            step: SequenceStep::SetupValves,
+            step_id: cfg.first_step_id().to_string(),
             step_started_at: None,
             pressure_at_close_psi: None,
-            notified_leak_pass: false,
-            notified_armed: false,
+            notified: HashSet::new(),
         }
     }
 }
@@ -194,10 +190,31 @@ pub fn command_name(cmd: &TelemetryCommand) -> &'static str {
         TelemetryCommand::RetractPlumbing => "RetractPlumbing",
         TelemetryCommand::Nitrogen => "Nitrogen",
         TelemetryCommand::Nitrous => "Nitrous",
+        TelemetryCommand::Deploy => "Deploy",
+        TelemetryCommand::FirmwareUpdate => "FirmwareUpdate",
     }
 }
 
-pub fn all_command_names() -> [&'static str; 9] {
+/// Inverse of [`command_name`] — used by the flight sim's scenario scripting to turn a
+/// JSON-authored command name back into the enum, same round-trip `static_cmd_name` already does
+/// for the sequence config's `EnabledCommandDef::cmd`.
+pub fn command_from_name(name: &str) -> Option<TelemetryCommand> {
+    Some(match name {
+        "Launch" => TelemetryCommand::Launch,
+        "Dump" => TelemetryCommand::Dump,
+        "Abort" => TelemetryCommand::Abort,
+        "NormallyOpen" => TelemetryCommand::NormallyOpen,
+        "Pilot" => TelemetryCommand::Pilot,
+        "Igniter" => TelemetryCommand::Igniter,
+        "RetractPlumbing" => TelemetryCommand::RetractPlumbing,
+        "Nitrogen" => TelemetryCommand::Nitrogen,
+        "Nitrous" => TelemetryCommand::Nitrous,
+        "Deploy" => TelemetryCommand::Deploy,
+        _ => return None,
+    })
+}
+
+pub fn all_command_names() -> [&'static str; 10] {
     [
         "Launch",
         "Dump",
@@ -208,9 +225,28 @@ pub fn all_command_names() -> [&'static str; 9] {
         "RetractPlumbing",
         "Nitrogen",
         "Nitrous",
+        "Deploy",
     ]
 }
 
+/// Maps a command name loaded from JSON (`EnabledCommandDef::cmd`) back to the `&'static str`
+/// `policy_with_overrides` keys its map on, so a typo'd command in a hand-edited sequence config
+/// is silently dropped instead of needing its own `'static` leak.
+fn static_cmd_name(name: &str) -> Option<&'static str> {
+    all_command_names().into_iter().find(|c| *c == name)
+}
+
+/// Commands whose consequence is hard or impossible to undo (ignition, deployment, plumbing
+/// changes, the abort itself) — the frontend gates these behind a second click within a timeout
+/// instead of firing `send_cmd` on the first one. Mirrors PX4's arming checklist philosophy of
+/// making the operator deliberately confirm anything that can't be taken back.
+fn requires_confirmation(cmd: &str) -> bool {
+    matches!(
+        cmd,
+        "Abort" | "Launch" | "Igniter" | "Nitrous" | "RetractPlumbing" | "Deploy"
+    )
+}
+
 pub fn default_action_policy() -> ActionPolicyMsg {
     let controls = all_command_names()
         .into_iter()
@@ -219,6 +255,7 @@ pub fn default_action_policy() -> ActionPolicyMsg {
             enabled: cmd == "Abort",
             blink: BlinkMode::None,
             actuated: None,
+            requires_confirmation: requires_confirmation(cmd),
         })
         .collect();
     ActionPolicyMsg {
@@ -239,6 +276,7 @@ fn policy_with_overrides(
             enabled: cmd == "Abort" || enabled.contains_key(cmd),
             blink: enabled.get(cmd).cloned().unwrap_or(BlinkMode::None),
             actuated: valves.actuated_for_cmd(cmd),
+            requires_confirmation: requires_confirmation(cmd),
         })
         .collect();
 
@@ -262,94 +300,123 @@ fn pending_mode(
     BlinkMode::Slow
 }
 
-fn update_sequence_runtime(
+/// Interprets one step of `seq_def` generically: a `leak_check` step captures a pressure
+/// baseline on first visit, waits out its hold duration, then compares against
+/// `max_drop_psi` and transitions to `pass_next`/`fail_next`; any other step transitions to
+/// `next` once every one of its `guards` is satisfied. This replaced a hardcoded match over
+/// `SequenceStep` so the whole fill/arm procedure can be redefined by editing the config file
+/// instead of recompiling.
+fn advance_sequence(
     state: &AppState,
     runtime: &mut SequenceRuntime,
-    cfg: &SequenceConfig,
+    seq_def: &SequenceDefConfig,
     valves: ValveSnapshot,
     pressure_psi: Option<f32>,
     now: Instant,
 ) {
-    let at_or_above = |p: Option<f32>, threshold: f32| p.is_some_and(|x| x >= threshold);
+    let Some(step) = seq_def.step(&runtime.step_id) else {
+        return; // unknown step id — shouldn't happen once the config's passed validate()
+    };
 
-    match runtime.step {
-        SequenceStep::SetupValves => {
-            if valves.normally_open == Some(false) && valves.dump_open == Some(false) {
-                runtime.step = SequenceStep::NitrogenFill;
-            }
-        }
-        SequenceStep::NitrogenFill => {
-            if valves.nitrogen_open == Some(true) && at_or_above(pressure_psi, cfg.pressure_min_psi)
-            {
-                runtime.step = SequenceStep::CloseNitrogen;
-            }
-        }
-        SequenceStep::CloseNitrogen => {
-            if valves.nitrogen_open == Some(false) {
-                runtime.pressure_at_close_psi = pressure_psi;
-                runtime.step_started_at = Some(now);
-                runtime.step = SequenceStep::NitrogenLeakCheck;
-            }
-        }
-        SequenceStep::NitrogenLeakCheck => {
-            let Some(started) = runtime.step_started_at else {
-                runtime.step_started_at = Some(now);
-                return;
-            };
-            if now.saturating_duration_since(started) < cfg.leak_check_duration {
-                return;
-            }
-
-            let baseline = runtime.pressure_at_close_psi.unwrap_or(0.0);
-            let current = pressure_psi.unwrap_or(0.0);
-            let pressure_ok = current >= baseline - cfg.max_leak_drop_psi;
-
-            if pressure_ok {
-                if !runtime.notified_leak_pass {
-                    state.add_notification(
-                        "Nitrogen hold check passed. Good to proceed to nitrous fill.",
-                    );
-                    runtime.notified_leak_pass = true;
-                }
-                runtime.step = SequenceStep::DumpNitrogen;
-                runtime.step_started_at = None;
-            } else {
-                state.add_notification(
-                    "Nitrogen hold check failed: pressure dropped. Refill required.",
-                );
-                runtime.step = SequenceStep::NitrogenFill;
-                runtime.step_started_at = None;
-            }
-        }
-        SequenceStep::DumpNitrogen => {
-            if valves.dump_open == Some(true) {
-                runtime.step = SequenceStep::CloseDump;
-            }
+    if let Some(msg) = &step.on_enter_message
+        && runtime.notified.insert(format!("{}:enter", step.id))
+    {
+        state.add_notification(msg);
+    }
+
+    if let Some(leak) = &step.leak_check {
+        let started = *runtime.step_started_at.get_or_insert(now);
+        if runtime.pressure_at_close_psi.is_none() {
+            runtime.pressure_at_close_psi = Some(pressure_psi.unwrap_or(0.0));
         }
-        SequenceStep::CloseDump => {
-            if valves.dump_open == Some(false) {
-                runtime.step = SequenceStep::OpenNitrous;
-            }
+        if now.saturating_duration_since(started) < Duration::from_secs(leak.hold_duration_sec) {
+            return;
         }
-        SequenceStep::OpenNitrous => {
-            if valves.nitrous_open == Some(true) {
-                runtime.step = SequenceStep::ArmedReady;
-            }
+
+        let baseline = runtime.pressure_at_close_psi.unwrap_or(0.0);
+        let current = pressure_psi.unwrap_or(0.0);
+        let pass = current >= baseline - leak.max_drop_psi;
+
+        let (next_id, message, tag) = if pass {
+            (&leak.pass_next, &leak.pass_message, "pass")
+        } else {
+            (&leak.fail_next, &leak.fail_message, "fail")
+        };
+
+        if let Some(msg) = message
+            && runtime.notified.insert(format!("{}:{tag}", step.id))
+        {
+            state.add_notification(msg);
         }
-        SequenceStep::ArmedReady => {
-            if !runtime.notified_armed {
-                state.add_notification(
-                    "Nitrous fill complete. Key is accepted; launch can proceed when enabled.",
-                );
-                runtime.notified_armed = true;
-            }
+
+        runtime.step_id = next_id.clone();
+        runtime.step_started_at = None;
+        runtime.pressure_at_close_psi = None;
+        return;
+    }
+
+    let valve_state = |cmd: &str| valves.actuated_for_cmd(cmd);
+    if step
+        .guards
+        .iter()
+        .all(|g| g.is_satisfied(&valve_state, pressure_psi))
+        && let Some(next_id) = &step.next
+    {
+        runtime.step_id = next_id.clone();
+    }
+}
+
+/// Builds the `enabled`/`blink` overrides for the current step: each of its
+/// `enabled_commands` lights up while its own valve hasn't yet reached the target state (or
+/// unconditionally, for a command like `Launch` with no valve of its own).
+fn fill_step_overrides(
+    state: &AppState,
+    step: &SequenceStepDef,
+    valves: ValveSnapshot,
+    now_ms: u64,
+    cfg: &SequenceConfig,
+) -> HashMap<&'static str, BlinkMode> {
+    let mut enabled = HashMap::new();
+
+    for ec in &step.enabled_commands {
+        let Some(name) = static_cmd_name(&ec.cmd) else {
+            continue;
+        };
+
+        let already_satisfied = match (&ec.valve, ec.equals) {
+            (Some(valve), Some(target)) => valves.actuated_for_cmd(valve) == Some(target),
+            _ => false,
+        };
+        if already_satisfied {
+            continue;
         }
+
+        let blink = ec
+            .fixed_blink
+            .clone()
+            .unwrap_or_else(|| pending_mode(state, name, now_ms, cfg));
+        enabled.insert(name, blink);
+    }
+
+    enabled
+}
+
+/// During `Coast`/`Descent`, surfaces `state.latest_deploy_advisory` as the "Deploy" control's
+/// blink: fast once `deploy_advisor::start_deploy_advisor_task` reports the window open, slow
+/// while it's still predicting a later drop, nothing outside those flight states.
+fn deploy_override(state: &AppState, flight_state: FlightState) -> Option<(&'static str, BlinkMode)> {
+    if !matches!(flight_state, FlightState::Coast | FlightState::Descent) {
+        return None;
     }
+    let advisory = (*state.latest_deploy_advisory.lock().unwrap())?;
+    let blink = if advisory.window_open { BlinkMode::Fast } else { BlinkMode::Slow };
+    Some(("Deploy", blink))
 }
 
 fn build_policy(
     state: &AppState,
     cfg: &SequenceConfig,
+    seq_def: &SequenceDefConfig,
     runtime: &SequenceRuntime,
     flight_state: FlightState,
     key_enabled: bool,
@@ -360,60 +427,22 @@ fn build_policy(
         return policy_with_overrides(false, valves, HashMap::new());
     }
 
-    if flight_state == FlightState::Armed {
+    let mut enabled = if flight_state == FlightState::Armed {
         let mut enabled = HashMap::new();
         enabled.insert("Launch", BlinkMode::Slow);
         enabled.insert("Dump", BlinkMode::None);
-        return policy_with_overrides(true, valves, enabled);
-    }
-
-    if !is_fill_state(flight_state) {
-        return policy_with_overrides(true, valves, HashMap::new());
-    }
-
-    let mut enabled: HashMap<&'static str, BlinkMode> = HashMap::new();
-
-    match runtime.step {
-        SequenceStep::SetupValves => {
-            if valves.normally_open != Some(false) {
-                enabled.insert(
-                    "NormallyOpen",
-                    pending_mode(state, "NormallyOpen", now_ms, cfg),
-                );
-            }
-            if valves.dump_open != Some(false) {
-                enabled.insert("Dump", pending_mode(state, "Dump", now_ms, cfg));
-            }
-        }
-        SequenceStep::NitrogenFill => {
-            if valves.nitrogen_open != Some(true) {
-                enabled.insert("Nitrogen", pending_mode(state, "Nitrogen", now_ms, cfg));
-            }
-        }
-        SequenceStep::CloseNitrogen => {
-            if valves.nitrogen_open != Some(false) {
-                enabled.insert("Nitrogen", pending_mode(state, "Nitrogen", now_ms, cfg));
-            }
-        }
-        SequenceStep::NitrogenLeakCheck => {}
-        SequenceStep::DumpNitrogen => {
-            if valves.dump_open != Some(true) {
-                enabled.insert("Dump", pending_mode(state, "Dump", now_ms, cfg));
-            }
-        }
-        SequenceStep::CloseDump => {
-            if valves.dump_open != Some(false) {
-                enabled.insert("Dump", pending_mode(state, "Dump", now_ms, cfg));
-            }
-        }
-        SequenceStep::OpenNitrous => {
-            if valves.nitrous_open != Some(true) {
-                enabled.insert("Nitrous", pending_mode(state, "Nitrous", now_ms, cfg));
-            }
-        }
-        SequenceStep::ArmedReady => {
-            enabled.insert("Launch", BlinkMode::Slow);
+        enabled
+    } else if !is_fill_state(flight_state) {
+        HashMap::new()
+    } else {
+        match seq_def.step(&runtime.step_id) {
+            Some(step) => fill_step_overrides(state, step, valves, now_ms, cfg),
+            None => HashMap::new(),
         }
+    };
+
+    if let Some((cmd, blink)) = deploy_override(state, flight_state) {
+        enabled.insert(cmd, blink);
     }
 
     policy_with_overrides(true, valves, enabled)
@@ -429,6 +458,14 @@ fn read_key_enabled(state: &AppState, cfg: &SequenceConfig) -> bool {
         .unwrap_or(false)
 }
 
+/// Whether the physical key-enable switch currently authorizes privileged actuation — the same
+/// check `start_sequence_task` polls every tick, exposed so other operations that need the same
+/// physical-authorization gate (e.g. an over-the-radio firmware update) don't re-derive
+/// `SequenceConfig` themselves.
+pub fn key_enabled(state: &AppState) -> bool {
+    read_key_enabled(state, &SequenceConfig::from_env())
+}
+
 pub fn start_sequence_task(state: Arc<AppState>) {
     let cfg = SequenceConfig::from_env();
     if cfg.key_required
@@ -440,9 +477,15 @@ pub fn start_sequence_task(state: Arc<AppState>) {
         );
     }
 
+    let seq_def = effective_sequence_config(
+        cfg.pressure_min_psi,
+        cfg.leak_check_duration.as_secs(),
+        cfg.max_leak_drop_psi,
+    );
+
     tokio::spawn(async move {
         let mut tick = tokio::time::interval(Duration::from_millis(200));
-        let mut runtime = SequenceRuntime::default();
+        let mut runtime = SequenceRuntime::new(&seq_def);
 
         loop {
             tick.tick().await;
@@ -454,10 +497,11 @@ pub fn start_sequence_task(state: Arc<AppState>) {
             let now_ms = crate::telemetry_task::get_current_timestamp_ms();
             let key_enabled = read_key_enabled(&state, &cfg);
 
-            update_sequence_runtime(&state, &mut runtime, &cfg, valves, pressure_psi, now);
+            advance_sequence(&state, &mut runtime, &seq_def, valves, pressure_psi, now);
             let policy = build_policy(
                 &state,
                 &cfg,
+                &seq_def,
                 &runtime,
                 flight_state,
                 key_enabled,
@@ -1,11 +1,42 @@
+use crate::command_channel::DeliveryState;
+use crate::deploy_advisor::DeployAdvisory;
+use crate::firmware_update::FirmwareUpdateStatus;
 use crate::gpio::GpioPins;
+use crate::radio::RadioDevice;
+use crate::safety_config::Phase;
 use crate::ring_buffer::RingBuffer;
-use crate::web::{ErrorMsg, FlightStateMsg, WarningMsg};
-use groundstation_shared::{FlightState, TelemetryCommand, TelemetryRow};
+use crate::telemetry_task::PendingInsert;
+use crate::web::{CommandAckMsg, ErrorMsg, FlightStateMsg, WarningMsg};
+use groundstation_shared::{AnnotationOp, FlightState, TelemetryCommand, TelemetryRow};
 use sedsprintf_rs_2026::telemetry_packet::TelemetryPacket;
+use serde::Deserialize;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use tokio::sync::{broadcast, mpsc};
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+/// Wraps a `TelemetryCommand` with an optional client-supplied correlation id. WS and REST
+/// callers set `id` so `telemetry_task` can echo a `CommandAckMsg` back to the right caller;
+/// producers with no caller to correlate to (the GPIO panel's physical buttons) leave it `None`.
+///
+/// `operator_id`/`operator_role` carry the same self-reported identity `WsCommand` does over
+/// WS — `web::operator_role_allows` gates on `operator_role` at every operator-facing entry
+/// point (REST `/api/command` and WS), not just WS, so a client can't get a command through by
+/// hitting the REST endpoint instead. Internal, non-operator producers (the GPIO panel's
+/// physical buttons, the geofence auto-abort) aren't subject to that gate in spirit — they set
+/// `operator_role: "flight_director"` themselves since a hardware/safety trigger doesn't have
+/// an operator to check a role for. `#[serde(default)]` (empty string) means a REST caller who
+/// omits these fields gets refused by `operator_role_allows` rather than silently passing.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CommandRequest {
+    #[serde(default)]
+    pub id: Option<u64>,
+    pub cmd: TelemetryCommand,
+    #[serde(default)]
+    pub operator_id: String,
+    #[serde(default)]
+    pub operator_role: String,
+}
 
 #[derive(Clone)]
 pub struct AppState {
@@ -13,7 +44,7 @@ pub struct AppState {
     pub ring_buffer: Arc<Mutex<RingBuffer<TelemetryPacket>>>,
 
     /// Commands from frontend → server (Arm, Disarm, Abort, etc.)
-    pub cmd_tx: mpsc::Sender<TelemetryCommand>,
+    pub cmd_tx: mpsc::Sender<CommandRequest>,
 
     /// Telemetry stream → frontend
     pub ws_tx: broadcast::Sender<TelemetryRow>,
@@ -35,4 +66,53 @@ pub struct AppState {
 
     /// GPIO interface
     pub gpio: Arc<GpioPins>,
+
+    /// Outcome of each `CommandRequest` `telemetry_task` processes, fanned out to WS clients
+    /// for display — keyed by `CommandRequest.id` on the frontend side.
+    pub cmd_ack_tx: broadcast::Sender<CommandAckMsg>,
+
+    /// REST `/api/command` callers blocked awaiting their specific command's outcome, removed
+    /// by `telemetry_task` once it answers (or by the handler itself, on timeout/send failure).
+    pub pending_acks: Arc<Mutex<HashMap<u64, oneshot::Sender<CommandAckMsg>>>>,
+
+    /// Most recent payload-deployment timing advisory from `deploy_advisor::start_deploy_advisor_task`
+    /// — read by `sequences::build_policy` to set the "Deploy" control's `BlinkMode`.
+    pub latest_deploy_advisory: Arc<Mutex<Option<DeployAdvisory>>>,
+
+    /// Operator annotation CRDT ops (see `groundstation_shared::AnnotationOp`) → every connected
+    /// dashboard. The server itself never applies these — it just relays what one client sent to
+    /// everyone else, the same as `cmd_ack_tx`; each client's own CRDT merges the ops.
+    pub annotations_tx: broadcast::Sender<AnnotationOp>,
+
+    /// The same radio handles `telemetry_task`'s outbound relay writes through, shared here so
+    /// `web::start_firmware_update` can push DFU frames over the board's existing reliable
+    /// command channel instead of opening a second connection to the same serial port.
+    pub rocket_radio: Arc<Mutex<Box<dyn RadioDevice>>>,
+    pub umbilical_radio: Arc<Mutex<Box<dyn RadioDevice>>>,
+
+    /// Progress/result of the most recent `firmware_update::run_firmware_update` call, polled by
+    /// the web UI — same "track latest state behind a `Mutex`, read-only for pollers" shape as
+    /// `latest_deploy_advisory`.
+    pub firmware_update_status: Arc<Mutex<Option<FirmwareUpdateStatus>>>,
+
+    /// `safety_task`'s live flight-phase estimate, updated once per tick — read by the frontend
+    /// (via `/api/phase`) to display where in the flight the vehicle currently is.
+    pub flight_phase: Arc<Mutex<Phase>>,
+
+    /// Queues rows for `telemetry_task::run_db_writer`'s background insert instead of
+    /// `handle_packet` writing them inline — keeps a slow disk from stalling the packet-ingestion
+    /// select loop (and therefore `safety_task`'s view of the ring buffer) the way `cmd_tx` keeps
+    /// a slow command handler off the radio read.
+    pub db_write_tx: mpsc::Sender<PendingInsert>,
+
+    /// The currently-open `flight_session` (if any), stamped onto every row queued on
+    /// `db_write_tx` so `/api/range` can later pull back just that flight — set by the
+    /// `/api/sessions` open/close handlers.
+    pub current_session: Arc<Mutex<Option<i64>>>,
+
+    /// Delivery state of every `command_channel::send_reliable` call, keyed by its sequence id —
+    /// polled by the frontend (via `/api/command/delivery`) the same way `firmware_update_status`
+    /// is, in addition to the human-readable `emit_warning` calls `send_reliable` makes on each
+    /// state change.
+    pub command_delivery: Arc<Mutex<HashMap<u64, DeliveryState>>>,
 }
@@ -0,0 +1,124 @@
+//! Validated `FlightState` transitions, modeled on PX4 commander's `state_machine_helper`.
+//!
+//! `AppState.state` used to be advanced blindly by the dummy telemetry generator and never
+//! checked against incoming `TelemetryCommand`s. This module gives `telemetry_task` a single
+//! place to ask "is `cmd` legal right now?" before committing a new state, instead of trusting
+//! every caller (frontend, GPIO panel, radio uplink) to get the sequencing right on its own.
+
+use groundstation_shared::{FlightState, TelemetryCommand};
+
+/// Why `try_transition` refused a command — reported to the operator via `ErrorMsg` rather than
+/// silently dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectReason {
+    pub from: FlightState,
+    pub cmd: &'static str,
+}
+
+impl std::fmt::Display for RejectReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is not a legal command from {:?}", self.cmd, self.from)
+    }
+}
+
+/// One legal `(from, cmd) -> to` edge. `from: None` means "legal from any state" (e.g. `Abort`).
+/// `cmd` is matched by name rather than by value so the table stays plain data instead of
+/// requiring `TelemetryCommand: PartialEq`.
+#[derive(Debug, Clone, Copy)]
+pub struct Transition {
+    pub from: Option<FlightState>,
+    pub cmd: &'static str,
+    pub to: FlightState,
+}
+
+/// The commands this machine governs, and the only state changes they're allowed to cause.
+/// Valve/sequence commands (`Dump`, `Nitrogen`, `Igniter`, ...) aren't listed here — they're
+/// gated separately by `sequences::build_policy`'s per-step `enabled_commands`, not by
+/// `AppState.state` itself, so they pass through `try_transition` unchanged. `FirmwareUpdate`
+/// is gated separately too, by `firmware_update::is_update_blocked_state`.
+pub const TRANSITIONS: &[Transition] = &[
+    Transition { from: Some(FlightState::Idle), cmd: "Arm", to: FlightState::Armed },
+    Transition { from: Some(FlightState::Armed), cmd: "Disarm", to: FlightState::Idle },
+    Transition { from: Some(FlightState::Armed), cmd: "Launch", to: FlightState::Launch },
+    Transition { from: None, cmd: "Abort", to: FlightState::Aborted },
+];
+
+fn command_name(cmd: &TelemetryCommand) -> &'static str {
+    match cmd {
+        TelemetryCommand::Arm => "Arm",
+        TelemetryCommand::Disarm => "Disarm",
+        TelemetryCommand::Abort => "Abort",
+        TelemetryCommand::Launch => "Launch",
+        TelemetryCommand::Dump => "Dump",
+        TelemetryCommand::NormallyOpen => "NormallyOpen",
+        TelemetryCommand::Pilot => "Pilot",
+        TelemetryCommand::Igniter => "Igniter",
+        TelemetryCommand::RetractPlumbing => "RetractPlumbing",
+        TelemetryCommand::Nitrogen => "Nitrogen",
+        TelemetryCommand::Nitrous => "Nitrous",
+        TelemetryCommand::FirmwareUpdate => "FirmwareUpdate",
+    }
+}
+
+/// Checks `cmd` against `TRANSITIONS` for the current flight state `from`.
+///
+/// - A command not named in `TRANSITIONS` at all (every valve command) isn't governed by the
+///   flight-state machine and passes through as a no-op (`Ok(from)`).
+/// - `Landed` is terminal: once there, every governed command is rejected, `Abort` included.
+/// - A governed command with no matching row for `from` is rejected with `RejectReason`.
+pub fn try_transition(from: FlightState, cmd: &TelemetryCommand) -> Result<FlightState, RejectReason> {
+    let name = command_name(cmd);
+    let governed = TRANSITIONS.iter().any(|t| t.cmd == name);
+    if !governed {
+        return Ok(from);
+    }
+
+    if from == FlightState::Landed {
+        return Err(RejectReason { from, cmd: name });
+    }
+
+    TRANSITIONS
+        .iter()
+        .find(|t| t.cmd == name && (t.from.is_none() || t.from == Some(from)))
+        .map(|t| t.to)
+        .ok_or(RejectReason { from, cmd: name })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arm_only_legal_from_idle() {
+        assert_eq!(try_transition(FlightState::Idle, &TelemetryCommand::Arm), Ok(FlightState::Armed));
+        assert!(try_transition(FlightState::Armed, &TelemetryCommand::Arm).is_err());
+        assert!(try_transition(FlightState::Descent, &TelemetryCommand::Arm).is_err());
+    }
+
+    #[test]
+    fn launch_only_legal_from_armed() {
+        assert_eq!(try_transition(FlightState::Armed, &TelemetryCommand::Launch), Ok(FlightState::Launch));
+        assert!(try_transition(FlightState::Idle, &TelemetryCommand::Launch).is_err());
+    }
+
+    #[test]
+    fn abort_legal_from_any_state_but_landed() {
+        for state in [FlightState::Idle, FlightState::Armed, FlightState::Ascent, FlightState::Descent] {
+            assert_eq!(try_transition(state, &TelemetryCommand::Abort), Ok(FlightState::Aborted));
+        }
+        assert!(try_transition(FlightState::Landed, &TelemetryCommand::Abort).is_err());
+    }
+
+    #[test]
+    fn no_transition_out_of_landed() {
+        for cmd in [TelemetryCommand::Arm, TelemetryCommand::Disarm, TelemetryCommand::Launch, TelemetryCommand::Abort] {
+            assert!(try_transition(FlightState::Landed, &cmd).is_err());
+        }
+    }
+
+    #[test]
+    fn ungoverned_commands_pass_through_unchanged() {
+        assert_eq!(try_transition(FlightState::Idle, &TelemetryCommand::Dump), Ok(FlightState::Idle));
+        assert_eq!(try_transition(FlightState::Armed, &TelemetryCommand::Nitrous), Ok(FlightState::Armed));
+    }
+}
@@ -1,18 +1,126 @@
-use crate::state::AppState;
+use crate::state::{AppState, CommandRequest};
+use crate::state_machine::try_transition;
+use crate::web::{emit_error, CommandAckMsg, FlightStateMsg};
 use groundstation_shared::TelemetryCommand;
 use groundstation_shared::TelemetryRow;
 use sedsprintf_rs_2026::config::DataType;
+use sqlx::SqlitePool;
 
 use crate::radio::RadioDevice;
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 use tokio::time::{interval, Duration};
 
+/// Bounded so a writer that's fallen behind (slow disk) sheds rows instead of stalling
+/// `handle_packet`'s caller — the same shed-on-backpressure shape
+/// `flight_recorder::record_line` uses on the frontend's tamper-evident logger.
+const DB_WRITE_CHANNEL_CAPACITY: usize = 256;
+
+/// One decoded telemetry row queued for [`run_db_writer`], tagged with whichever
+/// `flight_session` was open when `handle_packet` saw it (`None` outside any open session).
+pub struct PendingInsert {
+    pub row: TelemetryRow,
+    pub session_id: Option<i64>,
+}
+
+/// Spawns the background task that owns every insert into the `telemetry` table, and returns
+/// the sender `handle_packet` queues rows on. Kept off the ingestion select loop in
+/// `telemetry_task` itself so a stalled disk only backs up this channel, never radio reads,
+/// command processing, or `safety_task`'s ring-buffer reads.
+pub fn start_db_writer_task(db: SqlitePool) -> mpsc::Sender<PendingInsert> {
+    let (tx, rx) = mpsc::channel(DB_WRITE_CHANNEL_CAPACITY);
+    tokio::spawn(run_db_writer(db, rx));
+    tx
+}
+
+async fn run_db_writer(db: SqlitePool, mut rx: mpsc::Receiver<PendingInsert>) {
+    while let Some(PendingInsert { row, session_id }) = rx.recv().await {
+        let result = sqlx::query(
+            "INSERT INTO telemetry (timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7, session_id) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(row.timestamp_ms)
+        .bind(&row.data_type)
+        .bind(row.v0)
+        .bind(row.v1)
+        .bind(row.v2)
+        .bind(row.v3)
+        .bind(row.v4)
+        .bind(row.v5)
+        .bind(row.v6)
+        .bind(row.v7)
+        .bind(session_id)
+        .execute(&db)
+        .await;
+
+        if let Err(e) = result {
+            eprintln!("telemetry DB writer: insert failed for {}: {e}", row.data_type);
+        }
+    }
+}
+
+/// Runs `cmd` against the router and reports what happened — the `Result` a raw
+/// `router.log`/`log_queue` call would give, turned into the `(ok, error)` pair
+/// `CommandAckMsg` wants instead of the `.expect()` this replaced, which would have taken
+/// down the whole task (and every in-flight REST/WS caller) on one bad command.
+///
+/// `Abort` isn't handled here — the select loop in `telemetry_task` routes it through
+/// `command_channel::send_reliable` instead (see the comment there), since this is the one
+/// command every REST/WS-driven caller and the geofence auto-abort all funnel through, and a
+/// single unacked `router.log` gave an operator no way to tell whether the vehicle actually
+/// aborted.
+fn run_command(router: &sedsprintf_rs_2026::router::Router, cmd: &TelemetryCommand) -> Result<(), String> {
+    match cmd {
+        TelemetryCommand::Arm => {
+            router.log_queue(DataType::MessageData, "Arm".as_bytes()).map_err(|e| e.to_string())?;
+            println!("Arm command sent");
+        }
+        TelemetryCommand::Disarm => {
+            router.log_queue(DataType::MessageData, "Disarm".as_bytes()).map_err(|e| e.to_string())?;
+            println!("Disarm command sent");
+        }
+        TelemetryCommand::Abort => {
+            router.log::<u8>(DataType::Abort, &[]).map_err(|e| e.to_string())?;
+            println!("Abort command sent");
+        }
+    }
+    Ok(())
+}
+
+/// Runs `cmd` through `try_transition` against the current `AppState.state` before it ever
+/// reaches the router: on success commits the new state and broadcasts it on `state_tx` (a
+/// no-op re-broadcast for a command the machine doesn't govern, since `to == from`); on
+/// rejection reports why over `errors_tx` via `emit_error` instead of letting an operator send
+/// something like `Arm` mid-flight straight through to the hardware.
+fn apply_gated_command(state: &Arc<AppState>, cmd: &TelemetryCommand) -> Result<(), String> {
+    let from = *state.state.lock().unwrap();
+    let to = try_transition(from, cmd).map_err(|reject| {
+        emit_error(state, reject.to_string());
+        reject.to_string()
+    })?;
+
+    *state.state.lock().unwrap() = to;
+    let _ = state.state_tx.send(FlightStateMsg { state: to });
+    Ok(())
+}
+
+/// Resolves a processed `CommandRequest` to its caller(s): answers the REST `/api/command`
+/// oneshot waiting on this id (if any — a GPIO-originated request has none), and always fans
+/// the outcome out over `cmd_ack_tx` so every WS client can show it regardless of who sent it.
+fn report_command_outcome(state: &Arc<AppState>, id: u64, result: Result<(), String>) {
+    let ack = CommandAckMsg { id, ok: result.is_ok(), error: result.err() };
+
+    if let Some(waiter) = state.pending_acks.lock().unwrap().remove(&id) {
+        let _ = waiter.send(ack.clone());
+    }
+    let _ = state.cmd_ack_tx.send(ack);
+}
+
 pub async fn telemetry_task(
     state: Arc<AppState>,
     router: Arc<sedsprintf_rs_2026::router::Router>,
     radio: Arc<Mutex<Box<dyn RadioDevice>>>,
-    mut rx: mpsc::Receiver<TelemetryCommand>,
+    mut rx: mpsc::Receiver<CommandRequest>,
 ) {
     let mut radio_interval = interval(Duration::from_millis(1));
     let mut handle_interval = interval(Duration::from_millis(2));
@@ -34,29 +142,31 @@ pub async fn telemetry_task(
             _= router_interval.tick() => {
                     router.process_all_queues_with_timeout(20).expect("Failed to process all queues with timeout");
                 }
-                Some(cmd) = rx.recv() => {
-                    match cmd {
-                        TelemetryCommand::Arm => {
-                            router.log_queue(
-                                    DataType::MessageData,
-                                    "Arm".as_bytes()
-                                ).expect("failed to log Arm command");
-                            println!("Arm command sent");
-
-                        }
-                        TelemetryCommand::Disarm => {
-                            router.log_queue(
-                                    DataType::MessageData,
-                                    "Disarm".as_bytes()
-                                ).expect("failed to log Arm command");
-                            println!("Disarm command sent");
-                        }
-                        TelemetryCommand::Abort => {
-                            router.log::<u8>(
+                Some(req) = rx.recv() => {
+                    let id = req.id.unwrap_or_else(get_current_timestamp_ms);
+                    match apply_gated_command(&state, &req.cmd) {
+                        Err(e) => report_command_outcome(&state, id, Err(e)),
+                        Ok(()) if matches!(req.cmd, TelemetryCommand::Abort) => {
+                            // `send_reliable` retries on a backoff and can take seconds to
+                            // resolve; run it on its own task so a slow Abort doesn't stall
+                            // this select loop's radio reads/router processing for every other
+                            // in-flight command the way an inline `.await` here would.
+                            let state_for_abort = state.clone();
+                            let router_for_abort = router.clone();
+                            tokio::spawn(async move {
+                                let result = crate::command_channel::send_reliable(
+                                    &state_for_abort,
+                                    &router_for_abort,
                                     DataType::Abort,
-                                    &[],
-                                ).expect("failed to log Abort command");
-                            println!("Abort command sent");
+                                    "Abort command issued".as_bytes(),
+                                )
+                                .await;
+                                report_command_outcome(&state_for_abort, id, result);
+                            });
+                        }
+                        Ok(()) => {
+                            let result = run_command(&router, &req.cmd);
+                            report_command_outcome(&state, id, result);
                         }
                     }
                 }
@@ -94,24 +204,6 @@ pub async fn handle_packet(state: &Arc<AppState>) {
     let v6 = values.get(6).copied();
     let v7 = values.get(7).copied();
 
-    // Insert into DB
-    sqlx::query(
-        "INSERT INTO telemetry (timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-    )
-        .bind(ts_ms)
-        .bind(&data_type_str)
-        .bind(v0)
-        .bind(v1)
-        .bind(v2)
-        .bind(v3)
-        .bind(v4)
-        .bind(v5)
-        .bind(v6)
-        .bind(v7)
-        .execute(&state.db)
-        .await
-        .expect("DB insert into telemetry failed");
-
     // Build DTO to send to WebSocket listeners
     let row = TelemetryRow {
         timestamp_ms: ts_ms,
@@ -126,6 +218,14 @@ pub async fn handle_packet(state: &Arc<AppState>) {
         v7,
     };
 
+    // Queue for the background writer rather than inserting inline — non-blocking, so a writer
+    // that's fallen behind sheds this row instead of stalling the next `radio_interval`/
+    // `router_interval` tick in `telemetry_task`'s select loop.
+    let session_id = *state.current_session.lock().unwrap();
+    if state.db_write_tx.try_send(PendingInsert { row: row.clone(), session_id }).is_err() {
+        eprintln!("telemetry DB writer backlogged, dropping row for {}", row.data_type);
+    }
+
     let _ = state.ws_tx.send(row);
 }
 
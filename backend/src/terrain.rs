@@ -0,0 +1,145 @@
+//! Runtime DEM sampling: maps a lon/lat to its containing offline elevation tile (fetched by
+//! `build.rs`'s optional `GS_BUILD_ELEVATION` step, stored as `tiles/<z>/<x>/<y>.elev` next to
+//! the basemap imagery) and bilinearly interpolates the four nearest posts. This is what lets
+//! `geofence`/the map view reason about height-above-ground instead of absolute altitude.
+
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Elevation posts per tile edge and the zoom level they're sampled at — must match whatever
+/// `build.rs` wrote into each `.elev` file (one little-endian `i16` meters value per post,
+/// row-major, top-left first).
+const POSTS_PER_TILE: usize = 256;
+const ELEVATION_ZOOM: u32 = 8;
+
+fn region_base_dir(region: &str) -> PathBuf {
+    PathBuf::from(format!("./backend/data/maps/{region}"))
+}
+
+fn elevation_tile_path(region: &str, z: u32, x: u32, y: u32) -> PathBuf {
+    region_base_dir(region)
+        .join("tiles")
+        .join(z.to_string())
+        .join(x.to_string())
+        .join(format!("{y}.elev"))
+}
+
+#[derive(Clone)]
+struct ElevationTile {
+    posts: Arc<[i16]>,
+}
+
+impl ElevationTile {
+    fn post(&self, px: usize, py: usize) -> f64 {
+        self.posts[py * POSTS_PER_TILE + px] as f64
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != POSTS_PER_TILE * POSTS_PER_TILE * 2 {
+            return None;
+        }
+        let posts: Arc<[i16]> = bytes
+            .chunks_exact(2)
+            .map(|c| i16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        Some(Self { posts })
+    }
+}
+
+type TileCacheKey = (u32, u32, u32);
+
+fn tile_cache() -> &'static Mutex<HashMap<TileCacheKey, Option<ElevationTile>>> {
+    static CACHE: OnceLock<Mutex<HashMap<TileCacheKey, Option<ElevationTile>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Loads and caches the `(z, x, y)` elevation tile for `region`, returning `None` (and caching
+/// that) if it's missing, unreadable, or the wrong size — a campaign that skipped
+/// `GS_BUILD_ELEVATION` at build time just gets no terrain data rather than a panic.
+fn load_tile(region: &str, z: u32, x: u32, y: u32) -> Option<ElevationTile> {
+    let key = (z, x, y);
+    let mut cache = tile_cache().lock().unwrap();
+    if let Some(cached) = cache.get(&key) {
+        return cached.clone();
+    }
+
+    let tile = std::fs::read(elevation_tile_path(region, z, x, y))
+        .ok()
+        .and_then(|bytes| ElevationTile::from_bytes(&bytes));
+    cache.insert(key, tile.clone());
+    tile
+}
+
+/// Maps lon/lat to fractional tile-pixel coordinates at `zoom`: the integer part selects the
+/// tile, the fractional part is the position within it, in the same Web Mercator projection
+/// `map::lonlat_to_tile`/`build.rs::lonlat_to_tile` use for whole-tile indices.
+fn lonlat_to_tile_pixel(lon_deg: f64, lat_deg: f64, zoom: u32) -> (f64, f64) {
+    let lat_rad = lat_deg.to_radians();
+    let n = 2f64.powi(zoom as i32);
+
+    let x = (lon_deg + 180.0) / 360.0 * n;
+    let y = (1.0 - ((lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / PI)) / 2.0 * n;
+
+    (x.clamp(0.0, n), y.clamp(0.0, n))
+}
+
+/// Ground elevation in meters at `(lon, lat)`, bilinearly interpolated from the four elevation
+/// posts surrounding it in `region`'s offline DEM tiles. Returns `None` if the containing tile
+/// was never fetched or is missing/corrupt, so callers (e.g. `geofence`) should fall back to
+/// treating altitude as absolute rather than height-above-ground in that case.
+pub fn ground_elevation_m(region: &str, lon: f64, lat: f64) -> Option<f64> {
+    let (tx, ty) = lonlat_to_tile_pixel(lon, lat, ELEVATION_ZOOM);
+    let tile_x = tx.floor() as u32;
+    let tile_y = ty.floor() as u32;
+
+    let tile = load_tile(region, ELEVATION_ZOOM, tile_x, tile_y)?;
+
+    let px = (tx - tile_x as f64) * POSTS_PER_TILE as f64;
+    let py = (ty - tile_y as f64) * POSTS_PER_TILE as f64;
+
+    let x0 = (px.floor() as usize).min(POSTS_PER_TILE - 2);
+    let y0 = (py.floor() as usize).min(POSTS_PER_TILE - 2);
+    let fx = px - x0 as f64;
+    let fy = py - y0 as f64;
+
+    let p00 = tile.post(x0, y0);
+    let p10 = tile.post(x0 + 1, y0);
+    let p01 = tile.post(x0, y0 + 1);
+    let p11 = tile.post(x0 + 1, y0 + 1);
+
+    let top = p00 * (1.0 - fx) + p10 * fx;
+    let bottom = p01 * (1.0 - fx) + p11 * fx;
+    Some(top * (1.0 - fy) + bottom * fy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_tile_returns_none() {
+        assert_eq!(
+            ground_elevation_m("no_such_region_in_tests", -106.485, 31.7619),
+            None
+        );
+    }
+
+    #[test]
+    fn tile_pixel_coords_stay_in_bounds() {
+        let n = 2f64.powi(ELEVATION_ZOOM as i32);
+        let (x, y) = lonlat_to_tile_pixel(-170.0, 83.0, ELEVATION_ZOOM);
+        assert!((0.0..=n).contains(&x));
+        assert!((0.0..=n).contains(&y));
+    }
+
+    #[test]
+    fn flat_tile_posts_decode_to_that_constant() {
+        let flat = vec![42i16; POSTS_PER_TILE * POSTS_PER_TILE];
+        let bytes: Vec<u8> = flat.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let tile = ElevationTile::from_bytes(&bytes).expect("valid tile");
+        assert_eq!(tile.post(10, 10), 42.0);
+        assert_eq!(tile.post(200, 5), 42.0);
+    }
+}
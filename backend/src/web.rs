@@ -1,8 +1,11 @@
-use crate::state::AppState;
-use axum::http::header;
+use crate::firmware_update::{self, FirmwareSlot, FirmwareUpdateStatus};
+use crate::flight_session;
+use crate::state::{AppState, CommandRequest};
+use axum::body::Body;
+use axum::http::{header, StatusCode};
 use axum::{
-    extract::ws::{Message, Utf8Bytes, WebSocket, WebSocketUpgrade}, extract::{Query, State},
-    response::IntoResponse,
+    extract::ws::{CloseFrame, Message, Utf8Bytes, WebSocket, WebSocketUpgrade}, extract::{Path, Query, State},
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json,
     Router,
@@ -10,17 +13,28 @@ use axum::{
 use bytes::Bytes;
 use chrono::Utc;
 use futures::{SinkExt, StreamExt};
-use groundstation_shared::{FlightState, TelemetryCommand, TelemetryRow};
+use groundstation_shared::{AnnotationOp, FlightState, TelemetryCommand, TelemetryRow};
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::OnceCell;
 use tower_http::services::ServeDir;
 
 static FAVICON_DATA: OnceCell<Bytes> = OnceCell::const_new();
 
+/// Server protocol version, advertised in the WS `Hello` frame (see `HelloMsg`) and at
+/// `/api/version` — bump this whenever a `WsOutMsg`/`WsInbound` variant changes shape in a way
+/// an older client can't parse. There's no separate major/minor split yet: any mismatch between
+/// a client's `hello.protocol_version` and this constant is treated as incompatible.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Close code for a WS connection torn down because the client's `hello.protocol_version`
+/// didn't match `PROTOCOL_VERSION` — in the 4000-4999 private-use range RFC 6455 §7.4.2
+/// reserves for application use.
+const WS_CLOSE_PROTOCOL_MISMATCH: u16 = 4001;
+
 /// Public router constructor
 pub fn router(state: Arc<AppState>) -> Router {
     let static_dir = ServeDir::new("./frontend/dist");
@@ -30,6 +44,14 @@ pub fn router(state: Arc<AppState>) -> Router {
         .route("/api/command", post(send_command))
         .route("/api/history", get(get_history))
         .route("/api/alerts", get(get_alerts))
+        .route("/api/version", get(get_version))
+        .route("/api/firmware/update", post(start_firmware_update))
+        .route("/api/firmware/status", get(get_firmware_update_status))
+        .route("/api/phase", get(get_flight_phase))
+        .route("/api/command/delivery", get(get_command_delivery))
+        .route("/api/range", get(get_range))
+        .route("/api/sessions", get(list_sessions).post(open_session))
+        .route("/api/sessions/:id/close", post(close_session))
         .route("/favicon", get(get_favicon))
         .route("/flightstate", get(get_flight_state))
         .route("/ws", get(ws_handler))
@@ -43,6 +65,7 @@ pub fn router(state: Arc<AppState>) -> Router {
 ///   { "ty": "telemetry", "data": { ...TelemetryRow... } }
 ///   { "ty": "warning",   "data": { ...WarningMsg... } }
 ///   { "ty": "error",     "data": { ...ErrorMsg... } }
+///   { "ty": "Hello",     "data": { ...HelloMsg... } }   — always the first frame on a connection
 #[derive(Serialize)]
 #[serde(tag = "ty", content = "data")]
 pub enum WsOutMsg {
@@ -50,6 +73,93 @@ pub enum WsOutMsg {
     Warning(WarningMsg),
     FlightState(FlightStateMsg),
     Error(ErrorMsg),
+    Ack(AckMsg),
+    Resync(ResyncMsg),
+    CommandAck(CommandAckMsg),
+    Hello(HelloMsg),
+    /// Relayed verbatim from whichever client sent it — see `AppState::annotations_tx`.
+    Annotation(AnnotationOp),
+}
+
+/// `WsOutMsg`'s variant names, exactly as `#[serde(tag = "ty")]` serializes them — the
+/// vocabulary `HelloMsg.out_kinds` advertises and `accepted_kinds` filters against.
+const WS_OUT_KINDS: &[&str] = &[
+    "Telemetry",
+    "Warning",
+    "FlightState",
+    "Error",
+    "Ack",
+    "Resync",
+    "CommandAck",
+    "Hello",
+    "Annotation",
+];
+
+/// `TelemetryCommand` variants a `WsCommand.cmd` can name today — see `telemetry_task::run_command`.
+const WS_IN_CMDS: &[&str] = &["Arm", "Disarm", "Abort"];
+
+fn out_msg_kind(msg: &WsOutMsg) -> &'static str {
+    match msg {
+        WsOutMsg::Telemetry(_) => "Telemetry",
+        WsOutMsg::Warning(_) => "Warning",
+        WsOutMsg::FlightState(_) => "FlightState",
+        WsOutMsg::Error(_) => "Error",
+        WsOutMsg::Ack(_) => "Ack",
+        WsOutMsg::Resync(_) => "Resync",
+        WsOutMsg::CommandAck(_) => "CommandAck",
+        WsOutMsg::Hello(_) => "Hello",
+        WsOutMsg::Annotation(_) => "Annotation",
+    }
+}
+
+/// Sent as the very first frame on every `/ws` connection, before any telemetry or control
+/// frame, so a client can detect a protocol mismatch before handing anything else to its
+/// parser. `encodings` lists the `?enc=` values `ws_handler` accepts; `out_kinds`/`in_cmds`
+/// are what this server version actually emits/understands, for a client to feature-gate on
+/// rather than hardcoding an assumption that'll break silently the day it's wrong.
+#[derive(Clone, Serialize)]
+pub struct HelloMsg {
+    pub protocol_version: u32,
+    pub encodings: Vec<&'static str>,
+    pub out_kinds: Vec<&'static str>,
+    pub in_cmds: Vec<&'static str>,
+}
+
+fn hello_msg() -> HelloMsg {
+    HelloMsg {
+        protocol_version: PROTOCOL_VERSION,
+        encodings: vec!["json", "bin", "bin-zstd"],
+        out_kinds: WS_OUT_KINDS.to_vec(),
+        in_cmds: WS_IN_CMDS.to_vec(),
+    }
+}
+
+/// Sent when a client's broadcast subscription lagged and `tokio::sync::broadcast` dropped
+/// frames out from under it — there is no way to recover the dropped frames themselves, only
+/// to tell the client it's missing `dropped` of them since `last_seen_ts`, so it can patch the
+/// gap with a `/api/history` re-fetch instead of silently rendering a hole.
+#[derive(Clone, Serialize)]
+pub struct ResyncMsg {
+    pub dropped: u64,
+    pub last_seen_ts: i64,
+}
+
+/// Confirms receipt of a WS command so the frontend can stop retransmitting it. This is *not*
+/// confirmation the command did anything — that's `CommandAckMsg`, sent once `telemetry_task`
+/// has actually run the router call.
+#[derive(Clone, Serialize)]
+pub struct AckMsg {
+    pub seq: u64,
+}
+
+/// Reports whether a `CommandRequest`'s `router.log_queue`/`router.log` call in
+/// `telemetry_task` actually succeeded, echoing back the id the caller supplied (a WS client's
+/// `seq`, or the `id` in a REST `/api/command` body) so it can match this to the right request.
+#[derive(Clone, Serialize)]
+pub struct CommandAckMsg {
+    pub id: u64,
+    pub ok: bool,
+    pub error: Option<String>,
 }
 
 #[derive(Clone, Serialize)]
@@ -80,38 +190,108 @@ pub struct AlertDto {
     pub message: String,
 }
 
-async fn get_recent(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let now_ms = Utc::now().timestamp_millis();
-    let cutoff = now_ms - 20 * 60 * 1000; // 20 minutes
-
-    let rows_db = sqlx::query(
-        "SELECT timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7 \
-         FROM telemetry \
-         WHERE timestamp_ms >= ? \
-         ORDER BY timestamp_ms ASC",
-    )
-    .bind(cutoff)
-    .fetch_all(&state.db)
-    .await
-    .unwrap_or_default();
-
-    let rows: Vec<TelemetryRow> = rows_db
-        .into_iter()
-        .map(|row| TelemetryRow {
-            timestamp_ms: row.get::<i64, _>("timestamp_ms"),
-            data_type: row.get::<String, _>("data_type"),
-            v0: row.get::<Option<f32>, _>("v0"),
-            v1: row.get::<Option<f32>, _>("v1"),
-            v2: row.get::<Option<f32>, _>("v2"),
-            v3: row.get::<Option<f32>, _>("v3"),
-            v4: row.get::<Option<f32>, _>("v4"),
-            v5: row.get::<Option<f32>, _>("v5"),
-            v6: row.get::<Option<f32>, _>("v6"),
-            v7: row.get::<Option<f32>, _>("v7"),
-        })
-        .collect();
+/// `?format=ndjson|json` for `/api/recent` — the same `format` field `HistoryParams` and
+/// `RangeParams` carry for the other handlers below that stream DB rows instead of collecting
+/// them. NDJSON (one JSON object per line) is the default since it lets the
+/// frontend parse a long flight's history progressively instead of waiting on one giant array.
+#[derive(Deserialize)]
+struct FormatParam {
+    #[serde(default)]
+    format: Option<String>,
+}
+
+fn wants_json_array(format: &Option<String>) -> bool {
+    format.as_deref() == Some("json")
+}
+
+/// Turns a stream of rows into one NDJSON line per row — the hot path for large ranges, since
+/// nothing downstream of the DB has to buffer more than one row at a time.
+fn ndjson_body<S, T>(rows: S) -> impl futures::Stream<Item = Result<Bytes, std::io::Error>> + Send
+where
+    S: futures::Stream<Item = T> + Send,
+    T: Serialize,
+{
+    rows.map(|item| {
+        let mut line = serde_json::to_vec(&item).unwrap_or_default();
+        line.push(b'\n');
+        Ok(Bytes::from(line))
+    })
+}
+
+/// Turns a stream of rows into a JSON array written incrementally — `[`, then each row
+/// comma-separated as it arrives, then `]` — for callers that need a single JSON value rather
+/// than NDJSON, without giving up the bounded-memory property streaming is for.
+fn json_array_body<S, T>(rows: S) -> impl futures::Stream<Item = Result<Bytes, std::io::Error>> + Send
+where
+    S: futures::Stream<Item = T> + Send,
+    T: Serialize,
+{
+    let open = futures::stream::once(async { Ok(Bytes::from_static(b"[")) });
+    let items = rows.enumerate().map(|(i, item)| {
+        let mut chunk = if i > 0 { vec![b','] } else { Vec::new() };
+        chunk.extend(serde_json::to_vec(&item).unwrap_or_default());
+        Ok(Bytes::from(chunk))
+    });
+    let close = futures::stream::once(async { Ok(Bytes::from_static(b"]")) });
+    open.chain(items).chain(close)
+}
 
-    Json(rows)
+/// Wraps `rows` in an HTTP response whose body is produced incrementally as the stream yields,
+/// per `format` — peak memory stays bounded by one row at a time (plus whatever axum/hyper
+/// buffers for the wire) regardless of how many rows the query matched.
+fn stream_rows<S, T>(rows: S, format: &Option<String>) -> Response
+where
+    S: futures::Stream<Item = T> + Send + 'static,
+    T: Serialize + Send + 'static,
+{
+    let (content_type, body) = if wants_json_array(format) {
+        ("application/json", Body::from_stream(json_array_body(rows)))
+    } else {
+        ("application/x-ndjson", Body::from_stream(ndjson_body(rows)))
+    };
+
+    Response::builder().header(header::CONTENT_TYPE, content_type).body(body).unwrap()
+}
+
+/// Takes `db` by value (a `SqlitePool` clone is just bumping an internal `Arc`) so the returned
+/// stream is `'static` and can be handed straight to `Body::from_stream` instead of borrowing
+/// from the handler's stack frame.
+fn telemetry_row_stream(
+    query: &'static str,
+    cutoff: i64,
+    db: sqlx::SqlitePool,
+) -> impl futures::Stream<Item = TelemetryRow> + Send + 'static {
+    async_stream::stream! {
+        let mut rows = sqlx::query(query).bind(cutoff).fetch(&db);
+        while let Some(Ok(row)) = rows.next().await {
+            yield TelemetryRow {
+                timestamp_ms: row.get::<i64, _>("timestamp_ms"),
+                data_type: row.get::<String, _>("data_type"),
+                v0: row.get::<Option<f32>, _>("v0"),
+                v1: row.get::<Option<f32>, _>("v1"),
+                v2: row.get::<Option<f32>, _>("v2"),
+                v3: row.get::<Option<f32>, _>("v3"),
+                v4: row.get::<Option<f32>, _>("v4"),
+                v5: row.get::<Option<f32>, _>("v5"),
+                v6: row.get::<Option<f32>, _>("v6"),
+                v7: row.get::<Option<f32>, _>("v7"),
+            };
+        }
+    }
+}
+
+const TELEMETRY_SELECT: &str = "SELECT timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7 \
+     FROM telemetry \
+     WHERE timestamp_ms >= ? \
+     ORDER BY timestamp_ms ASC";
+
+async fn get_recent(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<FormatParam>,
+) -> impl IntoResponse {
+    let cutoff = Utc::now().timestamp_millis() - 20 * 60 * 1000; // 20 minutes
+    let rows = telemetry_row_stream(TELEMETRY_SELECT, cutoff, state.db.clone());
+    stream_rows(rows, &params.format)
 }
 
 async fn get_favicon() -> impl IntoResponse {
@@ -142,64 +322,593 @@ async fn get_flight_state(State(state): State<Arc<AppState>>) -> impl IntoRespon
         .unwrap_or(groundstation_shared::FlightState::Startup);
     Json(flight_state)
 }
+/// `/api/version` — the same `PROTOCOL_VERSION` the WS `Hello` frame advertises, for a caller
+/// that only ever speaks REST to check compatibility before trying `/ws` at all.
+#[derive(Serialize)]
+struct VersionDto {
+    protocol_version: u32,
+}
+
+async fn get_version() -> impl IntoResponse {
+    Json(VersionDto { protocol_version: PROTOCOL_VERSION })
+}
+
+/// Response body for `/api/command` — `status` is `"ok"`, `"error: <reason>"` from the router
+/// call itself, or `"error: timed out waiting for acknowledgment"` if `telemetry_task` never
+/// answered within `COMMAND_ACK_TIMEOUT`.
+#[derive(Serialize)]
+struct CommandResponse {
+    id: u64,
+    status: String,
+}
+
+const COMMAND_ACK_TIMEOUT: Duration = Duration::from_secs(5);
+
 async fn send_command(
     State(state): State<Arc<AppState>>,
-    Json(cmd): Json<TelemetryCommand>,
-) -> &'static str {
-    let _ = state.cmd_tx.send(cmd).await;
-    "ok"
+    Json(req): Json<CommandRequest>,
+) -> Json<CommandResponse> {
+    let id = req.id.unwrap_or_else(|| now_ms_i64() as u64);
+
+    if !operator_role_allows(&req.operator_role, &req.cmd) {
+        println!(
+            "Refused {:?} from operator {:?} (role {:?}) via /api/command",
+            req.cmd, req.operator_id, req.operator_role
+        );
+        return Json(CommandResponse {
+            id,
+            status: format!("error: role {:?} not permitted to issue this command", req.operator_role),
+        });
+    }
+
+    let (ack_tx, ack_rx) = tokio::sync::oneshot::channel();
+    state.pending_acks.lock().unwrap().insert(id, ack_tx);
+
+    if state
+        .cmd_tx
+        .send(CommandRequest {
+            id: Some(id),
+            cmd: req.cmd,
+            operator_id: req.operator_id,
+            operator_role: req.operator_role,
+        })
+        .await
+        .is_err()
+    {
+        state.pending_acks.lock().unwrap().remove(&id);
+        return Json(CommandResponse { id, status: "error: command channel closed".to_string() });
+    }
+
+    let status = match tokio::time::timeout(COMMAND_ACK_TIMEOUT, ack_rx).await {
+        Ok(Ok(ack)) if ack.ok => "ok".to_string(),
+        Ok(Ok(ack)) => format!("error: {}", ack.error.unwrap_or_default()),
+        Ok(Err(_)) => "error: command dropped before acknowledgment".to_string(),
+        Err(_) => "error: timed out waiting for acknowledgment".to_string(),
+    };
+
+    Json(CommandResponse { id, status })
 }
 
-async fn ws_handler(ws: WebSocketUpgrade, State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_ws(socket, state))
+/// `/api/firmware/update?board=rocket|umbilical&slot=a|b` — body is the raw firmware image.
+/// Kicks the DFU push off on a blocking task and returns immediately; poll
+/// `/api/firmware/status` for progress. Rejected with `409 Conflict` if a push is already
+/// in flight, since `run_firmware_update` holds the target radio's mutex for the whole transfer
+/// and a second call would just queue up behind it silently.
+#[derive(Deserialize)]
+struct FirmwareUpdateParams {
+    board: String,
+    slot: String,
+}
+
+async fn start_firmware_update(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<FirmwareUpdateParams>,
+    image: Bytes,
+) -> Response {
+    let radio = match params.board.as_str() {
+        "rocket" => state.rocket_radio.clone(),
+        "umbilical" => state.umbilical_radio.clone(),
+        other => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("unknown board {other:?}, expected \"rocket\" or \"umbilical\""),
+            )
+                .into_response()
+        }
+    };
+    let slot = match params.slot.as_str() {
+        "a" | "A" => FirmwareSlot::A,
+        "b" | "B" => FirmwareSlot::B,
+        other => {
+            return (StatusCode::BAD_REQUEST, format!("unknown slot {other:?}, expected \"a\" or \"b\""))
+                .into_response()
+        }
+    };
+
+    {
+        let mut status = state.firmware_update_status.lock().unwrap();
+        if status.as_ref().is_some_and(|s| !s.done) {
+            return (StatusCode::CONFLICT, "a firmware update is already in progress".to_string())
+                .into_response();
+        }
+        *status = Some(FirmwareUpdateStatus {
+            board: params.board.clone(),
+            slot,
+            progress: None,
+            error: None,
+            done: false,
+        });
+    }
+
+    let state_for_task = state.clone();
+    tokio::task::spawn_blocking(move || {
+        let status = state_for_task.firmware_update_status.clone();
+        let result = firmware_update::run_firmware_update(&state_for_task, &radio, slot, &image, 0, {
+            let status = status.clone();
+            move |progress| {
+                if let Some(s) = status.lock().unwrap().as_mut() {
+                    s.progress = Some(progress);
+                }
+            }
+        });
+        let mut guard = status.lock().unwrap();
+        if let Some(s) = guard.as_mut() {
+            s.done = true;
+            if let Err(e) = result {
+                s.error = Some(e.to_string());
+            }
+        }
+    });
+
+    StatusCode::ACCEPTED.into_response()
+}
+
+async fn get_firmware_update_status(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    Json(state.firmware_update_status.lock().unwrap().clone())
+}
+
+async fn get_flight_phase(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    Json(*state.flight_phase.lock().unwrap())
+}
+
+/// `/api/command/delivery` — every `command_channel::send_reliable` call's latest delivery
+/// state (pending/acked/failed), most recent first, so the frontend can show whether e.g. an
+/// abort actually got through instead of just that it was sent.
+async fn get_command_delivery(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    Json(crate::command_channel::delivery_snapshot(&state))
+}
+
+#[derive(Deserialize)]
+struct WsConnectParams {
+    // /ws?enc=json|bin|bin-zstd — negotiated once at connect; the server emits only this one
+    // encoding for the life of the socket. Defaults to json so existing clients need no query
+    // param. `bin-zstd` is the same tagged frames as `bin`, each individually zstd-compressed
+    // — see `send_ws_out`.
+    #[serde(default)]
+    enc: Option<String>,
+}
+
+async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<WsConnectParams>,
+) -> impl IntoResponse {
+    let encoding = match params.enc.as_deref() {
+        Some("bin") => WsEncoding::Binary,
+        Some("bin-zstd") => WsEncoding::BinaryZstd,
+        _ => WsEncoding::Json,
+    };
+    ws.on_upgrade(move |socket| handle_ws(socket, state, encoding))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum WsEncoding {
+    Json,
+    Binary,
+    BinaryZstd,
+}
+
+/// zstd's fastest level — these frames are small and sent at telemetry rate, so encode cost
+/// matters more than squeezing out the last few bytes.
+const ZSTD_LEVEL: i32 = 1;
+
+const BIN_TAG_TELEMETRY: u8 = 0;
+const BIN_TAG_WARNING: u8 = 1;
+const BIN_TAG_ERROR: u8 = 2;
+const BIN_TAG_FLIGHT_STATE: u8 = 3;
+/// Announces a `data_type` string's interned id before the first telemetry frame that uses it
+/// — the registry is per-connection and built lazily, so a client only ever sees registrations
+/// for data types that have actually appeared on its socket.
+const BIN_TAG_TYPE_REGISTRY: u8 = 4;
+/// `Ack`/`Resync` are control-plane and rare enough that a dedicated tag per variant isn't
+/// worth it — carries the same JSON `WsOutMsg` a JSON-mode client would get, length-prefixed so
+/// it stays self-describing inside an otherwise-binary stream.
+const BIN_TAG_CONTROL: u8 = 5;
+
+/// Interns `data_type` strings to `u8` ids, scoped to one connection — built lazily as
+/// telemetry for a never-before-seen type arrives, rather than negotiated up front, since the
+/// server doesn't know a flight's full set of data types ahead of time.
+#[derive(Default)]
+struct DataTypeRegistry {
+    ids: std::collections::HashMap<String, u8>,
+    next: u8,
+}
+
+impl DataTypeRegistry {
+    /// Returns the id for `name`, allocating one if this is the first time it's been seen on
+    /// this connection — the bool tells the caller whether a registration frame needs to go
+    /// out before the telemetry frame that uses this id.
+    fn id_for(&mut self, name: &str) -> (u8, bool) {
+        if let Some(&id) = self.ids.get(name) {
+            return (id, false);
+        }
+        let id = self.next;
+        self.next = self.next.wrapping_add(1);
+        self.ids.insert(name.to_string(), id);
+        (id, true)
+    }
+}
+
+fn encode_type_registration(id: u8, name: &str) -> Vec<u8> {
+    let name_bytes = name.as_bytes();
+    let len = name_bytes.len().min(u8::MAX as usize);
+    let mut buf = Vec::with_capacity(3 + len);
+    buf.push(BIN_TAG_TYPE_REGISTRY);
+    buf.push(id);
+    buf.push(len as u8);
+    buf.extend_from_slice(&name_bytes[..len]);
+    buf
+}
+
+/// The inverse of `telemetry_decode::decode_f32_values`: timestamp, interned data-type id, a
+/// value count, then that many little-endian f32s — `TelemetryRow`'s `None` slots (beyond
+/// `decode_f32_values`'s original count) are simply not emitted.
+fn encode_telemetry_row(row: &TelemetryRow, data_type_id: u8) -> Vec<u8> {
+    let values: Vec<f32> =
+        [row.v0, row.v1, row.v2, row.v3, row.v4, row.v5, row.v6, row.v7].into_iter().flatten().collect();
+
+    let mut buf = Vec::with_capacity(1 + 8 + 1 + 1 + values.len() * 4);
+    buf.push(BIN_TAG_TELEMETRY);
+    buf.extend_from_slice(&row.timestamp_ms.to_le_bytes());
+    buf.push(data_type_id);
+    buf.push(values.len().min(u8::MAX as usize) as u8);
+    for v in values {
+        buf.extend_from_slice(&v.to_le_bytes());
+    }
+    buf
+}
+
+fn encode_alert_frame(tag: u8, timestamp_ms: i64, message: &str) -> Vec<u8> {
+    let msg_bytes = message.as_bytes();
+    let len = msg_bytes.len().min(u16::MAX as usize);
+    let mut buf = Vec::with_capacity(1 + 8 + 2 + len);
+    buf.push(tag);
+    buf.extend_from_slice(&timestamp_ms.to_le_bytes());
+    buf.extend_from_slice(&(len as u16).to_le_bytes());
+    buf.extend_from_slice(&msg_bytes[..len]);
+    buf
+}
+
+fn encode_flight_state_frame(fs: &FlightStateMsg) -> Vec<u8> {
+    vec![BIN_TAG_FLIGHT_STATE, fs.state as u8]
+}
+
+fn encode_control_frame(msg: &WsOutMsg) -> Vec<u8> {
+    let text = serde_json::to_string(msg).unwrap_or_default();
+    let bytes = text.as_bytes();
+    let len = bytes.len().min(u16::MAX as usize);
+    let mut buf = Vec::with_capacity(3 + len);
+    buf.push(BIN_TAG_CONTROL);
+    buf.extend_from_slice(&(len as u16).to_le_bytes());
+    buf.extend_from_slice(&bytes[..len]);
+    buf
+}
+
+/// Turns one `WsOutMsg` into the frame(s) to send in binary mode — usually one, but a
+/// telemetry row whose `data_type` hasn't been seen yet on this connection is preceded by a
+/// `BIN_TAG_TYPE_REGISTRY` frame so the client can resolve the id before it needs it.
+fn encode_binary(msg: &WsOutMsg, registry: &mut DataTypeRegistry) -> Vec<Vec<u8>> {
+    match msg {
+        WsOutMsg::Telemetry(row) => {
+            let (id, is_new) = registry.id_for(&row.data_type);
+            let mut frames = Vec::with_capacity(2);
+            if is_new {
+                frames.push(encode_type_registration(id, &row.data_type));
+            }
+            frames.push(encode_telemetry_row(row, id));
+            frames
+        }
+        WsOutMsg::Warning(w) => vec![encode_alert_frame(BIN_TAG_WARNING, w.timestamp_ms, &w.message)],
+        WsOutMsg::Error(e) => vec![encode_alert_frame(BIN_TAG_ERROR, e.timestamp_ms, &e.message)],
+        WsOutMsg::FlightState(fs) => vec![encode_flight_state_frame(fs)],
+        WsOutMsg::Ack(_)
+        | WsOutMsg::Resync(_)
+        | WsOutMsg::CommandAck(_)
+        | WsOutMsg::Hello(_)
+        | WsOutMsg::Annotation(_) => {
+            vec![encode_control_frame(msg)]
+        }
+    }
+}
+
+/// Sends one `WsOutMsg` in whichever encoding this connection negotiated — JSON text, or one
+/// or more binary frames. Returns `false` on a send error, the same way the caller's old
+/// inline `sender.send(...).await.is_err()` checks did.
+async fn send_ws_out<S>(
+    sender: &mut S,
+    msg: &WsOutMsg,
+    encoding: WsEncoding,
+    registry: &mut DataTypeRegistry,
+) -> bool
+where
+    S: futures::Sink<Message> + Unpin,
+{
+    match encoding {
+        WsEncoding::Json => {
+            let text = serde_json::to_string(msg).unwrap_or_default();
+            sender.send(Message::Text(Utf8Bytes::from(text))).await.is_ok()
+        }
+        WsEncoding::Binary => {
+            for frame in encode_binary(msg, registry) {
+                if sender.send(Message::Binary(frame.into())).await.is_err() {
+                    return false;
+                }
+            }
+            true
+        }
+        WsEncoding::BinaryZstd => {
+            for frame in encode_binary(msg, registry) {
+                let compressed = match zstd::encode_all(frame.as_slice(), ZSTD_LEVEL) {
+                    Ok(bytes) => bytes,
+                    Err(_) => return false,
+                };
+                if sender.send(Message::Binary(compressed.into())).await.is_err() {
+                    return false;
+                }
+            }
+            true
+        }
+    }
 }
 
+/// App-level keepalive frames. Real `Message::Ping`/`Pong` control frames round-trip a native
+/// client automatically below tungstenite; a browser socket can't send control frames at all,
+/// so wasm clients send this as plain text instead and expect the matching reply in kind — see
+/// `WS_APP_PING`/`WS_APP_PONG` in `frontend/src/telemetry_dashboard/mod.rs`.
+const WS_APP_PING: &str = r#"{"type":"ping"}"#;
+const WS_APP_PONG: &str = r#"{"type":"pong"}"#;
+
 /// Shape of commands sent from the frontend over WebSocket:
-/// { "cmd": "Arm" } or { "cmd": "Disarm" }
+/// { "cmd": "Arm", "seq": 1, "operator_id": "jdoe", "operator_role": "flight_director" }
+/// `seq` is echoed back in an `Ack` so the frontend can stop retransmitting. `operator_role`
+/// is self-reported by the client (there's no server-side session/auth to resolve it from
+/// yet) — it mirrors the allow-list `send_cmd` already gates on in
+/// `frontend/src/telemetry_dashboard/operator.rs`, so a stock client can't send a command
+/// its own UI wouldn't let it send, even if `send_cmd`'s gate were ever removed or bypassed.
 #[derive(Deserialize)]
 struct WsCommand {
     cmd: TelemetryCommand,
+    seq: u64,
+    #[serde(default)]
+    operator_id: String,
+    #[serde(default)]
+    operator_role: String,
+}
+
+/// Client's reply to the server's `Hello` frame — optional, since every client before this
+/// request shipped without one and should keep working unnegotiated.
+/// `{ "hello": { "protocol_version": 1, "accept": ["Telemetry", "Warning", ...] } }`
+#[derive(Deserialize)]
+struct ClientHello {
+    protocol_version: u32,
+    /// `WS_OUT_KINDS` values this client can parse — anything else is dropped for this
+    /// connection instead of sent to a parser that doesn't know the shape. Empty (the
+    /// default) means "didn't say", taken as "accept everything" so a client that sends a
+    /// bare `{"hello":{"protocol_version":1}}` just to check compatibility is unaffected.
+    #[serde(default)]
+    accept: Vec<String>,
+}
+
+/// Everything a client can send over `/ws` besides the app-level ping text frame, which
+/// `recv_task` intercepts before this ever gets parsed. Untagged since `WsCommand` and the
+/// subscribe/unsubscribe/hello shapes are distinguished by which fields are present, not by a
+/// tag.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum WsInbound {
+    Command(WsCommand),
+    Subscribe { subscribe: Vec<String> },
+    Unsubscribe { unsubscribe: Vec<String> },
+    Hello { hello: ClientHello },
+    /// A locally-applied CRDT op this client wants relayed to every other connection —
+    /// see `AppState::annotations_tx`.
+    Annotation { annotation: AnnotationOp },
+}
+
+/// The set of `data_type`s (`TelemetryRow.data_type` / `DataTabSpec.channels` on the frontend)
+/// a connection wants telemetry for, shared between `recv_task` (which writes it on
+/// subscribe/unsubscribe) and `forward_broadcasts` (which reads it per row). Empty means "no
+/// filter configured yet" — i.e. forward everything — so a client that never subscribes keeps
+/// today's firehose behavior.
+type SubscriptionFilter = Arc<std::sync::RwLock<std::collections::HashSet<String>>>;
+
+/// The `WS_OUT_KINDS` a connection's client declared it can parse, via `hello.accept` — see
+/// `ClientHello`. `None` (the default, before any hello, or after one with an empty `accept`)
+/// means "accept everything", matching pre-negotiation behavior.
+type AcceptedKinds = Arc<std::sync::RwLock<Option<std::collections::HashSet<String>>>>;
+
+/// Mirrors `operator::role_allows` on the frontend — keep the two in sync. Every command a
+/// client can send today (`Arm`/`Disarm`/`Abort`) is flight-control, so the table collapses
+/// to "flight director or nothing"; it takes `_cmd` so a future read-only command can widen
+/// observers' allow-list without changing this signature.
+fn operator_role_allows(role: &str, _cmd: &TelemetryCommand) -> bool {
+    role == "flight_director"
+}
+
+/// Drains the six broadcast channels for one client and pushes everything into `out_tx`, a
+/// per-connection unbounded queue that — unlike the broadcast receivers it reads from — never
+/// silently drops a message it decided to forward. A broadcast receiver can still lag behind
+/// the producer if this task (or the socket write it feeds) falls behind; when it does,
+/// `recv()` returns `Lagged(n)` instead of failing the old `Ok(pkt) = rx.recv()` select pattern
+/// in silence, so this turns that into an explicit `WsOutMsg::Resync` the client can act on.
+async fn forward_broadcasts(
+    mut telemetry_rx: tokio::sync::broadcast::Receiver<TelemetryRow>,
+    mut warnings_rx: tokio::sync::broadcast::Receiver<WarningMsg>,
+    mut errors_rx: tokio::sync::broadcast::Receiver<ErrorMsg>,
+    mut state_rx: tokio::sync::broadcast::Receiver<FlightStateMsg>,
+    mut cmd_ack_rx: tokio::sync::broadcast::Receiver<CommandAckMsg>,
+    mut annotations_rx: tokio::sync::broadcast::Receiver<AnnotationOp>,
+    out_tx: tokio::sync::mpsc::UnboundedSender<WsOutMsg>,
+    filter: SubscriptionFilter,
+) {
+    use tokio::sync::broadcast::error::RecvError;
+
+    let mut last_seen_ts: i64 = 0;
+
+    loop {
+        tokio::select! {
+            res = telemetry_rx.recv() => match res {
+                Ok(pkt) => {
+                    last_seen_ts = pkt.timestamp_ms;
+                    let subscribed = {
+                        let set = filter.read().unwrap();
+                        set.is_empty() || set.contains(&pkt.data_type)
+                    };
+                    if subscribed && out_tx.send(WsOutMsg::Telemetry(pkt)).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(dropped)) => {
+                    if out_tx.send(WsOutMsg::Resync(ResyncMsg { dropped, last_seen_ts })).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            },
+
+            res = state_rx.recv() => match res {
+                Ok(fs) => {
+                    if out_tx.send(WsOutMsg::FlightState(fs)).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(dropped)) => {
+                    if out_tx.send(WsOutMsg::Resync(ResyncMsg { dropped, last_seen_ts })).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            },
+
+            res = warnings_rx.recv() => match res {
+                Ok(warn) => {
+                    if out_tx.send(WsOutMsg::Warning(warn)).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(dropped)) => {
+                    if out_tx.send(WsOutMsg::Resync(ResyncMsg { dropped, last_seen_ts })).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            },
+
+            res = errors_rx.recv() => match res {
+                Ok(err) => {
+                    if out_tx.send(WsOutMsg::Error(err)).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(dropped)) => {
+                    if out_tx.send(WsOutMsg::Resync(ResyncMsg { dropped, last_seen_ts })).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Closed) => break,
+            },
+
+            // Unlike the four channels above, a client missing a `CommandAck` here isn't a
+            // gap worth a `Resync` — the command it describes already happened (or didn't)
+            // regardless of whether this client heard about it, so a lagged receiver just
+            // resubscribes on the next tick instead of reporting anything.
+            res = cmd_ack_rx.recv() => match res {
+                Ok(ack) => {
+                    if out_tx.send(WsOutMsg::CommandAck(ack)).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            },
+
+            // Same reasoning as `cmd_ack_rx`: a dropped annotation op isn't a telemetry gap,
+            // it's a missed CRDT update from a peer — the CRDT itself is built to tolerate
+            // ops never arriving (WOOT integration only needs the ops it does see to be
+            // internally consistent), so a lagged receiver just carries on.
+            res = annotations_rx.recv() => match res {
+                Ok(op) => {
+                    if out_tx.send(WsOutMsg::Annotation(op)).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
 }
 
-async fn handle_ws(socket: WebSocket, state: Arc<AppState>) {
-    // Subscribe to all three broadcast channels
-    let mut telemetry_rx = state.ws_tx.subscribe();
-    let mut warnings_rx = state.warnings_tx.subscribe();
-    let mut errors_rx = state.errors_tx.subscribe();
-    let mut state_rx = state.state_tx.subscribe();
+async fn handle_ws(socket: WebSocket, state: Arc<AppState>, encoding: WsEncoding) {
+    // Subscribe to all six broadcast channels
+    let telemetry_rx = state.ws_tx.subscribe();
+    let warnings_rx = state.warnings_tx.subscribe();
+    let errors_rx = state.errors_tx.subscribe();
+    let state_rx = state.state_tx.subscribe();
+    let cmd_ack_rx = state.cmd_ack_tx.subscribe();
+    let annotations_rx = state.annotations_tx.subscribe();
 
     let cmd_tx = state.cmd_tx.clone();
+    let annotations_tx = state.annotations_tx.clone();
     let (mut sender, mut receiver) = socket.split();
+    let (ack_tx, mut ack_rx) = tokio::sync::mpsc::channel::<u64>(32);
+    let (pong_tx, mut pong_rx) = tokio::sync::mpsc::channel::<()>(8);
+    let (out_tx, mut out_rx) = tokio::sync::mpsc::unbounded_channel::<WsOutMsg>();
+    let (close_tx, mut close_rx) = tokio::sync::mpsc::channel::<(u16, String)>(1);
+    let filter: SubscriptionFilter = Arc::new(std::sync::RwLock::new(std::collections::HashSet::new()));
+    let recv_filter = filter.clone();
+    let accepted_kinds: AcceptedKinds = Arc::new(std::sync::RwLock::new(None));
+    let recv_accepted_kinds = accepted_kinds.clone();
+
+    // `Hello` is always the first frame queued — nothing else has had a chance to send yet.
+    let _ = out_tx.send(WsOutMsg::Hello(hello_msg()));
+
+    let forwarder = forward_broadcasts(
+        telemetry_rx,
+        warnings_rx,
+        errors_rx,
+        state_rx,
+        cmd_ack_rx,
+        annotations_rx,
+        out_tx,
+        filter,
+    );
 
     // Task: server -> client (all streams multiplexed)
     let send_task = async move {
+        let mut registry = DataTypeRegistry::default();
         loop {
             tokio::select! {
-                Ok(pkt) = telemetry_rx.recv() => {
-                    let msg = WsOutMsg::Telemetry(pkt);
-                    let text = serde_json::to_string(&msg).unwrap_or_default();
-                    if sender
-                        .send(Message::Text(Utf8Bytes::from(text)))
-                        .await
-                        .is_err()
-                    {
-                        break;
-                    }
-                }
-
-                Ok(fs) = state_rx.recv() => {
-                    let msg  = WsOutMsg::FlightState(fs);
-                    let text = serde_json::to_string(&msg).unwrap_or_default();
-                    if sender.send(Message::Text(Utf8Bytes::from(text))).await.is_err() {
+                Some(seq) = ack_rx.recv() => {
+                    let msg = WsOutMsg::Ack(AckMsg { seq });
+                    if !send_ws_out(&mut sender, &msg, encoding, &mut registry).await {
                         break;
                     }
                 }
 
-                Ok(warn) = warnings_rx.recv() => {
-                    let msg = WsOutMsg::Warning(warn);
-                    let text = serde_json::to_string(&msg).unwrap_or_default();
+                Some(()) = pong_rx.recv() => {
                     if sender
-                        .send(Message::Text(Utf8Bytes::from(text)))
+                        .send(Message::Text(Utf8Bytes::from(WS_APP_PONG)))
                         .await
                         .is_err()
                     {
@@ -207,17 +916,24 @@ async fn handle_ws(socket: WebSocket, state: Arc<AppState>) {
                     }
                 }
 
-                Ok(err) = errors_rx.recv() => {
-                    let msg = WsOutMsg::Error(err);
-                    let text = serde_json::to_string(&msg).unwrap_or_default();
-                    if sender
-                        .send(Message::Text(Utf8Bytes::from(text)))
-                        .await
-                        .is_err()
-                    {
+                Some(msg) = out_rx.recv() => {
+                    let accepted = {
+                        let kinds = accepted_kinds.read().unwrap();
+                        match kinds.as_ref() {
+                            Some(set) => set.contains(out_msg_kind(&msg)),
+                            None => true,
+                        }
+                    };
+                    if accepted && !send_ws_out(&mut sender, &msg, encoding, &mut registry).await {
                         break;
                     }
                 }
+
+                Some((code, reason)) = close_rx.recv() => {
+                    let frame = CloseFrame { code, reason: Utf8Bytes::from(reason) };
+                    let _ = sender.send(Message::Close(Some(frame))).await;
+                    break;
+                }
             }
         }
     };
@@ -226,10 +942,60 @@ async fn handle_ws(socket: WebSocket, state: Arc<AppState>) {
     let recv_task = async move {
         while let Some(Ok(msg)) = receiver.next().await {
             if let Message::Text(text) = msg {
-                match serde_json::from_str::<WsCommand>(&text) {
-                    Ok(cmd) => {
-                        if let Err(e) = cmd_tx.send(cmd.cmd).await {
+                if text.as_str() == WS_APP_PING {
+                    let _ = pong_tx.send(()).await;
+                    continue;
+                }
+                match serde_json::from_str::<WsInbound>(&text) {
+                    Ok(WsInbound::Command(cmd)) => {
+                        let seq = cmd.seq;
+                        if !operator_role_allows(&cmd.operator_role, &cmd.cmd) {
+                            println!(
+                                "Refused {:?} from operator {:?} (role {:?})",
+                                cmd.cmd, cmd.operator_id, cmd.operator_role
+                            );
+                        } else if let Err(e) = cmd_tx
+                            .send(CommandRequest {
+                                id: Some(seq),
+                                cmd: cmd.cmd,
+                                operator_id: cmd.operator_id,
+                                operator_role: cmd.operator_role,
+                            })
+                            .await
+                        {
                             println!("Failed to forward WS command to cmd_tx: {e}");
+                        } else {
+                            let _ = ack_tx.send(seq).await;
+                        }
+                    }
+                    Ok(WsInbound::Subscribe { subscribe }) => {
+                        let mut set = recv_filter.write().unwrap();
+                        set.extend(subscribe);
+                    }
+                    Ok(WsInbound::Unsubscribe { unsubscribe }) => {
+                        let mut set = recv_filter.write().unwrap();
+                        for data_type in unsubscribe {
+                            set.remove(&data_type);
+                        }
+                    }
+                    Ok(WsInbound::Annotation { annotation }) => {
+                        // Best-effort fan-out, same as every other broadcast::Sender here —
+                        // no subscribers (nobody else connected yet) isn't an error.
+                        let _ = annotations_tx.send(annotation);
+                    }
+                    Ok(WsInbound::Hello { hello }) => {
+                        if hello.protocol_version != PROTOCOL_VERSION {
+                            let reason = format!(
+                                "incompatible protocol version: client={} server={}",
+                                hello.protocol_version, PROTOCOL_VERSION
+                            );
+                            println!("Closing WS: {reason}");
+                            let _ = close_tx.send((WS_CLOSE_PROTOCOL_MISMATCH, reason)).await;
+                            break;
+                        }
+                        if !hello.accept.is_empty() {
+                            *recv_accepted_kinds.write().unwrap() =
+                                Some(hello.accept.into_iter().collect());
                         }
                     }
                     Err(e) => {
@@ -240,16 +1006,26 @@ async fn handle_ws(socket: WebSocket, state: Arc<AppState>) {
         }
     };
 
-    // Run both directions until one side ends
-    tokio::join!(send_task, recv_task);
+    // Run both directions, plus the broadcast forwarder, until one side ends
+    tokio::join!(send_task, recv_task, forwarder);
 }
 
 #[derive(Deserialize)]
 struct HistoryParams {
     // /api/history?minutes=20  (defaults to 20 if not provided)
     minutes: Option<u64>,
+    // /api/history?format=ndjson|json  (defaults to ndjson if not provided)
+    #[serde(default)]
+    format: Option<String>,
 }
 
+const ALERTS_SELECT: &str = r#"
+        SELECT timestamp_ms, severity, message
+        FROM alerts
+        WHERE timestamp_ms >= ?
+        ORDER BY timestamp_ms DESC
+        "#;
+
 async fn get_history(
     State(state): State<Arc<AppState>>,
     Query(params): Query<HistoryParams>,
@@ -261,39 +1037,12 @@ async fn get_history(
         .unwrap_or(0);
 
     let cutoff = now_ms - (minutes as i64) * 60_000;
-
-    let rows_db = sqlx::query(
-        "SELECT timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7 \
-         FROM telemetry \
-         WHERE timestamp_ms >= ? \
-         ORDER BY timestamp_ms ASC",
-    )
-    .bind(cutoff)
-    .fetch_all(&state.db)
-    .await
-    .unwrap_or_default();
-
-    let rows: Vec<TelemetryRow> = rows_db
-        .into_iter()
-        .map(|row| TelemetryRow {
-            timestamp_ms: row.get::<i64, _>("timestamp_ms"),
-            data_type: row.get::<String, _>("data_type"),
-            v0: row.get::<Option<f32>, _>("v0"),
-            v1: row.get::<Option<f32>, _>("v1"),
-            v2: row.get::<Option<f32>, _>("v2"),
-            v3: row.get::<Option<f32>, _>("v3"),
-            v4: row.get::<Option<f32>, _>("v4"),
-            v5: row.get::<Option<f32>, _>("v5"),
-            v6: row.get::<Option<f32>, _>("v6"),
-            v7: row.get::<Option<f32>, _>("v7"),
-        })
-        .collect();
-
-    Json(rows)
+    let rows = telemetry_row_stream(TELEMETRY_SELECT, cutoff, state.db.clone());
+    stream_rows(rows, &params.format)
 }
 
 /// NEW: /api/alerts – returns warnings + errors from `alerts` table
-/// Query param: `minutes` (optional, defaults to 20)
+/// Query params: `minutes` (optional, defaults to 20), `format` (optional, defaults to ndjson)
 async fn get_alerts(
     State(state): State<Arc<AppState>>,
     Query(params): Query<HistoryParams>,
@@ -301,29 +1050,139 @@ async fn get_alerts(
     let minutes = params.minutes.unwrap_or(20);
     let cutoff = now_ms_i64() - (minutes as i64) * 60_000;
 
-    let alerts_db = sqlx::query(
-        r#"
-        SELECT timestamp_ms, severity, message
-        FROM alerts
-        WHERE timestamp_ms >= ?
-        ORDER BY timestamp_ms DESC
-        "#,
-    )
-    .bind(cutoff)
-    .fetch_all(&state.db)
-    .await
-    .unwrap_or_default();
-
-    let alerts: Vec<AlertDto> = alerts_db
-        .into_iter()
+    let alerts = sqlx::query(ALERTS_SELECT)
+        .bind(cutoff)
+        .fetch(&state.db)
+        .filter_map(|res| async { res.ok() })
         .map(|row| AlertDto {
             timestamp_ms: row.get::<i64, _>("timestamp_ms"),
             severity: row.get::<String, _>("severity"),
             message: row.get::<String, _>("message"),
-        })
-        .collect();
+        });
+
+    stream_rows(alerts, &params.format)
+}
+
+#[derive(Deserialize)]
+struct RangeParams {
+    data_type: String,
+    t0: i64,
+    t1: i64,
+    #[serde(default)]
+    session_id: Option<i64>,
+    #[serde(default)]
+    format: Option<String>,
+}
+
+const RANGE_SELECT: &str = "SELECT timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7 \
+     FROM telemetry \
+     WHERE data_type = ? AND timestamp_ms >= ? AND timestamp_ms <= ? \
+     ORDER BY timestamp_ms ASC";
+
+const RANGE_SELECT_BY_SESSION: &str = "SELECT timestamp_ms, data_type, v0, v1, v2, v3, v4, v5, v6, v7 \
+     FROM telemetry \
+     WHERE data_type = ? AND timestamp_ms >= ? AND timestamp_ms <= ? AND session_id = ? \
+     ORDER BY timestamp_ms ASC";
+
+fn range_row_stream(
+    data_type: String,
+    t0: i64,
+    t1: i64,
+    session_id: Option<i64>,
+    db: sqlx::SqlitePool,
+) -> impl futures::Stream<Item = TelemetryRow> + Send + 'static {
+    async_stream::stream! {
+        let query = if session_id.is_some() { RANGE_SELECT_BY_SESSION } else { RANGE_SELECT };
+        let mut bound = sqlx::query(query).bind(data_type).bind(t0).bind(t1);
+        if let Some(sid) = session_id {
+            bound = bound.bind(sid);
+        }
+        let mut rows = bound.fetch(&db);
+        while let Some(Ok(row)) = rows.next().await {
+            yield TelemetryRow {
+                timestamp_ms: row.get::<i64, _>("timestamp_ms"),
+                data_type: row.get::<String, _>("data_type"),
+                v0: row.get::<Option<f32>, _>("v0"),
+                v1: row.get::<Option<f32>, _>("v1"),
+                v2: row.get::<Option<f32>, _>("v2"),
+                v3: row.get::<Option<f32>, _>("v3"),
+                v4: row.get::<Option<f32>, _>("v4"),
+                v5: row.get::<Option<f32>, _>("v5"),
+                v6: row.get::<Option<f32>, _>("v6"),
+                v7: row.get::<Option<f32>, _>("v7"),
+            };
+        }
+    }
+}
+
+/// `/api/range?data_type=ACCEL_DATA&t0=...&t1=...[&session_id=...][&format=...]` — the literal
+/// `range(data_type, t0, t1)` query API: one `DataType`'s rows over an explicit window, for
+/// post-flight review tooling that wants more than `/api/history`'s "everything, last N
+/// minutes" shape. `session_id` narrows further to one `flight_session`.
+async fn get_range(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<RangeParams>,
+) -> impl IntoResponse {
+    let rows = range_row_stream(
+        params.data_type,
+        params.t0,
+        params.t1,
+        params.session_id,
+        state.db.clone(),
+    );
+    stream_rows(rows, &params.format)
+}
+
+#[derive(Deserialize)]
+struct OpenSessionRequest {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct OpenSessionResponse {
+    id: i64,
+}
+
+/// `POST /api/sessions` — opens a new `flight_session` and makes it the active one, so every row
+/// `handle_packet` queues afterward is tagged with its id until it's closed.
+async fn open_session(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<OpenSessionRequest>,
+) -> impl IntoResponse {
+    match flight_session::open(&state.db, &req.name, now_ms_i64()).await {
+        Ok(id) => {
+            *state.current_session.lock().unwrap() = Some(id);
+            Json(OpenSessionResponse { id }).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+/// `POST /api/sessions/:id/close` — closes `id`, clearing `current_session` if it was the active
+/// one (later rows go back to being untagged, same as before any session was ever opened).
+async fn close_session(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match flight_session::close(&state.db, id, now_ms_i64()).await {
+        Ok(()) => {
+            let mut current = state.current_session.lock().unwrap();
+            if *current == Some(id) {
+                *current = None;
+            }
+            StatusCode::OK.into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
 
-    Json(alerts)
+/// `GET /api/sessions` — every `flight_session` ever opened, most recent first, so the frontend
+/// can offer "close this flight" / "query this flight's range" without tracking ids itself.
+async fn list_sessions(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    match flight_session::list(&state.db).await {
+        Ok(sessions) => Json(sessions).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
 }
 
 /// Helper: current timestamp in ms (i64) for warnings/errors/etc.
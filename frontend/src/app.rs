@@ -1,8 +1,7 @@
 use dioxus::prelude::*;
 use dioxus_router::{Routable, Router};
-
-#[cfg(not(target_arch = "wasm32"))]
 use dioxus_router::use_navigator;
+use serde::{Deserialize, Serialize};
 
 // --- your existing global css ---
 const GLOBAL_CSS: &str = r#"
@@ -29,13 +28,51 @@ const _BASE_URL_KEY: &str = "gs26_base_url";
 // NEW: show connect screen once on native targets
 const _CONNECT_SHOWN_KEY: &str = "gs26_connect_shown";
 
+// A JSON-encoded `Vec<ConnectionProfile>` — replaces `_BASE_URL_KEY` as the source of truth for
+// which backend(s) the operator connects to; kept alongside it rather than deleting it outright
+// since an older build's single saved URL is otherwise just lost.
+const _PROFILES_KEY: &str = "gs26_profiles";
+
+/// One saved backend the operator can connect to — the launch-pad Pi, a bench simulator, a
+/// field gateway, etc. `last_connected_ms` drives the most-recently-used ordering `Connect`
+/// renders and which profile `Root` auto-connects to.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub name: String,
+    pub url: String,
+    pub last_connected_ms: i64,
+}
+
+fn now_ms() -> i64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now() as i64
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or(0)
+    }
+}
+
+// The dashboard is a single route (not one route per tab) so switching tabs or panning
+// the map re-renders `TelemetryDashboard` with new props instead of unmounting/remounting
+// it — that would drop the live WebSocket connection and telemetry buffer on every click.
+// The active tab and the map's camera still live entirely in the URL query string, so a
+// refresh or a shared link (e.g. `#/dashboard?tab=map&lat=31.0&lon=-99.0&zoom=7`) reopens
+// on the exact same tab/pan/zoom instead of the compile-time defaults.
 #[derive(Clone, Routable, PartialEq)]
 pub enum Route {
     #[route("/")]
     Root {},
 
-    #[route("/dashboard")]
-    Dashboard {},
+    #[route("/dashboard?:tab&:lat&:lon&:zoom")]
+    Dashboard {
+        tab: Option<String>,
+        lat: Option<f64>,
+        lon: Option<f64>,
+        zoom: Option<f64>,
+    },
 
     // native only
     #[cfg(not(target_arch = "wasm32"))]
@@ -50,7 +87,7 @@ pub enum Route {
 #[cfg(target_arch = "wasm32")]
 mod persist {
     #[allow(unused_imports)]
-    use super::{_BASE_URL_KEY, _CONNECT_SHOWN_KEY};
+    use super::{ConnectionProfile, _BASE_URL_KEY, _CONNECT_SHOWN_KEY, _PROFILES_KEY};
 
     fn _read_key(key: &str) -> Option<String> {
         use web_sys::window;
@@ -85,11 +122,23 @@ mod persist {
     pub fn _write_connect_shown(v: bool) {
         _write_key(_CONNECT_SHOWN_KEY, if v { "true" } else { "false" });
     }
+
+    pub fn _read_profiles() -> Vec<ConnectionProfile> {
+        _read_key(_PROFILES_KEY)
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn _write_profiles(profiles: &[ConnectionProfile]) {
+        if let Ok(s) = serde_json::to_string(profiles) {
+            _write_key(_PROFILES_KEY, &s);
+        }
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 mod persist {
-    use super::{_BASE_URL_KEY, _CONNECT_SHOWN_KEY};
+    use super::{ConnectionProfile, _BASE_URL_KEY, _CONNECT_SHOWN_KEY, _PROFILES_KEY};
     use std::io;
 
     fn storage_dir() -> std::path::PathBuf {
@@ -114,11 +163,11 @@ mod persist {
         std::fs::write(path_for(key), v.as_bytes())
     }
 
-    pub fn read_base_url() -> Option<String> {
+    pub fn _read_base_url() -> Option<String> {
         read_key(_BASE_URL_KEY).filter(|s| !s.trim().is_empty())
     }
 
-    pub fn write_base_url(v: &str) -> Result<(), io::Error> {
+    pub fn _write_base_url(v: &str) -> Result<(), io::Error> {
         write_key(_BASE_URL_KEY, v)
     }
 
@@ -131,6 +180,18 @@ mod persist {
     pub fn write_connect_shown(v: bool) -> Result<(), io::Error> {
         write_key(_CONNECT_SHOWN_KEY, if v { "true" } else { "false" })
     }
+
+    pub fn read_profiles() -> Vec<ConnectionProfile> {
+        read_key(_PROFILES_KEY)
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn write_profiles(profiles: &[ConnectionProfile]) -> Result<(), io::Error> {
+        let s = serde_json::to_string(profiles)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        write_key(_PROFILES_KEY, &s)
+    }
 }
 
 // -------------------------
@@ -161,17 +222,36 @@ pub fn App() -> Element {
     }
 }
 
+/// `profiles`, newest-`last_connected_ms`-first — the order `Connect` renders them in and the
+/// order `Root` picks its auto-connect target from.
+fn sorted_profiles_mru(mut profiles: Vec<ConnectionProfile>) -> Vec<ConnectionProfile> {
+    profiles.sort_by(|a, b| b.last_connected_ms.cmp(&a.last_connected_ms));
+    profiles
+}
+
+fn default_dashboard_route() -> Route {
+    Route::Dashboard {
+        tab: None,
+        lat: None,
+        lon: None,
+        zoom: None,
+    }
+}
+
 #[component]
 pub fn Root() -> Element {
-    // Web: keep URL unchanged, just render dashboard (same-origin)
+    // Web: keep the URL unchanged, just render the dashboard at its defaults (same-origin)
     #[cfg(target_arch = "wasm32")]
     {
-        return rsx! { Dashboard {} };
+        return rsx! {
+            Dashboard { tab: None, lat: None, lon: None, zoom: None }
+        };
     }
 
     // Native:
     // - If connect has never been shown: go to connect (once)
-    // - Else: connect only if base URL missing
+    // - Else: auto-connect to the most-recently-used saved profile, falling back to connect
+    //   only once the profile list is empty
     #[cfg(not(target_arch = "wasm32"))]
     {
         let nav = use_navigator();
@@ -183,11 +263,14 @@ pub fn Root() -> Element {
                 return;
             }
 
-            let u = persist::read_base_url().unwrap_or_default();
-            if u.trim().is_empty() {
-                let _ = nav.replace(Route::Connect {});
-            } else {
-                let _ = nav.replace(Route::Dashboard {});
+            match sorted_profiles_mru(persist::read_profiles()).into_iter().next() {
+                Some(profile) => {
+                    crate::telemetry_dashboard::UrlConfig::set_base_url(profile.url);
+                    let _ = nav.replace(default_dashboard_route());
+                }
+                None => {
+                    let _ = nav.replace(Route::Connect {});
+                }
             }
         });
 
@@ -195,50 +278,170 @@ pub fn Root() -> Element {
     }
 }
 
+/// Renders `ts` (ms since epoch, 0 meaning "never") as a short local timestamp for the
+/// profile list — matches `connection_status_tab`'s heuristic rendering, but this screen only
+/// ever sees native timestamps so it skips the wasm32 branch.
+fn format_last_connected(ts: i64) -> String {
+    if ts <= 0 {
+        return "never connected".to_string();
+    }
+    use std::time::{Duration, UNIX_EPOCH};
+    let t = UNIX_EPOCH + Duration::from_millis(ts as u64);
+    let dt: chrono::DateTime<chrono::Local> = t.into();
+    dt.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[component]
 pub fn Connect() -> Element {
     let nav = use_navigator();
 
-    // Initial value from native persistence (file)
-    let initial = persist::read_base_url()
-        .filter(|s| !s.trim().is_empty())
-        .unwrap_or_else(|| "http://localhost:3000".to_string());
-
-    // Editable field
-    let mut url_edit = use_signal(|| initial);
+    let mut profiles = use_signal(|| sorted_profiles_mru(persist::read_profiles()));
+    let mut name_edit = use_signal(String::new);
+    let mut url_edit = use_signal(|| "http://localhost:3000".to_string());
+    // `Some(name)` while editing an existing profile (identified by its pre-edit name), so
+    // "Save" replaces that entry instead of adding a new one.
+    let mut editing = use_signal(|| Option::<String>::None);
+
+    let mut persist_profiles = move |next: Vec<ConnectionProfile>| {
+        let next = sorted_profiles_mru(next);
+        let _ = persist::write_profiles(&next);
+        profiles.set(next);
+    };
+
+    let mut connect_to = move |url: String| {
+        let _ = persist::write_connect_shown(true);
+        crate::telemetry_dashboard::UrlConfig::set_base_url(url);
+        let _ = nav.replace(default_dashboard_route());
+    };
 
     rsx! {
         div {
-            style: "height:100vh; display:flex; align-items:center; justify-content:center; background:#020617; color:#e5e7eb; font-family:system-ui;",
+            style: "min-height:100vh; display:flex; align-items:center; justify-content:center; background:#020617; color:#e5e7eb; font-family:system-ui; padding:24px 0;",
             div {
                 style: "width:min(560px, 92vw); padding:24px; border:1px solid #334155; border-radius:16px; background:#0b1220; box-shadow:0 12px 30px rgba(0,0,0,0.5);",
                 h1 { style: "margin:0 0 12px 0; font-size:20px;", "GroundStation 26" }
                 p { style: "margin:0 0 16px 0; color:#94a3b8;",
-                    "Enter the backend URL (including http:// or https://). Example: ",
+                    "Pick a saved backend, or enter a new one (including http:// or https://). Example: ",
                     code { "http://10.0.0.42:3000" }
                 }
 
-                input {
-                    style: "width:100%; padding:12px; border-radius:12px; border:1px solid #334155; background:#020617; color:#e5e7eb; outline:none;",
-                    value: "{url_edit()}",
-                    oninput: move |evt| url_edit.set(evt.value()),
+                if profiles.read().is_empty() {
+                    p { style: "margin:0 0 16px 0; color:#64748b; font-style:italic;", "No saved backends yet." }
+                } else {
+                    div { style: "display:flex; flex-direction:column; gap:8px; margin-bottom:16px;",
+                        for profile in profiles.read().iter().cloned() {
+                            div {
+                                style: "display:flex; align-items:center; gap:8px; padding:10px 12px; border:1px solid #334155; border-radius:12px; background:#111827;",
+                                div { style: "flex:1; min-width:0;",
+                                    div { style: "font-weight:600;", "{profile.name}" }
+                                    div { style: "color:#94a3b8; font-size:13px;", "{profile.url}" }
+                                    div { style: "color:#64748b; font-size:12px;", "{format_last_connected(profile.last_connected_ms)}" }
+                                }
+                                button {
+                                    style: "padding:8px 12px; border-radius:10px; border:1px solid #334155; background:#0b1220; color:#e5e7eb; cursor:pointer;",
+                                    onclick: {
+                                        let profile = profile.clone();
+                                        let mut persist_profiles = persist_profiles.clone();
+                                        let mut connect_to = connect_to.clone();
+                                        move |_| {
+                                            let mut next = profiles.read().clone();
+                                            if let Some(p) = next.iter_mut().find(|p| p.name == profile.name) {
+                                                p.last_connected_ms = now_ms();
+                                            }
+                                            persist_profiles(next);
+                                            connect_to(profile.url.clone());
+                                        }
+                                    },
+                                    "Connect"
+                                }
+                                button {
+                                    style: "padding:8px 12px; border-radius:10px; border:1px solid #334155; background:#0b1220; color:#e5e7eb; cursor:pointer;",
+                                    onclick: {
+                                        let profile = profile.clone();
+                                        move |_| {
+                                            name_edit.set(profile.name.clone());
+                                            url_edit.set(profile.url.clone());
+                                            editing.set(Some(profile.name.clone()));
+                                        }
+                                    },
+                                    "Edit"
+                                }
+                                button {
+                                    style: "padding:8px 12px; border-radius:10px; border:1px solid #7f1d1d; background:#0b1220; color:#fca5a5; cursor:pointer;",
+                                    onclick: {
+                                        let profile = profile.clone();
+                                        let mut persist_profiles = persist_profiles.clone();
+                                        move |_| {
+                                            let next: Vec<_> = profiles
+                                                .read()
+                                                .iter()
+                                                .filter(|p| p.name != profile.name)
+                                                .cloned()
+                                                .collect();
+                                            persist_profiles(next);
+                                        }
+                                    },
+                                    "Delete"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { style: "display:flex; flex-direction:column; gap:8px;",
+                    input {
+                        style: "width:100%; padding:12px; border-radius:12px; border:1px solid #334155; background:#020617; color:#e5e7eb; outline:none;",
+                        placeholder: "Name (e.g. Launch Pad Pi)",
+                        value: "{name_edit()}",
+                        oninput: move |evt| name_edit.set(evt.value()),
+                    }
+                    input {
+                        style: "width:100%; padding:12px; border-radius:12px; border:1px solid #334155; background:#020617; color:#e5e7eb; outline:none;",
+                        placeholder: "http://10.0.0.42:3000",
+                        value: "{url_edit()}",
+                        oninput: move |evt| url_edit.set(evt.value()),
+                    }
                 }
 
                 div { style: "display:flex; gap:12px; margin-top:16px; justify-content:flex-end;",
+                    if editing.read().is_some() {
+                        button {
+                            style: "padding:10px 14px; border-radius:12px; border:1px solid #334155; background:#111827; color:#e5e7eb; cursor:pointer;",
+                            onclick: move |_| {
+                                editing.set(None);
+                                name_edit.set(String::new());
+                                url_edit.set("http://localhost:3000".to_string());
+                            },
+                            "Cancel"
+                        }
+                    }
                     button {
                         style: "padding:10px 14px; border-radius:12px; border:1px solid #334155; background:#111827; color:#e5e7eb; cursor:pointer;",
                         onclick: move |_| {
-                            let u = url_edit().trim().to_string();
-                            if !u.is_empty() {
-                                // Persist base url + mark connect as shown
-                                let _ = persist::write_base_url(&u);
-                                let _ = persist::write_connect_shown(true);
-
-                                let _ = nav.replace(Route::Dashboard {});
+                            let url = url_edit().trim().to_string();
+                            if url.is_empty() {
+                                return;
                             }
+                            let name = {
+                                let n = name_edit().trim().to_string();
+                                if n.is_empty() { url.clone() } else { n }
+                            };
+
+                            let mut next = profiles.read().clone();
+                            let replacing = editing.read().clone();
+                            if let Some(orig_name) = &replacing {
+                                next.retain(|p| &p.name != orig_name);
+                            }
+                            next.retain(|p| p.name != name);
+                            next.push(ConnectionProfile { name, url, last_connected_ms: 0 });
+                            persist_profiles(next);
+
+                            editing.set(None);
+                            name_edit.set(String::new());
+                            url_edit.set("http://localhost:3000".to_string());
                         },
-                        "Connect"
+                        if editing.read().is_some() { "Save" } else { "Add" }
                     }
                 }
             }
@@ -247,6 +450,11 @@ pub fn Connect() -> Element {
 }
 
 #[component]
-pub fn Dashboard() -> Element {
-    rsx! { crate::telemetry_dashboard::TelemetryDashboard {} }
+pub fn Dashboard(tab: Option<String>, lat: Option<f64>, lon: Option<f64>, zoom: Option<f64>) -> Element {
+    rsx! {
+        crate::telemetry_dashboard::TelemetryDashboard {
+            route_tab: tab,
+            route_map_view: (lat, lon, zoom),
+        }
+    }
 }
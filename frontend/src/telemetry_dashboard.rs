@@ -214,52 +214,37 @@ pub fn TelemetryDashboard() -> impl IntoView {
     // Latest row for summary cards
     let latest_row = Signal::derive(move || tab_rows.get().last().cloned());
 
-    // Build SVG data: 7 paths + extra + y-scale + span
+    // Independent-axis toggle: Shared keeps every channel on one min/max (flattens small-range
+    // channels plotted next to large-range ones); PerSeries auto-scales each to its own domain.
+    let (y_scale, set_y_scale) = signal(YScale::Shared);
+
+    // Build SVG data: one rendered series per SERIES entry + span + shared time gridlines
     let graph_data = Signal::derive(move || {
         let data = tab_rows.get();
-        build_polyline(&data, 1200.0, 360.0)
+        build_series(&data, 1200.0, 360.0, DEFAULT_MAX_POINTS, &SERIES, y_scale.get())
     });
 
-    // v_paths: each signal only clones its one String
+    // v_paths: each signal only clones its one series' path String
     let v_paths: [Signal<String>; 8] = std::array::from_fn(|i| {
         let graph_data = graph_data.clone();
         Signal::derive(move || {
-            graph_data.with(
-                |(p0, p1, p2, p3, p4, p5, p6, p7, _ymin, _ymax, _span)| match i {
-                    0 => p0.clone(),
-                    1 => p1.clone(),
-                    2 => p2.clone(),
-                    3 => p3.clone(),
-                    4 => p4.clone(),
-                    5 => p5.clone(),
-                    6 => p6.clone(),
-                    7 => p7.clone(),
-                    _ => unreachable!(),
-                },
-            )
+            graph_data.with(|(rendered, _span, _xticks)| {
+                rendered.get(i).map(|r| r.path.clone()).unwrap_or_default()
+            })
         })
     });
 
-    // Scaling values: no String clones, just copy f32s
-    let y_min = Signal::derive({
-        let graph_data = graph_data.clone();
-        move || graph_data.with(|(_, _, _, _, _, _, _, _, ymin, _, _)| *ymin)
-    });
-
-    let y_max = Signal::derive({
+    // Axis gridlines: y_ticks comes off the first rendered series (under `YScale::Shared` every
+    // series shares the same domain anyway; under `PerSeries` it's just the primary channel's
+    // axis), x_ticks clones the (x_px, label) pairs shared by every series.
+    let y_ticks = Signal::derive({
         let graph_data = graph_data.clone();
-        move || graph_data.with(|(_, _, _, _, _, _, _, _, _, ymax, _)| *ymax)
+        move || graph_data.with(|(rendered, _, _)| rendered.first().map(|r| r.y_ticks.clone()).unwrap_or_default())
     });
 
-    let span_min = Signal::derive({
+    let x_ticks = Signal::derive({
         let graph_data = graph_data.clone();
-        move || graph_data.with(|(_, _, _, _, _, _, _, _, _, _, span)| *span)
-    });
-
-    // y_mid still just uses the two f32 signals
-    let y_mid = Signal::derive(move || {
-        let (lo, hi) = (y_min.get(), y_max.get());
-        (lo + hi) * 0.5
+        move || graph_data.with(|(_, _, xticks)| xticks.clone())
     });
 
     let fmt_opt = |v: Option<f32>| {
@@ -301,6 +286,20 @@ pub fn TelemetryDashboard() -> impl IntoView {
                     {sensor_tab("FUEL_TANK_PRESSURE", "Fuel Press", Signal::from(active_tab), set_active_tab)}
                 </nav>
 
+                {/* Y-axis scaling toggle: Shared flattens mixed-unit channels, PerSeries
+                    auto-scales each to its own min/max so it fills the plot height alone. */}
+                <label style="display:flex; align-items:center; gap:0.4rem; color:#9ca3af; font-size:0.85rem;">
+                    <input
+                        type="checkbox"
+                        checked=move || y_scale.get() == YScale::PerSeries
+                        on:change=move |ev| {
+                            let per_series = event_target_checked(&ev);
+                            set_y_scale.set(if per_series { YScale::PerSeries } else { YScale::Shared });
+                        }
+                    />
+                    "Independent Y scale per channel"
+                </label>
+
                 {/* Summary cards */}
                 <Show
                     when=move || latest_row.get().is_some()
@@ -452,51 +451,44 @@ pub fn TelemetryDashboard() -> impl IntoView {
                         <line x1="60" y1="20"  x2="60"  y2="340" stroke="#4b5563" stroke-width="1"/>
                         <line x1="60" y1="340" x2="1180" y2="340" stroke="#4b5563" stroke-width="1"/>
 
-                        {/* Y-axis labels */}
-                        <text x="10" y="26"  fill="#9ca3af" font-size="10">
-                            {move || format!("{:.2}", y_max.get())}
-                        </text>
-                        <text x="10" y="184" fill="#9ca3af" font-size="10">
-                            {move || format!("{:.2}", y_mid.get())}
-                        </text>
-                        <text x="10" y="344" fill="#9ca3af" font-size="10">
-                            {move || format!("{:.2}", y_min.get())}
-                        </text>
-
-                        {/* X-axis labels: dynamic span, capped at 20 min */}
-                        <text x="70"   y="355" fill="#9ca3af" font-size="10">
-                            {move || {
-                                let span = span_min.get(); // minutes, may be < 20
-                                format!("-{:.1} min", span)
-                            }}
-                        </text>
-                        <text x="600"  y="355" fill="#9ca3af" font-size="10">
-                            {move || {
-                                let span = span_min.get() / 2.0;
-                                format!("-{:.1} min", span)
-                            }}
-                        </text>
-                        <text x="1120" y="355" fill="#9ca3af" font-size="10">
-                            "now"
-                        </text>
+                        {/* Y gridlines + value labels, on a "nice" step from build_series */}
+                        {move || {
+                            y_ticks
+                                .get()
+                                .into_iter()
+                                .map(|(_value, y_px, label)| {
+                                    view! {
+                                        <line x1="60" y1=y_px x2="1180" y2=y_px stroke="#1f2937" stroke-width="1"/>
+                                        <text x="10" y=y_px + 4.0 fill="#9ca3af" font-size="10">
+                                            {label}
+                                        </text>
+                                    }
+                                })
+                                .collect_view()
+                        }}
 
-                        {
-                        let colors = [
-                            "#f97316", // v0
-                            "#22d3ee", // v1
-                            "#a3e635", // v2
-                            "#a3e635", // v3
-                            "#a3e635", // v4
-                            "#a3e635", // v5
-                            "#a3e635", // v6
-                            "#a3e547", // v7
-                        ];
+                        {/* X gridlines + HH:MM:SS labels, on a fixed-count grid from build_series */}
+                        {move || {
+                            x_ticks
+                                .get()
+                                .into_iter()
+                                .map(|(x_px, label)| {
+                                    view! {
+                                        <line x1=x_px y1="20" x2=x_px y2="340" stroke="#1f2937" stroke-width="1"/>
+                                        <text x=x_px y="355" fill="#9ca3af" font-size="10">
+                                            {label}
+                                        </text>
+                                    }
+                                })
+                                .collect_view()
+                        }}
 
+                        {
                         v_paths
                             .iter()
                             .enumerate()
                             .map(|(i, path_sig)| {
-                                let color = colors[i];
+                                let color = SERIES[i].color;
                                 let sig = *path_sig; // deref & copy the Signal
 
                                 view! {
@@ -563,78 +555,72 @@ fn sensor_tab(
     }
 }
 
-/// Build three SVG path strings (v0, v1, v2) for a single graph,
-/// plus y-min, y-max, and span_minutes (0–20).
+/// Threshold passed to `build_series`'s per-series LTTB downsampling when a caller doesn't
+/// need a different one.
+const DEFAULT_MAX_POINTS: usize = 2000;
+
+/// One horizontal value gridline: the value it marks, its pixel Y, and its label.
+type YTick = (f32, f32, String);
+/// One vertical time gridline: its pixel X and its wall-clock label.
+type XTick = (f32, String);
+
+/// One channel to plot: `accessor` pulls its sample out of a row, `color` is the stroke color
+/// for the path `build_series` renders for it.
+#[derive(Clone, Copy)]
+struct Series {
+    color: &'static str,
+    accessor: fn(&TelemetryRow) -> Option<f32>,
+}
+
+/// How the Y axis is shared across the series passed to `build_series`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum YScale {
+    /// One `min_v`/`max_v` across every series — mixing units (e.g. temperature with pressure)
+    /// flattens whichever one has the smaller range.
+    Shared,
+    /// Each series is auto-scaled to its own `min_v`/`max_v`, so it fills the plot height on its
+    /// own regardless of what the other series are doing.
+    PerSeries,
+}
+
+/// The eight fixed `v0..v7` channels, in `TelemetryRow` order, with the same palette the old
+/// hardcoded `p0..p7` rendering used.
+const SERIES: [Series; 8] = [
+    Series { color: "#f97316", accessor: |r| r.v0 },
+    Series { color: "#22d3ee", accessor: |r| r.v1 },
+    Series { color: "#a3e635", accessor: |r| r.v2 },
+    Series { color: "#a3e635", accessor: |r| r.v3 },
+    Series { color: "#a3e635", accessor: |r| r.v4 },
+    Series { color: "#a3e635", accessor: |r| r.v5 },
+    Series { color: "#a3e635", accessor: |r| r.v6 },
+    Series { color: "#a3e547", accessor: |r| r.v7 },
+];
+
+/// One series' rendered path, its domain, and the gridlines for that domain.
+#[derive(Clone, PartialEq)]
+struct RenderedSeries {
+    color: &'static str,
+    path: String,
+    min_v: f32,
+    max_v: f32,
+    y_ticks: Vec<YTick>,
+}
+
+/// Build one SVG path per entry in `series`, downsampled independently with LTTB, plus the time
+/// gridlines shared by all of them and `span_minutes` (0–20) describing the plotted window.
 ///
 /// X is based on timestamp_ms over a *dynamic* window whose size is:
 ///   min(20 minutes, newest_ts - oldest_ts)
-fn build_polyline(
+fn build_series(
     rows: &[TelemetryRow],
     width: f32,
     height: f32,
-) -> (
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    f32,
-    f32,
-    f32,
-) {
-    if rows.is_empty() {
-        return (
-            String::new(),
-            String::new(),
-            String::new(),
-            String::new(),
-            String::new(),
-            String::new(),
-            String::new(),
-            String::new(),
-            0.0,
-            1.0,
-            0.0,
-        );
-    }
-
-    // Find min/max across all v0..v7
-    let mut min_v: Option<f32> = None;
-    let mut max_v: Option<f32> = None;
-
-    for r in rows {
-        for v in [r.v0, r.v1, r.v2, r.v3, r.v4, r.v5, r.v6, r.v7] {
-            if let Some(x) = v {
-                min_v = Some(min_v.map(|m| m.min(x)).unwrap_or(x));
-                max_v = Some(max_v.map(|m| m.max(x)).unwrap_or(x));
-            }
-        }
-    }
-
-    let (min_v, mut max_v) = match (min_v, max_v) {
-        (Some(a), Some(b)) => (a, b),
-        _ => {
-            return (
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                0.0,
-                1.0,
-                0.0,
-            );
-        }
-    };
-
-    if (max_v - min_v).abs() < 1e-6 {
-        max_v = min_v + 1.0;
+    max_points: usize,
+    series: &[Series],
+    y_scale: YScale,
+) -> (Vec<RenderedSeries>, f32, Vec<XTick>) {
+    if rows.is_empty() || series.is_empty() {
+        return (Vec::new(), 0.0, Vec::new());
     }
 
     // Time window: dynamic span up to 20 minutes
@@ -660,125 +646,190 @@ fn build_polyline(
     let plot_width = right - left;
     let plot_height = bottom - top;
 
-    let map_y = |v: f32| bottom - ((v - min_v) / (max_v - min_v)) * plot_height;
-
-    let mut p0 = String::new();
-    let mut p1 = String::new();
-    let mut p2 = String::new();
-    let mut p3 = String::new();
-    let mut p4 = String::new();
-    let mut p5 = String::new();
-    let mut p6 = String::new();
-    let mut p7 = String::new();
-
-    let mut started0 = false;
-    let mut started1 = false;
-    let mut started2 = false;
-    let mut started3 = false;
-    let mut started4 = false;
-    let mut started5 = false;
-    let mut started6 = false;
-    let mut started7 = false;
-
-    // Downsample: limit number of points
-    let n = rows.len();
-    let max_points = 2000; // tweak to taste
-    let stride = if n > max_points {
-        (n as f32 / max_points as f32).ceil() as usize
-    } else {
-        1
+    let shared_domain = match y_scale {
+        YScale::Shared => Some(domain_of(rows, series)),
+        YScale::PerSeries => None,
     };
 
-    for (idx, r) in rows.iter().enumerate() {
-        if idx % stride != 0 {
-            continue; // skip to thin data
-        }
-
-        // Clamp timestamp into [window_start, newest_ts]
-        let dt_ms = (r.timestamp_ms - window_start).clamp(0, effective_span_ms) as f32;
-        let t = dt_ms / denom_time; // 0.0 = left, 1.0 = now
-        let x = left + plot_width * t;
+    let x_ticks = time_ticks(window_start, effective_span_ms, left, plot_width);
 
-        if let Some(v) = r.v0 {
-            let y = map_y(v);
-            if !started0 {
-                p0.push_str(&format!("M {:.2} {:.2}", x, y));
-                started0 = true;
-            } else {
-                p0.push_str(&format!(" L {:.2} {:.2}", x, y));
+    let rendered = series
+        .iter()
+        .map(|s| {
+            let samples: Vec<(i64, f32)> =
+                rows.iter().filter_map(|r| (s.accessor)(r).map(|v| (r.timestamp_ms, v))).collect();
+            let (min_v, max_v) = shared_domain.unwrap_or_else(|| domain_of_samples(&samples));
+            let map_y = |v: f32| bottom - ((v - min_v) / (max_v - min_v)) * plot_height;
+
+            // Downsample with LTTB rather than a shared row stride: each series has its own
+            // `Option` gaps, and a stride keyed off the row count thins sparsely-reporting
+            // series far more aggressively than dense ones, while still silently stepping over
+            // short spikes (a pressure or thrust transient) a denser series might have
+            // preserved. Building one path per series off its own present samples fixes both.
+            let thinned = lttb(&samples, max_points);
+            let mut path = String::new();
+            for (i, (ts, v)) in thinned.iter().enumerate() {
+                let dt_ms = (ts - window_start).clamp(0, effective_span_ms) as f32;
+                let t = dt_ms / denom_time; // 0.0 = left, 1.0 = now
+                let x = left + plot_width * t;
+                let y = map_y(*v);
+                if i == 0 {
+                    path.push_str(&format!("M {:.2} {:.2}", x, y));
+                } else {
+                    path.push_str(&format!(" L {:.2} {:.2}", x, y));
+                }
             }
-        }
 
-        if let Some(v) = r.v1 {
-            let y = map_y(v);
-            if !started1 {
-                p1.push_str(&format!("M {:.2} {:.2}", x, y));
-                started1 = true;
-            } else {
-                p1.push_str(&format!(" L {:.2} {:.2}", x, y));
-            }
-        }
+            RenderedSeries { color: s.color, path, min_v, max_v, y_ticks: value_ticks(min_v, max_v, map_y) }
+        })
+        .collect();
 
-        if let Some(v) = r.v2 {
-            let y = map_y(v);
-            if !started2 {
-                p2.push_str(&format!("M {:.2} {:.2}", x, y));
-                started2 = true;
-            } else {
-                p2.push_str(&format!(" L {:.2} {:.2}", x, y));
-            }
-        }
+    (rendered, span_minutes, x_ticks)
+}
 
-        if let Some(v) = r.v3 {
-            let y = map_y(v);
-            if !started3 {
-                p3.push_str(&format!("M {:.2} {:.2}", x, y));
-                started3 = true;
-            } else {
-                p3.push_str(&format!(" L {:.2} {:.2}", x, y));
+/// Min/max across every `series`' samples in `rows`, normalized so a flat or empty domain still
+/// yields a sane `(min_v, max_v)` with `max_v > min_v`.
+fn domain_of(rows: &[TelemetryRow], series: &[Series]) -> (f32, f32) {
+    let mut min_v: Option<f32> = None;
+    let mut max_v: Option<f32> = None;
+    for r in rows {
+        for s in series {
+            if let Some(v) = (s.accessor)(r) {
+                min_v = Some(min_v.map(|m| m.min(v)).unwrap_or(v));
+                max_v = Some(max_v.map(|m| m.max(v)).unwrap_or(v));
             }
         }
+    }
+    normalize_domain(min_v, max_v)
+}
 
-        if let Some(v) = r.v4 {
-            let y = map_y(v);
-            if !started4 {
-                p4.push_str(&format!("M {:.2} {:.2}", x, y));
-                started4 = true;
-            } else {
-                p4.push_str(&format!(" L {:.2} {:.2}", x, y));
-            }
-        }
+/// Same as `domain_of`, but over one series' own `(timestamp_ms, value)` samples — used for
+/// `YScale::PerSeries`.
+fn domain_of_samples(samples: &[(i64, f32)]) -> (f32, f32) {
+    let mut min_v: Option<f32> = None;
+    let mut max_v: Option<f32> = None;
+    for &(_, v) in samples {
+        min_v = Some(min_v.map(|m| m.min(v)).unwrap_or(v));
+        max_v = Some(max_v.map(|m| m.max(v)).unwrap_or(v));
+    }
+    normalize_domain(min_v, max_v)
+}
 
-        if let Some(v) = r.v5 {
-            let y = map_y(v);
-            if !started5 {
-                p5.push_str(&format!("M {:.2} {:.2}", x, y));
-                started5 = true;
-            } else {
-                p5.push_str(&format!(" L {:.2} {:.2}", x, y));
-            }
-        }
+fn normalize_domain(min_v: Option<f32>, max_v: Option<f32>) -> (f32, f32) {
+    let (min_v, max_v) = match (min_v, max_v) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return (0.0, 1.0),
+    };
+    if (max_v - min_v).abs() < 1e-6 {
+        (min_v, min_v + 1.0)
+    } else {
+        (min_v, max_v)
+    }
+}
 
-        if let Some(v) = r.v6 {
-            let y = map_y(v);
-            if !started6 {
-                p6.push_str(&format!("M {:.2} {:.2}", x, y));
-                started6 = true;
-            } else {
-                p6.push_str(&format!(" L {:.2} {:.2}", x, y));
-            }
-        }
+/// Round a raw tick step up to a "nice" 1/2/5×10ⁿ value so gridlines land on round numbers
+/// instead of whatever `(max_v - min_v) / N` happens to produce.
+fn nice_step(raw: f32) -> f32 {
+    if raw <= 0.0 || !raw.is_finite() {
+        return 1.0;
+    }
+    let exp = raw.log10().floor();
+    let base = 10f32.powf(exp);
+    let frac = raw / base;
+    let nice = if frac <= 1.0 {
+        1.0
+    } else if frac <= 2.0 {
+        2.0
+    } else if frac <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * base
+}
 
-        if let Some(v) = r.v7 {
-            let y = map_y(v);
-            if !started7 {
-                p7.push_str(&format!("M {:.2} {:.2}", x, y));
-                started7 = true;
-            } else {
-                p7.push_str(&format!(" L {:.2} {:.2}", x, y));
+/// Horizontal gridlines over `[min_v, max_v]` on a "nice" step, each with its pixel Y (via
+/// `map_y`) and a numeric label.
+fn value_ticks(min_v: f32, max_v: f32, map_y: impl Fn(f32) -> f32) -> Vec<YTick> {
+    let step = nice_step((max_v - min_v) / 5.0);
+    let first = (min_v / step).ceil() * step;
+
+    let mut ticks = Vec::new();
+    let mut v = first;
+    while v <= max_v + step * 1e-3 {
+        ticks.push((v, map_y(v), format!("{v:.2}")));
+        v += step;
+    }
+    ticks
+}
+
+/// Vertical time gridlines spanning `[window_start, window_start + effective_span_ms]`, each
+/// with its pixel X and an `HH:MM:SS` local-clock label.
+fn time_ticks(window_start: i64, effective_span_ms: i64, left: f32, plot_width: f32) -> Vec<XTick> {
+    const TICK_COUNT: usize = 6;
+    (0..=TICK_COUNT)
+        .map(|i| {
+            let t = i as f32 / TICK_COUNT as f32;
+            let x = left + plot_width * t;
+            let ts_ms = window_start + (effective_span_ms as f32 * t) as i64;
+            (x, format_clock(ts_ms))
+        })
+        .collect()
+}
+
+/// `ts_ms` (ms since epoch) as a local `HH:MM:SS` clock label for an X-axis tick.
+fn format_clock(ts_ms: i64) -> String {
+    use std::time::{Duration, UNIX_EPOCH};
+    let t = UNIX_EPOCH + Duration::from_millis(ts_ms.max(0) as u64);
+    let dt: chrono::DateTime<chrono::Local> = t.into();
+    dt.format("%H:%M:%S").to_string()
+}
+
+/// Largest-Triangle-Three-Buckets: always keeps the first and last sample, then for each of
+/// `threshold - 2` buckets of equal sample count picks whichever point forms the largest-area
+/// triangle with the previously selected point and the average x/y of the *next* bucket —
+/// preserving spikes a plain stride would step right over. `points` must be sorted by x.
+fn lttb(points: &[(i64, f32)], threshold: usize) -> Vec<(i64, f32)> {
+    let n = points.len();
+    if threshold >= n || threshold < 3 {
+        return points.to_vec();
+    }
+
+    let mut sampled = Vec::with_capacity(threshold);
+    sampled.push(points[0]);
+
+    let every = (n - 2) as f64 / (threshold - 2) as f64;
+    let mut a = 0usize;
+
+    for i in 0..(threshold - 2) {
+        let avg_range_start = ((i + 1) as f64 * every) as usize + 1;
+        let avg_range_end = (((i + 2) as f64 * every) as usize + 1).min(n);
+        let avg_range_end = avg_range_end.max(avg_range_start + 1);
+
+        let avg_slice = &points[avg_range_start..avg_range_end];
+        let avg_x = avg_slice.iter().map(|(t, _)| *t as f64).sum::<f64>() / avg_slice.len() as f64;
+        let avg_y = avg_slice.iter().map(|(_, v)| *v as f64).sum::<f64>() / avg_slice.len() as f64;
+
+        let range_start = (i as f64 * every) as usize + 1;
+        let range_end = (((i + 1) as f64 * every) as usize + 1).min(n - 1);
+
+        let (a_x, a_y) = (points[a].0 as f64, points[a].1 as f64);
+
+        let mut max_area = -1.0;
+        let mut max_area_index = range_start;
+        for (offset, (t, v)) in points[range_start..range_end].iter().enumerate() {
+            let range_offs = range_start + offset;
+            let area = ((a_x - avg_x) * (*v as f64 - a_y) - (a_x - *t as f64) * (avg_y - a_y)).abs() * 0.5;
+            if area > max_area {
+                max_area = area;
+                max_area_index = range_offs;
             }
         }
+
+        sampled.push(points[max_area_index]);
+        a = max_area_index;
     }
 
-    (p0, p1, p2, p3, p4, p5, p6, p7, min_v, max_v, span_minutes)
+    sampled.push(points[n - 1]);
+    sampled
 }
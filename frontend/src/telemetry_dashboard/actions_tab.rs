@@ -3,7 +3,31 @@
 use dioxus::prelude::*;
 
 use super::layout::ActionsTabLayout;
-use super::{ActionPolicyMsg, BlinkMode};
+use super::{ActionPolicyMsg, BlinkMode, CmdState};
+
+/// How long a "requires confirmation" control stays armed after its first click before the
+/// operator has to click it again from scratch — long enough to make a deliberate second click,
+/// short enough that a stray later click on the same button doesn't fire it unintentionally.
+const CONFIRM_TIMEOUT_MS: i64 = 3_000;
+
+fn cmd_status_color(state: CmdState) -> &'static str {
+    match state {
+        CmdState::Sent => "#facc15",
+        CmdState::Acked => "#86efac",
+        CmdState::Failed => "#fecaca",
+        CmdState::Refused => "#fca5a5",
+    }
+}
+
+fn cmd_status_text(cmd: &str, state: CmdState, retries: u32) -> String {
+    match state {
+        CmdState::Sent if retries > 0 => format!("{cmd}: sending… (retry {retries})"),
+        CmdState::Sent => format!("{cmd}: sending…"),
+        CmdState::Acked => format!("{cmd}: acked"),
+        CmdState::Failed => format!("{cmd}: timed out"),
+        CmdState::Refused => format!("{cmd}: not permitted"),
+    }
+}
 
 fn btn_style(
     border: &str,
@@ -50,6 +74,9 @@ fn btn_style(
 
 #[component]
 pub fn ActionsTab(layout: ActionsTabLayout, action_policy: Signal<ActionPolicyMsg>) -> Element {
+    // Command currently awaiting its confirming second click, and the time that arming expires.
+    let mut armed: Signal<Option<(String, i64)>> = use_signal(|| None);
+
     rsx! {
         div {
             style: "
@@ -60,8 +87,9 @@ pub fn ActionsTab(layout: ActionsTabLayout, action_policy: Signal<ActionPolicyMs
             ",
             h2 { style: "margin:0 0 8px 0; color:#e5e7eb;", "Actions" }
             p  { style: "margin:0 0 12px 0; color:#9ca3af; font-size:0.9rem;",
-                "All available actions are available all the time, use with caution as improper use \
-                can and will damage the system."
+                "Controls enable as the current flight state and sequence make them safe; a dimmed \
+                button is disabled, a pulsing one is recommended right now. Destructive commands \
+                need a second click within a few seconds to confirm."
             }
 
             div {
@@ -85,19 +113,50 @@ pub fn ActionsTab(layout: ActionsTabLayout, action_policy: Signal<ActionPolicyMs
                             .unwrap_or(action.cmd == "Abort");
                         let blink = control.as_ref().map(|c| c.blink.clone()).unwrap_or(BlinkMode::None);
                         let actuated = control.as_ref().and_then(|c| c.actuated);
+                        let needs_confirm = control
+                            .as_ref()
+                            .map(|c| c.requires_confirmation)
+                            .unwrap_or_else(|| super::operator::requires_confirmation(&action.cmd));
+                        let is_armed = needs_confirm
+                            && armed.read().as_ref().is_some_and(|(cmd, expires_at)| {
+                                *cmd == action.cmd && super::now_ms() < *expires_at
+                            });
+                        let label = if is_armed {
+                            format!("Confirm {}?", action.label)
+                        } else {
+                            action.label.clone()
+                        };
+                        let status = super::cmd_button_status(&action.cmd);
                         rsx! {
-                    button {
-                        style: "{btn_style(&action.border, &action.bg, &action.fg, enabled, blink, actuated)}",
-                        disabled: !enabled,
-                        onclick: {
-                            let cmd = action.cmd.clone();
-                            move |_| {
-                                if enabled {
-                                    crate::telemetry_dashboard::send_cmd(&cmd)
+                    div { style: "display:flex; flex-direction:column; gap:4px;",
+                        button {
+                            style: "{btn_style(&action.border, &action.bg, &action.fg, enabled, blink, actuated)}",
+                            disabled: !enabled,
+                            onclick: {
+                                let cmd = action.cmd.clone();
+                                move |_| {
+                                    if !enabled {
+                                        return;
+                                    }
+                                    if !needs_confirm {
+                                        crate::telemetry_dashboard::send_cmd(&cmd);
+                                        return;
+                                    }
+                                    if is_armed {
+                                        armed.set(None);
+                                        crate::telemetry_dashboard::send_cmd(&cmd);
+                                    } else {
+                                        armed.set(Some((cmd.clone(), super::now_ms() + CONFIRM_TIMEOUT_MS)));
+                                    }
                                 }
+                            },
+                            "{label}"
+                        }
+                        if let Some((state, retries)) = status {
+                            div { style: "font-size:0.72rem; color:{cmd_status_color(state)};",
+                                "{cmd_status_text(action.cmd.as_str(), state, retries)}"
                             }
-                        },
-                        "{action.label}"
+                        }
                     }
                         }
                     }
@@ -0,0 +1,159 @@
+// frontend/src/telemetry_dashboard/annotations.rs
+//
+// Shared operator markers on the telemetry timeline ("ignition", "anomaly at T+12s"), merged
+// across every connected dashboard with a WOOT-style sequence CRDT — each marker gets a unique
+// `AnnotationId` plus the left/right neighbor ids it was inserted next to, and a delete just
+// tombstones the id rather than removing it, so a concurrent insert that names it as a neighbor
+// still has something to land next to. Applying the same ops converges to the same visible
+// sequence on every client as long as each op arrives after the inserts it names (a `Delete`
+// for an id not yet inserted is dropped), with no central lock to insert under.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use dioxus_signals::{GlobalSignal, Signal, WritableExt};
+use groundstation_shared::{AnnotationId, AnnotationOp};
+
+struct Node {
+    left: Option<AnnotationId>,
+    right: Option<AnnotationId>,
+    timestamp_ms: i64,
+    text: String,
+    deleted: bool,
+}
+
+/// One merged marker, as `data_tab` needs it to render an overlay.
+#[derive(Clone)]
+pub(crate) struct Annotation {
+    pub id: AnnotationId,
+    pub timestamp_ms: i64,
+    pub text: String,
+}
+
+#[derive(Default)]
+pub(crate) struct AnnotationsCrdt {
+    nodes: HashMap<AnnotationId, Node>,
+    // WOOT sequence order, including tombstones — deletes keep their slot so a later insert
+    // that names a deleted id as a neighbor still resolves to a real position.
+    order: Vec<AnnotationId>,
+}
+
+impl AnnotationsCrdt {
+    /// Apply an op this client authored or received over the wire. Idempotent: re-applying an
+    /// `Insert` for an id already present, or a `Delete` for one already tombstoned, is a no-op,
+    /// so replaying the same op twice (e.g. after a reconnect) never duplicates a marker.
+    pub fn apply(&mut self, op: AnnotationOp) {
+        match op {
+            AnnotationOp::Insert { id, left, right, timestamp_ms, text } => {
+                self.integrate(id, left, right, timestamp_ms, text);
+            }
+            AnnotationOp::Delete { id } => {
+                if let Some(node) = self.nodes.get_mut(&id) {
+                    node.deleted = true;
+                }
+            }
+        }
+    }
+
+    fn integrate(&mut self, id: AnnotationId, left: Option<AnnotationId>, right: Option<AnnotationId>, timestamp_ms: i64, text: String) {
+        if self.nodes.contains_key(&id) {
+            return;
+        }
+        let left_pos = left.and_then(|l| self.order.iter().position(|x| *x == l)).map_or(0, |p| p + 1);
+        let right_pos = right.and_then(|r| self.order.iter().position(|x| *x == r)).unwrap_or(self.order.len());
+        let right_pos = right_pos.max(left_pos);
+
+        // Two clients can concurrently insert between the same left/right pair — break the tie
+        // deterministically by `AnnotationId` order so every replica lands on the same sequence
+        // without needing to talk to each other first.
+        let mut at = left_pos;
+        while at < right_pos && self.order[at] < id {
+            at += 1;
+        }
+
+        self.order.insert(at, id);
+        self.nodes.insert(id, Node { left, right, timestamp_ms, text, deleted: false });
+    }
+
+    /// Non-tombstoned markers, in sequence order.
+    pub fn visible(&self) -> Vec<Annotation> {
+        self.order
+            .iter()
+            .filter_map(|id| {
+                let node = self.nodes.get(id)?;
+                (!node.deleted).then(|| Annotation { id: *id, timestamp_ms: node.timestamp_ms, text: node.text.clone() })
+            })
+            .collect()
+    }
+
+    /// Author a new marker at `timestamp_ms`, placing it among the currently-visible markers in
+    /// time order, and apply it locally. Returns the op so the caller can also broadcast it.
+    fn insert_local(&mut self, id: AnnotationId, timestamp_ms: i64, text: String) -> AnnotationOp {
+        let visible_idx: Vec<usize> = self
+            .order
+            .iter()
+            .enumerate()
+            .filter(|(_, id)| !self.nodes[id].deleted)
+            .map(|(i, _)| i)
+            .collect();
+
+        let after = visible_idx.iter().rev().find(|&&i| self.nodes[&self.order[i]].timestamp_ms <= timestamp_ms).copied();
+        let left = after.map(|i| self.order[i]);
+        let right = match after {
+            Some(i) => visible_idx.iter().find(|&&j| j > i).map(|&j| self.order[j]),
+            None => visible_idx.first().map(|&j| self.order[j]),
+        };
+
+        let op = AnnotationOp::Insert { id, left, right, timestamp_ms, text };
+        self.apply(op.clone());
+        op
+    }
+
+    fn delete_local(&mut self, id: AnnotationId) -> AnnotationOp {
+        let op = AnnotationOp::Delete { id };
+        self.apply(op.clone());
+        op
+    }
+}
+
+pub(crate) static ANNOTATIONS: GlobalSignal<AnnotationsCrdt> = Signal::global(AnnotationsCrdt::default);
+
+/// Every annotation this client mints needs a globally-unique `AnnotationId`: a per-session
+/// `client_id` (random on wasm32, the clock time in nanoseconds elsewhere; stable for the
+/// process's lifetime) paired with a counter that only
+/// ever increases, mirroring the `now_ms`/`CMD_SEQ` split used for command delivery elsewhere
+/// in this module — the id just needs to never repeat, not to mean anything on its own.
+static CLIENT_ID: OnceLock<u64> = OnceLock::new();
+
+fn client_id() -> u64 {
+    *CLIENT_ID.get_or_init(|| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            (js_sys::Math::random() * u64::MAX as f64) as u64
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use std::time::{SystemTime, UNIX_EPOCH};
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(1)
+        }
+    })
+}
+
+static NEXT_COUNTER: GlobalSignal<u64> = Signal::global(|| 0);
+
+fn next_annotation_id() -> AnnotationId {
+    let mut counter = NEXT_COUNTER.write();
+    *counter += 1;
+    AnnotationId { client_id: client_id(), counter: *counter }
+}
+
+/// Author a marker, apply it to the local CRDT immediately (so it shows up without waiting on a
+/// round trip), and hand back the op for the caller to send over the wire.
+pub(crate) fn add_annotation(timestamp_ms: i64, text: String) -> AnnotationOp {
+    let id = next_annotation_id();
+    ANNOTATIONS.write().insert_local(id, timestamp_ms, text)
+}
+
+pub(crate) fn remove_annotation(id: AnnotationId) -> AnnotationOp {
+    ANNOTATIONS.write().delete_local(id)
+}
@@ -0,0 +1,146 @@
+// frontend/src/telemetry_dashboard/axis.rs
+//
+// Shared "nice tick" axis math, factored out of the Connection Status tab's latency chart
+// (`connection_status_tab.rs`'s `nice_step`/`nice_ticks`, from the chunk that first needed a
+// readable Y axis) so `chart.rs`'s `LineChart` and `data_tab.rs`'s SVG renderer for
+// `data_chart.rs` can draw the same kind of gridlines/labels instead of each re-deriving the
+// algorithm, or shipping with none at all.
+
+/// Round `raw_step` up to the nearest "nice" 1/2/5 x 10^k multiple — the classic axis-tick
+/// rounding rule, so a step reads as "0.5" or "20" instead of "0.4173".
+pub fn nice_step(raw_step: f64) -> f64 {
+    if raw_step <= 0.0 || !raw_step.is_finite() {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(raw_step.log10().floor());
+    let residual = raw_step / magnitude;
+    let nice_residual = if residual <= 1.0 {
+        1.0
+    } else if residual <= 2.0 {
+        2.0
+    } else if residual <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice_residual * magnitude
+}
+
+/// Evenly spaced "nice" axis values covering `[min, max]`, aiming for roughly `target_count`
+/// ticks — the step is rounded via [`nice_step`] and the covered range snapped outward to
+/// multiples of it, so the axis doesn't jitter between frames as the data's raw min/max wobbles.
+pub fn nice_ticks(min: f64, max: f64, target_count: usize) -> Vec<f64> {
+    if !(min.is_finite() && max.is_finite()) || max <= min {
+        return vec![min];
+    }
+    let step = nice_step((max - min) / target_count.max(1) as f64);
+    let start = (min / step).floor() * step;
+    let end = (max / step).ceil() * step;
+
+    let mut ticks = Vec::new();
+    let mut v = start;
+    while v <= end + step * 0.5 {
+        ticks.push(v);
+        v += step;
+    }
+    ticks
+}
+
+/// Human-friendly step sizes for a *time* axis, in milliseconds — unlike `nice_step`'s 1/2/5x10^k
+/// ladder, time ticks need to land on boundaries someone would actually read off a clock.
+const NICE_TIME_STEPS_MS: &[i64] = &[
+    1_000, 2_000, 5_000, 10_000, 15_000, 30_000, // seconds
+    60_000, 120_000, 300_000, 600_000, 900_000, 1_800_000, // minutes
+    3_600_000, 7_200_000, 21_600_000, // hours
+];
+
+/// Like [`nice_ticks`], but for an absolute-time axis: picks the smallest step from
+/// `NICE_TIME_STEPS_MS` that spans `[t_min, t_max]` in no more than `target_count` steps,
+/// then snaps tick positions to multiples of that step so they fall on whole seconds/minutes
+/// instead of wherever the window happens to start.
+pub fn nice_time_ticks(t_min: i64, t_max: i64, target_count: usize) -> Vec<i64> {
+    if t_max <= t_min || target_count == 0 {
+        return vec![t_min];
+    }
+    let span = (t_max - t_min) as f64;
+    let step = NICE_TIME_STEPS_MS
+        .iter()
+        .copied()
+        .find(|&s| span / s as f64 <= target_count as f64)
+        .unwrap_or(*NICE_TIME_STEPS_MS.last().unwrap());
+
+    let start = (t_min as f64 / step as f64).ceil() as i64 * step;
+    let mut ticks = Vec::new();
+    let mut v = start;
+    while v <= t_max {
+        ticks.push(v);
+        v += step;
+    }
+    if ticks.is_empty() {
+        ticks.push(t_min);
+    }
+    ticks
+}
+
+/// One glyph's horizontal advance, in the same unit as font-size (CSS px at size 1). A real
+/// text-shaping pass (e.g. a `swash` `ShapeContext`) would report the loaded font's actual
+/// hinted/kerned advance per glyph; without a shaping dependency available here, this is a fixed
+/// per-character-class table tuned to the dashboard's numeric/time labels ("-12.50", "03:45") —
+/// close enough to decide whether two labels' boxes would overlap, which is all callers need.
+fn glyph_advance(c: char) -> f64 {
+    match c {
+        '0'..='9' => 0.62,
+        '.' | ':' => 0.3,
+        '-' => 0.38,
+        ' ' => 0.3,
+        _ => 0.62,
+    }
+}
+
+/// A "shaped run" of glyph advances for one label — standing in for what a real shaper would
+/// return as `Vec<GlyphMetrics>` instead of an `str::len() * avg_char_width` guess.
+pub struct ShapedRun {
+    advances: Vec<f64>,
+}
+
+impl ShapedRun {
+    pub fn shape(label: &str) -> ShapedRun {
+        ShapedRun {
+            advances: label.chars().map(glyph_advance).collect(),
+        }
+    }
+
+    /// Total rendered width at `font_size_px`, summing this run's per-glyph advances.
+    pub fn width(&self, font_size_px: f64) -> f64 {
+        self.advances.iter().sum::<f64>() * font_size_px
+    }
+}
+
+/// Given candidate ticks already placed at pixel positions `(value, x_px)` and a label formatter,
+/// measure each label's shaped width and drop any tick whose label box would overlap the
+/// previously kept one — rather than guessing from a fixed character budget, which over-thins
+/// narrow labels ("5") and under-thins wide ones ("-123.45").
+pub fn thin_overlapping_labels<T: Copy, F: Fn(T) -> String>(
+    ticks: &[(T, f64)],
+    font_size_px: f64,
+    label_fmt: F,
+) -> Vec<(T, f64)> {
+    let mut kept: Vec<(T, f64)> = Vec::new();
+    let mut kept_right_edge: Option<f64> = None;
+
+    for &(value, x_px) in ticks {
+        let run = ShapedRun::shape(&label_fmt(value));
+        let half_w = run.width(font_size_px) / 2.0;
+        let left_edge = x_px - half_w;
+
+        if let Some(prev_right) = kept_right_edge {
+            if left_edge < prev_right {
+                continue;
+            }
+        }
+        kept.push((value, x_px));
+        kept_right_edge = Some(x_px + half_w);
+    }
+
+    kept
+}
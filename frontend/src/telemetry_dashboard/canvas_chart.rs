@@ -0,0 +1,75 @@
+// frontend/src/telemetry_dashboard/canvas_chart.rs
+//
+// Draws the same cached per-channel series `data_chart::charts_cache_get` turns into SVG `d=`
+// strings, but straight onto a `<canvas>` with `CanvasRenderingContext2d::move_to`/`line_to`/
+// `stroke` — no per-frame `String` allocation for a path attribute, and no cloning eight strings
+// out through a signal just to hand them to `<path>`.
+//
+// `data_chart::charts_cache_get_paint_ops` already separates the scaled points from the act of
+// drawing them; `paint` below is just the executor for that op list, gated to wasm32 since
+// `web_sys::CanvasRenderingContext2d` only exists in the browser.
+
+use dioxus::prelude::*;
+use dioxus_signals::{ReadableExt, Signal};
+
+use super::data_chart::{self, PaintOp};
+
+#[component]
+pub fn CanvasChart(data_type: String, width: f32, height: f32, tick: Signal<u64>) -> Element {
+    let canvas_id = format!("data-chart-canvas-{}", data_type.to_lowercase().replace(['_', ' '], "-"));
+
+    {
+        let canvas_id = canvas_id.clone();
+        use_effect(use_reactive(
+            (&data_type, &width, &height, &*tick.read()),
+            move |(data_type, width, height, _tick)| {
+                let (ops, _y_min, _y_max, _span_min) =
+                    data_chart::charts_cache_get_paint_ops(&data_type, width, height);
+                paint(&canvas_id, width, height, &ops);
+            },
+        ));
+    }
+
+    rsx! {
+        canvas {
+            id: "{canvas_id}",
+            width: "{width}",
+            height: "{height}",
+            style: "width:100%; height:auto; display:block; background:#020617; border-radius:10px; border:1px solid #1f2937;",
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn paint(canvas_id: &str, width: f32, height: f32, ops: &[PaintOp]) {
+    use wasm_bindgen::{JsCast, JsValue};
+    use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+    let Some(window) = web_sys::window() else { return };
+    let Some(document) = window.document() else { return };
+    let Some(el) = document.get_element_by_id(canvas_id) else { return };
+    let Ok(canvas) = el.dyn_into::<HtmlCanvasElement>() else { return };
+    let Ok(Some(ctx)) = canvas.get_context("2d") else { return };
+    let Ok(ctx) = ctx.dyn_into::<CanvasRenderingContext2d>() else { return };
+
+    ctx.clear_rect(0.0, 0.0, width as f64, height as f64);
+    ctx.set_line_width(2.0);
+
+    for op in ops {
+        ctx.set_stroke_style(&JsValue::from_str(op.color));
+        ctx.begin_path();
+        let mut first = true;
+        for (x, y) in &op.points {
+            if first {
+                ctx.move_to(*x as f64, *y as f64);
+                first = false;
+            } else {
+                ctx.line_to(*x as f64, *y as f64);
+            }
+        }
+        ctx.stroke();
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn paint(_canvas_id: &str, _width: f32, _height: f32, _ops: &[PaintOp]) {}
@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
 
+use super::axis::{nice_ticks, nice_time_ticks, thin_overlapping_labels};
+use super::downsample::lttb;
+
 /// Simple SVG line chart (polyline) for timeseries.
 /// `points`: Vec of (t_ms, y)
 #[component]
@@ -50,11 +53,25 @@ pub fn LineChart(
         (x, y_px)
     };
 
-    // (optional) downsample to keep SVG light
+    // "Nice" axis ticks (shared with the Connection Status latency chart and the data tab's
+    // SVG renderer via `axis.rs`) instead of the bare two-line baseline this chart used to draw.
+    let y_ticks = nice_ticks(y_min, y_max, 5);
+    let y_tick_px: Vec<(f64, f64)> = y_ticks.iter().map(|&v| (v, to_xy(t_min, v).1)).collect();
+
+    let x_tick_px: Vec<(i64, f64)> = nice_time_ticks(t_min, t_max, 6)
+        .into_iter()
+        .map(|t| (t, to_xy(t, y_min).0))
+        .collect();
+    let x_ticks = thin_overlapping_labels(&x_tick_px, 10.0, |t| format_ago_label(t, t_max));
+
+    // Downsample to keep the SVG light. LTTB (Largest-Triangle-Three-Buckets) rather than a
+    // stride, so peaks like an apogee spike or a pressure transient survive decimation instead
+    // of being stepped over — it only thins what gets drawn, never the stored history `points`
+    // was built from.
     let max_pts = 1200usize;
-    let stride = (points.len() / max_pts).max(1);
+    let plotted = lttb(&points, max_pts);
     let mut poly = String::new();
-    for (i, (t, y)) in points.iter().enumerate().step_by(stride) {
+    for (i, (t, y)) in plotted.iter().enumerate() {
         let (x, yy) = to_xy(*t, *y);
         if i == 0 { poly.push_str(&format!("{x:.2},{yy:.2}")); }
         else { poly.push_str(&format!(" {x:.2},{yy:.2}")); }
@@ -74,6 +91,21 @@ pub fn LineChart(
                 style: "width:100%; height:auto; display:block; background:#020617; border-radius:10px; border:1px solid #1f2937;",
                 view_box: "0 0 {width} {h}",
 
+                // y gridlines + labels, at "nice" tick values rather than raw min/mid/max
+                for (value, y_px) in y_tick_px.iter() {
+                    line { x1:"{pad_l}", y1:"{y_px}", x2:"{width - pad_r}", y2:"{y_px}",
+                        stroke:"#1f2937", "stroke-width":"1"
+                    }
+                    text { x:"2", y:"{y_px + 3.5}", fill:"#64748b", "font-size":"10", {format!("{value:.2}")} }
+                }
+                // x time-axis ticks, thinned so adjacent labels never overlap (see `axis.rs`)
+                for (t, x_px) in x_ticks.iter() {
+                    line { x1:"{x_px}", y1:"10", x2:"{x_px}", y2:"{h - 24.0}",
+                        stroke:"#1f2937", "stroke-width":"1"
+                    }
+                    text { x:"{x_px}", y:"{h - 6.0}", "text-anchor":"middle", fill:"#64748b", "font-size":"10", {format_ago_label(*t, t_max)} }
+                }
+
                 // axes baseline (subtle)
                 line { x1:"40", y1:"{h - 24.0}", x2:"{width - 10.0}", y2:"{h - 24.0}",
                     stroke:"#334155", "stroke-width":"1"
@@ -94,3 +126,16 @@ pub fn LineChart(
         }
     }
 }
+
+/// Render an X-axis tick timestamp as "how long ago, relative to the newest plotted sample"
+/// (`t_max`) — `mm:ss` ago, or "now" for the rightmost tick. Mirrors
+/// `connection_status_tab`'s latency-chart label of the same name.
+fn format_ago_label(t: i64, t_max: i64) -> String {
+    let ago_ms = (t_max - t).max(0);
+    if ago_ms == 0 {
+        "now".to_string()
+    } else {
+        let total_s = ago_ms / 1000;
+        format!("-{}:{:02}", total_s / 60, total_s % 60)
+    }
+}
@@ -2,14 +2,94 @@ use dioxus::prelude::*;
 use dioxus_signals::Signal;
 use std::collections::HashMap;
 
+use super::axis::{nice_ticks, nice_time_ticks, thin_overlapping_labels};
+use super::downsample::lttb;
 use super::layout::{ConnectionSectionKind, ConnectionTabLayout};
-use super::types::BoardStatusEntry;
+use super::theme::{self, Palette};
+use super::types::{Board, BoardStatusEntry};
 
 const LATENCY_WINDOW_MS: i64 = 20 * 60_000;
 const LATENCY_MAX_POINTS: usize = 2000;
 
 const SCROLL_TRIGGER_THRESHOLD_MS: i64 = 200;
 
+/// Window the board table's sparkline column plots — short enough to stay a glance, long enough
+/// to show a trend (a creeping age, not just a single blip).
+const SPARKLINE_WINDOW_MS: i64 = 60_000;
+/// A board whose `history` hasn't gained a fresh sample in longer than this hasn't sent a packet
+/// recently; the sparkline's final segment is drawn dotted to flag it the same way
+/// `build_latency_polylines`' scroll-gap segments are.
+const SPARKLINE_STALE_MS: i64 = 2_000;
+
+/// Warn/critical packet-age thresholds used to color the latency chart and the board table's
+/// status pill. Fleet-wide default; [`thresholds_for`] overrides it for boards whose normal
+/// cadence runs hotter or cooler than the rest (the DAQ board streams much faster, so "late"
+/// means something tighter for it than for, say, the gateway).
+#[derive(Clone, Copy, PartialEq)]
+struct LatencyThresholds {
+    warn_ms: f64,
+    critical_ms: f64,
+}
+
+const DEFAULT_THRESHOLDS: LatencyThresholds = LatencyThresholds {
+    warn_ms: 150.0,
+    critical_ms: 400.0,
+};
+
+fn thresholds_for(board: Board) -> LatencyThresholds {
+    match board {
+        Board::DaqBoard => LatencyThresholds {
+            warn_ms: 80.0,
+            critical_ms: 200.0,
+        },
+        _ => DEFAULT_THRESHOLDS,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LatencyZone {
+    Ok,
+    Warn,
+    Critical,
+}
+
+impl LatencyZone {
+    fn classify(age_ms: f64, thresholds: LatencyThresholds) -> Self {
+        if age_ms >= thresholds.critical_ms {
+            LatencyZone::Critical
+        } else if age_ms >= thresholds.warn_ms {
+            LatencyZone::Warn
+        } else {
+            LatencyZone::Ok
+        }
+    }
+
+    fn line_color(self, palette: &Palette) -> String {
+        match self {
+            LatencyZone::Ok => palette.accent.clone(),
+            LatencyZone::Warn => palette.warn.clone(),
+            LatencyZone::Critical => palette.err.clone(),
+        }
+    }
+
+    fn pill_colors(self, palette: &Palette) -> (String, String) {
+        let fg = match self {
+            LatencyZone::Ok => palette.ok.clone(),
+            LatencyZone::Warn => palette.warn.clone(),
+            LatencyZone::Critical => palette.err.clone(),
+        };
+        (palette.surface.clone(), fg)
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            LatencyZone::Ok => "OK",
+            LatencyZone::Warn => "WARN",
+            LatencyZone::Critical => "CRIT",
+        }
+    }
+}
+
 #[component]
 pub fn ConnectionStatusTab(
     boards: Signal<Vec<BoardStatusEntry>>,
@@ -20,6 +100,7 @@ pub fn ConnectionStatusTab(
     let mut show_latency = use_signal(|| true);
     let mut latency_fullscreen = use_signal(|| false);
     let history = use_signal(HashMap::<String, Vec<(i64, f64)>>::new);
+    let palette = theme::with_theme();
 
     {
         let boards = boards;
@@ -99,27 +180,27 @@ pub fn ConnectionStatusTab(
                         div { style: {
                                 let top_margin = if idx == 0 { "" } else { "margin-top:16px;" };
                                 format!(
-                                    "padding:14px; border:1px solid #334155; border-radius:14px; background:#0b1220;{}",
-                                    top_margin
+                                    "padding:14px; border:1px solid {}; border-radius:14px; background:{};{}",
+                                    palette.axis, palette.surface, top_margin
                                 )
                             },
                             div { style: "display:flex; align-items:center; justify-content:space-between; gap:12px; margin-bottom:8px;",
-                                div { style: "font-size:14px; color:#94a3b8;", "{section.title.clone().unwrap_or_else(|| \"Board Status\".to_string())}" }
+                                div { style: "font-size:14px; color:{palette.text_muted};", "{section.title.clone().unwrap_or_else(|| \"Board Status\".to_string())}" }
                                 div { style: "display:flex; gap:8px; flex-wrap:wrap;",
                                     button {
-                                        style: "padding:6px 12px; border-radius:999px; border:1px solid #60a5fa; background:#0b1a33; color:#bfdbfe; font-size:0.85rem; cursor:pointer;",
+                                        style: "padding:6px 12px; border-radius:999px; border:1px solid {palette.accent}; background:{palette.surface}; color:{palette.text}; font-size:0.85rem; cursor:pointer;",
                                         onclick: toggle_board,
                                         if *show_board.read() { "Collapse" } else { "Expand" }
                                     }
                                     button {
-                                        style: "padding:6px 12px; border-radius:999px; border:1px solid #60a5fa; background:#0b1a33; color:#bfdbfe; font-size:0.85rem; cursor:pointer;",
+                                        style: "padding:6px 12px; border-radius:999px; border:1px solid {palette.accent}; background:{palette.surface}; color:{palette.text}; font-size:0.85rem; cursor:pointer;",
                                         onclick: toggle_board_fullscreen,
                                         "Fullscreen"
                                     }
                                 }
                             }
                             if *show_board.read() {
-                                {render_board_table(&boards.read())}
+                                {render_board_table(&boards.read(), &history.read(), &palette)}
                             }
                         }
                     },
@@ -127,20 +208,20 @@ pub fn ConnectionStatusTab(
                         div { style: {
                                 let top_margin = if idx == 0 { "" } else { "margin-top:16px;" };
                                 format!(
-                                    "padding:14px; border:1px solid #334155; border-radius:14px; background:#0b1220;{}",
-                                    top_margin
+                                    "padding:14px; border:1px solid {}; border-radius:14px; background:{};{}",
+                                    palette.axis, palette.surface, top_margin
                                 )
                             },
                             div { style: "display:flex; align-items:center; justify-content:space-between; gap:12px; margin-bottom:8px;",
-                                div { style: "font-size:14px; color:#94a3b8;", "{section.title.clone().unwrap_or_else(|| \"Packet Age (ms)\".to_string())}" }
+                                div { style: "font-size:14px; color:{palette.text_muted};", "{section.title.clone().unwrap_or_else(|| \"Packet Age (ms)\".to_string())}" }
                                 div { style: "display:flex; gap:8px; flex-wrap:wrap;",
                                     button {
-                                        style: "padding:6px 12px; border-radius:999px; border:1px solid #60a5fa; background:#0b1a33; color:#bfdbfe; font-size:0.85rem; cursor:pointer;",
+                                        style: "padding:6px 12px; border-radius:999px; border:1px solid {palette.accent}; background:{palette.surface}; color:{palette.text}; font-size:0.85rem; cursor:pointer;",
                                         onclick: toggle_latency,
                                         if *show_latency.read() { "Collapse" } else { "Expand" }
                                     }
                                     button {
-                                        style: "padding:6px 12px; border-radius:999px; border:1px solid #60a5fa; background:#0b1a33; color:#bfdbfe; font-size:0.85rem; cursor:pointer;",
+                                        style: "padding:6px 12px; border-radius:999px; border:1px solid {palette.accent}; background:{palette.surface}; color:{palette.text}; font-size:0.85rem; cursor:pointer;",
                                         onclick: toggle_latency_fullscreen,
                                         "Fullscreen"
                                     }
@@ -150,11 +231,16 @@ pub fn ConnectionStatusTab(
                             if *show_latency.read() {
                                 div { style: "display:flex; flex-direction:column; gap:10px;",
                                     for entry in boards.read().iter() {
-                                        div { style: "padding:10px; border:1px solid #1f2937; border-radius:10px; background:#020617;",
-                                            div { style: "font-size:12px; color:#94a3b8; margin-bottom:6px;",
+                                        div { style: "padding:10px; border:1px solid {palette.grid}; border-radius:10px; background:{palette.base};",
+                                            div { style: "font-size:12px; color:{palette.text_muted}; margin-bottom:6px;",
                                                 "{entry.board.as_str()} ({entry.sender_id})"
                                             }
-                                            {render_latency_chart(history.read().get(&entry.sender_id), 360.0_f64)}
+                                            LatencyChart {
+                                                points: history.read().get(&entry.sender_id).cloned(),
+                                                height: 360.0_f64,
+                                                thresholds: thresholds_for(entry.board),
+                                                palette: palette.clone(),
+                                            }
                                         }
                                     }
                                 }
@@ -166,39 +252,41 @@ pub fn ConnectionStatusTab(
         }
 
         if *board_fullscreen.read() {
-            div { style: "position:fixed; inset:0; z-index:9998; padding:16px; background:#020617; display:flex; flex-direction:column; gap:12px; overflow:auto;",
+            div { style: "position:fixed; inset:0; z-index:9998; padding:16px; background:{palette.base}; display:flex; flex-direction:column; gap:12px; overflow:auto;",
                 div { style: "display:flex; align-items:center; justify-content:space-between; gap:12px;",
-                    h2 { style: "margin:0; color:#e2e8f0;", "Board Status" }
+                    h2 { style: "margin:0; color:{palette.text};", "Board Status" }
                     button {
-                        style: "padding:6px 12px; border-radius:999px; border:1px solid #60a5fa; background:#0b1a33; color:#bfdbfe; font-size:0.85rem; cursor:pointer;",
+                        style: "padding:6px 12px; border-radius:999px; border:1px solid {palette.accent}; background:{palette.surface}; color:{palette.text}; font-size:0.85rem; cursor:pointer;",
                         onclick: toggle_board_fullscreen,
                         "Exit Fullscreen"
                     }
                 }
-                {render_board_table(&boards.read())}
+                {render_board_table(&boards.read(), &history.read(), &palette)}
             }
         }
 
         if *latency_fullscreen.read() {
-            div { style: "position:fixed; inset:0; z-index:9998; padding:16px; background:#020617; display:flex; flex-direction:column; gap:12px; overflow:auto;",
+            div { style: "position:fixed; inset:0; z-index:9998; padding:16px; background:{palette.base}; display:flex; flex-direction:column; gap:12px; overflow:auto;",
                 div { style: "display:flex; align-items:center; justify-content:space-between; gap:12px;",
-                    h2 { style: "margin:0; color:#e2e8f0;", "Packet Age (ms)" }
+                    h2 { style: "margin:0; color:{palette.text};", "Packet Age (ms)" }
                     button {
-                        style: "padding:6px 12px; border-radius:999px; border:1px solid #60a5fa; background:#0b1a33; color:#bfdbfe; font-size:0.85rem; cursor:pointer;",
+                        style: "padding:6px 12px; border-radius:999px; border:1px solid {palette.accent}; background:{palette.surface}; color:{palette.text}; font-size:0.85rem; cursor:pointer;",
                         onclick: toggle_latency_fullscreen,
                         "Exit Fullscreen"
                     }
                 }
                 div { style: "display:flex; flex-direction:column; gap:10px;",
                     for entry in boards.read().iter() {
-                        div { style: "padding:10px; border:1px solid #1f2937; border-radius:10px; background:#020617;",
-                            div { style: "font-size:12px; color:#94a3b8; margin-bottom:6px;",
+                        div { style: "padding:10px; border:1px solid {palette.grid}; border-radius:10px; background:{palette.base};",
+                            div { style: "font-size:12px; color:{palette.text_muted}; margin-bottom:6px;",
                                 "{entry.board.as_str()} ({entry.sender_id})"
                             }
-                            {render_latency_chart(
-                                history.read().get(&entry.sender_id),
-                                fullscreen_latency_height(boards.read().len()),
-                            )}
+                            LatencyChart {
+                                points: history.read().get(&entry.sender_id).cloned(),
+                                height: fullscreen_latency_height(boards.read().len()),
+                                thresholds: thresholds_for(entry.board),
+                                palette: palette.clone(),
+                            }
                         }
                     }
                 }
@@ -222,16 +310,22 @@ fn js_now_ms() -> i64 {
     }
 }
 
-fn render_latency_chart(points: Option<&Vec<(i64, f64)>>, height: f64) -> Element {
+#[component]
+fn LatencyChart(
+    points: Option<Vec<(i64, f64)>>,
+    height: f64,
+    thresholds: LatencyThresholds,
+    palette: Palette,
+) -> Element {
     let Some(points) = points else {
         return rsx! {
-            div { style: "color:#64748b; font-size:12px;", "No data yet" }
+            div { style: "color:{palette.text_muted}; font-size:12px;", "No data yet" }
         };
     };
 
     if points.len() < 2 {
         return rsx! {
-            div { style: "color:#64748b; font-size:12px;", "Collecting…" }
+            div { style: "color:{palette.text_muted}; font-size:12px;", "Collecting…" }
         };
     }
 
@@ -241,73 +335,116 @@ fn render_latency_chart(points: Option<&Vec<(i64, f64)>>, height: f64) -> Elemen
     let pad_top = 20.0_f64;
     let pad_bottom = 20.0_f64;
     let inner_w = right - left;
-    let inner_h = height - pad_top - pad_bottom;
-    let grid_x_step = inner_w / 6.0_f64;
-    let grid_y_step = inner_h / 6.0_f64;
-    let (solid, dotted, y_min, y_max, span_min) =
-        build_latency_polylines(points.as_slice(), width, height, Some(LATENCY_WINDOW_MS));
-    if solid.is_empty() && dotted.is_empty() {
+    let poly = build_latency_polylines(
+        points.as_slice(),
+        width,
+        height,
+        Some(LATENCY_WINDOW_MS),
+        thresholds,
+    );
+    if poly.segments.is_empty() && poly.dotted.is_empty() {
         return rsx! {
-            div { style: "color:#64748b; font-size:12px;", "Collecting…" }
+            div { style: "color:{palette.text_muted}; font-size:12px;", "Collecting…" }
         };
     }
+    // "Nice" time-axis ticks (e.g. every 30s/1min rather than a fixed 6-way split), with
+    // collision-avoided labels via `thin_overlapping_labels` — the X-axis counterpart to
+    // `poly.ticks`'s Y-axis treatment below.
+    let t_max = poly.t_min + poly.t_span as i64;
+    let x_tick_positions: Vec<(i64, f64)> = nice_time_ticks(poly.t_min, t_max, 6)
+        .into_iter()
+        .map(|t| (t, poly.pad_l + ((t - poly.t_min) as f64 / poly.t_span) * poly.inner_w))
+        .collect();
+    let x_ticks = thin_overlapping_labels(&x_tick_positions, 10.0, |t| format_ago_label(t, t_max));
+
+    // Tracks the rendered pixel width of the `<svg>` (captured once via `onmounted`) so a
+    // mousemove's CSS-pixel offset can be rescaled back into the viewBox's own coordinate
+    // system before `hover_lookup` walks `poly.plotted` for the nearest sample.
+    let mut plot_px_width = use_signal(|| width);
+    let mut hover_x = use_signal(|| None::<f64>);
+    let hover = hover_x.read().and_then(|vb_x| hover_lookup(&poly, vb_x));
 
     rsx! {
         div { style: "display:flex; flex-direction:column;",
             svg {
-                style: "width:100%; height:auto; display:block; background:#020617; border-radius:10px; border:1px solid #1f2937;",
+                style: "width:100%; height:auto; display:block; background:{palette.base}; border-radius:10px; border:1px solid {palette.grid};",
                 view_box: "0 0 {width} {height}",
-
-                // gridlines
-                for i in 1..=5 {
+                onmounted: move |evt| {
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        spawn(async move {
+                            if let Ok(rect) = evt.get_client_rect().await
+                                && rect.size.width > 0.0
+                            {
+                                plot_px_width.set(rect.size.width);
+                            }
+                        });
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    {
+                        let _ = evt;
+                    }
+                },
+                onmousemove: move |evt| {
+                    let px_x = evt.element_coordinates().x as f64;
+                    let scale = width / plot_px_width.read().max(1.0);
+                    hover_x.set(Some(px_x * scale));
+                },
+                onmouseleave: move |_| hover_x.set(None),
+
+                // warn/critical bands — lowest values (good) at the bottom, highest (bad) at the top
+                rect { x:"{left}", y:"{pad_top}", width:"{inner_w}", height:"{(poly.critical_y - pad_top).max(0.0)}", fill:"{palette.err}", "fill-opacity":"0.18" }
+                rect { x:"{left}", y:"{poly.critical_y}", width:"{inner_w}", height:"{(poly.warn_y - poly.critical_y).max(0.0)}", fill:"{palette.warn}", "fill-opacity":"0.18" }
+                rect { x:"{left}", y:"{poly.warn_y}", width:"{inner_w}", height:"{(height - pad_bottom - poly.warn_y).max(0.0)}", fill:"{palette.ok}", "fill-opacity":"0.18" }
+
+                // y gridlines + labels, at "nice" tick values rather than raw min/mid/max
+                for (value, y_px) in poly.ticks.iter() {
                     line {
-                        x1:"{left}", y1:"{pad_top + grid_y_step * (i as f64)}",
-                        x2:"{right}", y2:"{pad_top + grid_y_step * (i as f64)}",
-                        stroke: "#1f2937",
+                        x1:"{left}", y1:"{y_px}",
+                        x2:"{right}", y2:"{y_px}",
+                        stroke: "{palette.grid}",
                         "stroke-width": "1"
                     }
+                    text { x:"10", y:"{y_px + 3.5}", fill:"{palette.text_muted}", "font-size":"10", {format!("{value:.0}")} }
                 }
-                for i in 1..=5 {
+                for (t, x_px) in x_ticks.iter() {
                     line {
-                        x1:"{left + grid_x_step * (i as f64)}", y1:"{pad_top}",
-                        x2:"{left + grid_x_step * (i as f64)}", y2:"{height - pad_bottom}",
-                        stroke: "#1f2937",
+                        x1:"{x_px}", y1:"{pad_top}",
+                        x2:"{x_px}", y2:"{height - pad_bottom}",
+                        stroke: "{palette.grid}",
                         "stroke-width": "1"
                     }
+                    text { x:"{x_px}", y:"{height - 5.0}", "text-anchor":"middle", fill:"{palette.text_muted}", "font-size":"10", {format_ago_label(*t, t_max)} }
                 }
 
                 // axes
-                line { x1:"{left}", y1:"{height - pad_bottom}", x2:"{right}", y2:"{height - pad_bottom}", stroke:"#334155", "stroke-width":"1" }
-                line { x1:"{left}", y1:"{pad_top}",  x2:"{left}",   y2:"{height - pad_bottom}", stroke:"#334155", "stroke-width":"1" }
-
-                // y labels
-                text { x:"10", y:"{pad_top + 6.0}", fill:"#94a3b8", "font-size":"10", {format!("{y_max}")} }
-                text { x:"10", y:"{pad_top + inner_h / 2.0 + 4.0}", fill:"#94a3b8", "font-size":"10", {format!("{}", (y_min + y_max) / 2f64)} }
-                text { x:"10", y:"{height - pad_bottom + 4.0}", fill:"#94a3b8", "font-size":"10", {format!("{y_min}")} }
+                line { x1:"{left}", y1:"{height - pad_bottom}", x2:"{right}", y2:"{height - pad_bottom}", stroke:"{palette.axis}", "stroke-width":"1" }
+                line { x1:"{left}", y1:"{pad_top}",  x2:"{left}",   y2:"{height - pad_bottom}", stroke:"{palette.axis}", "stroke-width":"1" }
 
-                // x labels (span in minutes)
-                text { x:"{left + 10.0}",   y:"{height - 5.0}", fill:"#94a3b8", "font-size":"10", {format!("-{:.1} min", span_min)} }
-                text { x:"{width * 0.5}",  y:"{height - 5.0}", fill:"#94a3b8", "font-size":"10", {format!("-{:.1} min", span_min * 0.5)} }
-                text { x:"{right - 60.0}", y:"{height - 5.0}", fill:"#94a3b8", "font-size":"10", "now" }
+                // warn/critical reference lines
+                line { x1:"{left}", y1:"{poly.warn_y}", x2:"{right}", y2:"{poly.warn_y}", stroke:"{palette.warn}", "stroke-width":"1", stroke_dasharray:"3 3" }
+                line { x1:"{left}", y1:"{poly.critical_y}", x2:"{right}", y2:"{poly.critical_y}", stroke:"{palette.err}", "stroke-width":"1", stroke_dasharray:"3 3" }
+                text { x:"{right - 80.0}", y:"{poly.warn_y - 4.0}", fill:"{palette.warn}", "font-size":"10", {format!("warn {:.0}ms", thresholds.warn_ms)} }
+                text { x:"{right - 80.0}", y:"{poly.critical_y - 4.0}", fill:"{palette.err}", "font-size":"10", {format!("crit {:.0}ms", thresholds.critical_ms)} }
 
-                for pts in solid.iter() {
-                    if !pts.is_empty() {
+                for seg in poly.segments.iter() {
+                    if !seg.points.is_empty() {
                         polyline {
-                            points: "{pts}",
+                            points: "{seg.points}",
                             fill: "none",
-                            stroke: "#22d3ee",
+                            stroke: seg.zone.line_color(&palette),
                             "stroke-width": "2",
                             "stroke-linejoin": "round",
                             "stroke-linecap": "round",
                         }
                     }
                 }
-                for pts in dotted.iter() {
+                for pts in poly.dotted.iter() {
                     if !pts.is_empty() {
                         polyline {
                             points: "{pts}",
                             fill: "none",
-                            stroke: "#fbbf24",
+                            stroke: "{palette.interpolated}",
                             "stroke-width": "2",
                             stroke_dasharray: "4 4",
                             "stroke-linejoin": "round",
@@ -315,17 +452,33 @@ fn render_latency_chart(points: Option<&Vec<(i64, f64)>>, height: f64) -> Elemen
                         }
                     }
                 }
+
+                if let Some(h) = &hover {
+                    line { x1:"{h.x_px}", y1:"{pad_top}", x2:"{h.x_px}", y2:"{height - pad_bottom}", stroke:"{palette.text}", "stroke-width":"1", stroke_dasharray:"2 2" }
+                    circle { cx:"{h.x_px}", cy:"{h.y_px}", r:"3.5", fill:"{palette.text}" }
+                }
+            }
+            if let Some(h) = &hover {
+                div { style: "margin-top:6px; font-size:12px; color:{palette.text}; background:{palette.surface}; border:1px solid {palette.grid}; border-radius:8px; padding:6px 10px; display:inline-flex; gap:10px; width:fit-content;",
+                    span { "{format_last_seen(Some(h.t as u64))}" }
+                    span { style: "color:{palette.text_muted};", "age {h.y as i64} ms" }
+                    if h.interpolated {
+                        span { style: "color:{palette.interpolated};", "interpolated" }
+                    } else {
+                        span { style: "color:{palette.ok};", "actual" }
+                    }
+                }
             }
-            div { style: "margin-top:8px; display:flex; gap:12px; align-items:center; font-size:12px; color:#cbd5f5;",
+            div { style: "margin-top:8px; display:flex; gap:12px; align-items:center; font-size:12px; color:{palette.text};",
                 div { style: "display:flex; align-items:center; gap:6px;",
                     svg { width:"26", height:"8", view_box:"0 0 26 8",
-                        line { x1:"1", y1:"4", x2:"25", y2:"4", stroke:"#22d3ee", stroke_width:"2", stroke_linecap:"round" }
+                        line { x1:"1", y1:"4", x2:"25", y2:"4", stroke:"{palette.accent}", stroke_width:"2", stroke_linecap:"round" }
                     }
                     "Actual"
                 }
                 div { style: "display:flex; align-items:center; gap:6px;",
                     svg { width:"26", height:"8", view_box:"0 0 26 8",
-                        line { x1:"1", y1:"4", x2:"25", y2:"4", stroke:"#fbbf24", stroke_width:"2", stroke_dasharray:"4 4", stroke_linecap:"round" }
+                        line { x1:"1", y1:"4", x2:"25", y2:"4", stroke:"{palette.interpolated}", stroke_width:"2", stroke_dasharray:"4 4", stroke_linecap:"round" }
                     }
                     "Interpolated"
                 }
@@ -354,14 +507,148 @@ fn fullscreen_latency_height(_count: usize) -> f64 {
     }
 }
 
+/// One contiguous run of the latency polyline, colored by which threshold zone it falls in —
+/// `build_latency_polylines` splits the line at every Ok/Warn/Critical boundary (in addition to
+/// the scroll-gap splits that produce `dotted`) so a spike through the critical band visibly
+/// changes color instead of staying a uniform cyan.
+struct LatencySegment {
+    points: String,
+    zone: LatencyZone,
+}
+
+struct LatencyPolylines {
+    segments: Vec<LatencySegment>,
+    dotted: Vec<String>,
+    y_min: f64,
+    y_max: f64,
+    span_min: f64,
+    /// Pixel y-coordinates of the warn/critical reference lines, clamped into the plot area —
+    /// used both for the reference lines themselves and to size the background bands.
+    warn_y: f64,
+    critical_y: f64,
+    /// The (decimated) samples actually plotted, plus the mapping constants used to place them —
+    /// kept around so `hover_lookup` can invert `to_xy` without re-deriving it from scratch.
+    plotted: Vec<(i64, f64)>,
+    t_min: i64,
+    t_span: f64,
+    pad_l: f64,
+    pad_t: f64,
+    inner_w: f64,
+    inner_h: f64,
+    gap_threshold_ms: i64,
+    /// Y-axis gridline/label positions: `(value, pixel_y)` pairs at "nice" round numbers rather
+    /// than the raw data min/mid/max, from [`nice_ticks`].
+    ticks: Vec<(f64, f64)>,
+}
+
+impl LatencyPolylines {
+    fn empty() -> Self {
+        Self {
+            segments: Vec::new(),
+            dotted: Vec::new(),
+            y_min: 0.0,
+            y_max: 0.0,
+            span_min: 0.0,
+            warn_y: 0.0,
+            critical_y: 0.0,
+            plotted: Vec::new(),
+            t_min: 0,
+            t_span: 1.0,
+            pad_l: 0.0,
+            pad_t: 0.0,
+            inner_w: 0.0,
+            inner_h: 0.0,
+            gap_threshold_ms: 0,
+            ticks: Vec::new(),
+        }
+    }
+
+    /// Pixel y for value `v`, using the same mapping `to_xy`/`val_to_y` used when the polylines
+    /// were built — shared so `hover_lookup`'s crosshair lines up with the plotted line exactly.
+    fn val_to_y(&self, v: f64) -> f64 {
+        let mut y_span = self.y_max - self.y_min;
+        if !y_span.is_finite() || y_span.abs() < 1e-9 {
+            y_span = 1.0;
+        }
+        let y_norm = (v - self.y_min) / y_span;
+        self.pad_t + (1.0 - y_norm) * self.inner_h
+    }
+}
+
+/// What the crosshair should show for one hovered x position: the nearest plotted sample, the
+/// pixel x/y it sits at, and whether it falls inside a scroll-gap span that `build_latency_polylines`
+/// drew dotted (so the readout can say "interpolated" rather than imply it's a real packet).
+struct HoverInfo {
+    t: i64,
+    y: f64,
+    x_px: f64,
+    y_px: f64,
+    interpolated: bool,
+}
+
+/// Invert the `to_xy` transform used when building `poly`: given a viewBox-space x, find the
+/// nearest plotted sample and report whether the two samples bracketing it are far enough apart
+/// in time (per `gap_threshold_ms`) that the line between them was drawn dotted.
+fn hover_lookup(poly: &LatencyPolylines, vb_x: f64) -> Option<HoverInfo> {
+    if poly.plotted.len() < 2 {
+        return None;
+    }
+
+    let frac = ((vb_x - poly.pad_l) / poly.inner_w).clamp(0.0, 1.0);
+    let target_t = poly.t_min + (frac * poly.t_span) as i64;
+
+    let idx = poly
+        .plotted
+        .partition_point(|(t, _)| *t < target_t)
+        .min(poly.plotted.len() - 1);
+    let (lo_idx, hi_idx) = if idx == 0 {
+        (0, 1.min(poly.plotted.len() - 1))
+    } else {
+        (idx - 1, idx)
+    };
+    let (lt, _) = poly.plotted[lo_idx];
+    let (ht, _) = poly.plotted[hi_idx];
+    let nearest = if (target_t - lt).abs() <= (ht - target_t).abs() {
+        lo_idx
+    } else {
+        hi_idx
+    };
+
+    let (t, y) = poly.plotted[nearest];
+    let x_px = poly.pad_l + ((t - poly.t_min) as f64 / poly.t_span) * poly.inner_w;
+    let y_px = poly.val_to_y(y);
+    let interpolated = (ht - lt).max(0) > poly.gap_threshold_ms && lo_idx != hi_idx;
+
+    Some(HoverInfo {
+        t,
+        y,
+        x_px,
+        y_px,
+        interpolated,
+    })
+}
+
+/// Render an X-axis tick timestamp as "how long ago, relative to the newest plotted sample"
+/// (`t_max`) — `mm:ss` ago, or "now" for the rightmost tick.
+fn format_ago_label(t: i64, t_max: i64) -> String {
+    let ago_ms = (t_max - t).max(0);
+    if ago_ms == 0 {
+        "now".to_string()
+    } else {
+        let total_s = ago_ms / 1000;
+        format!("-{}:{:02}", total_s / 60, total_s % 60)
+    }
+}
+
 fn build_latency_polylines(
     points: &[(i64, f64)],
     width: f64,
     height: f64,
     window_ms: Option<i64>,
-) -> (Vec<String>, Vec<String>, f64, f64, f64) {
+    thresholds: LatencyThresholds,
+) -> LatencyPolylines {
     if points.len() < 2 {
-        return (Vec::new(), Vec::new(), 0.0, 0.0, 0.0);
+        return LatencyPolylines::empty();
     }
 
     let mut pts: Vec<(i64, f64)> = points.to_vec();
@@ -378,7 +665,7 @@ fn build_latency_polylines(
     }
 
     if pts.len() < 2 {
-        return (Vec::new(), Vec::new(), 0.0, 0.0, 0.0);
+        return LatencyPolylines::empty();
     }
 
     let (t_min, t_max) = pts.iter().fold((i64::MAX, i64::MIN), |(mn, mx), (t, _)| {
@@ -403,12 +690,26 @@ fn build_latency_polylines(
     let inner_w = width - pad_l - pad_r;
     let inner_h = height - pad_t - pad_b;
 
+    // Decimate to roughly one sample per 2px of plot width (about 600 points at the chart's
+    // usual 1200px width) so a 20-minute window at 50ms polling doesn't hand the DOM a
+    // multi-thousand-vertex `points` attribute to repaint every tick. LTTB keeps the domain
+    // above honest (computed from the un-decimated `pts`) while still preserving spikes in the
+    // plotted line itself.
+    let target = (inner_w / 2.0).round().max(3.0) as usize;
+    let pts = lttb(&pts, target);
+
     let to_xy = |t: i64, y: f64| -> (f64, f64) {
         let x = pad_l + ((t - t_min) as f64 / t_span) * inner_w;
         let y_norm = (y - y_min) / y_span;
         let y_px = pad_t + (1.0 - y_norm) * inner_h;
         (x, y_px)
     };
+    let val_to_y = |v: f64| -> f64 {
+        let y_norm = (v - y_min) / y_span;
+        (pad_t + (1.0 - y_norm) * inner_h).clamp(pad_t, height - pad_b)
+    };
+    let warn_y = val_to_y(thresholds.warn_ms);
+    let critical_y = val_to_y(thresholds.critical_ms);
 
     // Detect large gaps (scroll pauses) and only interpolate those.
     let mut deltas: Vec<i64> = pts.windows(2).map(|w| (w[1].0 - w[0].0).max(0)).collect();
@@ -420,21 +721,41 @@ fn build_latency_polylines(
     };
     let gap_threshold_ms = median_dt.saturating_mul(5).max(SCROLL_TRIGGER_THRESHOLD_MS);
 
-    let mut solid: Vec<String> = Vec::new();
+    let mut segments: Vec<LatencySegment> = Vec::new();
     let mut dotted: Vec<String> = Vec::new();
     let mut cur_solid = String::new();
+    let mut cur_zone = LatencyZone::classify(pts[0].1, thresholds);
 
     for (idx, (t, y)) in pts.iter().enumerate() {
         let (x, yy) = to_xy(*t, *y);
+        let zone = LatencyZone::classify(*y, thresholds);
+
         if idx > 0 {
             let (pt, py) = pts[idx - 1];
             let dt = (*t - pt).max(0);
+
             if dt > gap_threshold_ms {
                 if !cur_solid.is_empty() {
-                    solid.push(std::mem::take(&mut cur_solid));
+                    segments.push(LatencySegment {
+                        points: std::mem::take(&mut cur_solid),
+                        zone: cur_zone,
+                    });
                 }
                 let (x0, y0) = to_xy(pt, py);
                 dotted.push(format!("{x0:.2},{y0:.2} {x:.2},{yy:.2}"));
+                cur_zone = zone;
+            } else if zone != cur_zone && !cur_solid.is_empty() {
+                // Close the run at this point (so the two segments meet with no visual seam),
+                // then start the next run from the same point in the new zone's color.
+                cur_solid.push(' ');
+                cur_solid.push_str(&format!("{x:.2},{yy:.2}"));
+                segments.push(LatencySegment {
+                    points: std::mem::take(&mut cur_solid),
+                    zone: cur_zone,
+                });
+                cur_zone = zone;
+                cur_solid.push_str(&format!("{x:.2},{yy:.2}"));
+                continue;
             }
         }
 
@@ -445,45 +766,189 @@ fn build_latency_polylines(
     }
 
     if !cur_solid.is_empty() {
-        solid.push(cur_solid);
+        segments.push(LatencySegment {
+            points: cur_solid,
+            zone: cur_zone,
+        });
     }
 
     let span_min = t_span / 60_000.0;
-    (solid, dotted, y_min, y_max, span_min)
+    let ticks = nice_ticks(y_min, y_max, 5)
+        .into_iter()
+        .map(|value| {
+            let y_norm = (value - y_min) / y_span;
+            let y_px = (pad_t + (1.0 - y_norm) * inner_h).clamp(pad_t, height - pad_b);
+            (value, y_px)
+        })
+        .collect();
+
+    LatencyPolylines {
+        segments,
+        dotted,
+        y_min,
+        y_max,
+        span_min,
+        warn_y,
+        critical_y,
+        plotted: pts,
+        t_min,
+        t_span,
+        pad_l,
+        pad_t,
+        inner_w,
+        inner_h,
+        gap_threshold_ms,
+        ticks,
+    }
 }
 
-fn render_board_table(boards: &[BoardStatusEntry]) -> Element {
+fn render_board_table(
+    boards: &[BoardStatusEntry],
+    history: &HashMap<String, Vec<(i64, f64)>>,
+    palette: &Palette,
+) -> Element {
     if boards.is_empty() {
         return rsx! {
-            div { style: "color:#94a3b8;", "No board status yet." }
+            div { style: "color:{palette.text_muted};", "No board status yet." }
         };
     }
 
+    let cell = format!("padding:8px; border-bottom:1px solid {}; border-right:1px solid {};", palette.grid, palette.grid);
+    let header_cell = format!("font-weight:600; color:{}; {}", palette.text, cell);
+
     rsx! {
-        div { style: "border:1px solid #1f2937; border-radius:10px; overflow:hidden;",
-            div { style: "display:grid; grid-template-columns: 1.1fr 1.1fr 0.7fr 1fr 1fr; font-size:13px; color:#cbd5f5;",
-                div { style: "font-weight:600; color:#e2e8f0; padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;", "Board" }
-                div { style: "font-weight:600; color:#e2e8f0; padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;", "Sender ID" }
-                div { style: "font-weight:600; color:#e2e8f0; padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;", "Seen" }
-                div { style: "font-weight:600; color:#e2e8f0; padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;", "Last Seen (ms)" }
-                div { style: "font-weight:600; color:#e2e8f0; padding:8px; border-bottom:1px solid #1f2937;", "Age (ms)" }
+        div { style: "border:1px solid {palette.grid}; border-radius:10px; overflow:hidden;",
+            div { style: "display:grid; grid-template-columns: 1.1fr 1.1fr 0.7fr 1fr 1fr 0.8fr 0.9fr; font-size:13px; color:{palette.text};",
+                div { style: "{header_cell}", "Board" }
+                div { style: "{header_cell}", "Sender ID" }
+                div { style: "{header_cell}", "Seen" }
+                div { style: "{header_cell}", "Last Seen (ms)" }
+                div { style: "{header_cell}", "Age (ms)" }
+                div { style: "{header_cell}", "Status" }
+                div { style: "font-weight:600; color:{palette.text}; padding:8px; border-bottom:1px solid {palette.grid};", "Trend" }
 
                 for entry in boards.iter() {
-                    div { style: "padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;", "{entry.board.as_str()}" }
-                    div { style: "padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;", "{entry.sender_id}" }
-                    div { style: "padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;", if entry.seen { "yes" } else { "no" } }
-                    div { style: "padding:8px; border-bottom:1px solid #1f2937; border-right:1px solid #1f2937;",
+                    div { style: "{cell}", "{entry.board.as_str()}" }
+                    div { style: "{cell}", "{entry.sender_id}" }
+                    div { style: "{cell}", if entry.seen { "yes" } else { "no" } }
+                    div { style: "{cell}",
                         "{format_last_seen(entry.last_seen_ms)}"
                     }
-                    div { style: "padding:8px; border-bottom:1px solid #1f2937;",
+                    div { style: "{cell}",
                         if let Some(age) = entry.age_ms { "{age}" } else { "—" }
                     }
+                    div { style: "{cell}",
+                        {render_status_pill(entry, palette)}
+                    }
+                    div { style: "padding:4px 8px; border-bottom:1px solid {palette.grid};",
+                        {render_sparkline(history.get(&entry.sender_id), entry.age_ms, entry.board, palette)}
+                    }
                 }
             }
         }
     }
 }
 
+/// Tiny fixed-size packet-age trend for one board's row in `render_board_table`, pulled from the
+/// same `history` the full-height latency charts plot — just the last `SPARKLINE_WINDOW_MS` of
+/// it, scaled to its own min/max rather than sharing a y-domain across boards.
+fn render_sparkline(
+    points: Option<&Vec<(i64, f64)>>,
+    age_ms: Option<u64>,
+    board: Board,
+    palette: &Palette,
+) -> Element {
+    let Some(points) = points else {
+        return rsx! { span { style: "color:{palette.text_muted}; font-size:11px;", "–" } };
+    };
+
+    let mut pts: Vec<(i64, f64)> = points.clone();
+    pts.sort_by_key(|(t, _)| *t);
+    let Some(&(newest, _)) = pts.last() else {
+        return rsx! { span { style: "color:{palette.text_muted}; font-size:11px;", "–" } };
+    };
+
+    let cutoff = newest.saturating_sub(SPARKLINE_WINDOW_MS);
+    let split = pts.partition_point(|(t, _)| *t < cutoff);
+    let pts = &pts[split..];
+    if pts.len() < 2 {
+        return rsx! { span { style: "color:{palette.text_muted}; font-size:11px;", "–" } };
+    }
+
+    let width = 72.0_f64;
+    let height = 20.0_f64;
+    let (t_min, t_max) = (pts[0].0, pts[pts.len() - 1].0);
+    let t_span = (t_max - t_min).max(1) as f64;
+    let (y_min, y_max) = pts
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(mn, mx), (_, y)| {
+            (mn.min(*y), mx.max(*y))
+        });
+    let mut y_span = y_max - y_min;
+    if !y_span.is_finite() || y_span.abs() < 1e-9 {
+        y_span = 1.0;
+    }
+
+    let to_xy = |t: i64, y: f64| -> (f64, f64) {
+        let x = (t - t_min) as f64 / t_span * width;
+        let y_norm = (y - y_min) / y_span;
+        (x, (1.0 - y_norm) * height)
+    };
+
+    let stale = js_now_ms() - newest > SPARKLINE_STALE_MS;
+    let plotted = if stale { &pts[..pts.len() - 1] } else { pts };
+
+    let mut poly = String::new();
+    for (i, (t, y)) in plotted.iter().enumerate() {
+        let (x, yy) = to_xy(*t, *y);
+        if i == 0 {
+            poly.push_str(&format!("{x:.1},{yy:.1}"));
+        } else {
+            poly.push_str(&format!(" {x:.1},{yy:.1}"));
+        }
+    }
+
+    let tail = stale.then(|| {
+        let (t0, y0) = pts[pts.len() - 2];
+        let (t1, y1) = pts[pts.len() - 1];
+        let (x0, y0) = to_xy(t0, y0);
+        let (x1, y1) = to_xy(t1, y1);
+        format!("{x0:.1},{y0:.1} {x1:.1},{y1:.1}")
+    });
+
+    let stroke = age_ms
+        .map(|age| LatencyZone::classify(age as f64, thresholds_for(board)))
+        .unwrap_or(LatencyZone::Ok)
+        .line_color(palette);
+
+    rsx! {
+        svg { width:"{width}", height:"{height}", view_box: "0 0 {width} {height}",
+            polyline { points: "{poly}", fill:"none", stroke:"{stroke}", "stroke-width":"1.5", "stroke-linejoin":"round", "stroke-linecap":"round" }
+            if let Some(tail) = tail {
+                polyline { points: "{tail}", fill:"none", stroke:"{stroke}", "stroke-width":"1.5", stroke_dasharray:"2 2", "stroke-linecap":"round" }
+            }
+        }
+    }
+}
+
+fn render_status_pill(entry: &BoardStatusEntry, palette: &Palette) -> Element {
+    let Some(age_ms) = entry.age_ms else {
+        return rsx! {
+            span { style: "padding:2px 8px; border-radius:999px; font-size:11px; background:{palette.grid}; color:{palette.text_muted};", "—" }
+        };
+    };
+
+    let zone = LatencyZone::classify(age_ms as f64, thresholds_for(entry.board));
+    let (bg, fg) = zone.pill_colors(palette);
+
+    rsx! {
+        span {
+            style: "padding:2px 8px; border-radius:999px; font-size:11px; font-weight:600; background:{bg}; color:{fg};",
+            "{zone.label()}"
+        }
+    }
+}
+
 fn format_last_seen(last_seen_ms: Option<u64>) -> String {
     let Some(ts) = last_seen_ms else {
         return "—".to_string();
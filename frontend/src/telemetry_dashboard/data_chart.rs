@@ -25,7 +25,7 @@
 //   for visual stability: once a bucket is in the past, it is frozen.
 
 use groundstation_shared::TelemetryRow;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, VecDeque};
 
 use super::HISTORY_MS;
@@ -44,6 +44,10 @@ const BUCKET_MS: i64 = 20;
 // Only this many most-recent buckets are kept (hard cap besides HISTORY_MS).
 const MAX_BUCKETS_PER_TYPE: usize = 60_000;
 
+// Floor for `charts_cache_reduce_quality` — however starved the redraw loop is, keep at least
+// this many buckets so the chart doesn't collapse to a handful of points.
+const MIN_BUCKETS_PER_TYPE: usize = 2_000;
+
 // Only the newest bucket is mutable. Older buckets are frozen.
 // If you want to allow small reordering/late packets, set this to 2 or 3.
 const LIVE_BUCKETS_BACK: i64 = 1;
@@ -64,6 +68,27 @@ pub fn charts_cache_request_refit() {
     CHARTS_CACHE.with(|c| c.borrow_mut().request_refit());
 }
 
+// Adaptive bucket cap: the redraw loop in `data_tab` halves this toward `MIN_BUCKETS_PER_TYPE`
+// when measured frame cost stays above its ceiling even at the slowest tick rate, trading chart
+// resolution for keeping the tick loop itself responsive. It only ever shrinks; a full
+// `charts_cache_reset_and_ingest` (e.g. on history refetch) restores it.
+thread_local! {
+    static ADAPTIVE_BUCKET_CAP: Cell<usize> = const { Cell::new(MAX_BUCKETS_PER_TYPE) };
+}
+
+/// Halve the adaptive bucket cap toward `MIN_BUCKETS_PER_TYPE`. Called when the redraw loop's
+/// EMA frame cost exceeds its ceiling even at the maximum tick interval.
+pub fn charts_cache_reduce_quality() {
+    ADAPTIVE_BUCKET_CAP.with(|c| {
+        let next = (c.get() / 2).max(MIN_BUCKETS_PER_TYPE);
+        c.set(next);
+    });
+}
+
+fn bucket_cap() -> usize {
+    ADAPTIVE_BUCKET_CAP.with(|c| c.get())
+}
+
 // ============================================================
 // Global cache
 // ============================================================
@@ -80,6 +105,7 @@ pub fn _charts_cache_is_dirty(data_type: &str) -> bool {
 }
 
 pub fn charts_cache_reset_and_ingest(rows: &[TelemetryRow]) {
+    ADAPTIVE_BUCKET_CAP.with(|c| c.set(MAX_BUCKETS_PER_TYPE));
     CHARTS_CACHE.with(|c| {
         let mut c = c.borrow_mut();
         c.clear();
@@ -106,6 +132,76 @@ pub fn charts_cache_get(data_type: &str, width: f32, height: f32) -> ([String; 8
     })
 }
 
+/// Same window as `charts_cache_get`, but each channel's `d` string is a *closed* polygon built
+/// from that channel's per-bucket `min`/`max` envelope (top edge left->right from `max`, bottom
+/// edge right->left from `min`) instead of its `last` center line — fill it at low opacity and
+/// draw `charts_cache_get`'s line on top to show the noise/spikes `BUCKET_MS` downsampling would
+/// otherwise hide. Empty for a channel with fewer than 2 plotted buckets (where `paths` still
+/// holds a lone `M` point).
+pub fn charts_cache_get_bands(data_type: &str, width: f32, height: f32) -> [String; 8] {
+    CHARTS_CACHE.with(|c| {
+        let mut c = c.borrow_mut();
+        c.get_bands(data_type, width, height)
+    })
+}
+
+/// Same scaling as `charts_cache_get`, but as raw `(x, y)` points per channel instead of
+/// pre-built SVG path strings — for a canvas renderer that draws with `moveTo`/`lineTo` and
+/// has no use for a `d=` attribute.
+pub fn charts_cache_get_points(
+    data_type: &str,
+    width: f32,
+    height: f32,
+) -> ([Vec<(f32, f32)>; 8], f32, f32, f32) {
+    CHARTS_CACHE.with(|c| {
+        let mut c = c.borrow_mut();
+        c.get_points(data_type, width, height)
+    })
+}
+
+/// Per-series draw instructions for a canvas renderer: color plus the same scaled points
+/// `charts_cache_get_points` returns, with empty/single-point series already dropped.
+///
+/// Splitting "what to draw" from "how to draw it" mirrors Servo's CanvasPaintTask, which takes
+/// canvas calls as a queued list of messages rather than executing them inline against the
+/// context — so this module never has to know `web_sys::CanvasRenderingContext2d` exists.
+#[derive(Clone)]
+pub struct PaintOp {
+    pub color: &'static str,
+    pub points: Vec<(f32, f32)>,
+}
+
+pub fn charts_cache_get_paint_ops(
+    data_type: &str,
+    width: f32,
+    height: f32,
+) -> (Vec<PaintOp>, f32, f32, f32) {
+    let (points, y_min, y_max, span_min) = charts_cache_get_points(data_type, width, height);
+    let ops = points
+        .into_iter()
+        .enumerate()
+        .filter(|(_, p)| p.len() >= 2)
+        .map(|(i, p)| PaintOp {
+            color: series_color(i),
+            points: p,
+        })
+        .collect();
+    (ops, y_min, y_max, span_min)
+}
+
+/// The same `(start_ms, end_ms, plot_left, plot_right)` window `build_if_needed` just rendered
+/// into, so a caller can map an absolute timestamp (e.g. an annotation) to an x pixel without
+/// duplicating the bucket-window math above. `None` if nothing has been ingested for `data_type`
+/// yet, same as an empty chart.
+pub fn charts_cache_get_time_window(data_type: &str, width: f32, height: f32) -> Option<(i64, i64, f32, f32)> {
+    CHARTS_CACHE.with(|c| {
+        let mut c = c.borrow_mut();
+        let ch = c.charts.get_mut(data_type)?;
+        ch.build_if_needed(width, height);
+        ch.time_window()
+    })
+}
+
 pub fn charts_cache_get_channel_minmax(
     data_type: &str,
     width: f32,
@@ -158,6 +254,24 @@ impl ChartsCache {
         }
     }
 
+    fn get_bands(&mut self, dt: &str, w: f32, h: f32) -> [String; 8] {
+        if let Some(c) = self.charts.get_mut(dt) {
+            c.build_if_needed(w, h);
+            c.bands.clone()
+        } else {
+            std::array::from_fn(|_| String::new())
+        }
+    }
+
+    fn get_points(&mut self, dt: &str, w: f32, h: f32) -> ([Vec<(f32, f32)>; 8], f32, f32, f32) {
+        if let Some(c) = self.charts.get_mut(dt) {
+            c.build_if_needed(w, h);
+            (c.points.clone(), c.disp_min, c.disp_max, c.span_min)
+        } else {
+            (std::array::from_fn(|_| Vec::new()), 0.0, 1.0, 0.0)
+        }
+    }
+
     fn request_refit(&mut self) {
         for ch in self.charts.values_mut() {
             ch.request_refit();
@@ -216,6 +330,11 @@ struct CachedChart {
 
     // cached output
     paths: [String; 8],
+    // same data as `paths`, as raw points — for the canvas renderer, so it never has to
+    // parse an SVG `d` string back into coordinates
+    points: [Vec<(f32, f32)>; 8],
+    // closed min/max envelope polygon per channel — see `charts_cache_get_bands`
+    bands: [String; 8],
 
     // per-window min/max (raw)
     raw_min: f32,
@@ -239,6 +358,12 @@ struct CachedChart {
 
     // if true: allow shrink of x-span and y-range until settled
     refit_pending: bool,
+
+    // rendered window, set by `build_if_needed` alongside `paths`/`points` — see `time_window`
+    window_start_ms: i64,
+    window_end_ms: i64,
+    plot_left: f32,
+    plot_right: f32,
 }
 
 impl CachedChart {
@@ -249,6 +374,8 @@ impl CachedChart {
             newest_ts: 0,
             dirty: true,
             paths: std::array::from_fn(|_| String::new()),
+            points: std::array::from_fn(|_| Vec::new()),
+            bands: std::array::from_fn(|_| String::new()),
             raw_min: 0.0,
             raw_max: 1.0,
             chan_min: [None; 8],
@@ -260,9 +387,22 @@ impl CachedChart {
             last_w: 0.0,
             last_h: 0.0,
             refit_pending: false,
+            window_start_ms: 0,
+            window_end_ms: 0,
+            plot_left: 0.0,
+            plot_right: 0.0,
         }
     }
 
+    /// See `charts_cache_get_time_window`. `None` once `build_if_needed` has run on an empty
+    /// chart (`plot_right` never gets past its zero default in that case).
+    fn time_window(&self) -> Option<(i64, i64, f32, f32)> {
+        if self.plot_right <= self.plot_left {
+            return None;
+        }
+        Some((self.window_start_ms, self.window_end_ms, self.plot_left, self.plot_right))
+    }
+
     fn request_refit(&mut self) {
         self.refit_pending = true;
         self.dirty = true;
@@ -324,7 +464,7 @@ impl CachedChart {
             }
         }
 
-        while self.buckets.len() > MAX_BUCKETS_PER_TYPE {
+        while self.buckets.len() > bucket_cap() {
             self.buckets.pop_front();
         }
 
@@ -409,6 +549,12 @@ impl CachedChart {
             for s in &mut self.paths {
                 s.clear();
             }
+            for p in &mut self.points {
+                p.clear();
+            }
+            for b in &mut self.bands {
+                b.clear();
+            }
             self.raw_min = 0.0;
             self.raw_max = 1.0;
             self.chan_min = [None; 8];
@@ -418,6 +564,10 @@ impl CachedChart {
             self.span_min = 0.0;
             self.prev_span_ms = 0;
             self.refit_pending = false;
+            self.window_start_ms = 0;
+            self.window_end_ms = 0;
+            self.plot_left = 0.0;
+            self.plot_right = 0.0;
             self.dirty = false;
             return;
         }
@@ -511,6 +661,11 @@ impl CachedChart {
         let pw = right - left;
         let ph = bottom - top;
 
+        self.window_start_ms = start_bid * BUCKET_MS;
+        self.window_end_ms = (newest_bid + 1) * BUCKET_MS;
+        self.plot_left = left;
+        self.plot_right = right;
+
         let y_min = self.disp_min;
         let y_max = self.disp_max;
         let map_y = |v: f32| -> f32 { bottom - (v - y_min) / (y_max - y_min) * ph };
@@ -518,6 +673,9 @@ impl CachedChart {
         for s in &mut self.paths {
             s.clear();
         }
+        for p in &mut self.points {
+            p.clear();
+        }
 
         // Build paths by iterating stable bucket ids in order.
         // If a bucket is missing (pruned gaps), we just skip it.
@@ -525,6 +683,11 @@ impl CachedChart {
         // Also: to keep line continuity, we carry-forward last_seen if a bucket has no value.
         // This does NOT mutate historical bucket values; it's just how we draw gaps.
         let mut last_seen: [Option<f32>; 8] = [None; 8];
+        // Same carry-forward, but for the envelope top/bottom edges below.
+        let mut last_seen_min: [Option<f32>; 8] = [None; 8];
+        let mut last_seen_max: [Option<f32>; 8] = [None; 8];
+        let mut band_top: [Vec<(f32, f32)>; 8] = std::array::from_fn(|_| Vec::new());
+        let mut band_bottom: [Vec<(f32, f32)>; 8] = std::array::from_fn(|_| Vec::new());
 
         let total = (newest_bid - start_bid + 1).max(1) as f32;
 
@@ -554,7 +717,38 @@ impl CachedChart {
                 } else {
                     out.push_str(&format!("L {:.2} {:.2} ", x, y));
                 }
+                self.points[ch].push((x, y));
+
+                let (min_opt, max_opt) = if b.has[ch] {
+                    last_seen_min[ch] = Some(b.min[ch]);
+                    last_seen_max[ch] = Some(b.max[ch]);
+                    (Some(b.min[ch]), Some(b.max[ch]))
+                } else {
+                    (last_seen_min[ch], last_seen_max[ch])
+                };
+                if let (Some(vmin), Some(vmax)) = (min_opt, max_opt) {
+                    band_top[ch].push((x, map_y(vmax)));
+                    band_bottom[ch].push((x, map_y(vmin)));
+                }
+            }
+        }
+
+        for ch in 0..8 {
+            let top = &band_top[ch];
+            if top.len() < 2 {
+                self.bands[ch].clear();
+                continue;
             }
+            let mut d = String::new();
+            for (i, (x, y)) in top.iter().enumerate() {
+                d.push_str(if i == 0 { "M " } else { "L " });
+                d.push_str(&format!("{x:.2} {y:.2} "));
+            }
+            for (x, y) in band_bottom[ch].iter().rev() {
+                d.push_str(&format!("L {x:.2} {y:.2} "));
+            }
+            d.push('Z');
+            self.bands[ch] = d;
         }
 
         self.span_min = (want_buckets as f32 * BUCKET_MS as f32) / 60_000.0;
@@ -575,17 +769,35 @@ pub fn series_color(i: usize) -> &'static str {
         .unwrap_or("#9ca3af")
 }
 
+/// Whether `charts_cache_get_bands`' envelope is worth drawing for this data type. Multi-axis
+/// vector types (gyro, accel, GPS) get real value from it — a spike on just one axis is exactly
+/// what flattening to `last[ch]` hides between bucket edges. Single-scalar and discrete-state
+/// types show their own noise fine as a plain `last[ch]` line, so they stay opted out rather than
+/// drawing a redundant (or meaningless, for on/off valves) band around one series.
+pub fn chart_supports_bands(dt: &str) -> bool {
+    !matches!(
+        dt,
+        "BATTERY_VOLTAGE"
+            | "BATTERY_CURRENT"
+            | "FUEL_FLOW"
+            | "FUEL_TANK_PRESSURE"
+            | "TANK_TEMPERATURE"
+            | "VALVE_STATE"
+    )
+}
+
 pub fn labels_for_datatype(dt: &str) -> [&'static str; 8] {
     match dt {
         "GYRO_DATA" => ["Roll", "Pitch", "Yaw", "", "", "", "", ""],
         "ACCEL_DATA" => ["X Accel", "Y Accel", "Z Accel", "", "", "", "", ""],
         "BAROMETER_DATA" => ["Pressure", "Temp", "Altitude", "", "", "", "", ""],
-        "KALMAN_FILTER_DATA" => ["X", "Y", "Z", "", "", "", "", ""],
+        "KALMAN_FILTER_DATA" => ["Altitude", "Velocity", "Accel", "qw", "qx", "qy", "qz", "Yaw"],
         "GPS_DATA" => ["Lat", "Lon", "", "", "", "", "", ""],
         "FUEL_TANK_PRESSURE" => ["Tank Pressure", "", "", "", "", "", "", ""],
         "BATTERY_VOLTAGE" => ["Voltage", "", "", "", "", "", "", ""],
         "BATTERY_CURRENT" => ["Current", "", "", "", "", "", "", ""],
         "FUEL_FLOW" => ["Flow Rate", "", "", "", "", "", "", ""],
+        "TANK_TEMPERATURE" => ["Tank Temp", "", "", "", "", "", "", ""],
         "VALVE_STATE" => [
             "Pilot",
             "NormallyOpen",
@@ -3,6 +3,79 @@ use dioxus::prelude::*;
 use dioxus_signals::{ReadableExt, Signal, WritableExt};
 use groundstation_shared::TelemetryRow;
 
+use super::annotations::ANNOTATIONS;
+use super::axis::{nice_ticks, nice_time_ticks, thin_overlapping_labels};
+use super::canvas_chart::CanvasChart;
+use super::data_chart;
+#[cfg(feature = "gpu_chart")]
+use super::gpu_chart::GpuLineChart;
+
+/// Which renderer draws the chart panel below the type selector. `Gpu` only exists when the
+/// `gpu_chart` feature is enabled — see `gpu_chart.rs` for why it's opt-in rather than on by
+/// default like `Canvas` is.
+#[derive(Clone, Copy, PartialEq)]
+enum RenderMode {
+    Svg,
+    Canvas,
+    #[cfg(feature = "gpu_chart")]
+    Gpu,
+}
+
+impl RenderMode {
+    fn label(self) -> &'static str {
+        match self {
+            RenderMode::Svg => "SVG",
+            RenderMode::Canvas => "Canvas",
+            #[cfg(feature = "gpu_chart")]
+            RenderMode::Gpu => "GPU",
+        }
+    }
+
+    fn next(self) -> RenderMode {
+        match self {
+            RenderMode::Svg => RenderMode::Canvas,
+            #[cfg(feature = "gpu_chart")]
+            RenderMode::Canvas => RenderMode::Gpu,
+            #[cfg(not(feature = "gpu_chart"))]
+            RenderMode::Canvas => RenderMode::Svg,
+            #[cfg(feature = "gpu_chart")]
+            RenderMode::Gpu => RenderMode::Svg,
+        }
+    }
+}
+
+const CHART_WIDTH: f32 = 900.0;
+const CHART_HEIGHT: f32 = 220.0;
+
+// Redraw cadence bounds for the adaptive tick loop below: 33ms (~30 FPS) is as fast as we'll
+// ever redraw, 250ms (4 FPS) is as slow as we'll let it get before the chart starts feeling
+// laggy. The actual delay floats between these based on measured frame cost.
+const FRAME_MS_FLOOR: f32 = 33.0;
+const FRAME_MS_CEIL: f32 = 250.0;
+
+// If the EMA frame cost is still above this even at `FRAME_MS_CEIL`, the tick loop can't buy
+// itself enough headroom by slowing down alone — shed load by shrinking the cached history
+// instead (see `data_chart::charts_cache_reduce_quality`).
+const FRAME_COST_CEILING_MS: f32 = 120.0;
+
+fn now_ms_f64() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.performance())
+            .map(|p| p.now())
+            .unwrap_or(0.0)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as f64)
+            .unwrap_or(0.0)
+    }
+}
+
 #[component]
 pub fn DataTab(rows: Signal<Vec<TelemetryRow>>, active_tab: Signal<String>) -> Element {
     // Collect unique data types (for buttons)
@@ -13,6 +86,121 @@ pub fn DataTab(rows: Signal<Vec<TelemetryRow>>, active_tab: Signal<String>) -> E
 
     let current = active_tab.read().clone();
 
+    // Ticks on a timer so the chart keeps repainting from `data_chart`'s cache even while
+    // `rows`/`active_tab` are otherwise unchanged (the cache is ingested out-of-band, not
+    // through a signal `DataTab` itself holds).
+    //
+    // The interval is adaptive: each iteration measures how long the cache rebuild for the
+    // current data type actually took, folds it into an EMA, and uses that to pick the next
+    // delay — back off under load (big backlog, many points), speed back up once idle. If the
+    // EMA stays pinned above `FRAME_COST_CEILING_MS` even at the slowest tick rate, slowing down
+    // further won't help, so shed load by shrinking the cache's retained history instead.
+    let mut tick = use_signal(|| 0u64);
+    let active_tab_for_loop = active_tab;
+    use_effect(move || {
+        spawn(async move {
+            let mut avg_cost_ms: f32 = 0.0;
+            loop {
+                let delay_ms = avg_cost_ms.mul_add(3.0, FRAME_MS_FLOOR).clamp(FRAME_MS_FLOOR, FRAME_MS_CEIL);
+
+                #[cfg(target_arch = "wasm32")]
+                gloo_timers::future::TimeoutFuture::new(delay_ms as u32).await;
+                #[cfg(not(target_arch = "wasm32"))]
+                tokio::time::sleep(std::time::Duration::from_millis(delay_ms as u64)).await;
+
+                let frame_start = now_ms_f64();
+                let dt = active_tab_for_loop.read().clone();
+                if !dt.is_empty() {
+                    let _ = data_chart::charts_cache_get(&dt, CHART_WIDTH, CHART_HEIGHT);
+                }
+                let frame_end = now_ms_f64();
+                let cost = (frame_end - frame_start) as f32;
+
+                avg_cost_ms = 0.8 * avg_cost_ms + 0.2 * cost;
+
+                if avg_cost_ms > FRAME_COST_CEILING_MS && delay_ms >= FRAME_MS_CEIL {
+                    data_chart::charts_cache_reduce_quality();
+                }
+
+                *tick.write() += 1;
+            }
+        });
+    });
+
+    let mut render_mode = use_signal(|| RenderMode::Svg);
+    let mode = *render_mode.read();
+
+    let mut marker_text = use_signal(String::new);
+    // Reading `ANNOTATIONS` here subscribes `DataTab` to it, so a marker added/removed from any
+    // connected dashboard (including this one) repaints the overlay immediately. Positions are
+    // resolved to a `left:%` ahead of the template, same as `filtered` below, rather than doing
+    // the window math inline in the markup.
+    let marker_positions: Vec<(groundstation_shared::AnnotationId, f32, String)> = {
+        let window = data_chart::charts_cache_get_time_window(&current, CHART_WIDTH, CHART_HEIGHT);
+        match window {
+            Some((win_start, win_end, plot_left, plot_right)) => {
+                let span = (win_end - win_start).max(1) as f32;
+                ANNOTATIONS
+                    .read()
+                    .visible()
+                    .into_iter()
+                    .filter(|m| m.timestamp_ms >= win_start && m.timestamp_ms <= win_end)
+                    .map(|m| {
+                        let frac = (m.timestamp_ms - win_start) as f32 / span;
+                        let x_pct = ((plot_left + frac * (plot_right - plot_left)) / CHART_WIDTH) * 100.0;
+                        (m.id, x_pct, m.text)
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        }
+    };
+
+    #[cfg(feature = "gpu_chart")]
+    let is_gpu_mode = mode == RenderMode::Gpu;
+    #[cfg(not(feature = "gpu_chart"))]
+    let is_gpu_mode = false;
+
+    let _ = *tick.read(); // subscribe so the SVG branch below rebuilds on every tick too
+    let (paths, y_min, y_max, _span_min) =
+        data_chart::charts_cache_get(&current, CHART_WIDTH, CHART_HEIGHT);
+    let labels = data_chart::labels_for_datatype(&current);
+    let bands = data_chart::chart_supports_bands(&current)
+        .then(|| data_chart::charts_cache_get_bands(&current, CHART_WIDTH, CHART_HEIGHT));
+
+    // "Nice" axis ticks for the SVG renderer, shared (via `axis.rs`) with the Connection Status
+    // latency chart and `chart.rs`'s `LineChart` instead of each chart guessing its own gridlines.
+    // Mapping matches `data_chart.rs`'s `build_if_needed` viewport (`left=60, right=w-20,
+    // top=20, bottom=h-20`) so gridlines line up with the `paths`/`bands` it already built.
+    const PLOT_LEFT: f32 = 60.0;
+    const PLOT_TOP: f32 = 20.0;
+    let plot_right = CHART_WIDTH - 20.0;
+    let plot_bottom = CHART_HEIGHT - 20.0;
+    let y_ticks: Vec<(f32, f32)> = nice_ticks(y_min as f64, y_max as f64, 5)
+        .into_iter()
+        .map(|v| {
+            let y_span = (y_max - y_min).abs().max(1e-6);
+            let y_px = plot_bottom - ((v as f32 - y_min) / y_span) * (plot_bottom - PLOT_TOP);
+            (v as f32, y_px)
+        })
+        .collect();
+    let time_window = data_chart::charts_cache_get_time_window(&current, CHART_WIDTH, CHART_HEIGHT);
+    let t_max_for_labels = time_window.map(|(_, win_end, _, _)| win_end).unwrap_or(0);
+    let x_ticks: Vec<(i64, f32)> = match time_window {
+        Some((win_start, win_end, plot_left, plot_right)) if win_end > win_start => {
+            let span = (win_end - win_start) as f64;
+            let raw: Vec<(i64, f64)> = nice_time_ticks(win_start, win_end, 6)
+                .into_iter()
+                .map(|t| (t, plot_left as f64 + ((t - win_start) as f64 / span) * (plot_right - plot_left) as f64))
+                .collect();
+            thin_overlapping_labels(&raw, 10.0, |t| format_ago_label(t, win_end))
+                .into_iter()
+                .map(|(t, x)| (t, x as f32))
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
     let filtered: Vec<TelemetryRow> = rows
         .read()
         .iter()
@@ -45,6 +233,127 @@ pub fn DataTab(rows: Signal<Vec<TelemetryRow>>, active_tab: Signal<String>) -> E
                 }
             }
 
+            // chart
+            div { style: "border:1px solid #334155; border-radius:14px; background:#0b1220; padding:12px;",
+                div { style: "display:flex; align-items:center; justify-content:space-between; margin-bottom:8px;",
+                    div { style: "display:flex; gap:10px; flex-wrap:wrap; font-size:11px; color:#94a3b8;",
+                        for (i, label) in labels.iter().enumerate() {
+                            if !label.is_empty() {
+                                div { style: "display:flex; align-items:center; gap:4px;",
+                                    span {
+                                        style: "width:10px; height:10px; border-radius:2px; background:{data_chart::series_color(i)};"
+                                    }
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+                    button {
+                        style: "padding:4px 10px; border-radius:999px; border:1px solid #334155; background:#0b1220; color:#e5e7eb; font-size:11px; cursor:pointer;",
+                        onclick: move |_| render_mode.set(mode.next()),
+                        "{mode.label()}"
+                    }
+                }
+
+                div { style: "display:flex; gap:6px; margin-bottom:8px;",
+                    input {
+                        r#type: "text",
+                        placeholder: "Mark this moment (e.g. \"ignition\")…",
+                        style: "flex:1; padding:4px 8px; border-radius:6px; border:1px solid #334155; background:#0b1220; color:#e5e7eb; font-size:12px;",
+                        value: "{marker_text}",
+                        oninput: move |e| marker_text.set(e.value()),
+                    }
+                    button {
+                        style: "padding:4px 10px; border-radius:6px; border:1px solid #334155; background:#0b1220; color:#e5e7eb; font-size:12px; cursor:pointer;",
+                        disabled: marker_text.read().trim().is_empty(),
+                        onclick: move |_| {
+                            let text = marker_text.read().trim().to_string();
+                            if text.is_empty() {
+                                return;
+                            }
+                            super::add_annotation(now_ms_f64() as i64, text);
+                            marker_text.set(String::new());
+                        },
+                        "Add marker"
+                    }
+                }
+
+                if current.is_empty() {
+                    div { style: "color:#64748b; font-size:12px;", "Select a data type to chart it." }
+                } else {
+                    div { style: "position:relative;",
+                        if mode == RenderMode::Canvas {
+                            CanvasChart { data_type: current.clone(), width: CHART_WIDTH, height: CHART_HEIGHT, tick: tick }
+                        } else if is_gpu_mode {
+                            {gpu_chart_element(current.clone(), tick)}
+                        } else {
+                            svg {
+                                view_box: "0 0 {CHART_WIDTH} {CHART_HEIGHT}",
+                                style: "width:100%; height:auto; display:block; background:#020617; border-radius:10px; border:1px solid #1f2937;",
+                                for (value, y_px) in y_ticks.iter() {
+                                    line {
+                                        x1: "{PLOT_LEFT}", y1: "{y_px}",
+                                        x2: "{plot_right}", y2: "{y_px}",
+                                        stroke: "#1f2937", "stroke-width": "1",
+                                    }
+                                    text { x: "2", y: "{y_px + 3.5}", fill: "#64748b", "font-size": "10", {format!("{value:.2}")} }
+                                }
+                                for (t, x_px) in x_ticks.iter() {
+                                    line {
+                                        x1: "{x_px}", y1: "{PLOT_TOP}",
+                                        x2: "{x_px}", y2: "{plot_bottom}",
+                                        stroke: "#1f2937", "stroke-width": "1",
+                                    }
+                                    text { x: "{x_px}", y: "{CHART_HEIGHT - 6.0}", "text-anchor": "middle", fill: "#64748b", "font-size": "10", {format_ago_label(*t, t_max_for_labels)} }
+                                }
+                                if let Some(bands) = &bands {
+                                    for (i, d) in bands.iter().enumerate() {
+                                        if !d.is_empty() {
+                                            path {
+                                                d: "{d}",
+                                                fill: "{data_chart::series_color(i)}",
+                                                "fill-opacity": "0.18",
+                                                stroke: "none",
+                                            }
+                                        }
+                                    }
+                                }
+                                for (i, d) in paths.iter().enumerate() {
+                                    if !d.is_empty() {
+                                        path {
+                                            d: "{d}",
+                                            fill: "none",
+                                            stroke: "{data_chart::series_color(i)}",
+                                            "stroke-width": "2",
+                                            "stroke-linejoin": "round",
+                                            "stroke-linecap": "round",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        for (id, x_pct, text) in marker_positions.iter() {
+                            {
+                                let id = *id;
+                                rsx! {
+                                    div {
+                                        key: "{id.client_id}-{id.counter}",
+                                        title: "{text}",
+                                        style: "position:absolute; top:0; bottom:0; left:{x_pct}%; width:0; border-left:1px dashed #facc15; cursor:pointer;",
+                                        onclick: move |_| super::remove_annotation(id),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if mode == RenderMode::Svg {
+                        div { style: "margin-top:4px; color:#64748b; font-size:11px;",
+                            {format!("y: {y_min:.3} .. {y_max:.3}")}
+                        }
+                    }
+                }
+            }
+
             // table
             div { style: "flex:1; overflow:auto; border:1px solid #334155; border-radius:14px; background:#0b1220;",
                 table { style: "width:100%; border-collapse:collapse; font-size:12px;",
@@ -79,9 +388,34 @@ pub fn DataTab(rows: Signal<Vec<TelemetryRow>>, active_tab: Signal<String>) -> E
     }
 }
 
+#[cfg(feature = "gpu_chart")]
+fn gpu_chart_element(data_type: String, tick: Signal<u64>) -> Element {
+    rsx! {
+        GpuLineChart { data_type, width: CHART_WIDTH, height: CHART_HEIGHT, tick }
+    }
+}
+
+#[cfg(not(feature = "gpu_chart"))]
+fn gpu_chart_element(_data_type: String, _tick: Signal<u64>) -> Element {
+    rsx! {}
+}
+
 fn fmt_opt(v: Option<f32>) -> String {
     match v {
         Some(x) => format!("{x:.4}"),
         None => "-".to_string(),
     }
 }
+
+/// Render an X-axis tick timestamp as "how long ago, relative to the newest plotted sample"
+/// (`t_max`) — `mm:ss` ago, or "now" for the rightmost tick. Mirrors `chart.rs` and
+/// `connection_status_tab.rs`'s latency-chart label of the same name.
+fn format_ago_label(t: i64, t_max: i64) -> String {
+    let ago_ms = (t_max - t).max(0);
+    if ago_ms == 0 {
+        "now".to_string()
+    } else {
+        let total_s = ago_ms / 1000;
+        format!("-{}:{:02}", total_s / 60, total_s % 60)
+    }
+}
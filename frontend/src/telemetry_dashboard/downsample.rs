@@ -0,0 +1,63 @@
+// frontend/src/telemetry_dashboard/downsample.rs
+//
+// Largest-Triangle-Three-Buckets, shared by `chart`'s render-time thinning and the row-storage
+// caps in `mod.rs`/`dynamic_rows.rs` — one algorithm instead of near-identical copies, so the
+// same guarantee (first/last point kept, one point per bucket picked by largest triangle area,
+// so a lone spike is kept) holds everywhere a stride would otherwise step over it.
+
+/// Indices into `points` (ascending, length `target`) that LTTB would keep: `0` and `n - 1`,
+/// then for each of `target - 2` equal-count buckets, whichever point forms the largest-area
+/// triangle with the previously selected point and the average x/y of the *next* bucket.
+/// `points` must already be sorted by x (`timestamp_ms`).
+pub(crate) fn lttb_indices(points: &[(i64, f64)], target: usize) -> Vec<usize> {
+    let n = points.len();
+    if target >= n || target < 3 {
+        return (0..n).collect();
+    }
+
+    let mut kept = Vec::with_capacity(target);
+    kept.push(0);
+
+    let every = (n - 2) as f64 / (target - 2) as f64;
+    let mut a = 0usize;
+
+    for i in 0..(target - 2) {
+        let avg_range_start = ((i + 1) as f64 * every) as usize + 1;
+        let avg_range_end = (((i + 2) as f64 * every) as usize + 1).min(n);
+        let avg_range_end = avg_range_end.max(avg_range_start + 1);
+
+        let avg_slice = &points[avg_range_start..avg_range_end];
+        let avg_x = avg_slice.iter().map(|(t, _)| *t as f64).sum::<f64>() / avg_slice.len() as f64;
+        let avg_y = avg_slice.iter().map(|(_, y)| *y).sum::<f64>() / avg_slice.len() as f64;
+
+        let range_start = (i as f64 * every) as usize + 1;
+        let range_end = (((i + 1) as f64 * every) as usize + 1).min(n - 1);
+
+        let (point_a_x, point_a_y) = (points[a].0 as f64, points[a].1);
+
+        let mut max_area = -1.0;
+        let mut max_area_index = range_start;
+        for (offset, (t, y)) in points[range_start..range_end].iter().enumerate() {
+            let range_offs = range_start + offset;
+            let area = ((point_a_x - avg_x) * (y - point_a_y)
+                - (point_a_x - *t as f64) * (avg_y - point_a_y))
+                .abs()
+                * 0.5;
+            if area > max_area {
+                max_area = area;
+                max_area_index = range_offs;
+            }
+        }
+
+        kept.push(max_area_index);
+        a = max_area_index;
+    }
+
+    kept.push(n - 1);
+    kept
+}
+
+/// Convenience wrapper over [`lttb_indices`] for callers that just want the kept points back.
+pub(crate) fn lttb(points: &[(i64, f64)], target: usize) -> Vec<(i64, f64)> {
+    lttb_indices(points, target).into_iter().map(|i| points[i]).collect()
+}
@@ -0,0 +1,131 @@
+// frontend/src/telemetry_dashboard/dynamic_rows.rs
+//
+// `TelemetryRow` is a fixed `v0..v7` shape — adding a sensor means a code change and a
+// redeploy. `WsInMsg::Dynamic` carries arbitrary JSON instead; `ingest` recursively flattens
+// it into dotted key paths (`imu.accel.x`, `sensors[2].temp`, following the nested-value
+// walk the stats-server's `serialize_value` does) so the table/plot UI can pick up fields it
+// has never seen before, mid-flight, without a rebuild.
+
+use dioxus_signals::{GlobalSignal, Signal};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub(crate) enum DynamicValue {
+    Number(f64),
+    Text(String),
+}
+
+impl DynamicValue {
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            DynamicValue::Number(n) => Some(*n),
+            DynamicValue::Text(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub(crate) struct DynamicRecord {
+    pub(crate) timestamp_ms: i64,
+    pub(crate) fields: BTreeMap<String, DynamicValue>,
+}
+
+/// Every dotted key path seen so far, in first-seen order — the table/plot UI renders
+/// columns off this rather than off any one row, since a field that only shows up later in
+/// the flight still needs a column from the moment it first appears.
+pub(crate) static DYNAMIC_COLUMNS: GlobalSignal<Vec<String>> = Signal::global(Vec::new);
+
+pub(crate) static DYNAMIC_ROWS: GlobalSignal<Vec<DynamicRecord>> = Signal::global(Vec::new);
+
+fn flatten(value: &serde_json::Value, prefix: &str, out: &mut BTreeMap<String, DynamicValue>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, v) in map {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{prefix}.{key}") };
+                flatten(v, &path, out);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                flatten(v, &format!("{prefix}[{i}]"), out);
+            }
+        }
+        serde_json::Value::Number(n) => {
+            if let Some(n) = n.as_f64() {
+                out.insert(prefix.to_string(), DynamicValue::Number(n));
+            }
+        }
+        serde_json::Value::Bool(b) => {
+            out.insert(prefix.to_string(), DynamicValue::Number(if *b { 1.0 } else { 0.0 }));
+        }
+        serde_json::Value::String(s) => {
+            out.insert(prefix.to_string(), DynamicValue::Text(s.clone()));
+        }
+        serde_json::Value::Null => {}
+    }
+}
+
+fn note_columns<'a>(keys: impl Iterator<Item = &'a String>) {
+    let mut columns = DYNAMIC_COLUMNS.write();
+    for key in keys {
+        if !columns.contains(key) {
+            columns.push(key.clone());
+        }
+    }
+}
+
+/// Flatten one `WsInMsg::Dynamic` payload and append it to `DYNAMIC_ROWS`, then apply the
+/// same time-window trim and LTTB cap `extend_telemetry_rows` applies to the typed history
+/// — see the comment above its `MAX_SAMPLES` cap for why the cap exists.
+pub(crate) fn ingest(value: serde_json::Value) {
+    let mut fields = BTreeMap::new();
+    flatten(&value, "", &mut fields);
+    if fields.is_empty() {
+        return;
+    }
+
+    let timestamp_ms = fields
+        .get("timestamp_ms")
+        .and_then(DynamicValue::as_f64)
+        .map(|ms| ms as i64)
+        .unwrap_or_else(super::now_ms);
+
+    note_columns(fields.keys());
+
+    let mut rows = DYNAMIC_ROWS.write();
+    rows.push(DynamicRecord { timestamp_ms, fields });
+
+    if let Some(last) = rows.last() {
+        let cutoff = last.timestamp_ms - super::HISTORY_MS;
+        let split = rows.partition_point(|r| r.timestamp_ms < cutoff);
+        if split > 0 {
+            rows.drain(0..split);
+        }
+    }
+
+    const MAX_SAMPLES: usize = 10_000;
+    if rows.len() > MAX_SAMPLES {
+        // No fixed `data_type` to group by here (that's the whole point of the dynamic path),
+        // so LTTB runs against a single rollup series — the per-record average of whatever
+        // numeric fields showed up — rather than a blind stride, to keep the envelope of
+        // whichever field spikes instead of aliasing it away.
+        let points: Vec<(i64, f64)> = rows.iter().map(|r| (r.timestamp_ms, numeric_rollup(r))).collect();
+        let kept: Vec<DynamicRecord> = super::downsample::lttb_indices(&points, MAX_SAMPLES)
+            .into_iter()
+            .map(|i| rows[i].clone())
+            .collect();
+        *rows = kept;
+    }
+}
+
+/// Average of every numeric field on `record` — the stand-in "value" LTTB uses to judge which
+/// dynamic records are safe to drop when the cap above has to shed some.
+fn numeric_rollup(record: &DynamicRecord) -> f64 {
+    let (sum, count) = record
+        .fields
+        .values()
+        .filter_map(DynamicValue::as_f64)
+        .fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    if count == 0 { 0.0 } else { sum / count as f64 }
+}
@@ -0,0 +1,107 @@
+// frontend/src/telemetry_dashboard/flight_recorder.rs
+//
+// Tamper-evident logging for the live telemetry/board-status stream, separate from
+// `recording`'s replay-focused session files: writes newline-delimited JSON into a timestamped
+// session file under the same `gs26` data dir the native `persist` module (see `app.rs`) already
+// uses, and maintains a sidecar `.manifest.sha256` holding a rolling SHA-256 digest of everything
+// written, so a recovered log can be checked byte-for-byte against tampering or truncation with
+// `verify_session`. Native only — wasm32 has no filesystem to write to.
+
+use sha2::{Digest, Sha256};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+/// Bounded so a writer that falls behind (slow disk, full session) sheds lines instead of
+/// stalling whoever is feeding the live ingest path — see `record_line`.
+const CHANNEL_CAPACITY: usize = 256;
+
+fn storage_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .or_else(dirs::data_dir)
+        .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| ".".into()))
+        .join("gs26")
+}
+
+fn manifest_path_for(session_path: &Path) -> PathBuf {
+    session_path.with_extension("manifest.sha256")
+}
+
+fn timestamp_suffix() -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis()).unwrap_or(0)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hasher.finalize().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Sender for the active recording's writer task, if one is running — a line queued via
+/// `record_line` after `stop_recording` (or before any `start_recording`) is just dropped.
+static ACTIVE: OnceLock<Mutex<Option<mpsc::Sender<String>>>> = OnceLock::new();
+
+fn active() -> &'static Mutex<Option<mpsc::Sender<String>>> {
+    ACTIVE.get_or_init(|| Mutex::new(None))
+}
+
+/// Starts a new session file (`gs26/flight_<unix_ms>.ndjson`) and its writer task, replacing
+/// whatever recording was previously active. Returns the session path so the caller can offer
+/// it for later `verify_session` or archival.
+pub fn start_recording() -> io::Result<PathBuf> {
+    let dir = storage_dir();
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("flight_{}.ndjson", timestamp_suffix()));
+
+    let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+    *active().lock().unwrap() = Some(tx);
+
+    tokio::spawn(run_writer(path.clone(), rx));
+    Ok(path)
+}
+
+/// Stops the active recording (if any): closing the channel lets `run_writer` drain whatever's
+/// still buffered, finalize the rolling digest, and write the manifest before it exits.
+pub fn stop_recording() {
+    active().lock().unwrap().take();
+}
+
+/// Queues one already-serialized JSON line (a `TelemetryRow` or `BoardStatusMsg`) for the
+/// active recording. A no-op if nothing is recording, or if the writer has fallen behind and
+/// its channel is full.
+pub fn record_line(line: String) {
+    if let Some(tx) = active().lock().unwrap().as_ref() {
+        let _ = tx.try_send(line);
+    }
+}
+
+async fn run_writer(path: PathBuf, mut rx: mpsc::Receiver<String>) {
+    let Ok(mut file) = tokio::fs::File::create(&path).await else {
+        return;
+    };
+
+    let mut hasher = Sha256::new();
+    while let Some(line) = rx.recv().await {
+        let mut bytes = line.into_bytes();
+        bytes.push(b'\n');
+        hasher.update(&bytes);
+        if file.write_all(&bytes).await.is_err() {
+            return;
+        }
+    }
+
+    let _ = file.flush().await;
+    let digest = hasher.finalize().iter().map(|b| format!("{b:02x}")).collect::<String>();
+    let _ = tokio::fs::write(manifest_path_for(&path), digest).await;
+}
+
+/// Re-hashes `path` and compares it against its sidecar manifest, so a recovered log can be
+/// trusted (or flagged as truncated/tampered-with) before it's used for post-flight review.
+pub async fn verify_session(path: &Path) -> io::Result<bool> {
+    let contents = tokio::fs::read(path).await?;
+    let expected = tokio::fs::read_to_string(manifest_path_for(path)).await?;
+    Ok(expected.trim() == sha256_hex(&contents))
+}
@@ -71,13 +71,22 @@ mod imp {
     }
 }
 
-// Optional: for linux/etc either stub or add another backend
+#[cfg(all(target_os = "linux", not(target_arch = "wasm32")))]
+mod imp {
+    use super::*;
+    pub fn start(user_gps: Signal<Option<(f64, f64)>>) {
+        crate::telemetry_dashboard::gps_linux::start(user_gps);
+    }
+}
+
+// Optional: for remaining targets either stub or add another backend
 #[cfg(not(any(
     target_arch = "wasm32",
     target_os = "windows",
     target_os = "macos",
     target_os = "ios",
-    target_os = "android"
+    target_os = "android",
+    target_os = "linux"
 )))]
 mod imp {
     use super::*;
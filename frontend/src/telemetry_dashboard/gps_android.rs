@@ -1,10 +1,12 @@
 // frontend/src/telemetry_dashboard/gps_android.rs
 #![cfg(target_os = "android")]
 
+use crate::telemetry_dashboard::location_provider::{Fix, FixCell, LocationProvider};
 use dioxus_signals::{Signal, WritableExt};
 use std::sync::OnceLock;
 
 static GPS_SIGNAL: OnceLock<Signal<Option<(f64, f64)>>> = OnceLock::new();
+static LATEST_FIX: FixCell = FixCell::new();
 
 pub fn start(user_gps: Signal<Option<(f64, f64)>>) {
     // store signal so JNI callback can update it
@@ -19,12 +21,33 @@ pub fn start(user_gps: Signal<Option<(f64, f64)>>) {
 extern "C" {
     /// Implemented on the Java/Kotlin side via JNI to start GPS updates.
     fn gs26_android_location_start();
+    /// Implemented on the Java/Kotlin side via JNI to stop GPS updates.
+    fn gs26_android_location_stop();
 }
 
 /// Called from Java/Kotlin when you receive a location update.
 #[no_mangle]
 pub extern "C" fn gs26_android_location_on_update(lat: f64, lon: f64) {
+    LATEST_FIX.set(Fix { lat, lon });
     if let Some(sig) = GPS_SIGNAL.get() {
         sig.set(Some((lat, lon)));
     }
 }
+
+/// [`LocationProvider`] wrapper around this module's JNI bridge, for callers that want the
+/// platform-neutral trait instead of `start`'s `Signal`-coupled API.
+pub struct AndroidLocationProvider;
+
+impl LocationProvider for AndroidLocationProvider {
+    fn start(&self) {
+        unsafe { gs26_android_location_start() };
+    }
+
+    fn stop(&self) {
+        unsafe { gs26_android_location_stop() };
+    }
+
+    fn latest_fix(&self) -> Option<Fix> {
+        LATEST_FIX.get()
+    }
+}
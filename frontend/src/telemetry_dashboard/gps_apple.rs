@@ -1,12 +1,16 @@
+use crate::telemetry_dashboard::location_provider::{Fix, FixCell, LocationProvider};
 use dioxus_signals::{Signal, WritableExt};
 
 unsafe extern "C" {
     fn gs26_location_start(cb: extern "C" fn(f64, f64));
+    fn gs26_location_stop();
 }
 
 static mut GPS_SIGNAL: Option<Signal<Option<(f64, f64)>>> = None;
+static LATEST_FIX: FixCell = FixCell::new();
 
 extern "C" fn on_loc(lat: f64, lon: f64) {
+    LATEST_FIX.set(Fix { lat, lon });
     unsafe {
         if let Some(mut sig) = GPS_SIGNAL {
             sig.set(Some((lat, lon)));
@@ -20,3 +24,21 @@ pub fn start(user_gps: Signal<Option<(f64, f64)>>) {
         gs26_location_start(on_loc);
     }
 }
+
+/// [`LocationProvider`] wrapper around this module's CoreLocation shim, for callers that want
+/// the platform-neutral trait instead of `start`'s `Signal`-coupled API.
+pub struct AppleLocationProvider;
+
+impl LocationProvider for AppleLocationProvider {
+    fn start(&self) {
+        unsafe { gs26_location_start(on_loc) };
+    }
+
+    fn stop(&self) {
+        unsafe { gs26_location_stop() };
+    }
+
+    fn latest_fix(&self) -> Option<Fix> {
+        LATEST_FIX.get()
+    }
+}
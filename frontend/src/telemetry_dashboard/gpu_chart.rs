@@ -0,0 +1,423 @@
+// frontend/src/telemetry_dashboard/gpu_chart.rs
+//
+// GPU-accelerated sibling to `canvas_chart.rs`'s `CanvasChart`: instead of rebuilding an SVG
+// `d=` string or replaying `move_to`/`line_to` into a 2D context every frame, this uploads each
+// channel's mapped (x, y) points straight into a persistent `wgpu::Buffer` and draws them as a
+// `LineStrip`. The points arrive already mapped to pixels, so although bucket values freeze
+// (see `CachedChart` in `data_chart.rs`), a sliding window or a changed Y range moves every
+// vertex; a frame re-uploads from the first vertex that differs from the previous upload, which
+// is only the tail when nothing before it moved.
+//
+// Opt-in behind the `gpu_chart` feature: wgpu's browser-surface setup is async and heavier than
+// the other two renderers, so the SVG `path` (and `CanvasChart`) stay the default in `DataTab`.
+
+use dioxus::prelude::*;
+use dioxus_signals::{ReadableExt, Signal};
+
+use super::data_chart;
+
+#[component]
+pub fn GpuLineChart(data_type: String, width: f32, height: f32, tick: Signal<u64>) -> Element {
+    let canvas_id = format!("gpu-chart-canvas-{}", data_type.to_lowercase().replace(['_', ' '], "-"));
+
+    {
+        let canvas_id = canvas_id.clone();
+        use_effect(use_reactive(
+            (&data_type, &width, &height, &*tick.read()),
+            move |(data_type, width, height, _tick)| {
+                let (points, _y_min, _y_max, _span_min) =
+                    data_chart::charts_cache_get_points(&data_type, width, height);
+                render_frame(&canvas_id, width, height, &points);
+            },
+        ));
+    }
+
+    rsx! {
+        canvas {
+            id: "{canvas_id}",
+            width: "{width}",
+            height: "{height}",
+            style: "width:100%; height:auto; display:block; background:#020617; border-radius:10px; border:1px solid #1f2937;",
+        }
+    }
+}
+
+#[cfg(not(feature = "gpu_chart"))]
+fn render_frame(_canvas_id: &str, _width: f32, _height: f32, _points: &[Vec<(f32, f32)>; 8]) {}
+
+#[cfg(all(feature = "gpu_chart", target_arch = "wasm32"))]
+fn render_frame(canvas_id: &str, width: f32, height: f32, points: &[Vec<(f32, f32)>; 8]) {
+    let canvas_id = canvas_id.to_string();
+    let points = points.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        gpu::paint(&canvas_id, width, height, &points).await;
+    });
+}
+
+#[cfg(all(feature = "gpu_chart", not(target_arch = "wasm32")))]
+fn render_frame(_canvas_id: &str, _width: f32, _height: f32, _points: &[Vec<(f32, f32)>; 8]) {}
+
+#[cfg(all(feature = "gpu_chart", target_arch = "wasm32"))]
+mod gpu {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    use super::super::data_chart::series_color;
+    use wasm_bindgen::JsCast;
+    use web_sys::HtmlCanvasElement;
+
+    const SHADER_SRC: &str = r#"
+struct Uniforms {
+    width: f32,
+    height: f32,
+    _pad0: f32,
+    _pad1: f32,
+};
+@group(0) @binding(0) var<uniform> u: Uniforms;
+
+struct PushConstants {
+    color: vec4<f32>,
+};
+var<push_constant> pc: PushConstants;
+
+@vertex
+fn vs_main(@location(0) position: vec2<f32>) -> @builtin(position) vec4<f32> {
+    let x_clip = (position.x / u.width) * 2.0 - 1.0;
+    let y_clip = 1.0 - (position.y / u.height) * 2.0;
+    return vec4<f32>(x_clip, y_clip, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return pc.color;
+}
+"#;
+
+    const VERTEX_SIZE: u64 = 8; // two f32s, matches `@location(0) position: vec2<f32>`
+
+    /// Per-channel GPU-resident vertex buffer. Sized generously on (re)creation so most frames
+    /// write into it instead of reallocating — `len` is the count actually drawn this frame,
+    /// which can be smaller than `capacity`.
+    struct ChannelBuffer {
+        buf: wgpu::Buffer,
+        capacity: usize,
+        len: usize,
+        /// The points the buffer currently holds, to find the unchanged prefix next frame.
+        uploaded: Vec<(f32, f32)>,
+    }
+
+    struct GpuState {
+        surface: wgpu::Surface<'static>,
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        pipeline: wgpu::RenderPipeline,
+        bind_group: wgpu::BindGroup,
+        uniform_buf: wgpu::Buffer,
+        config: wgpu::SurfaceConfiguration,
+        channels: [Option<ChannelBuffer>; 8],
+    }
+
+    thread_local! {
+        static STATES: RefCell<HashMap<String, GpuState>> = RefCell::new(HashMap::new());
+    }
+
+    fn pack_vertices(pts: &[(f32, f32)]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(pts.len() * VERTEX_SIZE as usize);
+        for (x, y) in pts {
+            out.extend_from_slice(&x.to_le_bytes());
+            out.extend_from_slice(&y.to_le_bytes());
+        }
+        out
+    }
+
+    fn pack_uniforms(width: f32, height: f32) -> [u8; 16] {
+        let mut out = [0u8; 16];
+        out[0..4].copy_from_slice(&width.to_le_bytes());
+        out[4..8].copy_from_slice(&height.to_le_bytes());
+        out
+    }
+
+    fn hex_to_rgba(hex: &str) -> [f32; 4] {
+        let hex = hex.trim_start_matches('#');
+        let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(255) as f32 / 255.0;
+        let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(255) as f32 / 255.0;
+        let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(255) as f32 / 255.0;
+        [r, g, b, 1.0]
+    }
+
+    async fn ensure_state(canvas_id: &str, width: f32, height: f32) {
+        let already_present = STATES.with(|s| s.borrow().contains_key(canvas_id));
+        if already_present {
+            return;
+        }
+
+        let Some(window) = web_sys::window() else { return };
+        let Some(document) = window.document() else { return };
+        let Some(el) = document.get_element_by_id(canvas_id) else { return };
+        let Ok(canvas) = el.dyn_into::<HtmlCanvasElement>() else { return };
+
+        let instance = wgpu::Instance::default();
+        let Ok(surface) = instance.create_surface(wgpu::SurfaceTarget::Canvas(canvas)) else { return };
+        let Some(adapter) = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::LowPower,
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: false,
+            })
+            .await
+        else {
+            return;
+        };
+
+        let Ok((device, queue)) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("gpu_chart_device"),
+                    required_features: wgpu::Features::PUSH_CONSTANTS,
+                    required_limits: wgpu::Limits {
+                        max_push_constant_size: 16,
+                        ..wgpu::Limits::downlevel_webgl2_defaults()
+                    },
+                    ..Default::default()
+                },
+                None,
+            )
+            .await
+        else {
+            return;
+        };
+
+        let format = surface
+            .get_capabilities(&adapter)
+            .formats
+            .first()
+            .copied()
+            .unwrap_or(wgpu::TextureFormat::Bgra8Unorm);
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format,
+            width: width.max(1.0) as u32,
+            height: height.max(1.0) as u32,
+            present_mode: wgpu::PresentMode::Fifo,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        surface.configure(&device, &config);
+
+        let uniform_buf = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("gpu_chart_uniforms"),
+            size: 16,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("gpu_chart_bgl"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("gpu_chart_bg"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buf.as_entire_binding(),
+            }],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("gpu_chart_shader"),
+            source: wgpu::ShaderSource::Wgsl(SHADER_SRC.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("gpu_chart_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[wgpu::PushConstantRange {
+                stages: wgpu::ShaderStages::FRAGMENT,
+                range: 0..16,
+            }],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("gpu_chart_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: VERTEX_SIZE,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &[wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x2,
+                        offset: 0,
+                        shader_location: 0,
+                    }],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::LineStrip,
+                strip_index_format: None,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        STATES.with(|s| {
+            s.borrow_mut().insert(
+                canvas_id.to_string(),
+                GpuState {
+                    surface,
+                    device,
+                    queue,
+                    pipeline,
+                    bind_group,
+                    uniform_buf,
+                    config,
+                    channels: std::array::from_fn(|_| None),
+                },
+            );
+        });
+    }
+
+    /// Upload this frame's points and draw. Only the tail of a channel's buffer is rewritten
+    /// when the new run is just the old one plus freshly-live samples — matching
+    /// `CachedChart`'s historical-bucket-is-frozen contract one layer down, at the vertex level.
+    pub async fn paint(canvas_id: &str, width: f32, height: f32, points: &[Vec<(f32, f32)>; 8]) {
+        ensure_state(canvas_id, width, height).await;
+
+        STATES.with(|s| {
+            let mut states = s.borrow_mut();
+            let Some(state) = states.get_mut(canvas_id) else { return };
+
+            let w = width.max(1.0) as u32;
+            let h = height.max(1.0) as u32;
+            if state.config.width != w || state.config.height != h {
+                state.config.width = w;
+                state.config.height = h;
+                state.surface.configure(&state.device, &state.config);
+            }
+            state
+                .queue
+                .write_buffer(&state.uniform_buf, 0, &pack_uniforms(width, height));
+
+            for (ch, pts) in points.iter().enumerate() {
+                let slot = &mut state.channels[ch];
+                if pts.len() < 2 {
+                    if let Some(buf) = slot {
+                        buf.len = 0;
+                    }
+                    continue;
+                }
+
+                let needs_alloc = match slot {
+                    Some(buf) => buf.capacity < pts.len(),
+                    None => true,
+                };
+                if needs_alloc {
+                    let capacity = (pts.len() * 2).max(256);
+                    *slot = Some(ChannelBuffer {
+                        buf: state.device.create_buffer(&wgpu::BufferDescriptor {
+                            label: Some("gpu_chart_vertices"),
+                            size: capacity as u64 * VERTEX_SIZE,
+                            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                            mapped_at_creation: false,
+                        }),
+                        capacity,
+                        len: 0,
+                        uploaded: Vec::new(),
+                    });
+                }
+                let buf = slot.as_mut().unwrap();
+
+                // Re-write from the first point that differs from the last upload; everything
+                // before it is already in the buffer.
+                let unchanged_prefix =
+                    buf.uploaded.iter().zip(pts.iter()).take_while(|(a, b)| a == b).count();
+                let fresh = &pts[unchanged_prefix..];
+                if !fresh.is_empty() {
+                    state
+                        .queue
+                        .write_buffer(&buf.buf, unchanged_prefix as u64 * VERTEX_SIZE, &pack_vertices(fresh));
+                }
+                buf.len = pts.len();
+                buf.uploaded.clone_from(pts);
+            }
+
+            let Ok(frame) = state.surface.get_current_texture() else { return };
+            let view = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let mut encoder = state
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("gpu_chart_encoder") });
+            {
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("gpu_chart_pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color { r: 0.008, g: 0.012, b: 0.024, a: 1.0 }),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                pass.set_pipeline(&state.pipeline);
+                pass.set_bind_group(0, &state.bind_group, &[]);
+                for (ch, slot) in state.channels.iter().enumerate() {
+                    let Some(buf) = slot else { continue };
+                    if buf.len < 2 {
+                        continue;
+                    }
+                    pass.set_push_constants(wgpu::ShaderStages::FRAGMENT, 0, bytes_of_rgba(hex_to_rgba(series_color(ch))));
+                    pass.set_vertex_buffer(0, buf.buf.slice(0..buf.len as u64 * VERTEX_SIZE));
+                    pass.draw(0..buf.len as u32, 0..1);
+                }
+            }
+            state.queue.submit(Some(encoder.finish()));
+            frame.present();
+        });
+    }
+
+    fn bytes_of_rgba(rgba: [f32; 4]) -> &'static [u8] {
+        // Push constant data must outlive the `set_push_constants` call; stash it in a
+        // thread-local scratch buffer sized for exactly one RGBA quad instead of leaking.
+        thread_local! {
+            static SCRATCH: RefCell<[u8; 16]> = const { RefCell::new([0u8; 16]) };
+        }
+        SCRATCH.with(|s| {
+            let mut buf = s.borrow_mut();
+            for (i, c) in rgba.iter().enumerate() {
+                buf[i * 4..i * 4 + 4].copy_from_slice(&c.to_le_bytes());
+            }
+            // SAFETY: `buf` is a 'static thread-local; the returned slice is only read
+            // synchronously by `set_push_constants` before this function is called again.
+            unsafe { std::slice::from_raw_parts(buf.as_ptr(), buf.len()) }
+        })
+    }
+}
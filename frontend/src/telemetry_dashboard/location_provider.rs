@@ -0,0 +1,61 @@
+// frontend/src/telemetry_dashboard/location_provider.rs
+//! One trait over the platform location backends (CoreLocation on Apple, GeoClue over D-Bus on
+//! Linux, `Windows.Devices.Geolocation` on Windows, the JNI bridge on Android), each implemented
+//! by its `gps_*` module, plus a no-op stub. There is no wasm32 implementation, and
+//! `gps::start_gps_updates` does not go through this trait: it does its own
+//! `cfg(target_os = ...)` dispatch to each module's `Signal`-based `start`.
+
+use std::sync::{Mutex, OnceLock};
+
+/// A single GPS fix, as reported by whichever platform backend is active.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fix {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+pub trait LocationProvider {
+    /// Begins watching for location updates. Backends that are already running should treat a
+    /// repeat call as a no-op, same as `gps::start_gps_updates`'s own `STARTED` guard.
+    fn start(&self);
+    /// Stops watching for updates. A no-op if `start` was never called.
+    fn stop(&self);
+    /// The most recent fix this backend has observed, if any.
+    fn latest_fix(&self) -> Option<Fix>;
+}
+
+/// Backing store for [`LocationProvider::latest_fix`] — each platform module owns one of these
+/// as a `static` and writes into it from whatever callback its native side invokes, so
+/// `latest_fix()` has something to read back without the platform FFI boundary needing to know
+/// about `Fix` or `Mutex` itself.
+pub(crate) struct FixCell(OnceLock<Mutex<Option<Fix>>>);
+
+impl FixCell {
+    pub(crate) const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    fn cell(&self) -> &Mutex<Option<Fix>> {
+        self.0.get_or_init(|| Mutex::new(None))
+    }
+
+    pub(crate) fn set(&self, fix: Fix) {
+        *self.cell().lock().unwrap() = Some(fix);
+    }
+
+    pub(crate) fn get(&self) -> Option<Fix> {
+        *self.cell().lock().unwrap()
+    }
+}
+
+/// For targets with no real location backend — always reports no fix, same as `gps.rs`'s
+/// pre-existing no-op `imp` module.
+pub struct NoopLocationProvider;
+
+impl LocationProvider for NoopLocationProvider {
+    fn start(&self) {}
+    fn stop(&self) {}
+    fn latest_fix(&self) -> Option<Fix> {
+        None
+    }
+}
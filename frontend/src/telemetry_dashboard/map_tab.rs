@@ -18,9 +18,18 @@
 //        - /web/ground_map.js (or bundle it into the page)
 //   2) ground_map.js must attach functions to `window`:
 //        window.initGroundMap = ...
-//        window.updateGroundMapMarkers = ...
+//        window.updateGroundMapMarkers = (rLat, rLon, uLat, uLon, uAccuracyM, uHeadingDeg) => ...
+//          uAccuracyM is NaN when unknown; draw an L.circle of that radius around the user marker.
+//          uHeadingDeg is NaN unless the browser reported a non-null speed; rotate an arrow marker
+//          to that heading when finite.
 //        window.centerGroundMapOn = ...
 //        window.getLastUserLatLng = ...
+//        window.getGroundMapView = () => ({lat, lon, zoom}) — current Leaflet center/zoom,
+//          kept fresh by binding it to the map's `moveend` event. We poll this so the route
+//          (and thus a refresh or shared link) tracks wherever the operator pans/zooms to.
+//        window.setGroundMapView = (lat, lon, zoom) => ... — pan/zoom to an exact view, used
+//          when the operator picks a saved launch-site preset (unlike `centerGroundMapOn`,
+//          which only pans, this also sets the zoom).
 //
 // Note:
 //   - This file also starts a browser-style watchPosition inside the webview on native.
@@ -32,6 +41,30 @@ use crate::telemetry_dashboard::UrlConfig;
 // #[cfg(target_arch = "wasm32")]
 // use gloo_timers::future::TimeoutFuture;
 
+/// Max buffered-but-undrained geolocation fixes before the oldest are dropped.
+const GEO_QUEUE_CAP: usize = 2048;
+
+/// Starting view when neither the route nor a saved preset says otherwise.
+const DEFAULT_CENTER: (f64, f64) = (31.0, -99.0);
+const DEFAULT_ZOOM: f64 = 7.0;
+
+/// localStorage keys for the saved launch-site presets (JSON array) and the name of
+/// whichever one was last selected.
+const LAUNCH_SITES_KEY: &str = "gs26_launch_sites";
+const LAST_SITE_KEY: &str = "gs26_last_launch_site";
+
+/// A saved map view an operator can jump back to — typically a launch or recovery site.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct LaunchSite {
+    name: String,
+    lat: f64,
+    lon: f64,
+    zoom: f64,
+    #[serde(default)]
+    #[allow(dead_code)]
+    tiles_base: Option<String>,
+}
+
 fn tiles_url() -> String {
     let base = UrlConfig::_get_base_url().unwrap_or_else(|| "http://localhost:3000".to_string());
     format!("{}/tiles/{{z}}/{{x}}/{{y}}.jpg", base.trim_end_matches('/'))
@@ -41,14 +74,77 @@ fn tiles_url() -> String {
 pub fn MapTab(
     rocket_gps: Signal<Option<(f64, f64)>>,
     user_gps: Signal<Option<(f64, f64)>>,
+    // Initial camera from the route's `lat`/`lon`/`zoom` query params, so a refresh or a
+    // shared link reopens on the same pan/zoom instead of `DEFAULT_CENTER`/`DEFAULT_ZOOM`.
+    #[props(default)] route_lat: Option<f64>,
+    #[props(default)] route_lon: Option<f64>,
+    #[props(default)] route_zoom: Option<f64>,
+    // The dashboard owns this signal; we write the live Leaflet center/zoom into it so it
+    // can mirror the camera back into the route (same pattern as `rocket_gps`/`user_gps`).
+    #[props(default)] camera_out: Option<Signal<Option<(f64, f64, f64)>>>,
 ) -> Element {
+    let route_has_view = route_lat.is_some() || route_lon.is_some();
+    let init_lat = route_lat.unwrap_or(DEFAULT_CENTER.0);
+    let init_lon = route_lon.unwrap_or(DEFAULT_CENTER.1);
+    let init_zoom = route_zoom.unwrap_or(DEFAULT_ZOOM);
+
     // Browser-derived location (from navigator.geolocation inside the webview/page)
     let browser_user_gps = use_signal(|| None::<(f64, f64)>);
+    // Full coords (accuracy/altitude/heading/speed) for the same fixes, kept as a parallel
+    // signal so `user_gps`/`browser_user_gps` (threaded from the dashboard down to every
+    // GPS backend) don't have to widen just to feed the map's accuracy circle + heading arrow.
+    let browser_user_fix = use_signal(|| None::<UserFix>);
     let has_centered_on_user = use_signal(|| false);
 
+    // Saved launch-site presets (name, lat, lon, zoom), loaded from localStorage on mount.
+    let launch_sites = use_signal(Vec::<LaunchSite>::new);
+    let selected_site = use_signal(|| None::<String>);
+    // Set once we've either found a saved preset to seed from or given up and fall back to
+    // `DEFAULT_CENTER`/`DEFAULT_ZOOM`, so the init effect below knows it's safe to proceed.
+    let resolved_initial_view = use_signal(|| None::<(f64, f64, f64)>);
+
+    // --- 0) Load saved presets, and resolve the view to open the map at ---
+    //
+    // Route query params win (a shared link should open exactly where it points); otherwise
+    // fall back to the last-used saved launch site; otherwise the compile-time default.
+    {
+        let mut launch_sites = launch_sites;
+        let mut selected_site = selected_site;
+        let mut resolved_initial_view = resolved_initial_view;
+        use_effect(move || {
+            spawn(async move {
+                let sites = js_read_launch_sites().await;
+                launch_sites.set(sites.clone());
+
+                let view = if route_has_view {
+                    (init_lat, init_lon, init_zoom)
+                } else if let Some(name) = js_read_last_site_name().await
+                    && let Some(site) = sites.iter().find(|s| s.name == name)
+                {
+                    selected_site.set(Some(site.name.clone()));
+                    (site.lat, site.lon, site.zoom)
+                } else {
+                    (init_lat, init_lon, init_zoom)
+                };
+                resolved_initial_view.set(Some(view));
+            });
+        });
+    }
+
     // --- 1) Ensure map + geolocation watch is started (idempotent on JS side) ---
     use_effect(move || {
         spawn(async move {
+            // Wait for the preset lookup above to resolve before we even know where to open.
+            let (lat, lon, zoom) = loop {
+                if let Some(view) = *resolved_initial_view.read() {
+                    break view;
+                }
+                #[cfg(target_arch = "wasm32")]
+                gloo_timers::future::TimeoutFuture::new(20).await;
+                #[cfg(not(target_arch = "wasm32"))]
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            };
+
             // Retry for ~5 seconds (or whatever you want)
             for _ in 0..100 {
                 // This is safe to run repeatedly.
@@ -59,7 +155,7 @@ pub fn MapTab(
                   try {{
                     if (window.__gs26_ground_station_loaded === true &&
                         typeof window.initGroundMap === "function") {{
-                      window.initGroundMap({tiles:?}, 31.0, -99.0, 7.0);
+                      window.initGroundMap({tiles:?}, {lat}, {lon}, {zoom});
                       // once this succeeds, your JS initGroundMap() already guards duplicates
                       return;
                     }}
@@ -69,6 +165,9 @@ pub fn MapTab(
                 }})();
                 "#,
                     tiles = tiles_url(),
+                    lat = lat,
+                    lon = lon,
+                    zoom = zoom,
                 ));
 
                 // Yield so scripts can load / event loop can run
@@ -88,104 +187,109 @@ pub fn MapTab(
         "#,
             );
 
-            // Now start geolocation watch (this is also idempotent on your JS side)
-            js_eval(
+            // Now start geolocation watch (this is also idempotent on your JS side).
+            //
+            // Every fix is pushed onto a bounded queue instead of clobbering a single
+            // window var, so fixes that arrive between Rust ticks aren't lost.
+            js_eval(&format!(
                 r#"
-          (function() {
+          (function() {{
             if (window.__gs26_geo_watch_started) return;
             window.__gs26_geo_watch_started = true;
             if (!navigator || !navigator.geolocation) return;
 
-            try {
+            window.__gs26_geo_queue = [];
+            window.__gs26_geo_seq = 0;
+
+            try {{
               navigator.geolocation.watchPosition(
-                (pos) => {
+                (pos) => {{
                   const c = pos.coords;
-                  window.__gs26_user_lat = c.latitude;
-                  window.__gs26_user_lon = c.longitude;
-                },
+                  const q = window.__gs26_geo_queue;
+                  q.push({{
+                    seq: ++window.__gs26_geo_seq,
+                    lat: c.latitude,
+                    lon: c.longitude,
+                    ts: pos.timestamp,
+                    accuracy: c.accuracy,
+                    altitude: c.altitude,
+                    altitudeAccuracy: c.altitudeAccuracy,
+                    heading: c.heading,
+                    speed: c.speed,
+                  }});
+                  if (q.length > {cap}) {{
+                    q.splice(0, q.length - {cap});
+                  }}
+                }},
                 (err) => console.warn("geolocation watch error:", err),
-                { enableHighAccuracy: true, maximumAge: 1000, timeout: 10000 }
+                {{ enableHighAccuracy: true, maximumAge: 0, timeout: 10000 }}
               );
-            } catch (e) {}
-          })();
+            }} catch (e) {{}}
+          }})();
         "#,
-            );
+                cap = GEO_QUEUE_CAP,
+            ));
         });
     });
 
-    // --- 2) Hydrate browser_user_gps once from JS cache/window vars (no Rust<->JS type bindings) ---
+    // --- 2) Hydrate browser_user_gps once from JS cache/window vars ---
     {
         let mut browser_user_gps = browser_user_gps;
         let mut has_centered_on_user = has_centered_on_user;
         use_effect(move || {
-            // First try getLastUserLatLng (your helper), else window vars.
-            if let Some((lat, lon)) = js_cached_user_latlon() {
-                browser_user_gps.set(Some((lat, lon)));
-                if !*has_centered_on_user.read() {
-                    js_center_on(lat, lon);
-                    has_centered_on_user.set(true);
-                }
-            } else if let Some((lat, lon)) = js_read_user_latlon_from_window() {
-                browser_user_gps.set(Some((lat, lon)));
-                if !*has_centered_on_user.read() {
-                    js_center_on(lat, lon);
-                    has_centered_on_user.set(true);
+            spawn(async move {
+                if let Some((lat, lon)) = js_cached_user_latlon().await {
+                    browser_user_gps.set(Some((lat, lon)));
+                    if !*has_centered_on_user.read() {
+                        js_center_on(lat, lon);
+                        has_centered_on_user.set(true);
+                    }
                 }
-            }
+            });
         });
     }
 
-    // --- 3) Poll window vars at 10 Hz and update browser_user_gps ---
+    // --- 3) Drain the geolocation fix queue at 10 Hz and update browser_user_gps ---
     //
-    // Why poll? It avoids any web_sys Position types, and works the same in wasm + native webview.
+    // We drain (not peek) the whole queue every tick, applying fixes in `seq` order, so a
+    // burst of fixes between ticks still lands in order instead of only the latest surviving.
     {
         let mut browser_user_gps = browser_user_gps;
+        let mut browser_user_fix = browser_user_fix;
         let mut has_centered_on_user = has_centered_on_user;
+        let mut launch_sites = launch_sites;
 
         use_effect(move || {
-            // install a single interval (JS-side guard)
-            js_eval(
-                r#"
-                (function() {
-                  if (window.__gs26_geo_poll_started) return;
-                  window.__gs26_geo_poll_started = true;
-
-                  window.__gs26_geo_poll_tick = function() {
-                    // no-op; Rust will read window vars
-                  };
-
-                  setInterval(() => {
-                    try { window.__gs26_geo_poll_tick(); } catch (e) {}
-                  }, 100);
-                })();
-                "#,
-            );
-
-            // On every tick, we read from window vars from Rust side by re-running this effect
-            // when any captured signals change — BUT we want periodic updates.
-            //
-            // Dioxus effects are not time-based. So we do *native* interval for native,
-            // and `setInterval`-driven "poke" is not visible to Rust.
-            //
-            // Solution: use a Dioxus interval on the Rust side.
-            //
-            // Dioxus 0.7 provides `use_future` + timers via `gloo_timers` on wasm,
-            // and tokio on native. The simplest cross-platform: spawn a task that loops.
             spawn(async move {
                 loop {
-                    // ~10 Hz
-                    #[cfg(target_arch = "wasm32")]
-                    use gloo_timers::future::TimeoutFuture;
-
                     #[cfg(target_arch = "wasm32")]
-                    TimeoutFuture::new(500).await;
+                    gloo_timers::future::TimeoutFuture::new(100).await;
                     #[cfg(not(target_arch = "wasm32"))]
-                    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-                    if let Some((lat, lon)) = js_read_user_latlon_from_window() {
-                        browser_user_gps.set(Some((lat, lon)));
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+                    let fixes = js_drain_geo_queue().await;
+                    for fix in fixes {
+                        browser_user_gps.set(Some((fix.lat, fix.lon)));
+                        browser_user_fix.set(Some(UserFix::from(&fix)));
                         if !*has_centered_on_user.read() {
-                            js_center_on(lat, lon);
+                            js_center_on(fix.lat, fix.lon);
                             has_centered_on_user.set(true);
+
+                            // No saved sites yet: the very first fix we ever see becomes the
+                            // "Home" preset, so the app opens at the right field next time
+                            // without anyone having to hunt for a "save site" button first.
+                            if launch_sites.read().is_empty() {
+                                let home = LaunchSite {
+                                    name: "Home".to_string(),
+                                    lat: fix.lat,
+                                    lon: fix.lon,
+                                    zoom: DEFAULT_ZOOM,
+                                    tiles_base: None,
+                                };
+                                launch_sites.set(vec![home.clone()]);
+                                js_write_launch_sites(&[home]);
+                                js_write_last_site_name("Home");
+                            }
                         }
                     }
                 }
@@ -207,11 +311,43 @@ pub fn MapTab(
         use_effect(move || {
             let r = rocket_gps.read().clone();
             let u = effective_user();
+            let u_fix = browser_user_fix.read().clone();
 
             let (r_lat, r_lon) = r.unwrap_or((f64::NAN, f64::NAN));
             let (u_lat, u_lon) = u.unwrap_or((f64::NAN, f64::NAN));
+            let u_accuracy = u_fix.as_ref().map(|f| f.accuracy).unwrap_or(f64::NAN);
+            // Only show a heading arrow once the browser reports the user is actually moving.
+            let u_heading = u_fix
+                .as_ref()
+                .filter(|f| f.speed.is_some())
+                .and_then(|f| f.heading)
+                .unwrap_or(f64::NAN);
+
+            js_update_markers(r_lat, r_lon, u_lat, u_lon, u_accuracy, u_heading);
+        });
+    }
+
+    // --- 5) Poll the live Leaflet camera and mirror it out to the route ---
+    //
+    // Polled rather than event-driven because the JS side only promises `getGroundMapView`
+    // stays fresh (bound to `moveend`); round-tripping through it at 2 Hz is cheap and avoids
+    // needing a second JS->Rust callback channel just for this.
+    if let Some(mut camera_out) = camera_out {
+        use_effect(move || {
+            spawn(async move {
+                loop {
+                    #[cfg(target_arch = "wasm32")]
+                    gloo_timers::future::TimeoutFuture::new(500).await;
+                    #[cfg(not(target_arch = "wasm32"))]
+                    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
 
-            js_update_markers(r_lat, r_lon, u_lat, u_lon);
+                    if let Some(view) = js_read_ground_map_view().await {
+                        if *camera_out.read() != Some(view) {
+                            camera_out.set(Some(view));
+                        }
+                    }
+                }
+            });
         });
     }
 
@@ -223,6 +359,47 @@ pub fn MapTab(
         }
     };
 
+    let mut selected_site_for_select = selected_site;
+    let on_select_site = move |evt: FormEvent| {
+        let name = evt.value();
+        if name.is_empty() {
+            return;
+        }
+        if let Some(site) = launch_sites.read().iter().find(|s| s.name == name).cloned() {
+            js_set_view(site.lat, site.lon, site.zoom);
+            js_write_last_site_name(&site.name);
+            selected_site_for_select.set(Some(site.name));
+        }
+    };
+
+    let mut launch_sites_for_save = launch_sites;
+    let mut selected_site_for_save = selected_site;
+    let on_save_site = move |_| {
+        spawn(async move {
+            let Some(name) = js_prompt("Name this launch site:").await else {
+                return;
+            };
+            let name = name.trim().to_string();
+            if name.is_empty() {
+                return;
+            }
+            let Some((lat, lon, zoom)) = js_read_ground_map_view().await else {
+                return;
+            };
+
+            let mut sites = launch_sites_for_save.read().clone();
+            let new_site = LaunchSite { name: name.clone(), lat, lon, zoom, tiles_base: None };
+            match sites.iter_mut().find(|s| s.name == name) {
+                Some(existing) => *existing = new_site,
+                None => sites.push(new_site),
+            }
+            js_write_launch_sites(&sites);
+            js_write_last_site_name(&name);
+            launch_sites_for_save.set(sites);
+            selected_site_for_save.set(Some(name));
+        });
+    };
+
     rsx! {
         div {
             style: "display:flex; flex-direction:column; gap:12px; width:100%; padding:12px; border-radius:12px; background:#020617ee; border:1px solid #4b5563; box-shadow:0 10px 25px rgba(0,0,0,0.45);",
@@ -234,6 +411,20 @@ pub fn MapTab(
                     onclick: on_center_me,
                     "Center on Me"
                 }
+                select {
+                    style: "padding:6px 10px; border-radius:999px; border:1px solid #4b5563; background:#0b1220; color:#e5e7eb; font-size:0.85rem;",
+                    value: "{selected_site.read().clone().unwrap_or_default()}",
+                    onchange: on_select_site,
+                    option { value: "", disabled: true, "Launch sites…" }
+                    for site in launch_sites.read().clone() {
+                        option { value: "{site.name.clone()}", "{site.name}" }
+                    }
+                }
+                button {
+                    style: "padding:6px 12px; border-radius:999px; border:1px solid #4b5563; background:#111827; color:#e5e7eb; font-size:0.85rem; cursor:pointer;",
+                    onclick: on_save_site,
+                    "Save current view"
+                }
             }
 
             div {
@@ -251,13 +442,13 @@ pub fn MapTab(
  * JS bridge helpers (no wasm-bindgen imports)
  * ============================================================================================== */
 
-fn js_update_markers(r_lat: f64, r_lon: f64, u_lat: f64, u_lon: f64) {
+fn js_update_markers(r_lat: f64, r_lon: f64, u_lat: f64, u_lon: f64, u_accuracy: f64, u_heading: f64) {
     js_eval(&format!(
         r#"
         (function() {{
           try {{
             if (typeof window.updateGroundMapMarkers === "function") {{
-              window.updateGroundMapMarkers({r_lat}, {r_lon}, {u_lat}, {u_lon});
+              window.updateGroundMapMarkers({r_lat}, {r_lon}, {u_lat}, {u_lon}, {u_accuracy}, {u_heading});
             }} else {{
               console.warn("updateGroundMapMarkers not found on window");
             }}
@@ -269,7 +460,9 @@ fn js_update_markers(r_lat: f64, r_lon: f64, u_lat: f64, u_lon: f64) {
         r_lat = r_lat,
         r_lon = r_lon,
         u_lat = u_lat,
-        u_lon = u_lon
+        u_lon = u_lon,
+        u_accuracy = u_accuracy,
+        u_heading = u_heading,
     ));
 }
 
@@ -293,30 +486,24 @@ fn js_center_on(lat: f64, lon: f64) {
     ));
 }
 
-fn js_cached_user_latlon() -> Option<(f64, f64)> {
-    // Ask JS for getLastUserLatLng() and return JSON via a temporary window var.
-    // We avoid JS<->Rust typed bindings by doing: window.__gs26_tmp = JSON.stringify(...)
-    js_eval(
+async fn js_cached_user_latlon() -> Option<(f64, f64)> {
+    // Ask JS for getLastUserLatLng() and get the JSON back over the bidirectional eval
+    // channel, which works identically on web and the desktop/iOS webview.
+    let s = js_read_string(
         r#"
-        (function() {
-          try {
-            if (typeof window.getLastUserLatLng === "function") {
-              const v = window.getLastUserLatLng();
-              window.__gs26_tmp_latlng = v ? JSON.stringify(v) : "";
-            } else {
-              window.__gs26_tmp_latlng = "";
-            }
-          } catch (e) {
-            window.__gs26_tmp_latlng = "";
+        try {
+          if (typeof window.getLastUserLatLng === "function") {
+            const v = window.getLastUserLatLng();
+            dioxus.send(v ? JSON.stringify(v) : null);
+          } else {
+            dioxus.send(null);
           }
-        })();
+        } catch (e) {
+          dioxus.send(null);
+        }
         "#,
-    );
-
-    let s = js_read_window_string("__gs26_tmp_latlng")?;
-    if s.is_empty() {
-        return None;
-    }
+    )
+    .await?;
 
     // Parse {lat,lon}
     let v: serde_json::Value = serde_json::from_str(&s).ok()?;
@@ -325,54 +512,175 @@ fn js_cached_user_latlon() -> Option<(f64, f64)> {
     Some((lat, lon))
 }
 
-fn js_read_user_latlon_from_window() -> Option<(f64, f64)> {
-    let lat = js_read_window_f64("__gs26_user_lat")?;
-    let lon = js_read_window_f64("__gs26_user_lon")?;
-    Some((lat, lon))
+/// One buffered geolocation fix, as pushed by the `watchPosition` success callback.
+#[derive(serde::Deserialize)]
+struct GeoFix {
+    #[allow(dead_code)]
+    seq: u64,
+    lat: f64,
+    lon: f64,
+    #[allow(dead_code)]
+    ts: f64,
+    accuracy: f64,
+    altitude: Option<f64>,
+    #[serde(rename = "altitudeAccuracy")]
+    altitude_accuracy: Option<f64>,
+    heading: Option<f64>,
+    speed: Option<f64>,
+}
+
+/// Full `GeolocationCoordinates` for a user fix, kept alongside the plain `(lat, lon)`
+/// signal so the map can draw an accuracy circle and a heading arrow.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct UserFix {
+    accuracy: f64,
+    #[allow(dead_code)]
+    altitude: Option<f64>,
+    #[allow(dead_code)]
+    altitude_accuracy: Option<f64>,
+    heading: Option<f64>,
+    speed: Option<f64>,
 }
 
-fn js_read_window_f64(key: &str) -> Option<f64> {
+impl From<&GeoFix> for UserFix {
+    fn from(fix: &GeoFix) -> Self {
+        UserFix {
+            accuracy: fix.accuracy,
+            altitude: fix.altitude,
+            altitude_accuracy: fix.altitude_accuracy,
+            heading: fix.heading,
+            speed: fix.speed,
+        }
+    }
+}
+
+/// Drain the entire `__gs26_geo_queue` buffer, oldest-first, and clear it.
+///
+/// Draining rather than peeking means fixes that land between ticks are never skipped:
+/// the queue only ever grows between drains, and is capped (oldest dropped) so a stalled
+/// tab can't grow it unbounded.
+async fn js_drain_geo_queue() -> Vec<GeoFix> {
+    let s = js_read_string(
+        r#"
+        const q = window.__gs26_geo_queue || [];
+        window.__gs26_geo_queue = [];
+        dioxus.send(JSON.stringify(q));
+        "#,
+    )
+    .await
+    .unwrap_or_default();
+
+    serde_json::from_str(&s).unwrap_or_default()
+}
+
+fn js_set_view(lat: f64, lon: f64, zoom: f64) {
     js_eval(&format!(
         r#"
         (function() {{
           try {{
-            const v = window[{key:?}];
-            window.__gs26_tmp_num = (typeof v === "number") ? String(v) : "";
+            if (typeof window.setGroundMapView === "function") {{
+              window.setGroundMapView({lat}, {lon}, {zoom});
+            }} else {{
+              console.warn("setGroundMapView not found on window");
+            }}
           }} catch (e) {{
-            window.__gs26_tmp_num = "";
+            console.warn("setGroundMapView threw:", e);
           }}
         }})();
         "#,
-        key = key
+        lat = lat,
+        lon = lon,
+        zoom = zoom,
     ));
-    let s = js_read_window_string("__gs26_tmp_num")?;
-    if s.is_empty() {
-        None
-    } else {
-        s.parse::<f64>().ok()
-    }
 }
 
-fn js_read_window_string(key: &str) -> Option<String> {
+/// Ask the operator for a name via `window.prompt`, over the bidirectional eval channel.
+async fn js_prompt(message: &str) -> Option<String> {
+    js_read_string(&format!(
+        r#"
+        try {{
+          dioxus.send(window.prompt({message:?}));
+        }} catch (e) {{
+          dioxus.send(null);
+        }}
+        "#,
+        message = message,
+    ))
+    .await
+}
+
+async fn js_read_launch_sites() -> Vec<LaunchSite> {
+    let s = js_read_string(&format!(
+        r#"
+        try {{
+          dioxus.send(localStorage.getItem({key:?}));
+        }} catch (e) {{
+          dioxus.send(null);
+        }}
+        "#,
+        key = LAUNCH_SITES_KEY,
+    ))
+    .await;
+
+    s.and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default()
+}
+
+fn js_write_launch_sites(sites: &[LaunchSite]) {
+    let json = serde_json::to_string(sites).unwrap_or_else(|_| "[]".to_string());
     js_eval(&format!(
+        r#"try {{ localStorage.setItem({key:?}, {json:?}); }} catch (e) {{}}"#,
+        key = LAUNCH_SITES_KEY,
+        json = json,
+    ));
+}
+
+async fn js_read_last_site_name() -> Option<String> {
+    js_read_string(&format!(
         r#"
-        (function() {{
-          try {{
-            const v = window[{key:?}];
-            window.__gs26_tmp_str = (typeof v === "string") ? v : "";
-          }} catch (e) {{
-            window.__gs26_tmp_str = "";
-          }}
-        }})();
+        try {{
+          dioxus.send(localStorage.getItem({key:?}));
+        }} catch (e) {{
+          dioxus.send(null);
+        }}
         "#,
-        key = key
+        key = LAST_SITE_KEY,
+    ))
+    .await
+}
+
+fn js_write_last_site_name(name: &str) {
+    js_eval(&format!(
+        r#"try {{ localStorage.setItem({key:?}, {name:?}); }} catch (e) {{}}"#,
+        key = LAST_SITE_KEY,
+        name = name,
     ));
+}
+
+async fn js_read_ground_map_view() -> Option<(f64, f64, f64)> {
+    let s = js_read_string(
+        r#"
+        try {
+          if (typeof window.getGroundMapView === "function") {
+            dioxus.send(JSON.stringify(window.getGroundMapView()));
+          } else {
+            dioxus.send(null);
+          }
+        } catch (e) {
+          dioxus.send(null);
+        }
+        "#,
+    )
+    .await?;
 
-    js_get_tmp_str()
+    let v: serde_json::Value = serde_json::from_str(&s).ok()?;
+    let lat = v.get("lat")?.as_f64()?;
+    let lon = v.get("lon")?.as_f64()?;
+    let zoom = v.get("zoom")?.as_f64()?;
+    Some((lat, lon, zoom))
 }
 
 /* ================================================================================================
- * Cross-platform "eval JS"
+ * Cross-platform "eval JS" with a return channel
  * ============================================================================================== */
 
 #[cfg(target_arch = "wasm32")]
@@ -382,50 +690,16 @@ fn js_eval(js: &str) {
 
 #[cfg(not(target_arch = "wasm32"))]
 fn js_eval(js: &str) {
-    // Works on desktop + iOS because you’re running via dioxus-desktop (tao/wry webview).
-    // If your renderer changes, this is the one function you’ll adjust.
-    // use dioxus_desktop::use_window;
-
-    // NOTE: hooks can't be called here; but use_window() is a hook.
-    // So: we avoid calling it here directly.
-    //
-    // Instead we stash the JS into a global queue and have a component effect flush it.
-    // To keep this file "complete" and working without more plumbing, we implement a
-    // minimal global "last script" mechanism and execute it from an effect inside MapTab.
-    //
-    // HOWEVER: MapTab already calls js_eval from effects/tasks, so we need a direct eval.
-    //
-    // If your dioxus-desktop version exposes a non-hook global eval, use it.
-    // Most builds expose `dioxus_desktop::window()` OR you can do this:
-    //
-    //   let window = dioxus_desktop::use_window();
-    //   window.eval(js);
-    //
-    // But `use_window()` is a hook and must be called in the component body.
-    //
-    // ✅ So on native we rely on `document::eval`, which dioxus-desktop provides.
-    // If you don’t have it, replace this with a hook-based `let window = use_window(); window.eval(...)`
-    // by moving js_eval calls into closures that capture `window`.
+    // Works on desktop + iOS because we're running via dioxus-desktop (tao/wry webview).
     dioxus::document::eval(js);
 }
 
-#[cfg(target_arch = "wasm32")]
-fn js_get_tmp_str() -> Option<String> {
-    let win = web_sys::window()?;
-    let v = js_sys::Reflect::get(&win, &wasm_bindgen::JsValue::from_str("__gs26_tmp_str")).ok()?;
-    v.as_string()
-}
-
-#[cfg(not(target_arch = "wasm32"))]
-fn js_get_tmp_str() -> Option<String> {
-    // On native we can still read window.__gs26_tmp_str by asking JS to copy it to a known place
-    // and then returning it isn't directly possible without a return channel.
-    //
-    // The simplest: avoid relying on return values for native by using only window vars.
-    //
-    // For cached user lat/lon we already set window.__gs26_user_lat/lon from localStorage in JS,
-    // so native can skip parsing JSON here.
-    //
-    // Therefore, for native we just return None, and the caller will fall back to window vars.
-    None
+/// Evaluate `js` and await a value the script sends back via `dioxus.send(...)`.
+///
+/// This uses Dioxus's bidirectional eval handle, which gives native (desktop/iOS wry)
+/// a real return channel instead of round-tripping through `window.__gs26_tmp_*` vars,
+/// so it behaves identically on web and native.
+async fn js_read_string(js: &str) -> Option<String> {
+    let mut eval = dioxus::document::eval(js);
+    eval.recv::<Option<String>>().await.ok().flatten()
 }
@@ -1,48 +1,108 @@
 // frontend/src/telemetry_dashboard/mod.rs
 
 mod actions_tab;
+mod annotations;
+mod axis;
+mod canvas_chart;
 mod chart;
+mod data_chart;
 pub mod data_tab;
+mod downsample;
+mod dynamic_rows;
 pub mod errors_tab;
+#[cfg(not(target_arch = "wasm32"))]
+mod flight_recorder;
 mod gps;
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+mod gps_apple;
+#[cfg(target_os = "android")]
+mod gps_android;
+#[cfg(all(
+    target_os = "linux",
+    not(target_arch = "wasm32")
+))]
+mod gps_linux;
+#[cfg(target_os = "windows")]
+mod gps_windows;
+mod location_provider;
+#[cfg(feature = "gpu_chart")]
+mod gpu_chart;
 pub mod map_tab;
+mod operator;
+mod recording;
+mod session;
+mod stats;
 pub mod state_tab;
+mod theme;
 pub mod warnings_tab;
 
+use crate::app::Route;
 use crate::telemetry_dashboard::actions_tab::ActionsTab;
 use data_tab::DataTab;
 use dioxus::prelude::*;
+use dioxus_router::use_navigator;
 use dioxus_signals::Signal;
 use errors_tab::ErrorsTab;
-use groundstation_shared::{FlightState, TelemetryRow};
+use groundstation_shared::{AnnotationOp, FlightState, TelemetryRow};
 use map_tab::MapTab;
-use serde::Deserialize;
+use operator::{OPERATOR, Role};
+use serde::{Deserialize, Serialize};
+use session::{
+    LIVE_SESSION_KEY, PlaybackState, ReplayClock, Session, SessionKind, ACTIVE_SESSION_ID,
+    REPLAY_CLOCKS, SESSIONS,
+};
 use state_tab::StateTab;
 use warnings_tab::WarningsTab;
 
 // Matches your existing schema. (ty + data)
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(tag = "ty", content = "data")]
 enum WsInMsg {
     Telemetry(TelemetryRow),
+    /// Several samples coalesced into one frame — no current encoder emits this (the tagged
+    /// binary frames send one `TelemetryRow` per frame, see `decode_bin_frame`), but dispatch
+    /// still handles it so a future batching encoder has somewhere to land.
+    TelemetryBatch(Vec<TelemetryRow>),
+    /// Untyped telemetry for a sensor the fixed `TelemetryRow` shape doesn't have a column
+    /// for yet — flattened and stored separately, see `dynamic_rows`.
+    Dynamic(serde_json::Value),
     FlightState(FlightStateMsg),
     Warning(AlertMsg),
     Error(AlertMsg),
+    Ack(AckMsg),
+    /// The backend's per-client broadcast forwarder lagged and had to drop `dropped` frames
+    /// after `last_seen_ts` — there's no recovering those particular frames, but `/api/history`
+    /// still has them, so `dispatch_ws_msg` patches the gap with a re-fetch instead of leaving
+    /// a silent hole in `rows`.
+    Resync(ResyncMsg),
+    /// A CRDT op relayed from another dashboard — merged into `annotations::ANNOTATIONS`.
+    Annotation(AnnotationOp),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+struct ResyncMsg {
+    dropped: u64,
+    last_seen_ts: i64,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 struct FlightStateMsg {
     state: FlightState,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
+struct AckMsg {
+    seq: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct AlertMsg {
     pub timestamp_ms: i64,
     pub message: String,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
-enum MainTab {
+pub(crate) enum MainTab {
     State,
     Map,
     Actions,
@@ -61,7 +121,6 @@ macro_rules! log {
 pub const HISTORY_MS: i64 = 60_000 * 20; // 20 minutes
 const _WARNING_ACK_STORAGE_KEY: &str = "gs_last_warning_ack_ts";
 const _ERROR_ACK_STORAGE_KEY: &str = "gs_last_error_ack_ts";
-const _MAIN_TAB_STORAGE_KEY: &str = "gs_main_tab";
 const _DATA_TAB_STORAGE_KEY: &str = "gs_data_tab";
 
 // --------------------------
@@ -85,14 +144,14 @@ fn storage_set_i64(key: &str, val: i64) {
 }
 
 #[cfg(target_arch = "wasm32")]
-fn storage_get_string(key: &str) -> Option<String> {
+pub(crate) fn storage_get_string(key: &str) -> Option<String> {
     let window = web_sys::window()?;
     let storage = window.local_storage().ok().flatten()?;
     storage.get_item(key).ok().flatten()
 }
 
 #[cfg(target_arch = "wasm32")]
-fn storage_set_string(key: &str, val: &str) {
+pub(crate) fn storage_set_string(key: &str, val: &str) {
     if let Some(window) = web_sys::window()
         && let Ok(Some(storage)) = window.local_storage()
     {
@@ -100,7 +159,7 @@ fn storage_set_string(key: &str, val: &str) {
     }
 }
 
-fn _main_tab_to_str(tab: MainTab) -> &'static str {
+pub(crate) fn main_tab_to_str(tab: MainTab) -> &'static str {
     match tab {
         MainTab::State => "state",
         MainTab::Map => "map",
@@ -111,7 +170,7 @@ fn _main_tab_to_str(tab: MainTab) -> &'static str {
     }
 }
 
-fn _main_tab_from_str(s: &str) -> MainTab {
+pub(crate) fn main_tab_from_str(s: &str) -> MainTab {
     match s {
         "state" => MainTab::State,
         "map" => MainTab::Map,
@@ -179,11 +238,31 @@ struct WsSender {
 
     #[cfg(not(target_arch = "wasm32"))]
     tx: tokio::sync::mpsc::UnboundedSender<String>,
+
+    /// Native only: a true WS `Ping` control frame can't travel over `tx` (that channel only
+    /// ever carries `Message::Text`), so it gets its own channel into the writer task. Browsers
+    /// can't send control frames at all — `send_ping` sends an app-level text frame instead.
+    #[cfg(not(target_arch = "wasm32"))]
+    ping_tx: tokio::sync::mpsc::UnboundedSender<()>,
 }
 
+/// The keepalive's app-level ping/pong, for links (wasm) that can't use real WS control
+/// frames. Matched as a literal since it carries no payload worth parsing.
+const WS_APP_PING: &str = r#"{"type":"ping"}"#;
+const WS_APP_PONG: &str = r#"{"type":"pong"}"#;
+
 impl WsSender {
-    fn send_cmd(&self, cmd: &str) {
-        let msg = format!(r#"{{"cmd":"{}"}}"#, cmd);
+    /// Send a command stamped with its delivery `seq` and the current operator's id, so the
+    /// backend can enforce the same role allow-list `send_cmd` already gated on. Raw — does
+    /// not track acks; callers go through `send_cmd`/the retransmit supervisor for that.
+    fn send_raw(&self, cmd: &str, seq: u64) {
+        let operator = OPERATOR.read().clone();
+        let operator_id = operator.as_ref().map(|op| op.id.clone()).unwrap_or_default();
+        let operator_id = serde_json::to_string(&operator_id).unwrap_or_else(|_| "\"\"".to_string());
+        let operator_role = operator.as_ref().map(|op| op.role.as_str()).unwrap_or("observer");
+        let msg = format!(
+            r#"{{"cmd":"{cmd}","seq":{seq},"operator_id":{operator_id},"operator_role":"{operator_role}"}}"#
+        );
 
         #[cfg(target_arch = "wasm32")]
         {
@@ -195,13 +274,163 @@ impl WsSender {
             let _ = self.tx.send(msg);
         }
     }
+
+    /// Relay a locally-applied annotation CRDT op to every other connected dashboard — see
+    /// `WsInbound::Annotation` on the backend. Fire-and-forget, like every other op here; a
+    /// dropped send is not retried, and nothing re-sends it on reconnect.
+    fn send_annotation(&self, op: &AnnotationOp) {
+        let msg = serde_json::json!({ "annotation": op }).to_string();
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = self.ws.send_with_str(&msg);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = self.tx.send(msg);
+        }
+    }
+
+    /// Probe the link: a real WS `Ping` on native, an app-level `WS_APP_PING` text frame on
+    /// wasm (where the browser API has no control-frame send).
+    fn send_ping(&self) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = self.ws.send_with_str(WS_APP_PING);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = self.ping_tx.send(());
+        }
+    }
 }
 
 static WS_SENDER: GlobalSignal<Option<WsSender>> = Signal::global(|| None::<WsSender>);
 
-// ---------- Public root component ----------
+// ---------- Keepalive + stale-link detection ----------
+//
+// `LAST_FRAME_MS` is bumped on every inbound frame (data or pong) — `LINK_STATUS` is the
+// coarser signal the UI actually renders, derived from it alongside `CONN_STATE`: a socket
+// can be `Open` yet `Stale` if nothing has arrived in `LINK_STALE_TIMEOUT_MS`, which is the
+// case the raw socket state can't see by itself.
+const PING_INTERVAL_MS: u64 = 5_000;
+const LINK_STALE_TIMEOUT_MS: i64 = PING_INTERVAL_MS as i64 * 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LinkStatus {
+    Connected { last_seen_ms: i64 },
+    Stale { last_seen_ms: i64 },
+    Reconnecting { last_seen_ms: i64 },
+}
+
+static LAST_FRAME_MS: GlobalSignal<i64> = Signal::global(|| 0);
+static LINK_STATUS: GlobalSignal<LinkStatus> =
+    Signal::global(|| LinkStatus::Reconnecting { last_seen_ms: 0 });
+
+fn note_frame_received() {
+    let now = now_ms();
+    *LAST_FRAME_MS.write() = now;
+    if matches!(*CONN_STATE.read(), ConnState::Open) {
+        *LINK_STATUS.write() = LinkStatus::Connected { last_seen_ms: now };
+    }
+}
+
+/// Runs alongside the message-receive loop for the lifetime of one socket: pings on
+/// `PING_INTERVAL_MS`, and if nothing — not even a pong — has arrived inside
+/// `LINK_STALE_TIMEOUT_MS`, flags the link `Stale`, surfaces a warning, and proactively ends
+/// the socket so `connect_ws_supervisor` dials a fresh one rather than waiting on a link that
+/// may be half-open.
+async fn run_keepalive(warnings: Signal<Vec<AlertMsg>>) -> String {
+    loop {
+        #[cfg(target_arch = "wasm32")]
+        gloo_timers::future::TimeoutFuture::new(PING_INTERVAL_MS as u32).await;
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::time::sleep(std::time::Duration::from_millis(PING_INTERVAL_MS)).await;
+
+        let Some(sender) = WS_SENDER.read().clone() else {
+            return "no sender".to_string();
+        };
+        sender.send_ping();
+        stats::tick(PING_INTERVAL_MS);
+
+        let idle_ms = now_ms() - *LAST_FRAME_MS.read();
+        if idle_ms >= LINK_STALE_TIMEOUT_MS {
+            *LINK_STATUS.write() = LinkStatus::Stale { last_seen_ms: *LAST_FRAME_MS.read() };
+            push_link_alert(warnings, "Telemetry link stale — no data or pong received");
+            return "stale link".to_string();
+        }
+    }
+}
+
+// ---------- Reliable command delivery (seq + ack + retransmit) ----------
+//
+// `send_cmd` is used for things like ABORT, which must not be fire-and-forget: every
+// outgoing command gets a monotonic `seq`, is tracked in `PENDING_CMDS` until the backend
+// acks it, and a supervisor (alongside the flash loop, in `TelemetryDashboard`) retransmits
+// anything still pending past `CMD_ACK_TIMEOUT_MS`, giving up after `CMD_MAX_RETRIES`.
+const CMD_ACK_TIMEOUT_MS: i64 = 800;
+const CMD_MAX_RETRIES: u32 = 5;
+
+#[derive(Clone)]
+struct PendingCmd {
+    cmd: String,
+    sent_at_ms: i64,
+    retries: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CmdState {
+    Sent,
+    Acked,
+    Failed,
+    Refused,
+}
+
+static CMD_SEQ: GlobalSignal<u64> = Signal::global(|| 0);
+static PENDING_CMDS: GlobalSignal<std::collections::HashMap<u64, PendingCmd>> =
+    Signal::global(std::collections::HashMap::new);
+/// The most recently sent command and its delivery state, for the ABORT button to surface.
+static LAST_CMD_STATUS: GlobalSignal<Option<(String, CmdState)>> = Signal::global(|| None);
+
+/// Per-command delivery status for `ActionsTab` to render under each button, rather than only
+/// the single most-recent command via `LAST_CMD_STATUS`. While a command is still outstanding
+/// this comes from `PENDING_CMDS` (so the retry count updates live); once it's settled it falls
+/// back to `LAST_CMD_STATUS` if that was the last command sent with this name.
+pub(crate) fn cmd_button_status(cmd: &str) -> Option<(CmdState, u32)> {
+    if let Some(pending) = PENDING_CMDS.read().values().find(|p| p.cmd == cmd) {
+        return Some((CmdState::Sent, pending.retries));
+    }
+    LAST_CMD_STATUS
+        .read()
+        .clone()
+        .and_then(|(c, state)| (c == cmd).then_some((state, 0)))
+}
+
+fn now_ms() -> i64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now() as i64
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or(0)
+    }
+}
+
+/// `route_tab`/`route_map_view` mirror the `tab`/`lat`/`lon`/`zoom` query params on
+/// `Route::Dashboard` — the URL, not `localStorage`, is now the source of truth for which
+/// tab and map camera reopen on refresh or a shared link.
 #[component]
-pub fn TelemetryDashboard() -> Element {
+pub fn TelemetryDashboard(
+    #[props(default)] route_tab: Option<String>,
+    #[props(default)] route_map_view: (Option<f64>, Option<f64>, Option<f64>),
+) -> Element {
     // data
     let rows = use_signal(Vec::<TelemetryRow>::new);
     let active_data_tab = use_signal(|| "GYRO_DATA".to_string());
@@ -211,8 +440,12 @@ pub fn TelemetryDashboard() -> Element {
 
     let flight_state = use_signal(|| FlightState::Startup);
 
-    // main tabs
-    let active_main_tab = use_signal(|| MainTab::State);
+    // main tabs: initialized from the route, kept in sync with it below so browser
+    // back/forward and shared links still work.
+    let active_main_tab =
+        use_signal(|| route_tab.as_deref().map(main_tab_from_str).unwrap_or(MainTab::State));
+
+    let nav = use_navigator();
 
     // ack timestamps
     let ack_warning_ts = use_signal(|| 0_i64);
@@ -224,6 +457,25 @@ pub fn TelemetryDashboard() -> Element {
     // gps extracted from telemetry rows
     let rocket_gps = use_signal(|| None::<(f64, f64)>);
     let user_gps = use_signal(|| None::<(f64, f64)>);
+
+    // Operator identity: restored from localStorage on web, asked for via the setup modal
+    // below otherwise (native always starts unidentified).
+    #[cfg(target_arch = "wasm32")]
+    use_effect(|| operator::restore_persisted_operator());
+
+    // Abort's two-step confirmation: the header button only opens this, `send_cmd("Abort")`
+    // fires once `abort_confirm_text` matches.
+    let mut show_abort_confirm = use_signal(|| false);
+    let mut abort_confirm_text = use_signal(String::new);
+    const ABORT_CONFIRM_PHRASE: &str = "ABORT";
+
+    // Recordings available to replay, refreshed once on mount.
+    let mut recording_ids = use_signal(Vec::<String>::new);
+    use_effect(move || {
+        spawn(async move {
+            recording_ids.set(recording::list_sessions().await);
+        });
+    });
     use_effect({
         let user_gps = user_gps.clone();
         move || {
@@ -231,11 +483,78 @@ pub fn TelemetryDashboard() -> Element {
         }
     });
 
+    // ----------------------------------------
+    // Register the live session — the `Session` whose signals are the ones above, keyed
+    // under `LIVE_SESSION_KEY` so the replay switcher (below) and the tab-mounting match
+    // at the bottom of this component can address it the same way as any replay session.
+    // ----------------------------------------
+    use_effect(move || {
+        SESSIONS
+            .write()
+            .entry(LIVE_SESSION_KEY.to_string())
+            .or_insert(Session {
+                kind: SessionKind::Live,
+                rows,
+                warnings,
+                errors,
+                flight_state,
+                rocket_gps,
+            });
+    });
+
+    // Keep `active_main_tab` synced to the route (e.g. the user hits back/forward).
+    {
+        let mut active_main_tab = active_main_tab;
+        use_effect(use_reactive((&route_tab.clone(),), move |(route_tab,)| {
+            let tab = route_tab.as_deref().map(main_tab_from_str).unwrap_or(MainTab::State);
+            if tab != *active_main_tab.read() {
+                active_main_tab.set(tab);
+            }
+        }));
+    }
+
+    // Navigate to `tab`, replacing the current map camera query params only when staying
+    // on the map (so switching away from the map doesn't forget its last view).
+    let goto_tab = move |tab: MainTab| {
+        let (lat, lon, zoom) = if tab == MainTab::Map {
+            route_map_view
+        } else {
+            (None, None, None)
+        };
+        let _ = nav.push(Route::Dashboard {
+            tab: Some(main_tab_to_str(tab).to_string()),
+            lat,
+            lon,
+            zoom,
+        });
+    };
+
+    // `MapTab` writes its Leaflet center/zoom here as the user pans (same pattern as
+    // `rocket_gps`/`user_gps` below — a signal the child owns writes-to, not a callback
+    // prop). We mirror it into the route with `replace` (not `push`) so panning doesn't
+    // flood browser history with one entry per `moveend`.
+    let map_camera = use_signal(|| None::<(f64, f64, f64)>);
+    {
+        use_effect(move || {
+            if let Some((lat, lon, zoom)) = *map_camera.read() {
+                let _ = nav.replace(Route::Dashboard {
+                    tab: Some(main_tab_to_str(MainTab::Map).to_string()),
+                    lat: Some(lat),
+                    lon: Some(lon),
+                    zoom: Some(zoom),
+                });
+            }
+        });
+    }
+
     // ----------------------------------------
     // Web-only: restore persisted UI state
     // ----------------------------------------
     #[cfg(target_arch = "wasm32")]
     {
+        // restore theme
+        use_effect(|| theme::restore_persisted_theme());
+
         // restore ack timestamps
         {
             let mut ack_warning_ts = ack_warning_ts;
@@ -250,25 +569,6 @@ pub fn TelemetryDashboard() -> Element {
             });
         }
 
-        // restore active main tab
-        {
-            let mut active_main_tab = active_main_tab;
-            use_effect(move || {
-                if let Some(s) = storage_get_string(_MAIN_TAB_STORAGE_KEY) {
-                    active_main_tab.set(_main_tab_from_str(&s));
-                }
-            });
-        }
-
-        // persist active main tab when it changes
-        {
-            let active_main_tab = active_main_tab;
-            use_effect(move || {
-                let s = _main_tab_to_str(*active_main_tab.read());
-                storage_set_string(_MAIN_TAB_STORAGE_KEY, s);
-            });
-        }
-
         // restore inner data tab
         {
             let mut active_data_tab = active_data_tab;
@@ -314,6 +614,50 @@ pub fn TelemetryDashboard() -> Element {
         });
     }
 
+    // ----------------------------------------
+    // Command delivery supervisor: retransmit anything still un-acked past the timeout,
+    // giving up (and surfacing a failure) after `CMD_MAX_RETRIES`.
+    // ----------------------------------------
+    {
+        use_effect(move || {
+            spawn(async move {
+                loop {
+                    #[cfg(target_arch = "wasm32")]
+                    gloo_timers::future::TimeoutFuture::new(250).await;
+                    #[cfg(not(target_arch = "wasm32"))]
+                    tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+
+                    let now = now_ms();
+                    let due: Vec<(u64, PendingCmd)> = PENDING_CMDS
+                        .read()
+                        .iter()
+                        .filter(|(_, p)| now - p.sent_at_ms >= CMD_ACK_TIMEOUT_MS)
+                        .map(|(seq, p)| (*seq, p.clone()))
+                        .collect();
+
+                    for (seq, mut pending) in due {
+                        if pending.retries >= CMD_MAX_RETRIES {
+                            PENDING_CMDS.write().remove(&seq);
+                            if *CMD_SEQ.read() == seq {
+                                *LAST_CMD_STATUS.write() = Some((pending.cmd, CmdState::Failed));
+                            }
+                            continue;
+                        }
+
+                        pending.retries += 1;
+                        pending.sent_at_ms = now;
+                        let cmd = pending.cmd.clone();
+                        PENDING_CMDS.write().insert(seq, pending);
+
+                        if let Some(sender) = WS_SENDER.read().clone() {
+                            sender.send_raw(&cmd, seq);
+                        }
+                    }
+                }
+            });
+        });
+    }
+
     // ----------------------------------------
     // Derived state: counts + unacked + border
     // ----------------------------------------
@@ -340,12 +684,17 @@ pub fn TelemetryDashboard() -> Element {
     let has_unacked_warnings = latest_warning_ts > 0 && latest_warning_ts > *ack_warning_ts.read();
     let has_unacked_errors = latest_error_ts > 0 && latest_error_ts > *ack_error_ts.read();
 
+    let current_operator = OPERATOR.read().clone();
+    let current_role = current_operator.as_ref().map(|op| op.role).unwrap_or(Role::Observer);
+    let can_abort = operator::role_allows(current_role, "Abort");
+
+    let palette = theme::with_theme();
     let border_style = if has_unacked_errors && *flash_on.read() {
-        "2px solid #ef4444"
+        format!("2px solid {}", palette.err)
     } else if has_unacked_errors && has_errors {
-        "1px solid #ef4444"
+        format!("1px solid {}", palette.err)
     } else {
-        "1px solid transparent"
+        "1px solid transparent".to_string()
     };
 
     // ----------------------------------------
@@ -363,17 +712,13 @@ pub fn TelemetryDashboard() -> Element {
     }
 
     // ----------------------------------------
-    // WebSocket connect once
+    // WebSocket connect, with auto-reconnect
     // ----------------------------------------
     {
         use_effect(move || {
             spawn(async move {
-                if let Err(e) =
-                    connect_ws_loop(rows, warnings, errors, flight_state, rocket_gps, user_gps)
-                        .await
-                {
-                    log!("ws loop ended: {e:?}");
-                }
+                connect_ws_supervisor(rows, warnings, errors, flight_state, rocket_gps, user_gps)
+                    .await;
             });
         });
     }
@@ -384,13 +729,17 @@ pub fn TelemetryDashboard() -> Element {
     let tab_style_active = |color: &str| {
         format!(
             "padding:0.4rem 0.8rem; border-radius:0.5rem;\
-             border:1px solid {color}; background:#111827;\
-             color:{color}; cursor:pointer;"
+             border:1px solid {color}; background:{};\
+             color:{color}; cursor:pointer;",
+            palette.surface,
         )
     };
-    let tab_style_inactive = "padding:0.4rem 0.8rem; border-radius:0.5rem;\
-                             border:1px solid #4b5563; background:#020617;\
-                             color:#e5e7eb; cursor:pointer;";
+    let tab_style_inactive = format!(
+        "padding:0.4rem 0.8rem; border-radius:0.5rem;\
+         border:1px solid #4b5563; background:{};\
+         color:{}; cursor:pointer;",
+        palette.base, palette.text,
+    );
 
     // ----------------------------------------
     // MAIN UI (Leptos-like shell)
@@ -414,9 +763,11 @@ pub fn TelemetryDashboard() -> Element {
                 div { style: "flex:0; min-width:200px; display:flex; align-items:center; gap:10px;",
                 h1 { style: "color:#f97316; margin:0; font-size:22px; font-weight:800;", "Rocket Dashboard" }
 
-                // Always-available ABORT
+                // Always-visible, but greyed out for anyone whose role can't issue it —
+                // opens the confirm modal rather than firing straight away.
                 button {
-                    style: "
+                    style: if can_abort {
+                        "
                             padding:0.45rem 0.85rem;
                             border-radius:0.75rem;
                             border:1px solid #ef4444;
@@ -424,10 +775,44 @@ pub fn TelemetryDashboard() -> Element {
                             color:#fecaca;
                             font-weight:900;
                             cursor:pointer;
-                        ",
-                    onclick: move |_| send_cmd("Abort"),
+                        "
+                    } else {
+                        "
+                            padding:0.45rem 0.85rem;
+                            border-radius:0.75rem;
+                            border:1px solid #4b5563;
+                            background:#1f2937;
+                            color:#6b7280;
+                            font-weight:900;
+                            cursor:not-allowed;
+                        "
+                    },
+                    disabled: !can_abort,
+                    onclick: move |_| {
+                        if can_abort {
+                            abort_confirm_text.set(String::new());
+                            show_abort_confirm.set(true);
+                        }
+                    },
                     "ABORT"
                 }
+
+                if let Some((cmd, state)) = LAST_CMD_STATUS.read().clone() {
+                    span {
+                        style: match state {
+                            CmdState::Sent => "font-size:0.8rem; color:#facc15;",
+                            CmdState::Acked => "font-size:0.8rem; color:#86efac;",
+                            CmdState::Failed => "font-size:0.8rem; color:#fecaca; font-weight:700;",
+                            CmdState::Refused => "font-size:0.8rem; color:#fca5a5; font-style:italic;",
+                        },
+                        match state {
+                            CmdState::Sent => format!("{cmd}: sending…"),
+                            CmdState::Acked => format!("{cmd}: acked"),
+                            CmdState::Failed => format!("{cmd}: not acknowledged!"),
+                            CmdState::Refused => format!("{cmd}: not permitted for your role"),
+                        }
+                    }
+                }
             }
 
                 // centered nav card
@@ -443,18 +828,18 @@ pub fn TelemetryDashboard() -> Element {
 
                             button {
                                 style: if *active_main_tab.read() == MainTab::State { tab_style_active("#38bdf8") } else { tab_style_inactive.to_string() },
-                                onclick: { let mut t = active_main_tab; move |_| t.set(MainTab::State) },
+                                onclick: move |_| goto_tab(MainTab::State),
                                 "Flight"
                             }
                             button {
                                 style: if *active_main_tab.read() == MainTab::Map { tab_style_active("#22c55e") } else { tab_style_inactive.to_string() },
-                                onclick: { let mut t = active_main_tab; move |_| t.set(MainTab::Map) },
+                                onclick: move |_| goto_tab(MainTab::Map),
                                 "Map"
                             }
 
                             button {
                                 style: if *active_main_tab.read() == MainTab::Actions { tab_style_active("#a78bfa") } else { tab_style_inactive.to_string() },
-                                onclick: { let mut t = active_main_tab; move |_| t.set(MainTab::Actions) },
+                                onclick: move |_| goto_tab(MainTab::Actions),
                                 "Actions"
                             }
 
@@ -466,7 +851,7 @@ pub fn TelemetryDashboard() -> Element {
                                     // inactive, but we still show icon if warnings exist
                                     tab_style_inactive.to_string()
                                 },
-                                onclick: { let mut t = active_main_tab; move |_| t.set(MainTab::Warnings) },
+                                onclick: move |_| goto_tab(MainTab::Warnings),
                                 span { "Warnings" }
                                 if has_warnings {
                                     span {
@@ -490,7 +875,7 @@ pub fn TelemetryDashboard() -> Element {
                                 } else {
                                     tab_style_inactive.to_string()
                                 },
-                                onclick: { let mut t = active_main_tab; move |_| t.set(MainTab::Errors) },
+                                onclick: move |_| goto_tab(MainTab::Errors),
                                 span { "Errors" }
                                 if has_errors {
                                     span {
@@ -510,15 +895,21 @@ pub fn TelemetryDashboard() -> Element {
 
                             button {
                                 style: if *active_main_tab.read() == MainTab::Data { tab_style_active("#f97316") } else { tab_style_inactive.to_string() },
-                                onclick: { let mut t = active_main_tab; move |_| t.set(MainTab::Data) },
+                                onclick: move |_| goto_tab(MainTab::Data),
                                 "Data"
                             }
                         }
                     }
                 }
 
-                // right spacer (keeps nav centered)
-                div { style: "flex:0; min-width:200px;" }
+                // right spacer (keeps nav centered) — also hosts the theme toggle
+                div { style: "flex:0; min-width:200px; display:flex; justify-content:flex-end;",
+                    button {
+                        style: "padding:0.4rem 0.8rem; border-radius:0.5rem; border:1px solid #4b5563; background:{palette.surface}; color:{palette.text}; cursor:pointer;",
+                        onclick: move |_| theme::set_theme(theme::active_theme_name().other()),
+                        "{theme::active_theme_name().other().label()}"
+                    }
+                }
             }
 
             // Status pill row
@@ -530,22 +921,50 @@ pub fn TelemetryDashboard() -> Element {
                 ",
                     span { style: "color:#9ca3af;", "Status:" }
 
+                    {
+                        let (label, color) = match *CONN_STATE.read() {
+                            ConnState::Connecting => ("Connecting…".to_string(), "#facc15"),
+                            ConnState::Open => ("Link up".to_string(), "#86efac"),
+                            ConnState::Backoff { next_retry_ms } => {
+                                (format!("Reconnecting in {next_retry_ms}ms…"), "#fb923c")
+                            }
+                            ConnState::Closed => ("Link down".to_string(), "#fecaca"),
+                        };
+                        rsx! {
+                            span { style: "color:{color}; font-size:0.8rem;", "{label}" }
+                        }
+                    }
+
+                    {
+                        let (label, color) = match *LINK_STATUS.read() {
+                            LinkStatus::Connected { .. } => ("Data fresh".to_string(), "#86efac"),
+                            LinkStatus::Stale { last_seen_ms } => {
+                                let idle_s = (now_ms() - last_seen_ms).max(0) / 1000;
+                                (format!("Stale ({idle_s}s)"), "#fb923c")
+                            }
+                            LinkStatus::Reconnecting { .. } => ("No data".to_string(), "#fecaca"),
+                        };
+                        rsx! {
+                            span { style: "color:{color}; font-size:0.8rem;", "{label}" }
+                        }
+                    }
+
                     if !has_warnings && !has_errors {
-                        span { style: "color:#22c55e; font-weight:600;", "Nominal" }
-                        span { style: "color:#93c5fd; margin-left:0.75rem;",
+                        span { style: "color:{palette.ok}; font-weight:600;", "Nominal" }
+                        span { style: "color:{palette.info}; margin-left:0.75rem;",
                             "(Flight state: ",
                             "{flight_state.read().to_string()}",
                             ")"
                         }
                     } else {
                         if has_errors {
-                            span { style: "color:#fecaca;", {format!("{err_count} error(s)")} }
+                            span { style: "color:{palette.err}", {format!("{err_count} error(s)")} }
                         }
                         if has_warnings {
-                            span { style: "color:#fecaca;", {format!("{warn_count} warnings(s)")} }
+                            span { style: "color:{palette.warn}", {format!("{warn_count} warnings(s)")} }
                         }
 
-                        span { style: "color:#93c5fd; margin-left:0.75rem;",
+                        span { style: "color:{palette.info}; margin-left:0.75rem;",
                             "(Flight state: ",
                             "{flight_state.read().to_string()}",
                             ")"
@@ -605,39 +1024,357 @@ pub fn TelemetryDashboard() -> Element {
                 }
             }
 
-            // Main body
-            div { style: "flex:1; min-height:0;",
-                match *active_main_tab.read() {
-                    MainTab::State => rsx! {
-                        StateTab { flight_state: flight_state }
-                    },
-                    MainTab::Map => rsx! {
-                        MapTab { rocket_gps: rocket_gps, user_gps: user_gps }
-                    },
-                    MainTab::Actions => rsx! {
-                        ActionsTab {}
-                    },
-                    MainTab::Warnings => rsx! {
-                        WarningsTab { warnings: warnings }
+            // Sessions: switch between the live feed and any recordings opened for replay.
+            div { style: "margin-bottom:12px; display:flex; gap:8px; align-items:center; flex-wrap:wrap;",
+                span { style: "color:#9ca3af; font-size:0.8rem;", "Session:" }
+
+                button {
+                    style: if *ACTIVE_SESSION_ID.read() == LIVE_SESSION_KEY {
+                        "padding:0.25rem 0.7rem; border-radius:999px; border:1px solid #22c55e; background:#052e16; color:#86efac; font-size:0.75rem; cursor:pointer;"
+                    } else {
+                        "padding:0.25rem 0.7rem; border-radius:999px; border:1px solid #4b5563; background:#111827; color:#9ca3af; font-size:0.75rem; cursor:pointer;"
                     },
-                    MainTab::Errors => rsx! {
-                        ErrorsTab { errors: errors }
+                    onclick: move |_| *ACTIVE_SESSION_ID.write() = LIVE_SESSION_KEY.to_string(),
+                    "LIVE"
+                }
+
+                for id in recording_ids.read().iter() {
+                    button {
+                        style: if *ACTIVE_SESSION_ID.read() == *id {
+                            "padding:0.25rem 0.7rem; border-radius:999px; border:1px solid #60a5fa; background:#0b2a55; color:#dbeafe; font-size:0.75rem; cursor:pointer;"
+                        } else {
+                            "padding:0.25rem 0.7rem; border-radius:999px; border:1px solid #4b5563; background:#111827; color:#9ca3af; font-size:0.75rem; cursor:pointer;"
+                        },
+                        onclick: {
+                            let id = id.clone();
+                            move |_| {
+                                if SESSIONS.read().contains_key(&id) {
+                                    *ACTIVE_SESSION_ID.write() = id.clone();
+                                } else {
+                                    spawn(open_replay(id.clone()));
+                                }
+                            }
+                        },
+                        "{id}"
+                    }
+                }
+
+                if *ACTIVE_SESSION_ID.read() != LIVE_SESSION_KEY {
+                    {
+                        let id = ACTIVE_SESSION_ID.read().clone();
+                        let clock = REPLAY_CLOCKS.read().get(&id).copied();
+                        rsx! {
+                            if let Some(clock) = clock {
+                                button {
+                                    style: "padding:0.25rem 0.7rem; border-radius:999px; border:1px solid #4b5563; background:#111827; color:#e5e7eb; font-size:0.75rem; cursor:pointer;",
+                                    onclick: {
+                                        let id = id.clone();
+                                        move |_| {
+                                            if let Some(c) = REPLAY_CLOCKS.write().get_mut(&id) {
+                                                c.state = match c.state {
+                                                    PlaybackState::Playing => PlaybackState::Paused,
+                                                    PlaybackState::Paused => PlaybackState::Playing,
+                                                };
+                                            }
+                                        }
+                                    },
+                                    if clock.state == PlaybackState::Playing { "Pause" } else { "Play" }
+                                }
+
+                                span { style: "color:#9ca3af; font-size:0.75rem;",
+                                    "{clock.cursor_line}/{clock.total_lines}"
+                                }
+
+                                for mult in [0.5_f64, 1.0, 2.0, 4.0] {
+                                    button {
+                                        style: if clock.speed == mult {
+                                            "padding:0.2rem 0.5rem; border-radius:999px; border:1px solid #60a5fa; background:#0b2a55; color:#dbeafe; font-size:0.7rem; cursor:pointer;"
+                                        } else {
+                                            "padding:0.2rem 0.5rem; border-radius:999px; border:1px solid #4b5563; background:#111827; color:#9ca3af; font-size:0.7rem; cursor:pointer;"
+                                        },
+                                        onclick: {
+                                            let id = id.clone();
+                                            move |_| {
+                                                if let Some(c) = REPLAY_CLOCKS.write().get_mut(&id) {
+                                                    c.speed = mult;
+                                                }
+                                            }
+                                        },
+                                        "{mult}x"
+                                    }
+                                }
+
+                                button {
+                                    style: "padding:0.25rem 0.7rem; border-radius:999px; border:1px solid #ef4444; background:#450a0a; color:#fecaca; font-size:0.75rem; cursor:pointer;",
+                                    onclick: {
+                                        let id = id.clone();
+                                        move |_| close_replay(&id)
+                                    },
+                                    "Close"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Main body — rendered from whichever session is active (live, or a replay the
+            // operator opened from the session row above); falls back to the live signals if the session map
+            // hasn't been populated by the registration effect yet.
+            {
+                let active = SESSIONS
+                    .read()
+                    .get(&*ACTIVE_SESSION_ID.read())
+                    .copied()
+                    .unwrap_or(Session {
+                        kind: SessionKind::Live,
+                        rows,
+                        warnings,
+                        errors,
+                        flight_state,
+                        rocket_gps,
+                    });
+
+                rsx! {
+                    div { style: "flex:1; min-height:0;",
+                        match *active_main_tab.read() {
+                            MainTab::State => rsx! {
+                                StateTab { flight_state: active.flight_state }
+                            },
+                            MainTab::Map => rsx! {
+                                MapTab {
+                                    rocket_gps: active.rocket_gps,
+                                    user_gps: user_gps,
+                                    route_lat: route_map_view.0,
+                                    route_lon: route_map_view.1,
+                                    route_zoom: route_map_view.2,
+                                    camera_out: map_camera,
+                                }
+                            },
+                            MainTab::Actions => rsx! {
+                                ActionsTab {}
+                            },
+                            MainTab::Warnings => rsx! {
+                                WarningsTab { warnings: active.warnings, flight_state: active.flight_state }
+                            },
+                            MainTab::Errors => rsx! {
+                                ErrorsTab { errors: active.errors }
+                            },
+                            MainTab::Data => rsx! {
+                                DataTab {
+                                    rows: active.rows,
+                                    active_tab: active_data_tab
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            // Abort confirm: type-to-confirm rather than a bare "are you sure", since a
+            // single click/Enter is too easy to fire by accident on the one command that
+            // ends a flight.
+            if *show_abort_confirm.read() {
+                div {
+                    style: "
+                        position:fixed; inset:0; background:rgba(2,6,23,0.75);
+                        display:flex; align-items:center; justify-content:center; z-index:50;
+                    ",
+                    div { style: "
+                        background:#111827; border:1px solid #ef4444; border-radius:0.75rem;
+                        padding:1.5rem; min-width:20rem; max-width:28rem;
+                    ",
+                        h2 { style: "color:#fecaca; margin:0 0 0.5rem 0;", "Confirm ABORT" }
+                        p { style: "color:#9ca3af; font-size:0.85rem; margin:0 0 0.75rem 0;",
+                            "Type {ABORT_CONFIRM_PHRASE} to send the abort command."
+                        }
+                        input {
+                            style: "
+                                width:100%; box-sizing:border-box; padding:0.4rem 0.6rem;
+                                border-radius:0.5rem; border:1px solid #4b5563;
+                                background:#020617; color:#e5e7eb; margin-bottom:0.75rem;
+                            ",
+                            value: "{abort_confirm_text}",
+                            oninput: move |e| abort_confirm_text.set(e.value()),
+                        }
+                        div { style: "display:flex; gap:0.5rem; justify-content:flex-end;",
+                            button {
+                                style: "
+                                    padding:0.35rem 0.8rem; border-radius:0.5rem;
+                                    border:1px solid #4b5563; background:#1f2937;
+                                    color:#e5e7eb; cursor:pointer;
+                                ",
+                                onclick: move |_| show_abort_confirm.set(false),
+                                "Cancel"
+                            }
+                            button {
+                                style: if *abort_confirm_text.read() == ABORT_CONFIRM_PHRASE {
+                                    "
+                                        padding:0.35rem 0.8rem; border-radius:0.5rem;
+                                        border:1px solid #ef4444; background:#450a0a;
+                                        color:#fecaca; font-weight:700; cursor:pointer;
+                                    "
+                                } else {
+                                    "
+                                        padding:0.35rem 0.8rem; border-radius:0.5rem;
+                                        border:1px solid #4b5563; background:#1f2937;
+                                        color:#6b7280; cursor:not-allowed;
+                                    "
+                                },
+                                disabled: *abort_confirm_text.read() != ABORT_CONFIRM_PHRASE,
+                                onclick: move |_| {
+                                    if *abort_confirm_text.read() == ABORT_CONFIRM_PHRASE {
+                                        send_cmd("Abort");
+                                        show_abort_confirm.set(false);
+                                    }
+                                },
+                                "Confirm ABORT"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Operator identity: blocks nothing else (the socket connects regardless) but
+            // every gated button stays disabled — and every destructive command stays
+            // refused — until this is filled in.
+            if current_operator.is_none() {
+                OperatorSetup {}
+            }
+        }
+    }
+}
+
+/// First-run modal asking "who is operating this dashboard" — sets `operator::OPERATOR`,
+/// persisted on web so a reload doesn't ask again.
+#[component]
+fn OperatorSetup() -> Element {
+    let mut id_input = use_signal(String::new);
+    let mut role_input = use_signal(|| Role::Observer);
+
+    rsx! {
+        div {
+            style: "
+                position:fixed; inset:0; background:rgba(2,6,23,0.85);
+                display:flex; align-items:center; justify-content:center; z-index:100;
+            ",
+            div { style: "
+                background:#111827; border:1px solid #4b5563; border-radius:0.75rem;
+                padding:1.5rem; min-width:20rem; max-width:28rem;
+            ",
+                h2 { style: "color:#e5e7eb; margin:0 0 0.5rem 0;", "Identify yourself" }
+                p { style: "color:#9ca3af; font-size:0.85rem; margin:0 0 0.75rem 0;",
+                    "Your role decides which commands you're allowed to send."
+                }
+                input {
+                    style: "
+                        width:100%; box-sizing:border-box; padding:0.4rem 0.6rem;
+                        border-radius:0.5rem; border:1px solid #4b5563;
+                        background:#020617; color:#e5e7eb; margin-bottom:0.75rem;
+                    ",
+                    placeholder: "name or callsign",
+                    value: "{id_input}",
+                    oninput: move |e| id_input.set(e.value()),
+                }
+                div { style: "display:flex; gap:1rem; margin-bottom:1rem; color:#e5e7eb; font-size:0.85rem;",
+                    label { style: "display:flex; align-items:center; gap:0.35rem; cursor:pointer;",
+                        input {
+                            r#type: "radio",
+                            name: "operator_role",
+                            checked: *role_input.read() == Role::Observer,
+                            onclick: move |_| role_input.set(Role::Observer),
+                        }
+                        "Observer"
+                    }
+                    label { style: "display:flex; align-items:center; gap:0.35rem; cursor:pointer;",
+                        input {
+                            r#type: "radio",
+                            name: "operator_role",
+                            checked: *role_input.read() == Role::FlightDirector,
+                            onclick: move |_| role_input.set(Role::FlightDirector),
+                        }
+                        "Flight director"
+                    }
+                }
+                button {
+                    style: if id_input.read().trim().is_empty() {
+                        "
+                            padding:0.4rem 0.9rem; border-radius:0.5rem;
+                            border:1px solid #4b5563; background:#1f2937;
+                            color:#6b7280; cursor:not-allowed;
+                        "
+                    } else {
+                        "
+                            padding:0.4rem 0.9rem; border-radius:0.5rem;
+                            border:1px solid #38bdf8; background:#0b2a55;
+                            color:#dbeafe; cursor:pointer;
+                        "
                     },
-                    MainTab::Data => rsx! {
-                        DataTab {
-                            rows: rows,
-                            active_tab: active_data_tab
+                    disabled: id_input.read().trim().is_empty(),
+                    onclick: move |_| {
+                        let id = id_input.read().trim().to_string();
+                        if !id.is_empty() {
+                            operator::set_operator(id, *role_input.read());
                         }
                     },
+                    "Continue"
                 }
             }
         }
     }
 }
 
+/// Gate + dispatch a command. Refuses anything the current operator's role isn't on the
+/// allow-list for (client-side — `web.rs` repeats the check against the `operator_role`
+/// `send_raw` stamps on the wire; that role is self-reported by the client). No confirmation
+/// is asked here: the header's ABORT button goes through the type-to-confirm modal first, and
+/// `ActionsTab` asks for a second click on any control that needs confirmation.
 fn send_cmd(cmd: &str) {
+    let role = OPERATOR.read().as_ref().map(|op| op.role).unwrap_or(Role::Observer);
+    if !operator::role_allows(role, cmd) {
+        *LAST_CMD_STATUS.write() = Some((cmd.to_string(), CmdState::Refused));
+        return;
+    }
+
+    let seq = {
+        let mut seq = CMD_SEQ.write();
+        *seq += 1;
+        *seq
+    };
+    PENDING_CMDS.write().insert(
+        seq,
+        PendingCmd {
+            cmd: cmd.to_string(),
+            sent_at_ms: now_ms(),
+            retries: 0,
+        },
+    );
+    *LAST_CMD_STATUS.write() = Some((cmd.to_string(), CmdState::Sent));
+
+    // While reconnecting there's no live socket to write to — leave it in `PENDING_CMDS`
+    // and let the retransmit supervisor flush it once the socket reopens, rather than
+    // dropping it on the floor.
+    if *CONN_STATE.read() == ConnState::Open
+        && let Some(sender) = WS_SENDER.read().clone()
+    {
+        sender.send_raw(cmd, seq);
+    }
+}
+
+/// Author a timeline marker and relay it to every other dashboard — unlike `send_cmd` there's
+/// no ack/retry here: a marker whose op never makes it out stays visible on this client only
+/// (later ops carry only their own marker, so they don't bring it along).
+pub(crate) fn add_annotation(timestamp_ms: i64, text: String) {
+    let op = annotations::add_annotation(timestamp_ms, text);
     if let Some(sender) = WS_SENDER.read().clone() {
-        sender.send_cmd(cmd);
+        sender.send_annotation(&op);
+    }
+}
+
+pub(crate) fn remove_annotation(id: groundstation_shared::AnnotationId) {
+    let op = annotations::remove_annotation(id);
+    if let Some(sender) = WS_SENDER.read().clone() {
+        sender.send_annotation(&op);
     }
 }
 
@@ -650,6 +1387,75 @@ fn row_to_gps(row: &TelemetryRow) -> Option<(f64, f64)> {
     Some((row.v0? as f64, row.v1? as f64))
 }
 
+/// Append `new_rows` to `rows`, then apply the time-window trim and `MAX_SAMPLES` LTTB cap
+/// once — shared by `Telemetry` (one row) and `TelemetryBatch` (many) so a batched frame
+/// doesn't pay the trim/cap cost once per row it carries.
+fn extend_telemetry_rows(
+    mut rows: Signal<Vec<TelemetryRow>>,
+    new_rows: impl IntoIterator<Item = TelemetryRow>,
+) {
+    let mut v = rows.read().clone();
+    let before = v.len();
+    v.extend(new_rows);
+    for r in &v[before..] {
+        data_chart::charts_cache_ingest_row(r);
+    }
+
+    // Time-window trim (prefer timestamp-based)
+    if let Some(last) = v.last() {
+        let cutoff = last.timestamp_ms - HISTORY_MS;
+        let split = v.partition_point(|r| r.timestamp_ms < cutoff);
+        if split > 0 {
+            v.drain(0..split);
+        }
+    }
+
+    // cheap cap as safety — LTTB per `data_type` rather than a blind stride, so a transient
+    // spike (a pressure or current reading) in any one type survives the cap instead of being
+    // stepped over; the budget is split across types by their current share of `v` so a
+    // high-rate type doesn't starve a low-rate one.
+    const MAX_SAMPLES: usize = 10_000;
+    if v.len() > MAX_SAMPLES {
+        v = lttb_cap_rows(v, MAX_SAMPLES);
+    }
+
+    rows.set(v);
+}
+
+/// First non-null `v*` field on `row` — the series LTTB treats as "the" value for `row.data_type`
+/// when deciding which rows a cap can drop without losing the type's visual envelope.
+fn primary_value(row: &TelemetryRow) -> f64 {
+    [row.v0, row.v1, row.v2, row.v3, row.v4, row.v5, row.v6, row.v7]
+        .into_iter()
+        .flatten()
+        .next()
+        .unwrap_or(0.0) as f64
+}
+
+/// Cap `rows` to at most `budget` entries by running LTTB independently per `data_type` — each
+/// type gets a share of `budget` proportional to how much of `rows` it currently holds — then
+/// merging the kept rows back in timestamp order.
+fn lttb_cap_rows(rows: Vec<TelemetryRow>, budget: usize) -> Vec<TelemetryRow> {
+    let total = rows.len();
+    let mut by_type: std::collections::HashMap<&str, Vec<usize>> = std::collections::HashMap::new();
+    for (i, row) in rows.iter().enumerate() {
+        by_type.entry(row.data_type.as_str()).or_default().push(i);
+    }
+
+    let mut keep: Vec<usize> = Vec::with_capacity(budget);
+    for indices in by_type.values() {
+        let target = ((indices.len() * budget) / total).max(2).min(indices.len());
+        let points: Vec<(i64, f64)> =
+            indices.iter().map(|&i| (rows[i].timestamp_ms, primary_value(&rows[i]))).collect();
+        for local in downsample::lttb_indices(&points, target) {
+            keep.push(indices[local]);
+        }
+    }
+
+    keep.sort_unstable();
+    keep.into_iter().map(|i| rows[i].clone()).collect()
+}
+
 // ---------- Web vs Native logging ----------
 fn log(msg: &str) {
     #[cfg(target_arch = "wasm32")]
@@ -694,29 +1500,167 @@ async fn http_get_json<T: for<'de> Deserialize<'de>>(path: &str) -> Result<T, St
         .map_err(|e| e.to_string())
 }
 
-// ---------- WebSocket loop ----------
-async fn connect_ws_loop(
+// ---------- Connection state + reconnect supervisor ----------
+//
+// `connect_ws_once` opens a single socket and resolves once it closes (cleanly or not).
+// `connect_ws_supervisor` is the long-lived loop around it: on every close it re-dials
+// with exponential backoff (floor doubling up to a cap, plus ±20% jitter so a fleet of
+// clients doesn't all retry in lockstep), similar to how a pooled client connector
+// releases its key and re-dials on failure. The backoff only resets to the floor once a
+// connection has stayed `Open` for at least `WS_STABLE_MS` — a link that flaps open/closed
+// faster than that keeps backing off instead of hammering the backend at the floor delay.
+const WS_BACKOFF_FLOOR_MS: u64 = 250;
+const WS_BACKOFF_CAP_MS: u64 = 10_000;
+const WS_STABLE_MS: i64 = 10_000;
+const WS_JITTER_FRACTION: f64 = 0.2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ConnState {
+    Connecting,
+    Open,
+    /// Was `Open` before; the socket dropped and the supervisor is waiting out `next_retry_ms`.
+    Backoff { next_retry_ms: u64 },
+    /// Never reached `Open` yet — still waiting out the very first connection attempt.
+    Closed,
+}
+
+static CONN_STATE: GlobalSignal<ConnState> = Signal::global(|| ConnState::Connecting);
+
+/// Timestamp of the most recent transition into `ConnState::Open`, so the supervisor can
+/// tell a link that merely flickered open from one that was genuinely stable.
+static OPEN_SINCE_MS: GlobalSignal<i64> = Signal::global(|| 0);
+
+/// Id the live feed is recorded under — stable for the lifetime of the dashboard (survives
+/// any number of reconnects), generated on first use so a recording can be replayed later
+/// even though nothing else names "the current flight" up front.
+static LIVE_SESSION_ID: GlobalSignal<String> = Signal::global(String::new);
+
+fn live_session_id() -> String {
+    let mut id = LIVE_SESSION_ID.write();
+    if id.is_empty() {
+        *id = format!("live-{}", now_ms());
+    }
+    id.clone()
+}
+
+/// Push a synthetic link-status notice into `warnings`, the same way a real `WsInMsg::Warning`
+/// would land via `handle_ws_message` — link flaps are operationally a warning, not a separate
+/// notification channel.
+fn push_link_alert(mut warnings: Signal<Vec<AlertMsg>>, message: &str) {
+    let mut v = warnings.read().clone();
+    v.insert(0, AlertMsg { timestamp_ms: now_ms(), message: message.to_string() });
+    if v.len() > 500 {
+        v.truncate(500);
+    }
+    warnings.set(v);
+}
+
+async fn connect_ws_supervisor(
     rows: Signal<Vec<TelemetryRow>>,
     warnings: Signal<Vec<AlertMsg>>,
     errors: Signal<Vec<AlertMsg>>,
     flight_state: Signal<FlightState>,
     rocket_gps: Signal<Option<(f64, f64)>>,
     user_gps: Signal<Option<(f64, f64)>>,
+) {
+    let mut backoff_ms = WS_BACKOFF_FLOOR_MS;
+    let mut attempt: u32 = 0;
+    // Whether the link has ever made it to `Open` at least once — lets the badge tell "still
+    // trying the very first connection" (`Closed`) apart from "was live, now reconnecting"
+    // (`Backoff`), instead of showing a reconnect countdown before there was ever a link to lose.
+    let mut ever_connected = false;
+
+    loop {
+        *CONN_STATE.write() = ConnState::Connecting;
+
+        if let Err(e) = connect_ws_once(
+            rows,
+            warnings,
+            errors,
+            flight_state,
+            rocket_gps,
+            user_gps,
+            attempt,
+        )
+        .await
+        {
+            log!("ws loop ended: {e:?}");
+        }
+
+        *WS_SENDER.write() = None;
+        *LINK_STATUS.write() = LinkStatus::Reconnecting { last_seen_ms: *LAST_FRAME_MS.read() };
+        attempt += 1;
+
+        let stayed_open = *CONN_STATE.read() == ConnState::Open;
+        if stayed_open {
+            ever_connected = true;
+            push_link_alert(warnings, "Link lost — reconnecting…");
+            if now_ms() - *OPEN_SINCE_MS.read() >= WS_STABLE_MS {
+                backoff_ms = WS_BACKOFF_FLOOR_MS;
+            }
+        }
+
+        let span_ms = (backoff_ms as f64 * WS_JITTER_FRACTION) as i64;
+        let jitter_ms = if span_ms > 0 { (now_ms() % (2 * span_ms + 1)) - span_ms } else { 0 };
+        let delay_ms = (backoff_ms as i64 + jitter_ms).max(0) as u64;
+        *CONN_STATE.write() = if ever_connected {
+            ConnState::Backoff { next_retry_ms: delay_ms }
+        } else {
+            ConnState::Closed
+        };
+
+        #[cfg(target_arch = "wasm32")]
+        gloo_timers::future::TimeoutFuture::new(delay_ms as u32).await;
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+
+        backoff_ms = (backoff_ms * 2).min(WS_BACKOFF_CAP_MS);
+    }
+}
+
+// ---------- WebSocket session (resolves once the socket closes) ----------
+async fn connect_ws_once(
+    rows: Signal<Vec<TelemetryRow>>,
+    warnings: Signal<Vec<AlertMsg>>,
+    errors: Signal<Vec<AlertMsg>>,
+    flight_state: Signal<FlightState>,
+    rocket_gps: Signal<Option<(f64, f64)>>,
+    user_gps: Signal<Option<(f64, f64)>>,
+    attempt: u32,
 ) -> Result<(), String> {
     #[cfg(target_arch = "wasm32")]
     {
         use wasm_bindgen::JsCast;
         use wasm_bindgen::closure::Closure;
-        use web_sys::{MessageEvent, WebSocket};
+        use web_sys::{CloseEvent, Event, MessageEvent, WebSocket};
 
         let base_ws = UrlConfig::base_ws();
-        let ws_url = format!("{base_ws}/ws");
+        let ws_url = format!("{base_ws}/ws{}", negotiated_ws_query());
 
         let ws = WebSocket::new(&ws_url).map_err(|_| "failed to create websocket".to_string())?;
         *WS_SENDER.write() = Some(WsSender { ws: ws.clone() });
 
+        let (closed_tx, closed_rx) = tokio::sync::oneshot::channel::<()>();
+        let closed_tx = std::rc::Rc::new(std::cell::RefCell::new(Some(closed_tx)));
+        let mut bin_registry = BinRegistry::default();
+
+        let onopen = Closure::<dyn FnMut(_)>::new(move |_: Event| {
+            *CONN_STATE.write() = ConnState::Open;
+            *OPEN_SINCE_MS.write() = now_ms();
+            note_frame_received();
+            if attempt > 0 {
+                push_link_alert(warnings, "Link restored");
+            }
+        });
+        ws.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+        onopen.forget();
+
         let onmessage = Closure::<dyn FnMut(_)>::new(move |e: MessageEvent| {
             if let Some(s) = e.data().as_string() {
+                note_frame_received();
+                if s == WS_APP_PONG {
+                    return;
+                }
                 handle_ws_message(
                     &s,
                     rows,
@@ -726,13 +1670,57 @@ async fn connect_ws_loop(
                     rocket_gps,
                     user_gps,
                 );
+                spawn(async move {
+                    recording::record_line(&live_session_id(), &s).await;
+                });
+            } else if let Ok(buf) = e.data().dyn_into::<js_sys::ArrayBuffer>() {
+                note_frame_received();
+                let bytes = js_sys::Uint8Array::new(&buf).to_vec();
+                if let Some(msg) = handle_ws_binary(
+                    &bytes,
+                    &mut bin_registry,
+                    rows,
+                    warnings,
+                    errors,
+                    flight_state,
+                    rocket_gps,
+                    user_gps,
+                ) {
+                    let s = serde_json::to_string(&msg).unwrap_or_default();
+                    spawn(async move {
+                        recording::record_line(&live_session_id(), &s).await;
+                    });
+                }
             }
         });
-
         ws.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
         onmessage.forget();
 
-        Ok(())
+        let close_signal = closed_tx.clone();
+        let onclose = Closure::<dyn FnMut(_)>::new(move |_: CloseEvent| {
+            if let Some(tx) = close_signal.borrow_mut().take() {
+                let _ = tx.send(());
+            }
+        });
+        ws.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+        onclose.forget();
+
+        let onerror = Closure::<dyn FnMut(_)>::new(move |_: Event| {
+            if let Some(tx) = closed_tx.borrow_mut().take() {
+                let _ = tx.send(());
+            }
+        });
+        ws.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        onerror.forget();
+
+        let ws_for_keepalive = ws.clone();
+        tokio::select! {
+            r = closed_rx => r.map_err(|e| e.to_string()),
+            reason = run_keepalive(warnings) => {
+                let _ = ws_for_keepalive.close();
+                Err(reason)
+            }
+        }
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -740,47 +1728,104 @@ async fn connect_ws_loop(
         use futures_util::{SinkExt, StreamExt};
 
         let base_ws = UrlConfig::base_ws();
-        let ws_url = format!("{base_ws}/ws");
+        let ws_url = format!("{base_ws}/ws{}", negotiated_ws_query());
 
         let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<String>();
-        *WS_SENDER.write() = Some(WsSender { tx });
+        let (ping_tx, mut ping_rx) = tokio::sync::mpsc::unbounded_channel::<()>();
+        *WS_SENDER.write() = Some(WsSender { tx, ping_tx });
 
         let (ws_stream, _) = tokio_tungstenite::connect_async(ws_url.as_str())
             .await
             .map_err(|e| e.to_string())?;
+        *CONN_STATE.write() = ConnState::Open;
+        *OPEN_SINCE_MS.write() = now_ms();
+        note_frame_received();
+        if attempt > 0 {
+            push_link_alert(warnings, "Link restored");
+        }
 
         let (mut write, mut read) = ws_stream.split();
 
-        // writer task
+        // writer task: plain text commands and keepalive pings share one socket half
         let writer = tokio::spawn(async move {
-            while let Some(msg) = rx.recv().await {
-                let _ = write
-                    .send(tokio_tungstenite::tungstenite::Message::Text(msg.into()))
-                    .await;
+            loop {
+                tokio::select! {
+                    msg = rx.recv() => {
+                        let Some(msg) = msg else { break };
+                        let _ = write
+                            .send(tokio_tungstenite::tungstenite::Message::Text(msg.into()))
+                            .await;
+                    }
+                    ping = ping_rx.recv() => {
+                        if ping.is_none() {
+                            break;
+                        }
+                        let _ = write
+                            .send(tokio_tungstenite::tungstenite::Message::Ping(Vec::new().into()))
+                            .await;
+                    }
+                }
             }
         });
 
-        // reader loop
-        while let Some(item) = read.next().await {
-            let msg = item.map_err(|e| e.to_string())?;
-            if let tokio_tungstenite::tungstenite::Message::Text(s) = msg {
-                handle_ws_message(
-                    &s,
-                    rows,
-                    warnings,
-                    errors,
-                    flight_state,
-                    rocket_gps,
-                    user_gps,
-                );
+        // reader loop, raced against the keepalive watchdog so a stale link closes the
+        // socket and lets `connect_ws_supervisor` redial instead of waiting forever on
+        // `read.next()` with no more traffic coming.
+        let reader = async {
+            let mut bin_registry = BinRegistry::default();
+            while let Some(item) = read.next().await {
+                let msg = item.map_err(|e| e.to_string())?;
+                match msg {
+                    tokio_tungstenite::tungstenite::Message::Text(s) => {
+                        note_frame_received();
+                        handle_ws_message(
+                            &s,
+                            rows,
+                            warnings,
+                            errors,
+                            flight_state,
+                            rocket_gps,
+                            user_gps,
+                        );
+                        recording::record_line(&live_session_id(), &s).await;
+                        flight_recorder::record_line(s);
+                    }
+                    tokio_tungstenite::tungstenite::Message::Binary(bytes) => {
+                        note_frame_received();
+                        let bytes = decompress_if_zstd(&bytes);
+                        if let Some(msg) = handle_ws_binary(
+                            &bytes,
+                            &mut bin_registry,
+                            rows,
+                            warnings,
+                            errors,
+                            flight_state,
+                            rocket_gps,
+                            user_gps,
+                        ) {
+                            let s = serde_json::to_string(&msg).unwrap_or_default();
+                            recording::record_line(&live_session_id(), &s).await;
+                            flight_recorder::record_line(s);
+                        }
+                    }
+                    tokio_tungstenite::tungstenite::Message::Pong(_) => note_frame_received(),
+                    _ => {}
+                }
             }
-        }
+            Ok(())
+        };
 
-        let _ = writer.await;
-        Ok(())
+        let result = tokio::select! {
+            r = reader => r,
+            reason = run_keepalive(warnings) => Err(reason),
+        };
+
+        writer.abort();
+        result
     }
 }
 
+/// Text frames: JSON-encoded `WsInMsg` (the original, still-default wire format).
 fn handle_ws_message(
     s: &str,
     rows: Signal<Vec<TelemetryRow>>,
@@ -790,48 +1835,189 @@ fn handle_ws_message(
     rocket_gps: Signal<Option<(f64, f64)>>,
     user_gps: Signal<Option<(f64, f64)>>,
 ) {
-    let mut rows = rows;
+    stats::note_bytes(s.len());
+    let Ok(msg) = serde_json::from_str::<WsInMsg>(s) else {
+        return;
+    };
+    dispatch_ws_msg(msg, rows, warnings, errors, flight_state, rocket_gps, user_gps);
+}
+
+const BIN_TAG_TELEMETRY: u8 = 0;
+const BIN_TAG_WARNING: u8 = 1;
+const BIN_TAG_ERROR: u8 = 2;
+const BIN_TAG_FLIGHT_STATE: u8 = 3;
+const BIN_TAG_TYPE_REGISTRY: u8 = 4;
+const BIN_TAG_CONTROL: u8 = 5;
+
+/// `BIN_TAG_FLIGHT_STATE` frames carry `state as u8` (see `encode_flight_state_frame` in
+/// `backend/src/web.rs`), i.e. `FlightState`'s declaration order — mirrored here by hand
+/// since the binary wire format is the one place that encoding is observable on this side.
+fn flight_state_from_u8(code: u8) -> Option<FlightState> {
+    const STATES: &[FlightState] = &[
+        FlightState::Startup,
+        FlightState::Idle,
+        FlightState::PreFill,
+        FlightState::FillTest,
+        FlightState::NitrogenFill,
+        FlightState::NitrousFill,
+        FlightState::Armed,
+        FlightState::Launch,
+        FlightState::Ascent,
+        FlightState::Coast,
+        FlightState::Apogee,
+        FlightState::ParachuteDeploy,
+        FlightState::Descent,
+        FlightState::Landed,
+        FlightState::Recovery,
+        FlightState::Aborted,
+    ];
+    STATES.get(code as usize).copied()
+}
+
+/// Per-connection counterpart to the server's `DataTypeRegistry` (see `encode_binary` in
+/// `backend/src/web.rs`) — the server interns each `data_type` string to a `u8` id lazily and
+/// announces it once via `BIN_TAG_TYPE_REGISTRY`, so the client has to remember that mapping
+/// for the life of the socket to make sense of later `BIN_TAG_TELEMETRY` frames.
+#[derive(Default)]
+struct BinRegistry {
+    names: std::collections::HashMap<u8, String>,
+}
+
+/// Binary frames: the server's hand-rolled tagged format (`encode_binary`), optionally
+/// zstd-compressed if this connection negotiated `enc=bin-zstd` — see `decompress_if_zstd`.
+/// Denser than JSON for high-rate links, at the cost of the client having to track the
+/// `BinRegistry` across frames. Returns the decoded message (if the frame carries one the
+/// dashboard cares about) so the caller can re-serialize it to JSON for `recording.rs`, which
+/// only ever stores text — recordings don't need to be bandwidth-efficient.
+fn handle_ws_binary(
+    bytes: &[u8],
+    registry: &mut BinRegistry,
+    rows: Signal<Vec<TelemetryRow>>,
+    warnings: Signal<Vec<AlertMsg>>,
+    errors: Signal<Vec<AlertMsg>>,
+    flight_state: Signal<FlightState>,
+    rocket_gps: Signal<Option<(f64, f64)>>,
+    user_gps: Signal<Option<(f64, f64)>>,
+) -> Option<WsInMsg> {
+    stats::note_bytes(bytes.len());
+    let msg = decode_bin_frame(bytes, registry)?;
+    dispatch_ws_msg(msg.clone(), rows, warnings, errors, flight_state, rocket_gps, user_gps);
+    Some(msg)
+}
+
+fn decode_bin_frame(bytes: &[u8], registry: &mut BinRegistry) -> Option<WsInMsg> {
+    let (&tag, rest) = bytes.split_first()?;
+    match tag {
+        BIN_TAG_TELEMETRY => {
+            let timestamp_ms = i64::from_le_bytes(rest.get(0..8)?.try_into().ok()?);
+            let data_type_id = *rest.get(8)?;
+            let count = *rest.get(9)? as usize;
+            let mut values = [None; 8];
+            let mut cursor = 10;
+            for slot in values.iter_mut().take(count) {
+                let bytes4: [u8; 4] = rest.get(cursor..cursor + 4)?.try_into().ok()?;
+                *slot = Some(f32::from_le_bytes(bytes4));
+                cursor += 4;
+            }
+            let data_type = registry.names.get(&data_type_id)?.clone();
+            Some(WsInMsg::Telemetry(TelemetryRow {
+                timestamp_ms,
+                data_type,
+                v0: values[0],
+                v1: values[1],
+                v2: values[2],
+                v3: values[3],
+                v4: values[4],
+                v5: values[5],
+                v6: values[6],
+                v7: values[7],
+            }))
+        }
+        BIN_TAG_WARNING | BIN_TAG_ERROR => {
+            let timestamp_ms = i64::from_le_bytes(rest.get(0..8)?.try_into().ok()?);
+            let len = u16::from_le_bytes(rest.get(8..10)?.try_into().ok()?) as usize;
+            let message = String::from_utf8(rest.get(10..10 + len)?.to_vec()).ok()?;
+            let alert = AlertMsg { timestamp_ms, message };
+            Some(if tag == BIN_TAG_WARNING { WsInMsg::Warning(alert) } else { WsInMsg::Error(alert) })
+        }
+        BIN_TAG_FLIGHT_STATE => {
+            let state = flight_state_from_u8(*rest.first()?)?;
+            Some(WsInMsg::FlightState(FlightStateMsg { state }))
+        }
+        BIN_TAG_TYPE_REGISTRY => {
+            let id = *rest.first()?;
+            let len = *rest.get(1)? as usize;
+            let name = String::from_utf8(rest.get(2..2 + len)?.to_vec()).ok()?;
+            registry.names.insert(id, name);
+            None
+        }
+        BIN_TAG_CONTROL => {
+            let len = u16::from_le_bytes(rest.get(0..2)?.try_into().ok()?) as usize;
+            let text = std::str::from_utf8(rest.get(2..2 + len)?).ok()?;
+            serde_json::from_str::<WsInMsg>(text).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Reverses the server's per-frame zstd compression when this connection negotiated
+/// `enc=bin-zstd`; a no-op passthrough for plain `enc=bin` (see `negotiated_ws_query`).
+#[cfg(not(target_arch = "wasm32"))]
+fn decompress_if_zstd(bytes: &[u8]) -> Vec<u8> {
+    zstd::decode_all(bytes).unwrap_or_else(|_| bytes.to_vec())
+}
+
+/// Which binary sub-format to ask the server for (see `WsConnectParams` in
+/// `backend/src/web.rs`). Native sockets negotiate the zstd-compressed variant since the
+/// `zstd` crate is a thin C binding that isn't set up for the wasm32 target here; the web
+/// build asks for plain tagged frames instead.
+fn negotiated_ws_query() -> &'static str {
+    #[cfg(target_arch = "wasm32")]
+    {
+        "?enc=bin"
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        "?enc=bin-zstd"
+    }
+}
+
+fn dispatch_ws_msg(
+    msg: WsInMsg,
+    rows: Signal<Vec<TelemetryRow>>,
+    warnings: Signal<Vec<AlertMsg>>,
+    errors: Signal<Vec<AlertMsg>>,
+    flight_state: Signal<FlightState>,
+    rocket_gps: Signal<Option<(f64, f64)>>,
+    user_gps: Signal<Option<(f64, f64)>>,
+) {
     let mut warnings = warnings;
     let mut errors = errors;
     let mut flight_state = flight_state;
     let mut rocket_gps = rocket_gps;
     let _user_gps = user_gps;
 
-    let Ok(msg) = serde_json::from_str::<WsInMsg>(s) else {
-        return;
-    };
-
     match msg {
         WsInMsg::Telemetry(row) => {
             if let Some((lat, lon)) = row_to_gps(&row) {
                 rocket_gps.set(Some((lat, lon)));
             }
+            stats::note_row(&row);
+            extend_telemetry_rows(rows, std::iter::once(row));
+        }
 
-            let mut v = rows.read().clone();
-            v.push(row);
-
-            // Time-window trim (prefer timestamp-based)
-            if let Some(last) = v.last() {
-                let cutoff = last.timestamp_ms - HISTORY_MS;
-                let split = v.partition_point(|r| r.timestamp_ms < cutoff);
-                if split > 0 {
-                    v.drain(0..split);
-                }
+        WsInMsg::TelemetryBatch(batch) => {
+            if let Some((lat, lon)) = batch.iter().rev().find_map(row_to_gps) {
+                rocket_gps.set(Some((lat, lon)));
             }
-
-            // cheap cap as safety
-            const MAX_SAMPLES: usize = 10_000;
-            if v.len() > MAX_SAMPLES {
-                let n = v.len();
-                let stride = (n as f32 / MAX_SAMPLES as f32).ceil() as usize;
-                v = v
-                    .into_iter()
-                    .enumerate()
-                    .filter_map(|(i, row)| (i % stride == 0).then_some(row))
-                    .collect();
+            for row in &batch {
+                stats::note_row(row);
             }
+            extend_telemetry_rows(rows, batch);
+        }
 
-            rows.set(v);
+        WsInMsg::Dynamic(value) => {
+            dynamic_rows::ingest(value);
         }
 
         WsInMsg::FlightState(st) => {
@@ -855,5 +2041,167 @@ fn handle_ws_message(
             }
             errors.set(v);
         }
+
+        WsInMsg::Ack(ack) => {
+            let pending = PENDING_CMDS.write().remove(&ack.seq);
+            if let Some(pending) = pending
+                && *CMD_SEQ.read() == ack.seq
+            {
+                *LAST_CMD_STATUS.write() = Some((pending.cmd, CmdState::Acked));
+            }
+        }
+
+        WsInMsg::Resync(resync) => {
+            push_link_alert(
+                warnings,
+                &format!("Dropped {} telemetry frame(s) — re-fetching history…", resync.dropped),
+            );
+            spawn(async move {
+                refetch_history(rows).await;
+            });
+        }
+
+        WsInMsg::Annotation(op) => {
+            annotations::ANNOTATIONS.write().apply(op);
+        }
     }
 }
+
+/// Patches a gap flagged by `WsInMsg::Resync` by pulling the full `HISTORY_MS` window back
+/// from `/api/history` and replacing `rows` wholesale — simpler than trying to splice in just
+/// the missing span, and cheap enough since `/api/history` is already bounded the same way.
+async fn refetch_history(mut rows: Signal<Vec<TelemetryRow>>) {
+    let minutes = HISTORY_MS / 60_000;
+    if let Ok(fresh) = http_get_json::<Vec<TelemetryRow>>(&format!("/api/history?minutes={minutes}")).await
+    {
+        data_chart::charts_cache_reset_and_ingest(&fresh);
+        rows.set(fresh);
+    }
+}
+
+// ---------- Replay ----------
+//
+// Opening a recording creates a fresh `Replay` session and hands it to
+// `spawn_replay_driver`, which hydrates it one recorded line at a time — never the whole
+// file up front — pacing itself off the records' own timestamps (scaled by `speed`) so it
+// behaves like a virtual clock rather than a fixed-rate tick.
+const REPLAY_POLL_MS: u64 = 100;
+const REPLAY_MAX_STEP_MS: i64 = 2_000;
+
+async fn open_replay(session_id: String) {
+    let total = recording::count_lines(&session_id).await;
+
+    SESSIONS
+        .write()
+        .insert(session_id.clone(), Session::new(SessionKind::Replay));
+    REPLAY_CLOCKS.write().insert(
+        session_id.clone(),
+        ReplayClock { cursor_line: 0, total_lines: total, speed: 1.0, state: PlaybackState::Paused },
+    );
+    *ACTIVE_SESSION_ID.write() = session_id.clone();
+
+    spawn(async move {
+        spawn_replay_driver(session_id).await;
+    });
+}
+
+fn close_replay(session_id: &str) {
+    SESSIONS.write().remove(session_id);
+    REPLAY_CLOCKS.write().remove(session_id);
+    if *ACTIVE_SESSION_ID.read() == session_id {
+        *ACTIVE_SESSION_ID.write() = LIVE_SESSION_KEY.to_string();
+    }
+}
+
+/// Extract the timestamp a `WsInMsg` is "at", for pacing the virtual clock. Only telemetry
+/// and alerts pace it; every other variant replays instantly, at the previous
+/// record's timestamp.
+fn ws_in_msg_timestamp_ms(msg: &WsInMsg) -> Option<i64> {
+    match msg {
+        WsInMsg::Telemetry(row) => Some(row.timestamp_ms),
+        WsInMsg::TelemetryBatch(batch) => batch.last().map(|r| r.timestamp_ms),
+        WsInMsg::Warning(a) | WsInMsg::Error(a) => Some(a.timestamp_ms),
+        WsInMsg::FlightState(_)
+        | WsInMsg::Ack(_)
+        | WsInMsg::Annotation(_)
+        | WsInMsg::Dynamic(_)
+        | WsInMsg::Resync(_) => None,
+    }
+}
+
+async fn spawn_replay_driver(session_id: String) {
+    let mut prev_ts: Option<i64> = None;
+
+    loop {
+        // The operator closed this replay out from under us — stop.
+        if !SESSIONS.read().contains_key(&session_id) {
+            return;
+        }
+
+        let clock = match REPLAY_CLOCKS.read().get(&session_id).copied() {
+            Some(c) => c,
+            None => return,
+        };
+
+        if clock.state == PlaybackState::Paused || clock.cursor_line >= clock.total_lines {
+            #[cfg(target_arch = "wasm32")]
+            gloo_timers::future::TimeoutFuture::new(REPLAY_POLL_MS as u32).await;
+            #[cfg(not(target_arch = "wasm32"))]
+            tokio::time::sleep(std::time::Duration::from_millis(REPLAY_POLL_MS)).await;
+            continue;
+        }
+
+        let lines =
+            recording::read_range(&session_id, clock.cursor_line, clock.cursor_line + 1).await;
+        let Some(line) = lines.first() else {
+            return;
+        };
+
+        if let Ok(msg) = serde_json::from_str::<WsInMsg>(line)
+            && let Some(session) = SESSIONS.read().get(&session_id).copied()
+        {
+            handle_ws_message(
+                line,
+                session.rows,
+                session.warnings,
+                session.errors,
+                session.flight_state,
+                session.rocket_gps,
+                scratch_gps_signal(),
+            );
+
+            let ts = ws_in_msg_timestamp_ms(&msg);
+            let step_ms = match (prev_ts, ts) {
+                (Some(prev), Some(now)) => (now - prev).clamp(0, REPLAY_MAX_STEP_MS) as u64,
+                _ => 0,
+            };
+            if ts.is_some() {
+                prev_ts = ts;
+            }
+
+            if let Some(c) = REPLAY_CLOCKS.write().get_mut(&session_id) {
+                c.cursor_line += 1;
+            }
+
+            let delay_ms = ((step_ms as f64) / clock.speed.max(0.1)) as u64;
+            if delay_ms > 0 {
+                #[cfg(target_arch = "wasm32")]
+                gloo_timers::future::TimeoutFuture::new(delay_ms.min(REPLAY_MAX_STEP_MS as u64) as u32)
+                    .await;
+                #[cfg(not(target_arch = "wasm32"))]
+                tokio::time::sleep(std::time::Duration::from_millis(
+                    delay_ms.min(REPLAY_MAX_STEP_MS as u64),
+                ))
+                .await;
+            }
+        } else if let Some(c) = REPLAY_CLOCKS.write().get_mut(&session_id) {
+            c.cursor_line += 1;
+        }
+    }
+}
+
+/// A replay never has its own operator GPS fix — `handle_ws_message` needs the parameter
+/// regardless, so hand it a scratch signal nobody reads.
+fn scratch_gps_signal() -> Signal<Option<(f64, f64)>> {
+    Signal::new(None)
+}
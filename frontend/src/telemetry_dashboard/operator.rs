@@ -0,0 +1,95 @@
+// frontend/src/telemetry_dashboard/operator.rs
+//
+// Who is at the keyboard, and what are they allowed to do. `send_cmd` used to dispatch any
+// command from anyone with the page open — this gives it an operator identity (set once,
+// persisted across reloads the way `theme`'s persisted choice is) and an allow-list of which
+// `Role` may issue which command, so the backend's enforcement in `web.rs` and the frontend's
+// gate in `send_cmd` are checking the exact same table.
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Role {
+    Observer,
+    FlightDirector,
+}
+
+impl Role {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Role::Observer => "observer",
+            Role::FlightDirector => "flight_director",
+        }
+    }
+
+    pub(crate) fn from_str(s: &str) -> Self {
+        match s {
+            "flight_director" => Role::FlightDirector,
+            _ => Role::Observer,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Operator {
+    pub(crate) id: String,
+    pub(crate) role: Role,
+}
+
+/// Commands a given role may issue. An observer is read-only — every command a human can
+/// send right now (`Arm`/`Disarm`/`Abort`) is flight-control, so only a flight director is
+/// on the list; this grows as a match arm, same as `role_allows` below, if a non-destructive
+/// command ever needs a looser allow-list.
+const FLIGHT_DIRECTOR_COMMANDS: &[&str] = &["Arm", "Disarm", "Abort"];
+const OBSERVER_COMMANDS: &[&str] = &[];
+
+fn commands_allowed_for(role: Role) -> &'static [&'static str] {
+    match role {
+        Role::Observer => OBSERVER_COMMANDS,
+        Role::FlightDirector => FLIGHT_DIRECTOR_COMMANDS,
+    }
+}
+
+pub(crate) fn role_allows(role: Role, cmd: &str) -> bool {
+    commands_allowed_for(role).contains(&cmd)
+}
+
+/// Commands that need a two-step confirmation before they reach `send_cmd`, regardless of
+/// role — currently just the one that ends a flight. `ActionsTab` falls back to this for a
+/// control the backend's action policy has no entry for.
+pub(crate) fn requires_confirmation(cmd: &str) -> bool {
+    cmd == "Abort"
+}
+
+pub(crate) static OPERATOR: dioxus_signals::GlobalSignal<Option<Operator>> =
+    dioxus_signals::Signal::global(|| None::<Operator>);
+
+const OPERATOR_ID_STORAGE_KEY: &str = "gs_operator_id";
+const OPERATOR_ROLE_STORAGE_KEY: &str = "gs_operator_role";
+
+/// Load a persisted operator identity (web only — native has no localStorage, so it always
+/// starts unidentified and the setup modal below asks again every launch).
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn restore_persisted_operator() {
+    use super::storage_get_string;
+
+    if let Some(id) = storage_get_string(OPERATOR_ID_STORAGE_KEY)
+        && !id.is_empty()
+    {
+        let role = storage_get_string(OPERATOR_ROLE_STORAGE_KEY)
+            .as_deref()
+            .map(Role::from_str)
+            .unwrap_or(Role::Observer);
+        *OPERATOR.write() = Some(Operator { id, role });
+    }
+}
+
+pub(crate) fn set_operator(id: String, role: Role) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use super::storage_set_string;
+        storage_set_string(OPERATOR_ID_STORAGE_KEY, &id);
+        storage_set_string(OPERATOR_ROLE_STORAGE_KEY, role.as_str());
+    }
+
+    *OPERATOR.write() = Some(Operator { id, role });
+}
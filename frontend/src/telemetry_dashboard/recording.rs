@@ -0,0 +1,145 @@
+// frontend/src/telemetry_dashboard/recording.rs
+//
+// Streams every raw `WsInMsg` line to durable storage, keyed by session id, so a flight's
+// telemetry survives the tab closing (or the app quitting):
+//   - web (wasm32):     the same bidirectional eval bridge `map_tab.rs` uses to talk to
+//                       `ground_map.js`, calling `window.gs26RecordAppend` /
+//                       `window.gs26RecordList` / `window.gs26RecordRead` when the page
+//                       defines them. No script in this repository defines them, so on web
+//                       nothing is recorded and the replay list is empty.
+//   - native (desktop): one newline-delimited JSON file per session under `./recordings`,
+//                       appended to with `tokio::fs` (native already owns the socket directly
+//                       rather than going through a webview, so it gets a real filesystem too).
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+/// Cross-platform "eval JS, ignore the result" — see `map_tab::js_eval` for the native half
+/// of this story; duplicated locally (rather than shared) because it's a two-line wrapper
+/// not worth threading a `pub(crate)` through another tab's module for.
+#[cfg(target_arch = "wasm32")]
+fn js_eval(js: &str) {
+    let _ = js_sys::eval(js);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn js_eval(js: &str) {
+    dioxus::document::eval(js);
+}
+
+/// Evaluate `js` and await a value sent back via `dioxus.send(...)` — see
+/// `map_tab::js_read_string` for the sibling copy used by the map's eval bridge.
+#[cfg(target_arch = "wasm32")]
+async fn js_read_string(js: &str) -> Option<String> {
+    let mut eval = dioxus::document::eval(js);
+    eval.recv::<Option<String>>().await.ok().flatten()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn recordings_dir() -> PathBuf {
+    PathBuf::from("recordings")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn recording_path(session_id: &str) -> PathBuf {
+    recordings_dir().join(format!("{session_id}.ndjson"))
+}
+
+/// Append one already-serialized `WsInMsg` line to `session_id`'s recording.
+pub(crate) async fn record_line(session_id: &str, line: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let session_id = serde_json::to_string(session_id).unwrap_or_default();
+        let line = serde_json::to_string(line).unwrap_or_default();
+        js_eval(&format!(
+            "window.gs26RecordAppend && window.gs26RecordAppend({session_id}, {line});"
+        ));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use tokio::io::AsyncWriteExt;
+        if tokio::fs::create_dir_all(recordings_dir()).await.is_err() {
+            return;
+        }
+        if let Ok(mut file) = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(recording_path(session_id))
+            .await
+        {
+            let _ = file.write_all(line.as_bytes()).await;
+            let _ = file.write_all(b"\n").await;
+        }
+    }
+}
+
+/// List session ids with a recording available to replay.
+pub(crate) async fn list_sessions() -> Vec<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_read_string(
+            "window.gs26RecordList ? JSON.stringify(window.gs26RecordList()) : '[]'",
+        )
+        .await
+        .and_then(|s| serde_json::from_str::<Vec<String>>(&s).ok())
+        .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let mut ids = Vec::new();
+        if let Ok(mut entries) = tokio::fs::read_dir(recordings_dir()).await {
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                if let Some(name) = entry.path().file_stem().and_then(|s| s.to_str()) {
+                    ids.push(name.to_string());
+                }
+            }
+        }
+        ids.sort();
+        ids
+    }
+}
+
+/// Count the lines in `session_id`'s recording (its replay length).
+pub(crate) async fn count_lines(session_id: &str) -> usize {
+    read_range(session_id, 0, usize::MAX).await.len()
+}
+
+/// Fetch lines `[start, end)` of `session_id`'s recording — used to hydrate a replay
+/// session's buffer lazily, one line at a time as playback advances, rather than loading the
+/// whole history up front. Playback only moves forward; there is no scrubbing.
+pub(crate) async fn read_range(session_id: &str, start: usize, end: usize) -> Vec<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let session_id = serde_json::to_string(session_id).unwrap_or_default();
+        js_read_string(&format!(
+            "window.gs26RecordRead ? JSON.stringify(window.gs26RecordRead({session_id}, {start}, {end})) : '[]'"
+        ))
+        .await
+        .and_then(|s| serde_json::from_str::<Vec<String>>(&s).ok())
+        .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use tokio::io::AsyncBufReadExt;
+
+        let Ok(file) = tokio::fs::File::open(recording_path(session_id)).await else {
+            return Vec::new();
+        };
+        let mut lines = tokio::io::BufReader::new(file).lines();
+        let mut out = Vec::new();
+        let mut i = 0;
+        while let Ok(Some(line)) = lines.next_line().await {
+            if i >= end {
+                break;
+            }
+            if i >= start {
+                out.push(line);
+            }
+            i += 1;
+        }
+        out
+    }
+}
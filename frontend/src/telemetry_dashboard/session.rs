@@ -0,0 +1,73 @@
+// frontend/src/telemetry_dashboard/session.rs
+//
+// A `Session` bundles the signals one tab-set renders from. `Live` is fed by the socket
+// (see `connect_ws_once` in `mod.rs`); `Replay` is a recording scrubbed through a virtual
+// clock (see `spawn_replay_driver` in `mod.rs`). `SESSIONS` keeps every session the operator
+// currently has open — live plus any replays — so the dashboard can switch which one the
+// tabs render, the way a terminal keeps multiple named conversations open at once.
+
+use super::AlertMsg;
+use dioxus_signals::Signal;
+use groundstation_shared::{FlightState, TelemetryRow};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SessionKind {
+    Live,
+    Replay,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PlaybackState {
+    Playing,
+    Paused,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct Session {
+    pub(crate) kind: SessionKind,
+    pub(crate) rows: Signal<Vec<TelemetryRow>>,
+    pub(crate) warnings: Signal<Vec<AlertMsg>>,
+    pub(crate) errors: Signal<Vec<AlertMsg>>,
+    pub(crate) flight_state: Signal<FlightState>,
+    pub(crate) rocket_gps: Signal<Option<(f64, f64)>>,
+}
+
+impl Session {
+    pub(crate) fn new(kind: SessionKind) -> Self {
+        Self {
+            kind,
+            rows: Signal::new(Vec::new()),
+            warnings: Signal::new(Vec::new()),
+            errors: Signal::new(Vec::new()),
+            flight_state: Signal::new(FlightState::Startup),
+            rocket_gps: Signal::new(None),
+        }
+    }
+}
+
+/// A replay's position in its recording and how fast/whether it's advancing. Hydration is
+/// lazy — `cursor_line` only grows as the playback driver asks `recording::read_range` for
+/// the next line, never by loading the whole file up front. There is no seek: playback only
+/// moves forward from line 0.
+#[derive(Clone, Copy)]
+pub(crate) struct ReplayClock {
+    pub(crate) cursor_line: usize,
+    pub(crate) total_lines: usize,
+    pub(crate) speed: f64,
+    pub(crate) state: PlaybackState,
+}
+
+pub(crate) static SESSIONS: dioxus_signals::GlobalSignal<HashMap<String, Session>> =
+    dioxus_signals::Signal::global(HashMap::new);
+
+pub(crate) static REPLAY_CLOCKS: dioxus_signals::GlobalSignal<HashMap<String, ReplayClock>> =
+    dioxus_signals::Signal::global(HashMap::new);
+
+/// Empty string means "the live session" — the fixed key (`LIVE_SESSION_KEY`) the live session
+/// is registered under in `SESSIONS`, so switching back to live never depends on the live
+/// recording's id having been assigned yet.
+pub(crate) static ACTIVE_SESSION_ID: dioxus_signals::GlobalSignal<String> =
+    dioxus_signals::Signal::global(String::new);
+
+pub(crate) const LIVE_SESSION_KEY: &str = "";
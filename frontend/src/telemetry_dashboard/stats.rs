@@ -0,0 +1,120 @@
+// frontend/src/telemetry_dashboard/stats.rs
+//
+// Link/telemetry health at a glance. `note_bytes`/`note_row` update the cheap parts of
+// `TELEMETRY_STATS` (per-field min/max/last, dropped/out-of-order count) inline as each
+// frame is dispatched; `tick` folds the heavier rate/jitter math in on the same cadence as
+// the keepalive ping (`run_keepalive` in `mod.rs`) rather than every frame, so the hot path
+// stays a handful of field updates regardless of how much history is in view.
+
+use dioxus_signals::{GlobalSignal, Signal};
+use groundstation_shared::TelemetryRow;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub(crate) struct FieldStats {
+    pub(crate) min: f32,
+    pub(crate) max: f32,
+    pub(crate) last: f32,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub(crate) struct TelemetryStats {
+    pub(crate) message_rate_hz: f64,
+    pub(crate) bytes_per_sec: f64,
+    pub(crate) jitter_mean_ms: f64,
+    pub(crate) jitter_max_ms: f64,
+    pub(crate) dropped_or_reordered: u64,
+    /// Keyed `"{data_type}.v{n}"` (e.g. `"GYRO_DATA.v0"`) since a slot's meaning depends on
+    /// `data_type`, same as on `TelemetryRow` itself.
+    pub(crate) fields: HashMap<String, FieldStats>,
+}
+
+pub(crate) static TELEMETRY_STATS: GlobalSignal<TelemetryStats> =
+    Signal::global(TelemetryStats::default);
+
+/// Raw tallies since the last `tick`, reset at the end of each one.
+#[derive(Default)]
+struct Accumulator {
+    message_count: u64,
+    byte_count: u64,
+    gap_sum_ms: i64,
+    gap_count: u64,
+    gap_max_ms: i64,
+    last_timestamp_ms: Option<i64>,
+}
+
+static ACCUMULATOR: GlobalSignal<Accumulator> = Signal::global(Accumulator::default);
+
+/// Count one inbound frame's raw size, before it's decoded — called from both
+/// `handle_ws_message` (text) and `handle_ws_binary`.
+pub(crate) fn note_bytes(len: usize) {
+    ACCUMULATOR.write().byte_count += len as u64;
+}
+
+/// Fold one telemetry sample into the running stats: bumps the message count, feeds this
+/// tick's jitter accumulator, flags a timestamp earlier than the previous one as dropped (an
+/// equal one counts as a zero gap), and
+/// updates the sample's fields' min/max/last immediately (cheap enough to do inline, unlike
+/// the rate/jitter math `tick` does periodically).
+pub(crate) fn note_row(row: &TelemetryRow) {
+    {
+        let mut acc = ACCUMULATOR.write();
+        acc.message_count += 1;
+        if let Some(prev) = acc.last_timestamp_ms {
+            let gap = row.timestamp_ms - prev;
+            if gap < 0 {
+                TELEMETRY_STATS.write().dropped_or_reordered += 1;
+            } else {
+                acc.gap_sum_ms += gap;
+                acc.gap_count += 1;
+                acc.gap_max_ms = acc.gap_max_ms.max(gap);
+            }
+        }
+        acc.last_timestamp_ms = Some(row.timestamp_ms);
+    }
+
+    let slots: [(&str, Option<f32>); 8] = [
+        ("v0", row.v0),
+        ("v1", row.v1),
+        ("v2", row.v2),
+        ("v3", row.v3),
+        ("v4", row.v4),
+        ("v5", row.v5),
+        ("v6", row.v6),
+        ("v7", row.v7),
+    ];
+
+    let mut stats = TELEMETRY_STATS.write();
+    for (name, value) in slots {
+        let Some(value) = value else { continue };
+        let key = format!("{}.{name}", row.data_type);
+        stats
+            .fields
+            .entry(key)
+            .and_modify(|f| {
+                f.min = f.min.min(value);
+                f.max = f.max.max(value);
+                f.last = value;
+            })
+            .or_insert(FieldStats { min: value, max: value, last: value });
+    }
+}
+
+/// Recompute `message_rate_hz`/`bytes_per_sec`/jitter from the tally built up since the last
+/// call, then reset it for the next interval. Called once per `PING_INTERVAL_MS` tick from
+/// `run_keepalive`, so this only ever runs on a fixed cadence, never per frame.
+pub(crate) fn tick(interval_ms: u64) {
+    let mut acc = ACCUMULATOR.write();
+    let interval_s = interval_ms as f64 / 1000.0;
+
+    let mut stats = TELEMETRY_STATS.write();
+    stats.message_rate_hz = acc.message_count as f64 / interval_s;
+    stats.bytes_per_sec = acc.byte_count as f64 / interval_s;
+    stats.jitter_mean_ms =
+        if acc.gap_count > 0 { acc.gap_sum_ms as f64 / acc.gap_count as f64 } else { 0.0 };
+    stats.jitter_max_ms = acc.gap_max_ms as f64;
+    drop(stats);
+
+    *acc = Accumulator { last_timestamp_ms: acc.last_timestamp_ms, ..Accumulator::default() };
+}
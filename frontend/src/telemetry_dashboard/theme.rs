@@ -0,0 +1,261 @@
+// frontend/src/telemetry_dashboard/theme.rs
+//
+// Central color palette so tabs stop hand-picking hex literals. A palette is authored once,
+// in HSL, as the dark variant; the light sibling is derived mechanically (not hand-tuned) by
+// flipping lightness across an inverted curve while holding hue/saturation fixed. The chosen
+// theme persists (web only) through `storage_get_string`/`storage_set_string`, the same way
+// other bits of dashboard UI state do through `localStorage`.
+
+use dioxus_signals::{GlobalSignal, ReadableExt, Signal, WritableExt};
+
+const THEME_STORAGE_KEY: &str = "gs26_theme";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Hsl {
+    h: f64,
+    s: f64,
+    l: f64,
+}
+
+impl Hsl {
+    const fn new(h: f64, s: f64, l: f64) -> Self {
+        Hsl { h, s, l }
+    }
+
+    /// Flip lightness across the midpoint (`1 - l`), preserving hue/saturation. Clamped to
+    /// `[0.06, 0.94]` so a near-black role doesn't flip to pure white, nor a near-white one to
+    /// pure black.
+    fn inverted(self) -> Hsl {
+        Hsl::new(self.h, self.s, (1.0 - self.l).clamp(0.06, 0.94))
+    }
+
+    fn to_hex(self) -> String {
+        let (r, g, b) = hsl_to_rgb(self.h, self.s, self.l);
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    if s <= 0.0 {
+        let v = (l * 255.0).round() as u8;
+        return (v, v, v);
+    }
+    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let p = 2.0 * l - q;
+    let hue_to_rgb = |t: f64| -> f64 {
+        let mut t = t;
+        if t < 0.0 {
+            t += 1.0;
+        }
+        if t > 1.0 {
+            t -= 1.0;
+        }
+        if t < 1.0 / 6.0 {
+            return p + (q - p) * 6.0 * t;
+        }
+        if t < 1.0 / 2.0 {
+            return q;
+        }
+        if t < 2.0 / 3.0 {
+            return p + (q - p) * (2.0 / 3.0 - t) * 6.0;
+        }
+        p
+    };
+    let h = h / 360.0;
+    let r = hue_to_rgb(h + 1.0 / 3.0);
+    let g = hue_to_rgb(h);
+    let b = hue_to_rgb(h - 1.0 / 3.0);
+    (
+        (r * 255.0).round() as u8,
+        (g * 255.0).round() as u8,
+        (b * 255.0).round() as u8,
+    )
+}
+
+/// Named color roles every tab should pull from instead of inlining hex literals.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    pub base: String,
+    pub surface: String,
+    pub text: String,
+    pub accent: String,
+    pub warn: String,
+    pub err: String,
+    pub ok: String,
+    pub info: String,
+    /// Faint gridlines/dividers (the Connection Status tab's chart gridlines, table rules).
+    pub grid: String,
+    /// Axis lines and borders that need to read a step stronger than `grid`.
+    pub axis: String,
+    /// De-emphasized text (axis labels, captions) next to `text`'s full-strength body copy.
+    pub text_muted: String,
+    /// Dashed/interpolated line segments (e.g. a latency chart's scroll-gap fill-in). A role of
+    /// its own, separate from `warn`, though `DARK` and `HIGH_CONTRAST` give both the same amber.
+    pub interpolated: String,
+}
+
+struct PaletteHsl {
+    base: Hsl,
+    surface: Hsl,
+    text: Hsl,
+    accent: Hsl,
+    warn: Hsl,
+    err: Hsl,
+    ok: Hsl,
+    info: Hsl,
+    grid: Hsl,
+    axis: Hsl,
+    text_muted: Hsl,
+    interpolated: Hsl,
+}
+
+impl PaletteHsl {
+    fn invert(&self) -> PaletteHsl {
+        PaletteHsl {
+            base: self.base.inverted(),
+            surface: self.surface.inverted(),
+            text: self.text.inverted(),
+            accent: self.accent,
+            warn: self.warn,
+            err: self.err,
+            ok: self.ok,
+            info: self.info,
+            grid: self.grid.inverted(),
+            axis: self.axis.inverted(),
+            text_muted: self.text_muted.inverted(),
+            interpolated: self.interpolated,
+        }
+    }
+
+    fn into_palette(self) -> Palette {
+        Palette {
+            base: self.base.to_hex(),
+            surface: self.surface.to_hex(),
+            text: self.text.to_hex(),
+            accent: self.accent.to_hex(),
+            warn: self.warn.to_hex(),
+            err: self.err.to_hex(),
+            ok: self.ok.to_hex(),
+            info: self.info.to_hex(),
+            grid: self.grid.to_hex(),
+            axis: self.axis.to_hex(),
+            text_muted: self.text_muted.to_hex(),
+            interpolated: self.interpolated.to_hex(),
+        }
+    }
+}
+
+// The dashboard's existing hardcoded hex literals (#020617, #e5e7eb, #38bdf8, ...), expressed
+// as the one authored palette. Accent/status roles stay fixed across variants (a warning
+// should read the same shade of amber whether you're in dark or light mode) — only the
+// base/surface/text and grid/axis/text_muted roles, which actually encode "dark vs. light",
+// get inverted.
+const DARK: PaletteHsl = PaletteHsl {
+    base: Hsl::new(222.0, 0.47, 0.04),
+    surface: Hsl::new(217.0, 0.19, 0.12),
+    text: Hsl::new(220.0, 0.14, 0.90),
+    accent: Hsl::new(199.0, 0.89, 0.64),
+    warn: Hsl::new(48.0, 0.96, 0.59),
+    err: Hsl::new(0.0, 0.84, 0.60),
+    ok: Hsl::new(142.0, 0.71, 0.45),
+    info: Hsl::new(213.0, 0.94, 0.78),
+    grid: Hsl::new(217.0, 0.19, 0.18),
+    axis: Hsl::new(215.0, 0.16, 0.30),
+    text_muted: Hsl::new(215.0, 0.16, 0.65),
+    interpolated: Hsl::new(48.0, 0.96, 0.59),
+};
+
+// Not derived from `DARK` by inversion like `Light` is — outdoor/launch-day readability wants
+// near-maximal contrast and saturation on every role, not just a lightness flip, so this is a
+// second hand-authored palette: pure black/white base pair, fully saturated status colors.
+const HIGH_CONTRAST: PaletteHsl = PaletteHsl {
+    base: Hsl::new(0.0, 0.0, 0.0),
+    surface: Hsl::new(0.0, 0.0, 0.08),
+    text: Hsl::new(0.0, 0.0, 1.0),
+    accent: Hsl::new(190.0, 1.0, 0.60),
+    warn: Hsl::new(48.0, 1.0, 0.55),
+    err: Hsl::new(0.0, 1.0, 0.60),
+    ok: Hsl::new(120.0, 1.0, 0.55),
+    info: Hsl::new(210.0, 1.0, 0.75),
+    grid: Hsl::new(0.0, 0.0, 0.30),
+    axis: Hsl::new(0.0, 0.0, 0.55),
+    text_muted: Hsl::new(0.0, 0.0, 0.80),
+    interpolated: Hsl::new(48.0, 1.0, 0.55),
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThemeName {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeName {
+    fn palette(self) -> Palette {
+        match self {
+            ThemeName::Dark => DARK.into_palette(),
+            // `invert()` is a plain field-for-field remap of the same `DARK` constant, not a
+            // second hand-authored palette — that's the point of storing roles as HSL.
+            ThemeName::Light => DARK.invert().into_palette(),
+            ThemeName::HighContrast => HIGH_CONTRAST.into_palette(),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high_contrast",
+        }
+    }
+
+    fn from_str(s: &str) -> ThemeName {
+        match s {
+            "light" => ThemeName::Light,
+            "high_contrast" => ThemeName::HighContrast,
+            _ => ThemeName::Dark,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeName::Dark => "Dark mode",
+            ThemeName::Light => "Light mode",
+            ThemeName::HighContrast => "High-contrast mode",
+        }
+    }
+
+    pub fn other(self) -> ThemeName {
+        match self {
+            ThemeName::Dark => ThemeName::Light,
+            ThemeName::Light => ThemeName::HighContrast,
+            ThemeName::HighContrast => ThemeName::Dark,
+        }
+    }
+}
+
+static ACTIVE_THEME: GlobalSignal<ThemeName> = Signal::global(|| ThemeName::Dark);
+
+/// The currently active palette. Tabs call this instead of inlining hex literals.
+pub fn with_theme() -> Palette {
+    ACTIVE_THEME.read().palette()
+}
+
+pub fn active_theme_name() -> ThemeName {
+    *ACTIVE_THEME.read()
+}
+
+pub fn set_theme(name: ThemeName) {
+    *ACTIVE_THEME.write() = name;
+    #[cfg(target_arch = "wasm32")]
+    super::storage_set_string(THEME_STORAGE_KEY, name.as_str());
+}
+
+/// Restore the persisted theme choice, if any. Web-only, matching the other
+/// `storage_get_*`-backed UI state restored in `TelemetryDashboard`'s mount effect.
+#[cfg(target_arch = "wasm32")]
+pub fn restore_persisted_theme() {
+    if let Some(s) = super::storage_get_string(THEME_STORAGE_KEY) {
+        *ACTIVE_THEME.write() = ThemeName::from_str(&s);
+    }
+}
@@ -1,81 +1,215 @@
-use leptos::prelude::*;
+use dioxus::prelude::*;
+use dioxus_signals::Signal;
+use std::collections::HashSet;
 
-use super::WarningRow;
+use super::AlertMsg;
+use groundstation_shared::FlightState;
 
-#[component]
-pub fn WarningsTab(rows: Signal<Vec<WarningRow>>) -> impl IntoView {
-    // Sorted view (most recent first)
-    let sorted_rows = Signal::derive(move || {
-        let mut list = rows.get();
-        // Newest first
-        list.sort_by_key(|r| -r.timestamp_ms);
-        list
-    });
+/// How urgent a warning is — inferred client-side from its message text since the backend
+/// only ever sends a timestamp/message pair over `warnings_tx` (see `emit_warning`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
 
-    view! {
-        <div style="
-            display:flex;
-            flex-direction:column;
-            gap:0.75rem;
-            flex:1;
-        ">
-            <div style="
-                display:flex;
-                justify-content:space-between;
-                align-items:center;
-                margin-bottom:0.5rem;
-            ">
-                <h2 style="font-size:1.1rem; color:#facc15; margin:0;">
-                    "Warnings"
-                </h2>
-            </div>
+impl Severity {
+    fn label(self) -> &'static str {
+        match self {
+            Severity::Info => "Info",
+            Severity::Warning => "Warning",
+            Severity::Critical => "Critical",
+        }
+    }
 
-            <div style="
-                max-height:360px;
-                overflow:auto;
-                display:flex;
-                flex-direction:column;
-                gap:0.4rem;
-            ">
-                <Show
-                    // If there *are* rows, show the list (fallback).
-                    // If empty, show the "No active warnings" message (children).
-                    when=move || sorted_rows.get().is_empty()
-                    fallback=move || {
-                        let list = sorted_rows.get();
-                        list
-                            .into_iter()
-                            .map(|r| view! { <WarningRowItem row=r /> })
-                            .collect_view()
-                    }
-                >
-                    <p style="color:#f9fafb; font-size:0.85rem; margin:0;">
-                        "No active warnings."
-                    </p>
-                </Show>
-            </div>
-        </div>
+    fn color(self) -> &'static str {
+        match self {
+            Severity::Info => "#38bdf8",
+            Severity::Warning => "#facc15",
+            Severity::Critical => "#ef4444",
+        }
+    }
+}
+
+/// Crude keyword sniff over `emit_warning`'s free-text message — good enough to separate an
+/// abort-adjacent warning from a routine one until the backend sends a real severity field.
+fn infer_severity(message: &str) -> Severity {
+    let lower = message.to_lowercase();
+    if lower.contains("critical") || lower.contains("abort") || lower.contains("breach") {
+        Severity::Critical
+    } else if lower.contains("info") || lower.contains("restored") {
+        Severity::Info
+    } else {
+        Severity::Warning
+    }
+}
+
+/// Same idea as `infer_severity`: which subsystem raised the warning, read off the message
+/// text so the row can show a board tag without a wire-format change.
+fn infer_board(message: &str) -> Option<&'static str> {
+    let lower = message.to_lowercase();
+    if lower.contains("geofence") || lower.contains("boundary") {
+        Some("Geofence")
+    } else if lower.contains("acceleration") {
+        Some("IMU")
+    } else if lower.contains("gps") {
+        Some("GPS")
+    } else if lower.contains("link") || lower.contains("pong") || lower.contains("telemetry") {
+        Some("Link")
+    } else if lower.contains("valve") || lower.contains("fill") || lower.contains("nitrous") || lower.contains("nitrogen") {
+        Some("Fill")
+    } else {
+        None
+    }
+}
+
+/// The `FlightState`s a board's warnings are actually relevant for — once the vehicle moves
+/// past them, older entries for that board are stale rather than actionable. Boards with no
+/// entry here (e.g. `Link`) are considered relevant in every state.
+fn relevant_states(board: &str) -> Option<&'static [FlightState]> {
+    match board {
+        "Fill" => Some(&[
+            FlightState::PreFill,
+            FlightState::FillTest,
+            FlightState::NitrogenFill,
+            FlightState::NitrousFill,
+        ]),
+        "Geofence" => Some(&[
+            FlightState::Launch,
+            FlightState::Ascent,
+            FlightState::Coast,
+            FlightState::Apogee,
+            FlightState::Descent,
+        ]),
+        _ => None,
+    }
+}
+
+fn is_stale(board: Option<&'static str>, flight_state: FlightState) -> bool {
+    match board.and_then(relevant_states) {
+        Some(states) => !states.contains(&flight_state),
+        None => false,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SeverityFilter {
+    All,
+    Warning,
+    Critical,
+}
+
+impl SeverityFilter {
+    fn matches(self, severity: Severity) -> bool {
+        match self {
+            SeverityFilter::All => true,
+            SeverityFilter::Warning => severity == Severity::Warning,
+            SeverityFilter::Critical => severity == Severity::Critical,
+        }
     }
 }
 
 #[component]
-fn WarningRowItem(row: WarningRow) -> impl IntoView {
-    view! {
-        <div style="
-            padding:0.45rem 0.7rem;
-            border-radius:0.5rem;
-            background:#1f2937;
-            border:1px solid #4b5563;
-            display:flex;
-            flex-direction:column;
-            gap:0.25rem;
-        ">
-            <div style="font-size:0.8rem; color:#facc15; font-weight:600;">
-                "Warning"
-            </div>
-            <div style="font-size:0.9rem; color:#f9fafb;">
-                {row.message}
-            </div>
-        </div>
+pub fn WarningsTab(warnings: Signal<Vec<AlertMsg>>, flight_state: Signal<FlightState>) -> Element {
+    let mut acknowledged = use_signal(HashSet::<i64>::new);
+    let mut filter = use_signal(|| SeverityFilter::All);
+    let mut show_acknowledged = use_signal(|| true);
+
+    let current_state = *flight_state.read();
+    let mut rows: Vec<(AlertMsg, Severity, Option<&'static str>)> = warnings
+        .read()
+        .iter()
+        .cloned()
+        .map(|w| {
+            let severity = infer_severity(&w.message);
+            let board = infer_board(&w.message);
+            (w, severity, board)
+        })
+        .collect();
+    rows.sort_by_key(|(w, _, _)| -w.timestamp_ms);
+
+    let visible: Vec<_> = rows
+        .into_iter()
+        .filter(|(_, severity, _)| filter.read().matches(*severity))
+        .filter(|(w, _, _)| *show_acknowledged.read() || !acknowledged.read().contains(&w.timestamp_ms))
+        .collect();
+
+    rsx! {
+        div { style: "padding:16px; display:flex; flex-direction:column; gap:10px; flex:1;",
+            div { style: "display:flex; justify-content:space-between; align-items:center;",
+                h2 { style: "margin:0;", "Warnings" }
+                div { style: "display:flex; gap:8px; align-items:center;",
+                    select {
+                        value: match *filter.read() { SeverityFilter::All => "all", SeverityFilter::Warning => "warning", SeverityFilter::Critical => "critical" },
+                        onchange: move |evt| {
+                            filter.set(match evt.value().as_str() {
+                                "warning" => SeverityFilter::Warning,
+                                "critical" => SeverityFilter::Critical,
+                                _ => SeverityFilter::All,
+                            });
+                        },
+                        option { value: "all", "All severities" }
+                        option { value: "warning", "Warning" }
+                        option { value: "critical", "Critical" }
+                    }
+                    label { style: "font-size:13px; color:#94a3b8; display:flex; gap:4px; align-items:center;",
+                        input {
+                            r#type: "checkbox",
+                            checked: *show_acknowledged.read(),
+                            onchange: move |evt| show_acknowledged.set(evt.checked()),
+                        }
+                        "Show acknowledged"
+                    }
+                    button {
+                        style: "padding:0.3rem 0.6rem; border-radius:0.5rem; border:1px solid #4b5563; background:#1f2937; color:#f9fafb; cursor:pointer;",
+                        onclick: move |_| acknowledged.write().clear(),
+                        "Clear acknowledged"
+                    }
+                }
+            }
+
+            div { style: "display:flex; flex-direction:column; gap:10px;",
+                for (w , severity , board) in visible {
+                    {
+                        let acked = acknowledged.read().contains(&w.timestamp_ms);
+                        let stale = is_stale(board, current_state);
+                        let ts = w.timestamp_ms;
+                        rsx! {
+                            div {
+                                style: "border:1px solid {severity.color()}; background:#1f2937; color:#f9fafb; padding:12px; border-radius:12px; display:flex; justify-content:space-between; align-items:center; gap:12px; opacity:{if acked || stale { \"0.55\" } else { \"1\" }};",
+                                div {
+                                    div { style: "display:flex; gap:8px; align-items:center; font-size:12px; opacity:0.85;",
+                                        span { style: "color:{severity.color()}; font-weight:600;", "{severity.label()}" }
+                                        if let Some(board) = board {
+                                            span { style: "color:#94a3b8;", "{board}" }
+                                        }
+                                        span { "{w.timestamp_ms}" }
+                                        if stale {
+                                            span { style: "color:#94a3b8;", "(stale)" }
+                                        }
+                                    }
+                                    div { style: "font-size:14px;", "{w.message}" }
+                                }
+                                button {
+                                    style: "padding:0.3rem 0.6rem; border-radius:0.5rem; border:1px solid #4b5563; background:#111827; color:#f9fafb; cursor:pointer; white-space:nowrap;",
+                                    onclick: move |_| {
+                                        if acked {
+                                            acknowledged.write().remove(&ts);
+                                        } else {
+                                            acknowledged.write().insert(ts);
+                                        }
+                                    },
+                                    if acked { "Unacknowledge" } else { "Acknowledge" }
+                                }
+                            }
+                        }
+                    }
+                }
+                if warnings.read().is_empty() {
+                    div { style: "color:#94a3b8;", "No active warnings." }
+                }
+            }
+        }
     }
 }
@@ -1,5 +1,6 @@
 use std::f64::consts::PI;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::{env, fs};
 
 use anyhow::Result;
@@ -7,8 +8,13 @@ use futures::stream::{self, StreamExt};
 use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use reqwest::Client;
 use tokio::fs as async_fs;
+use tokio::sync::Mutex;
 use tokio::time::{sleep, Duration};
 
+mod pmtiles;
+
+use pmtiles::PmTilesWriter;
+
 /// Region name (used for directory layout)
 const REGION: &str = "north_america";
 
@@ -32,11 +38,24 @@ const NA_BOUNDS: (f64, f64, f64, f64) = (-170.0, 5.0, -50.0, 83.0);
 /// Tune this: higher = faster but more load on GIBS / your network.
 const MAX_CONCURRENT: usize = 256;
 
+/// Where fetched tiles end up. `Files` is the original one-JPEG-per-tile layout; `PmTiles`
+/// collapses the whole region into a single archive (see `pmtiles` module) so a field laptop
+/// can grab one file instead of walking a tree with millions of inodes.
+#[derive(Clone)]
+enum TileSink {
+    Files,
+    PmTiles(Arc<Mutex<PmTilesWriter>>),
+}
+
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> Result<()> {
     // Optional override for region, but default to north_america
     let region = env::var("MAP_REGION").unwrap_or_else(|_| REGION.to_string());
 
+    // MAP_OUTPUT=pmtiles opts into the single-archive writer; default stays the loose-file
+    // layout `ensure_map_data`/`tile_service` in the backend already expect.
+    let use_pmtiles = env::var("MAP_OUTPUT").as_deref() == Ok("pmtiles");
+
     // Use CARGO_MANIFEST_DIR if present (when run via `cargo run`),
     // otherwise fall back to current directory.
     let manifest_dir = env::var("CARGO_MANIFEST_DIR")
@@ -50,26 +69,56 @@ async fn main() -> Result<()> {
         .join(&region);
     let tiles_root = data_dir.join("tiles");
 
-    fs::create_dir_all(&tiles_root)?;
-    println!(
-        "fetch_gibs_tiles_async: populating GIBS tiles for region '{}' into {} (z={MIN_ZOOM}..={MAX_ZOOM})",
-        region,
-        tiles_root.display()
-    );
-
     // Async HTTP client
     let client = Client::builder()
         .user_agent("GroundStationOfflineTileFetcher/0.1")
         .build()?;
 
+    let sink = if use_pmtiles {
+        println!(
+            "fetch_gibs_tiles_async: populating GIBS tiles for region '{}' into a single PMTiles archive under {} (z={MIN_ZOOM}..={MAX_ZOOM})",
+            region,
+            data_dir.display()
+        );
+        TileSink::PmTiles(Arc::new(Mutex::new(PmTilesWriter::new(
+            GIBS_LAYER,
+            GIBS_TILE_MATRIX_SET,
+            MIN_ZOOM,
+            MAX_ZOOM,
+            NA_BOUNDS,
+        ))))
+    } else {
+        fs::create_dir_all(&tiles_root)?;
+        println!(
+            "fetch_gibs_tiles_async: populating GIBS tiles for region '{}' into {} (z={MIN_ZOOM}..={MAX_ZOOM})",
+            region,
+            tiles_root.display()
+        );
+        TileSink::Files
+    };
+
     for z in MIN_ZOOM..=MAX_ZOOM {
-        if let Err(e) = fetch_tiles_for_zoom_async(z, &tiles_root, &client).await {
+        if let Err(e) = fetch_tiles_for_zoom_async(z, &tiles_root, &client, &sink).await {
             eprintln!(
                 "fetch_gibs_tiles_async: WARNING: failed to fetch tiles for z={z}: {e}"
             );
         }
     }
 
+    if let TileSink::PmTiles(writer) = sink {
+        let writer = Arc::try_unwrap(writer)
+            .unwrap_or_else(|arc| {
+                panic!("pmtiles writer still has {} outstanding references", Arc::strong_count(&arc))
+            })
+            .into_inner();
+        let tile_count = writer.tile_count();
+        writer.finish(&data_dir).await?;
+        println!(
+            "fetch_gibs_tiles_async: wrote {tile_count} tiles to {}",
+            data_dir.join("tiles.pmtiles").display()
+        );
+    }
+
     println!("fetch_gibs_tiles_async: done populating GIBS tiles.");
     Ok(())
 }
@@ -79,6 +128,7 @@ async fn fetch_tiles_for_zoom_async(
     z: u32,
     tiles_root: &Path,
     client: &Client,
+    sink: &TileSink,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let (lon_min, lat_min, lon_max, lat_max) = NA_BOUNDS;
 
@@ -105,18 +155,21 @@ async fn fetch_tiles_for_zoom_async(
         x_start, x_end, y_start, y_end, total
     );
 
-    // Create the base z directory once (sync is fine here)
+    // Create the base z directory once (sync is fine here). Not needed when writing into a
+    // single PMTiles archive — there's no per-tile file to place.
     let z_dir = tiles_root.join(format!("{z}"));
-    fs::create_dir_all(&z_dir)?;
-
-    // Pre-create all x directories once (avoid per-tile mkdir)
-    for x in x_start..=x_end {
-        let x_dir = z_dir.join(format!("{x}"));
-        if let Err(e) = fs::create_dir_all(&x_dir) {
-            eprintln!(
-                "fetch_gibs_tiles_async: failed to create directory {}: {e}",
-                x_dir.display()
-            );
+    if matches!(sink, TileSink::Files) {
+        fs::create_dir_all(&z_dir)?;
+
+        // Pre-create all x directories once (avoid per-tile mkdir)
+        for x in x_start..=x_end {
+            let x_dir = z_dir.join(format!("{x}"));
+            if let Err(e) = fs::create_dir_all(&x_dir) {
+                eprintln!(
+                    "fetch_gibs_tiles_async: failed to create directory {}: {e}",
+                    x_dir.display()
+                );
+            }
         }
     }
 
@@ -134,26 +187,33 @@ async fn fetch_tiles_for_zoom_async(
     let z_dir_arc = z_dir.clone();
     let client_arc = client.clone(); // cheap clone
     let pb_clone = pb.clone();
+    let sink_clone = sink.clone();
     // Build an async stream of all coordinate tasks
     stream::iter(coords)
         .for_each_concurrent(MAX_CONCURRENT, move |(x, y)| {
             let z_dir = z_dir_arc.clone();
             let client = client_arc.clone();
             let pb = pb_clone.clone();
+            let sink = sink_clone.clone();
 
             async move {
+                // File mode can skip work it already did on a previous (resumed) run; the
+                // PMTiles archive is only ever written once at the very end, so there's nothing
+                // on disk yet to check against.
                 let tile_path = z_dir.join(format!("{x}/{y}.{TILE_EXT}"));
                 let part_path = tile_path.with_extension(format!("{}.part", TILE_EXT));
 
-                // Skip if final tile already exists
-                if async_fs::try_exists(&tile_path).await.unwrap_or(false) {
-                    pb.inc(1);
-                    return;
-                }
+                if let TileSink::Files = &sink {
+                    // Skip if final tile already exists
+                    if async_fs::try_exists(&tile_path).await.unwrap_or(false) {
+                        pb.inc(1);
+                        return;
+                    }
 
-                // Remove any leftover .part file
-                if async_fs::try_exists(&part_path).await.unwrap_or(false) {
-                    let _ = async_fs::remove_file(&part_path).await;
+                    // Remove any leftover .part file
+                    if async_fs::try_exists(&part_path).await.unwrap_or(false) {
+                        let _ = async_fs::remove_file(&part_path).await;
+                    }
                 }
 
                 let url = format!(
@@ -179,16 +239,21 @@ async fn fetch_tiles_for_zoom_async(
 
                             if status.is_success() {
                                 match resp.bytes().await {
-                                    Ok(bytes) => {
-                                        if let Err(e) =
-                                            write_tile_atomic_async(&tile_path, &bytes).await
-                                        {
-                                            eprintln!(
-                                                "fetch_gibs_tiles_async: failed to write tile {}: {e}",
-                                                tile_path.display()
-                                            );
+                                    Ok(bytes) => match &sink {
+                                        TileSink::Files => {
+                                            if let Err(e) =
+                                                write_tile_atomic_async(&tile_path, &bytes).await
+                                            {
+                                                eprintln!(
+                                                    "fetch_gibs_tiles_async: failed to write tile {}: {e}",
+                                                    tile_path.display()
+                                                );
+                                            }
                                         }
-                                    }
+                                        TileSink::PmTiles(writer) => {
+                                            writer.lock().await.add_tile(z, x, y, &bytes);
+                                        }
+                                    },
                                     Err(e) => {
                                         eprintln!(
                                             "fetch_gibs_tiles_async: failed reading bytes for {}: {e}",
@@ -0,0 +1,275 @@
+// map_downloader/src/pmtiles.rs
+//
+// `fetch_tiles_for_zoom_async` writing one file per tile under `tiles/<z>/<x>/<y>.jpg` is fine
+// up to a few thousand tiles, but z=0..=12 over a continent-sized bbox produces millions of
+// inodes and a directory tree slow enough to make `cp -r` to a field laptop take longer than
+// the flight. `PmTilesWriter` collapses all of that into one archive file instead.
+//
+// Layout follows the PMTiles design (header -> directory -> tile data) but the directory here
+// is a fixed-size record per entry rather than protomaps' varint/delta encoding — simpler to
+// write, and plenty compact once duplicate tiles (solid-ocean JPEGs, mostly) are deduplicated
+// by content hash.
+//
+// Directory entry (24 bytes, little-endian): tile_id: u64, offset: u64, length: u32,
+// run_length: u32. `run_length == 0` marks a *leaf pointer* instead of a tile: `offset`/`length`
+// then point at a run of entries in the leaf-directory section rather than bytes in the tile
+// data section. Root directories larger than `MAX_ROOT_ENTRIES` get split this way.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+use tokio::fs as async_fs;
+
+const MAGIC: &[u8; 7] = b"GSPMTv1";
+const VERSION: u16 = 1;
+const ENTRY_SIZE: usize = 24;
+
+/// Root directories larger than this are split into leaf directories referenced by pointer
+/// entries, so no single directory read is larger than 16,384 × 24 B = 384 KiB.
+const MAX_ROOT_ENTRIES: usize = 16_384;
+
+#[derive(Clone, Copy)]
+struct DirEntry {
+    tile_id: u64,
+    offset: u64,
+    length: u32,
+    run_length: u32,
+}
+
+/// Accumulates tiles in memory, deduplicating identical byte content by SHA-256, then serializes
+/// everything to a single `tiles.pmtiles` archive (plus a `tiles.meta.json` sidecar) on [`finish`].
+///
+/// [`finish`]: PmTilesWriter::finish
+pub struct PmTilesWriter {
+    layer: String,
+    tile_matrix_set: String,
+    min_zoom: u32,
+    max_zoom: u32,
+    bounds: (f64, f64, f64, f64),
+    tile_data: Vec<u8>,
+    by_hash: HashMap<[u8; 32], (u64, u32)>,
+    entries: Vec<DirEntry>,
+}
+
+impl PmTilesWriter {
+    pub fn new(
+        layer: impl Into<String>,
+        tile_matrix_set: impl Into<String>,
+        min_zoom: u32,
+        max_zoom: u32,
+        bounds: (f64, f64, f64, f64),
+    ) -> Self {
+        Self {
+            layer: layer.into(),
+            tile_matrix_set: tile_matrix_set.into(),
+            min_zoom,
+            max_zoom,
+            bounds,
+            tile_data: Vec::new(),
+            by_hash: HashMap::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add one tile's bytes. Identical content (by SHA-256) is stored once — every subsequent
+    /// tile with the same bytes just records another directory entry pointing at the same
+    /// offset/length, which `finish` then collapses into a `run_length` run if they also sort
+    /// adjacently.
+    pub fn add_tile(&mut self, z: u32, x: u32, y: u32, bytes: &[u8]) {
+        let hash: [u8; 32] = Sha256::digest(bytes).into();
+        let (offset, length) = *self.by_hash.entry(hash).or_insert_with(|| {
+            let offset = self.tile_data.len() as u64;
+            self.tile_data.extend_from_slice(bytes);
+            (offset, bytes.len() as u32)
+        });
+
+        self.entries.push(DirEntry {
+            tile_id: zxy_to_tile_id(z, x, y),
+            offset,
+            length,
+            run_length: 1,
+        });
+    }
+
+    pub fn tile_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Sort entries by `tile_id` (Hilbert order, so spatially-close tiles land near each other on
+    /// disk), collapse consecutive identical (offset, length) runs, split into leaf directories
+    /// if the root would exceed `MAX_ROOT_ENTRIES`, and write `<out_dir>/tiles.pmtiles` plus
+    /// `<out_dir>/tiles.meta.json`.
+    pub async fn finish(mut self, out_dir: &Path) -> io::Result<()> {
+        self.entries.sort_by_key(|e| e.tile_id);
+        let collapsed = collapse_runs(&self.entries);
+        let (root_dir, leaf_dirs) = split_directories(collapsed, MAX_ROOT_ENTRIES);
+
+        let mut root_bytes = Vec::with_capacity(root_dir.len() * ENTRY_SIZE);
+        for e in &root_dir {
+            write_entry(&mut root_bytes, e);
+        }
+
+        let mut leaf_bytes = Vec::new();
+        for leaf in &leaf_dirs {
+            for e in leaf {
+                write_entry(&mut leaf_bytes, e);
+            }
+        }
+
+        let metadata_json = self.metadata_json();
+        let metadata_bytes = metadata_json.as_bytes();
+
+        let header_len = header_len();
+        let root_dir_offset = header_len as u64;
+        let leaf_dir_offset = root_dir_offset + root_bytes.len() as u64;
+        let metadata_offset = leaf_dir_offset + leaf_bytes.len() as u64;
+        let tile_data_offset = metadata_offset + metadata_bytes.len() as u64;
+
+        let mut archive = Vec::with_capacity(
+            header_len + root_bytes.len() + leaf_bytes.len() + metadata_bytes.len() + self.tile_data.len(),
+        );
+        archive.extend_from_slice(MAGIC);
+        archive.extend_from_slice(&VERSION.to_le_bytes());
+        archive.extend_from_slice(&root_dir_offset.to_le_bytes());
+        archive.extend_from_slice(&(root_bytes.len() as u64).to_le_bytes());
+        archive.extend_from_slice(&(root_dir.len() as u64).to_le_bytes());
+        archive.extend_from_slice(&leaf_dir_offset.to_le_bytes());
+        archive.extend_from_slice(&(leaf_bytes.len() as u64).to_le_bytes());
+        archive.extend_from_slice(&metadata_offset.to_le_bytes());
+        archive.extend_from_slice(&(metadata_bytes.len() as u64).to_le_bytes());
+        archive.extend_from_slice(&tile_data_offset.to_le_bytes());
+        archive.extend_from_slice(&(self.tile_data.len() as u64).to_le_bytes());
+        archive.push(self.min_zoom as u8);
+        archive.push(self.max_zoom as u8);
+        for v in [self.bounds.0, self.bounds.1, self.bounds.2, self.bounds.3] {
+            archive.extend_from_slice(&v.to_le_bytes());
+        }
+
+        archive.extend_from_slice(&root_bytes);
+        archive.extend_from_slice(&leaf_bytes);
+        archive.extend_from_slice(metadata_bytes);
+        archive.extend_from_slice(&self.tile_data);
+
+        async_fs::create_dir_all(out_dir).await?;
+        async_fs::write(out_dir.join("tiles.pmtiles"), &archive).await?;
+        async_fs::write(out_dir.join("tiles.meta.json"), metadata_bytes).await?;
+        Ok(())
+    }
+
+    fn metadata_json(&self) -> String {
+        format!(
+            "{{\"layer\":{:?},\"tile_matrix_set\":{:?},\"min_zoom\":{},\"max_zoom\":{},\"bounds\":[{},{},{},{}]}}",
+            self.layer,
+            self.tile_matrix_set,
+            self.min_zoom,
+            self.max_zoom,
+            self.bounds.0,
+            self.bounds.1,
+            self.bounds.2,
+            self.bounds.3,
+        )
+    }
+}
+
+const fn header_len() -> usize {
+    7 // magic
+        + 2 // version
+        + 8 * 2 // root dir offset/length
+        + 8 // root dir entry count
+        + 8 * 2 // leaf dir offset/length
+        + 8 * 2 // metadata offset/length
+        + 8 * 2 // tile data offset/length
+        + 1 * 2 // min/max zoom
+        + 8 * 4 // bounds
+}
+
+fn write_entry(out: &mut Vec<u8>, e: &DirEntry) {
+    out.extend_from_slice(&e.tile_id.to_le_bytes());
+    out.extend_from_slice(&e.offset.to_le_bytes());
+    out.extend_from_slice(&e.length.to_le_bytes());
+    out.extend_from_slice(&e.run_length.to_le_bytes());
+}
+
+/// Collapse consecutive entries that share both (offset, length) and a contiguous `tile_id`
+/// range into a single entry with `run_length` set — this is what actually pays off the content
+/// dedup above: a band of identical ocean tiles becomes one directory row instead of thousands.
+fn collapse_runs(entries: &[DirEntry]) -> Vec<DirEntry> {
+    let mut collapsed: Vec<DirEntry> = Vec::with_capacity(entries.len());
+    for &e in entries {
+        if let Some(last) = collapsed.last_mut() {
+            if last.offset == e.offset
+                && last.length == e.length
+                && last.tile_id + last.run_length as u64 == e.tile_id
+            {
+                last.run_length += 1;
+                continue;
+            }
+        }
+        collapsed.push(e);
+    }
+    collapsed
+}
+
+/// If `entries` fits within `max_root_entries`, it *is* the root directory and there are no leaf
+/// directories. Otherwise it's chunked into leaf directories of at most `max_root_entries` rows
+/// each, and the root becomes one pointer entry (`run_length == 0`) per leaf, keyed by that
+/// leaf's first `tile_id`.
+fn split_directories(entries: Vec<DirEntry>, max_root_entries: usize) -> (Vec<DirEntry>, Vec<Vec<DirEntry>>) {
+    if entries.len() <= max_root_entries {
+        return (entries, Vec::new());
+    }
+
+    let leaves: Vec<Vec<DirEntry>> = entries
+        .chunks(max_root_entries)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+
+    let mut root = Vec::with_capacity(leaves.len());
+    let mut leaf_offset = 0u64;
+    for leaf in &leaves {
+        let leaf_bytes_len = (leaf.len() * ENTRY_SIZE) as u64;
+        root.push(DirEntry {
+            tile_id: leaf.first().map(|e| e.tile_id).unwrap_or(0),
+            offset: leaf_offset,
+            length: leaf_bytes_len as u32,
+            run_length: 0,
+        });
+        leaf_offset += leaf_bytes_len;
+    }
+
+    (root, leaves)
+}
+
+/// Hilbert-curve distance of `(x, y)` within an `n`x`n` grid (`n` a power of two) — the classic
+/// xy2d rotation algorithm. Tiles with a small distance apart on the curve are also close on
+/// disk, which is the point: panning the map touches a tight byte range instead of scattering
+/// reads across the whole archive.
+fn hilbert_d_for_xy(n: u32, mut x: u32, mut y: u32) -> u64 {
+    let mut d: u64 = 0;
+    let mut s = n / 2;
+    while s > 0 {
+        let rx: u32 = if (x & s) > 0 { 1 } else { 0 };
+        let ry: u32 = if (y & s) > 0 { 1 } else { 0 };
+        d += (s as u64) * (s as u64) * ((3 * rx) ^ ry) as u64;
+
+        if ry == 0 {
+            if rx == 1 {
+                x = n - 1 - x;
+                y = n - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    d
+}
+
+/// `tile_id` for `(z, x, y)`: a per-zoom base offset (the tile count of every zoom level below
+/// `z` in the quadtree pyramid) plus the Hilbert distance of `(x, y)` within zoom `z`'s grid —
+/// so ids from different zooms never collide and still sort zoom-major, curve-minor.
+fn zxy_to_tile_id(z: u32, x: u32, y: u32) -> u64 {
+    let base = (4u64.pow(z) - 1) / 3;
+    base + hilbert_d_for_xy(1 << z, x, y)
+}
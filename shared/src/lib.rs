@@ -10,6 +10,36 @@ pub enum TelemetryCommand {
     Abort,
 }
 
+/// Identity for one element of the shared annotation CRDT (see `AnnotationOp`): which client
+/// minted it plus a per-client monotonic counter, so two clients can never produce the same id
+/// without coordinating, and ids order deterministically (`client_id` then `counter`) when used
+/// as a tie-break between concurrent inserts at the same position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AnnotationId {
+    pub client_id: u64,
+    pub counter: u64,
+}
+
+/// One operation on the shared operator-annotation timeline, replicated WOOT-style: `Insert`
+/// places a marker relative to the (possibly absent, meaning "sequence start"/"sequence end")
+/// neighbors it was authored next to, `Delete` tombstones one by id. Applying every op a peer
+/// has ever seen, in any order, converges to the same visible sequence everywhere — there's no
+/// central lock to insert under.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op")]
+pub enum AnnotationOp {
+    Insert {
+        id: AnnotationId,
+        left: Option<AnnotationId>,
+        right: Option<AnnotationId>,
+        timestamp_ms: i64,
+        text: String,
+    },
+    Delete {
+        id: AnnotationId,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TelemetryRow {
     pub timestamp_ms: i64,